@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::policy::{Policy, ProjectAccess};
 use crate::project_cache::{CacheConfig, ProjectCacheManager};
 use backlog_api_client::client::BacklogApiClient;
 use backlog_core::identifier::ProjectId;
@@ -8,9 +9,12 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
+/// Decides which projects the server may read and write, combining the
+/// `BACKLOG_PROJECTS` allowlist with the access policy file.
 #[derive(Debug, Clone)]
 pub struct AccessControl {
     allowed_projects: Option<Vec<ProjectKey>>,
+    policy: Policy,
     project_cache: Arc<ProjectCacheManager>,
 }
 
@@ -35,6 +39,11 @@ impl AccessControl {
             None
         };
 
+        Ok(Self::with_config(allowed_projects, Policy::from_env()?))
+    }
+
+    /// Creates access control from an allowlist and policy instead of the environment.
+    pub fn with_config(allowed_projects: Option<Vec<ProjectKey>>, policy: Policy) -> Self {
         // Cache configuration: 5 minute TTL, max 1000 projects
         let cache_config = CacheConfig {
             ttl: Some(Duration::from_secs(300)),
@@ -42,10 +51,21 @@ impl AccessControl {
         };
         let project_cache = Arc::new(ProjectCacheManager::with_config(cache_config));
 
-        Ok(Self {
+        Self {
             allowed_projects,
+            policy,
             project_cache,
-        })
+        }
+    }
+
+    /// Replaces the policy loaded from `BACKLOG_POLICY_FILE`.
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn policy(&self) -> &Policy {
+        &self.policy
     }
 
     async fn resolve_project_by_id(
@@ -64,69 +84,153 @@ impl AccessControl {
         Ok(project.project_key.clone())
     }
 
-    pub async fn check_project_access_by_id_async(
+    async fn project_key_of(
         &self,
         project_id: &ProjectId,
         client: &BacklogApiClient,
-    ) -> Result<(), Error> {
-        if !self.is_enabled() {
-            return Ok(());
+    ) -> Option<ProjectKey> {
+        if let Some(project) = self.project_cache.get_from_cache_by_id(project_id).await {
+            return Some(project.project_key.clone());
         }
-        let allowed_keys = self.allowed_projects.as_ref().unwrap();
+        self.resolve_project_by_id(project_id, client).await.ok()
+    }
 
-        if let Some(project) = self.project_cache.get_from_cache_by_id(project_id).await
-            && allowed_keys.contains(&project.project_key)
+    fn project_access(&self, project_key: &ProjectKey) -> ProjectAccess {
+        if let Some(allowed_keys) = &self.allowed_projects
+            && !allowed_keys.contains(project_key)
         {
-            return Ok(());
+            return ProjectAccess::None;
         }
+        self.policy.project_access(project_key)
+    }
 
-        if let Ok(project_key) = self.resolve_project_by_id(project_id, client).await
-            && allowed_keys.contains(&project_key)
-        {
-            return Ok(());
+    /// Checks `project_key` for `required` access, naming the project as `project` in errors.
+    fn check_access(
+        &self,
+        project_key: &ProjectKey,
+        project: impl ToString,
+        required: ProjectAccess,
+    ) -> Result<(), Error> {
+        let granted = self.project_access(project_key);
+        if granted >= required {
+            Ok(())
+        } else if granted >= ProjectAccess::Read {
+            Err(Error::ProjectWriteDenied {
+                project: project.to_string(),
+            })
+        } else {
+            Err(self.access_denied(project))
+        }
+    }
+
+    fn access_denied(&self, project: impl ToString) -> Error {
+        let allowed_projects = match &self.allowed_projects {
+            Some(keys) => keys
+                .iter()
+                .filter(|key| self.policy.project_access(key) >= ProjectAccess::Read)
+                .map(|key| key.to_string())
+                .collect(),
+            None => self
+                .policy
+                .listed_projects(ProjectAccess::Read)
+                .map(String::from)
+                .collect(),
+        };
+        Error::ProjectAccessDenied {
+            project: project.to_string(),
+            allowed_projects,
         }
+    }
+
+    async fn check_access_by_id(
+        &self,
+        project_id: &ProjectId,
+        client: &BacklogApiClient,
+        required: ProjectAccess,
+    ) -> Result<(), Error> {
+        match self.project_key_of(project_id, client).await {
+            Some(project_key) => self.check_access(&project_key, project_id, required),
+            None => Err(self.access_denied(project_id)),
+        }
+    }
+
+    async fn check_access_id_or_key(
+        &self,
+        project: &ProjectIdOrKey,
+        client: &BacklogApiClient,
+        required: ProjectAccess,
+    ) -> Result<(), Error> {
+        match project {
+            ProjectIdOrKey::Id(id) | ProjectIdOrKey::EitherIdOrKey(id, _) => {
+                self.check_access_by_id(id, client, required).await
+            }
+            ProjectIdOrKey::Key(key) => self.check_access(key, key, required),
+        }
+    }
 
-        Err(Error::ProjectAccessDenied {
-            project: project_id.to_string(),
-            allowed_projects: allowed_keys.iter().map(|k| k.to_string()).collect(),
-        })
+    pub async fn check_project_access_by_id_async(
+        &self,
+        project_id: &ProjectId,
+        client: &BacklogApiClient,
+    ) -> Result<(), Error> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        self.check_access_by_id(project_id, client, ProjectAccess::Read)
+            .await
     }
 
     pub async fn check_project_access_by_key_async(
         &self,
         project_key: &ProjectKey,
+    ) -> Result<(), Error> {
+        self.check_access(project_key, project_key, ProjectAccess::Read)
+    }
+
+    pub async fn check_project_access_id_or_key_async(
+        &self,
+        project: &ProjectIdOrKey,
+        client: &BacklogApiClient,
     ) -> Result<(), Error> {
         if !self.is_enabled() {
             return Ok(());
         }
+        self.check_access_id_or_key(project, client, ProjectAccess::Read)
+            .await
+    }
 
-        let allowed_keys = self.allowed_projects.as_ref().unwrap();
-        if allowed_keys.contains(project_key) {
+    /// Like [`Self::check_project_access_by_id_async`], but for tools that change data.
+    pub async fn check_project_write_access_by_id_async(
+        &self,
+        project_id: &ProjectId,
+        client: &BacklogApiClient,
+    ) -> Result<(), Error> {
+        if !self.restricts_writes() {
             return Ok(());
         }
-
-        Err(Error::ProjectAccessDenied {
-            project: project_key.to_string(),
-            allowed_projects: allowed_keys.iter().map(|k| k.to_string()).collect(),
-        })
+        self.check_access_by_id(project_id, client, ProjectAccess::Write)
+            .await
     }
 
-    pub async fn check_project_access_id_or_key_async(
+    pub async fn check_project_write_access_id_or_key_async(
         &self,
         project: &ProjectIdOrKey,
         client: &BacklogApiClient,
     ) -> Result<(), Error> {
-        match project {
-            ProjectIdOrKey::Id(id) => self.check_project_access_by_id_async(id, client).await,
-            ProjectIdOrKey::Key(key) => self.check_project_access_by_key_async(key).await,
-            ProjectIdOrKey::EitherIdOrKey(id, _) => {
-                self.check_project_access_by_id_async(id, client).await
-            }
+        if !self.restricts_writes() {
+            return Ok(());
         }
+        self.check_access_id_or_key(project, client, ProjectAccess::Write)
+            .await
     }
 
+    /// Returns true if some project may not be read, so results must be limited to projects.
     pub fn is_enabled(&self) -> bool {
-        self.allowed_projects.is_some()
+        self.allowed_projects.is_some() || self.policy.restricts(ProjectAccess::Read)
+    }
+
+    fn restricts_writes(&self) -> bool {
+        self.allowed_projects.is_some() || self.policy.restricts(ProjectAccess::Write)
     }
 
     pub fn project_cache(&self) -> &Arc<ProjectCacheManager> {
@@ -140,34 +244,11 @@ impl AccessControl {
             return Ok(());
         }
 
-        let allowed_keys = self.allowed_projects.as_ref().unwrap();
-        Err(Error::ProjectAccessDenied {
-            project: project_id.to_string(),
-            allowed_projects: allowed_keys.iter().map(|k| k.to_string()).collect(),
-        })
+        Err(self.access_denied(project_id))
     }
 
     pub fn check_project_access_by_key(&self, project_key: &ProjectKey) -> Result<(), Error> {
-        if !self.is_enabled() {
-            return Ok(());
-        }
-
-        if let Some(allowed_keys) = &self.allowed_projects
-            && allowed_keys.contains(project_key)
-        {
-            return Ok(());
-        }
-
-        Err(Error::ProjectAccessDenied {
-            project: project_key.to_string(),
-            allowed_projects: self
-                .allowed_projects
-                .as_ref()
-                .unwrap()
-                .iter()
-                .map(|key| key.to_string())
-                .collect::<Vec<String>>(),
-        })
+        self.check_access(project_key, project_key, ProjectAccess::Read)
     }
 
     pub fn check_project_access_id_or_key(&self, project: &ProjectIdOrKey) -> Result<(), Error> {
@@ -181,16 +262,7 @@ impl AccessControl {
 
 impl Default for AccessControl {
     fn default() -> Self {
-        Self::new().unwrap_or_else(|_| {
-            let cache_config = CacheConfig {
-                ttl: Some(Duration::from_secs(300)),
-                max_size: Some(1000),
-            };
-            Self {
-                allowed_projects: None,
-                project_cache: Arc::new(ProjectCacheManager::with_config(cache_config)),
-            }
-        })
+        Self::new().unwrap_or_else(|_| Self::with_config(None, Policy::default()))
     }
 }
 
@@ -469,4 +541,43 @@ mod tests {
                 .is_ok()
         );
     }
+
+    #[test]
+    fn test_policy_combined_with_allowed_projects() {
+        let _lock = TEST_MUTEX.lock().unwrap();
+        unsafe {
+            env::set_var("BACKLOG_PROJECTS", "PROJECT_A,PROJECT_B");
+        }
+        let policy = r#"{ "projects": { "PROJECT_A": "write", "PROJECT_B": "read", "PROJECT_C": "write" } }"#
+            .parse()
+            .unwrap();
+        let access_control = AccessControl::new().unwrap().with_policy(policy);
+        assert!(access_control.is_enabled());
+
+        let project_a = ProjectKey::from_str("PROJECT_A").unwrap();
+        let project_b = ProjectKey::from_str("PROJECT_B").unwrap();
+        let project_c = ProjectKey::from_str("PROJECT_C").unwrap();
+
+        assert!(
+            access_control
+                .check_access(&project_a, &project_a, ProjectAccess::Write)
+                .is_ok()
+        );
+        assert!(
+            access_control
+                .check_project_access_by_key(&project_b)
+                .is_ok()
+        );
+        assert!(matches!(
+            access_control.check_access(&project_b, &project_b, ProjectAccess::Write),
+            Err(crate::error::Error::ProjectWriteDenied { project }) if project == "PROJECT_B"
+        ));
+        // The allowlist still applies to projects the policy would allow.
+        match access_control.check_access(&project_c, &project_c, ProjectAccess::Write) {
+            Err(crate::error::Error::ProjectAccessDenied {
+                allowed_projects, ..
+            }) => assert_eq!(allowed_projects, vec!["PROJECT_A", "PROJECT_B"]),
+            other => panic!("Expected ProjectAccessDenied error, got {other:?}"),
+        }
+    }
 }
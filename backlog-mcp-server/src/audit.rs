@@ -0,0 +1,341 @@
+use crate::toolset::is_read_tool;
+use crate::util::{parse_flag, parse_number};
+use chrono::{DateTime, Utc};
+use rmcp::ErrorData as McpError;
+use rmcp::model::{CallToolRequestParam, CallToolResult};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::env;
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+const DEFAULT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_MAX_FILES: u32 = 5;
+/// String arguments such as issue descriptions are cut to this many characters.
+const MAX_STRING_CHARS: usize = 200;
+/// Arguments whose names contain one of these are never written.
+const SECRET_KEYS: [&str; 4] = ["password", "secret", "token", "api_key"];
+/// Fields of a tool result that identify what was read or changed in Backlog.
+const ID_KEYS: [&str; 6] = ["id", "issueKey", "keyId", "number", "projectId", "wikiId"];
+
+/// Where and what to audit, chosen at startup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditConfig {
+    pub path: PathBuf,
+    /// Only record tools that change data.
+    pub writes_only: bool,
+    /// Size at which the log is rotated; 0 never rotates.
+    pub max_bytes: u64,
+    /// Rotated logs to keep, as `<path>.1` (newest) to `<path>.<max_files>`.
+    pub max_files: u32,
+}
+
+impl AuditConfig {
+    /// Reads `BACKLOG_AUDIT_LOG`, `BACKLOG_AUDIT_WRITES_ONLY`, `BACKLOG_AUDIT_MAX_BYTES`
+    /// and `BACKLOG_AUDIT_MAX_FILES`. Returns `None` when no log path is set.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        let Some(path) = env::var("BACKLOG_AUDIT_LOG")
+            .ok()
+            .filter(|path| !path.trim().is_empty())
+        else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            path: PathBuf::from(path.trim()),
+            writes_only: parse_flag(
+                "BACKLOG_AUDIT_WRITES_ONLY",
+                env::var("BACKLOG_AUDIT_WRITES_ONLY").ok().as_deref(),
+            )?,
+            max_bytes: parse_number("BACKLOG_AUDIT_MAX_BYTES", DEFAULT_MAX_BYTES)?,
+            max_files: parse_number("BACKLOG_AUDIT_MAX_FILES", DEFAULT_MAX_FILES)?,
+        }))
+    }
+}
+
+/// Append-only JSONL log of tool calls, one [`AuditEntry`] per line.
+#[derive(Clone)]
+pub struct AuditLog {
+    config: AuditConfig,
+    file: Arc<Mutex<LogFile>>,
+}
+
+struct LogFile {
+    file: File,
+    size: u64,
+}
+
+impl AuditLog {
+    pub fn open(config: AuditConfig) -> Result<Self, String> {
+        let file = open_append(&config.path)
+            .map_err(|e| format!("Failed to open audit log '{}': {e}", config.path.display()))?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok(Self {
+            config,
+            file: Arc::new(Mutex::new(LogFile { file, size })),
+        })
+    }
+
+    /// Returns true if calls to the tool are recorded.
+    pub fn covers(&self, tool_name: &str) -> bool {
+        !self.config.writes_only || !is_read_tool(tool_name)
+    }
+
+    /// Appends the entry. Failing to log never fails the tool call, so errors are only reported.
+    pub fn record(&self, entry: &AuditEntry) {
+        let result = serde_json::to_vec(entry)
+            .map_err(io::Error::from)
+            .and_then(|mut line| {
+                line.push(b'\n');
+                self.append(&line)
+            });
+        if let Err(e) = result {
+            eprintln!(
+                "Failed to write audit log '{}': {e}",
+                self.config.path.display()
+            );
+        }
+    }
+
+    fn append(&self, line: &[u8]) -> io::Result<()> {
+        let mut log = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        if self.config.max_bytes > 0
+            && log.size > 0
+            && log.size + line.len() as u64 > self.config.max_bytes
+        {
+            log.file = self.rotate()?;
+            log.size = 0;
+        }
+        log.file.write_all(line)?;
+        log.size += line.len() as u64;
+        Ok(())
+    }
+
+    fn rotate(&self) -> io::Result<File> {
+        if self.config.max_files == 0 {
+            fs::remove_file(&self.config.path)?;
+        } else {
+            for i in (1..self.config.max_files).rev() {
+                let from = self.rotated_path(i);
+                if from.exists() {
+                    fs::rename(from, self.rotated_path(i + 1))?;
+                }
+            }
+            fs::rename(&self.config.path, self.rotated_path(1))?;
+        }
+        open_append(&self.config.path)
+    }
+
+    fn rotated_path(&self, index: u32) -> PathBuf {
+        let mut path = OsString::from(self.config.path.as_os_str());
+        path.push(format!(".{index}"));
+        PathBuf::from(path)
+    }
+}
+
+fn open_append(path: &PathBuf) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Success,
+    Error,
+}
+
+/// One tool call as written to the audit log.
+#[derive(Debug, Serialize)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    pub tool: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<String>,
+    pub arguments: Value,
+    pub outcome: Outcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub backlog_ids: Map<String, Value>,
+    pub duration_ms: u64,
+    #[serde(skip)]
+    started: Instant,
+}
+
+impl AuditEntry {
+    /// Starts an entry for a call before it runs.
+    pub fn begin(request: &CallToolRequestParam) -> Self {
+        let arguments = request.arguments.clone().unwrap_or_default();
+        let text = |key: &str| match arguments.get(key)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        };
+        Self {
+            timestamp: Utc::now(),
+            tool: request.name.to_string(),
+            project: text("project_id_or_key"),
+            issue: text("issue_id_or_key"),
+            arguments: sanitize(None, &Value::Object(arguments.clone())),
+            outcome: Outcome::Success,
+            error: None,
+            backlog_ids: Map::new(),
+            duration_ms: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Completes the entry with the outcome of the call.
+    pub fn finish(mut self, result: &Result<CallToolResult, McpError>) -> Self {
+        self.duration_ms = self.started.elapsed().as_millis() as u64;
+        match result {
+            Ok(result) if result.is_error != Some(true) => {
+                self.backlog_ids = backlog_ids(result);
+            }
+            Ok(result) => {
+                self.outcome = Outcome::Error;
+                self.error = result
+                    .content
+                    .iter()
+                    .find_map(|content| content.as_text())
+                    .map(|text| text.text.clone());
+            }
+            Err(e) => {
+                self.outcome = Outcome::Error;
+                self.error = Some(e.message.to_string());
+            }
+        }
+        self
+    }
+}
+
+fn sanitize(key: Option<&str>, value: &Value) -> Value {
+    if let Some(key) = key {
+        let key = key.to_ascii_lowercase();
+        if SECRET_KEYS.iter().any(|secret| key.contains(secret)) {
+            return Value::String("[redacted]".to_string());
+        }
+    }
+    match value {
+        Value::String(s) if s.chars().count() > MAX_STRING_CHARS => {
+            let head: String = s.chars().take(MAX_STRING_CHARS).collect();
+            Value::String(format!("{head}… ({} chars)", s.chars().count()))
+        }
+        Value::Array(items) => Value::Array(items.iter().map(|v| sanitize(None, v)).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), sanitize(Some(k), v)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Picks the identifiers of the returned object, such as the ID and key of a created issue.
+fn backlog_ids(result: &CallToolResult) -> Map<String, Value> {
+    let value = result.structured_content.clone().or_else(|| {
+        result
+            .content
+            .iter()
+            .find_map(|content| content.as_text())
+            .and_then(|text| serde_json::from_str(&text.text).ok())
+    });
+    let Some(Value::Object(object)) = value else {
+        return Map::new();
+    };
+    ID_KEYS
+        .iter()
+        .filter_map(|key| Some((key.to_string(), object.get(*key)?.clone())))
+        .filter(|(_, value)| !value.is_null())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::Content;
+    use serde_json::json;
+
+    fn request(name: &'static str, arguments: Value) -> CallToolRequestParam {
+        CallToolRequestParam {
+            name: name.into(),
+            arguments: arguments.as_object().cloned(),
+        }
+    }
+
+    #[test]
+    fn test_entry() {
+        let long = "x".repeat(300);
+        let entry = AuditEntry::begin(&request(
+            "backlog_issue_add",
+            json!({ "project_id_or_key": "WEB", "summary": "Fix", "description": long, "api_token": "abc" }),
+        ));
+        assert_eq!(entry.project.as_deref(), Some("WEB"));
+        assert_eq!(entry.arguments["api_token"], "[redacted]");
+        assert!(
+            entry.arguments["description"]
+                .as_str()
+                .unwrap()
+                .ends_with("… (300 chars)")
+        );
+
+        let result = CallToolResult::success(vec![
+            Content::json(json!({ "id": 42, "issueKey": "WEB-7", "summary": "Fix" })).unwrap(),
+        ]);
+        let entry = entry.finish(&Ok(result));
+        assert_eq!(entry.outcome, Outcome::Success);
+        assert_eq!(
+            Value::Object(entry.backlog_ids.clone()),
+            json!({ "id": 42, "issueKey": "WEB-7" })
+        );
+
+        let entry = AuditEntry::begin(&request(
+            "backlog_issue_update",
+            json!({ "issue_id_or_key": "WEB-7" }),
+        ))
+        .finish(&Err(McpError::invalid_params("Access denied", None)));
+        assert_eq!(entry.issue.as_deref(), Some("WEB-7"));
+        assert_eq!(entry.outcome, Outcome::Error);
+        assert_eq!(entry.error.as_deref(), Some("Access denied"));
+        assert!(entry.backlog_ids.is_empty());
+    }
+
+    #[test]
+    fn test_log_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let log = AuditLog::open(AuditConfig {
+            path: path.clone(),
+            writes_only: true,
+            max_bytes: 400,
+            max_files: 2,
+        })
+        .unwrap();
+        assert!(log.covers("backlog_issue_update"));
+        assert!(!log.covers("backlog_issue_details_get"));
+
+        let entry = AuditEntry::begin(&request("issue_comment_add", json!({ "content": "hi" })))
+            .finish(&Ok(CallToolResult::success(vec![])));
+        for _ in 0..10 {
+            log.record(&entry);
+        }
+
+        let lines = |path: &PathBuf| fs::read_to_string(path).unwrap().lines().count();
+        let rotated = |i: u32| log.rotated_path(i);
+        assert!(fs::metadata(&path).unwrap().len() <= 400);
+        assert!(rotated(1).exists() && rotated(2).exists());
+        assert!(!rotated(3).exists());
+        assert_eq!(lines(&rotated(1)), lines(&rotated(2)));
+
+        let first = fs::read_to_string(&path).unwrap();
+        let record: Value = serde_json::from_str(first.lines().next().unwrap()).unwrap();
+        assert_eq!(record["tool"], "issue_comment_add");
+        assert_eq!(record["outcome"], "success");
+        assert_eq!(record["arguments"], json!({ "content": "hi" }));
+    }
+}
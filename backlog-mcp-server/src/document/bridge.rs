@@ -57,12 +57,20 @@ pub(crate) async fn download_document_attachment_bridge(
         .await?;
 
     let attachment_id = DocumentAttachmentId::new(req.attachment_id);
+    if let Some(metadata) = document
+        .attachments
+        .iter()
+        .find(|attachment| attachment.id == attachment_id)
+    {
+        access_control.policy().check_download_size(metadata.size)?;
+    }
+
     let params = DownloadAttachmentParams::new(document_id, attachment_id);
-    client_guard
-        .document()
-        .download_attachment(params)
-        .await
-        .map_err(crate::error::Error::from)
+    let file = client_guard.document().download_attachment(params).await?;
+    access_control
+        .policy()
+        .check_download_size(file.bytes.len() as u64)?;
+    Ok(file)
 }
 
 pub(crate) async fn get_document_tree_tool(
@@ -88,3 +96,38 @@ pub(crate) async fn get_document_tree_tool(
         .await
         .map_err(crate::error::Error::from)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::test_utils::{mock_projects, restricted_access};
+    use serde_json::json;
+    use wiremock::{Mock, MockServer, ResponseTemplate, matchers};
+
+    #[tokio::test]
+    async fn test_document_tree_of_disallowed_project_is_denied() {
+        let mock_server = MockServer::start().await;
+        mock_projects(&mock_server).await;
+        Mock::given(matchers::path("/api/v2/documents/tree"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let client = Arc::new(Mutex::new(
+            BacklogApiClient::new(&mock_server.uri()).unwrap(),
+        ));
+
+        for project in ["SECRET", "2"] {
+            let request = GetDocumentTreeRequest {
+                project_id_or_key: project.to_string(),
+            };
+            let result =
+                get_document_tree_tool(client.clone(), request, &restricted_access()).await;
+            assert!(
+                matches!(result, Err(Error::ProjectAccessDenied { .. })),
+                "{project}"
+            );
+        }
+    }
+}
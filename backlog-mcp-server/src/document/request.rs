@@ -6,12 +6,14 @@ pub(crate) struct GetDocumentDetailsRequest {
     This should be in the format 32 digit hex string. Ensure there are no leading or trailing spaces.
     When you access https://example.backlog.com/document/PROJECT/0195faa11fcb7aaab4c4005a7ada4b6f,
     the document id is '0195faa11fcb7aaab4c4005a7ada4b6f'.")]
+    #[serde(deserialize_with = "crate::web_url::document_id")]
     pub document_id: String,
 }
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub(crate) struct DownloadDocumentAttachmentRequest {
     #[schemars(description = "The document ID (a 32-digit hexadecimal string).")]
+    #[serde(deserialize_with = "crate::web_url::document_id")]
     pub document_id: String,
     #[schemars(description = "The numeric ID of the attachment to download.")]
     pub attachment_id: u32,
@@ -27,5 +29,6 @@ pub(crate) struct GetDocumentTreeRequest {
     #[schemars(
         description = "The project ID or project key for which to retrieve the document tree. Examples: \"MYPROJECTKEY\", \"123\"."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
 }
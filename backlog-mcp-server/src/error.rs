@@ -1,5 +1,5 @@
 use backlog_api_client::{
-    ApiError, CoreError, ProjectIdOrKey, PullRequestNumber, RepositoryIdOrName,
+    ApiError, CoreError, IssueQueryError, ProjectIdOrKey, PullRequestNumber, RepositoryIdOrName,
 };
 use rmcp::ErrorData as McpError;
 use thiserror::Error as ThisError;
@@ -43,6 +43,28 @@ pub enum Error {
 
     #[error("{0}")]
     ProjectNotFound(String),
+
+    #[error("Write access to project '{project}' is denied by the access policy.")]
+    ProjectWriteDenied { project: String },
+
+    #[error(
+        "User {user_id} cannot be {action} under the access policy. Allowed user IDs: {allowed_user_ids:?}"
+    )]
+    UserNotAllowed {
+        user_id: u32,
+        action: &'static str,
+        allowed_user_ids: Vec<u32>,
+    },
+
+    #[error(
+        "The file is {size} bytes, which exceeds the download limit of {limit} bytes set by the access policy."
+    )]
+    DownloadTooLarge { size: u64, limit: u64 },
+
+    #[error(
+        "This needs the '{0}' tool, which is not served: its toolset is disabled or the access policy denies it."
+    )]
+    ToolNotServed(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -59,6 +81,15 @@ impl From<CoreError> for Error {
     }
 }
 
+impl From<IssueQueryError> for Error {
+    fn from(err: IssueQueryError) -> Self {
+        match err {
+            IssueQueryError::Query(e) => Error::Parameter(e.to_string()),
+            IssueQueryError::Api(e) => Error::Api(e),
+        }
+    }
+}
+
 impl From<Error> for McpError {
     fn from(err: Error) -> Self {
         match err {
@@ -113,6 +144,10 @@ impl From<Error> for McpError {
             }
             Error::ProjectAccessDenied { .. } => McpError::invalid_params(err.to_string(), None),
             Error::ProjectNotFound(_) => McpError::invalid_params(err.to_string(), None),
+            Error::ProjectWriteDenied { .. } => McpError::invalid_params(err.to_string(), None),
+            Error::UserNotAllowed { .. } => McpError::invalid_params(err.to_string(), None),
+            Error::DownloadTooLarge { .. } => McpError::invalid_request(err.to_string(), None),
+            Error::ToolNotServed(_) => McpError::invalid_request(err.to_string(), None),
         }
     }
 }
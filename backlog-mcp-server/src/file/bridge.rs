@@ -2,7 +2,7 @@ use crate::access_control::AccessControl;
 use crate::error::{Error as McpError, Result};
 use crate::file::request::{DownloadSharedFileRequest, GetSharedFilesListRequest};
 use backlog_api_client::{DownloadedFile, client::BacklogApiClient};
-use backlog_core::{ProjectIdOrKey, identifier::SharedFileId};
+use backlog_core::{BacklogUrl, ProjectIdOrKey, identifier::SharedFileId, web_url};
 use backlog_file::{GetFileParams, GetSharedFilesListParams, SharedFile};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -24,7 +24,7 @@ pub(crate) async fn get_shared_files_list_tool(
 
     let params = GetSharedFilesListParams {
         project_id_or_key,
-        path: request.path,
+        path: shared_file_directory(&request.path)?,
         order: request.order,
         offset: request.offset,
         count: request.count,
@@ -33,6 +33,19 @@ pub(crate) async fn get_shared_files_list_tool(
     Ok(client_guard.file().get_shared_files_list(params).await?)
 }
 
+/// Accepts a shared file URL such as `https://example.backlog.com/file/PROJ/docs/`
+/// in place of a directory path.
+fn shared_file_directory(path: &str) -> Result<String> {
+    if !web_url::is_url(path) {
+        return Ok(path.to_string());
+    }
+    let url = BacklogUrl::from_str(path)?;
+    let directory = url.resource().shared_file_path().ok_or_else(|| {
+        McpError::Parameter(format!("URL does not point to a shared file: {path}"))
+    })?;
+    Ok(directory.trim_matches('/').to_string())
+}
+
 pub(crate) async fn download_shared_file_bridge(
     client: Arc<Mutex<BacklogApiClient>>,
     request: DownloadSharedFileRequest,
@@ -50,5 +63,10 @@ pub(crate) async fn download_shared_file_bridge(
     let shared_file_id = SharedFileId::new(request.shared_file_id);
     let params = GetFileParams::new(project_id_or_key, shared_file_id);
 
-    Ok(client_guard.file().get_file(params).await?)
+    // Shared files can't be looked up by ID alone, so the size is only known after download.
+    let file = client_guard.file().get_file(params).await?;
+    access_control
+        .policy()
+        .check_download_size(file.bytes.len() as u64)?;
+    Ok(file)
 }
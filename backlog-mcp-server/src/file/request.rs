@@ -1,3 +1,4 @@
+use backlog_core::Order;
 use rmcp::schemars;
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
@@ -5,14 +6,15 @@ pub(crate) struct GetSharedFilesListRequest {
     #[schemars(
         description = "The project ID or project key to retrieve shared files for. Examples: 'MYPROJECTKEY', '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     #[schemars(
-        description = "The path to retrieve shared files from. Root directory is '', not '/'."
+        description = "The path to retrieve shared files from, or a shared file URL such as 'https://example.backlog.com/file/PROJ/docs/'. Root directory is '', not '/'."
     )]
     pub path: String,
     #[schemars(description = "Sort order: 'asc' or 'desc'.")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<Order>,
     #[schemars(description = "Offset for pagination.")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
@@ -24,6 +26,7 @@ pub(crate) struct GetSharedFilesListRequest {
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub(crate) struct DownloadSharedFileRequest {
     #[schemars(description = "The project ID or project key. Examples: 'MYPROJECTKEY', '123'.")]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     #[schemars(description = "The shared file ID to download.")]
     pub shared_file_id: u32,
@@ -83,7 +83,7 @@ pub(crate) async fn get_pull_request(
 ) -> Result<PullRequest> {
     let proj_id_or_key = req.project_id_or_key.parse::<ProjectIdOrKey>()?;
     let repo_id_or_name = RepositoryIdOrName::from_str(req.repo_id_or_name.trim())?;
-    let pr_number = PullRequestNumber::from(req.pr_number);
+    let pr_number = req.pr_number.pull_request_number()?;
 
     let client_guard = client.lock().await;
 
@@ -103,7 +103,7 @@ pub(crate) async fn get_pull_request_attachment_list_tool(
 ) -> Result<Vec<PullRequestAttachment>> {
     let project_id_or_key = req.project_id_or_key.parse::<ProjectIdOrKey>()?;
     let repo_id_or_name = RepositoryIdOrName::from_str(req.repo_id_or_name.trim())?;
-    let pr_number = PullRequestNumber::from(req.pr_number);
+    let pr_number = req.pr_number.pull_request_number()?;
 
     let client_guard = client.lock().await;
 
@@ -126,7 +126,7 @@ pub(crate) async fn download_pr_attachment_bridge(
 ) -> Result<DownloadedFile> {
     let project_id_or_key = req.project_id_or_key.parse::<ProjectIdOrKey>()?;
     let repo_id_or_name = RepositoryIdOrName::from_str(req.repo_id_or_name.trim())?;
-    let pr_number = PullRequestNumber::from(req.pr_number);
+    let pr_number = req.pr_number.pull_request_number()?;
     let attachment_id_for_download = PullRequestAttachmentId::new(req.attachment_id);
 
     let client_guard = client.lock().await;
@@ -135,6 +135,21 @@ pub(crate) async fn download_pr_attachment_bridge(
         .check_project_access_id_or_key_async(&project_id_or_key, &client_guard)
         .await?;
 
+    let attachments = client_guard
+        .git()
+        .get_pull_request_attachment_list(GetPullRequestAttachmentListParams::new(
+            project_id_or_key.clone(),
+            repo_id_or_name.clone(),
+            pr_number,
+        ))
+        .await?;
+    if let Some(metadata) = attachments
+        .iter()
+        .find(|attachment| attachment.id == attachment_id_for_download)
+    {
+        access_control.policy().check_download_size(metadata.size)?;
+    }
+
     let params = DownloadPullRequestAttachmentParams::new(
         project_id_or_key,
         repo_id_or_name,
@@ -142,11 +157,14 @@ pub(crate) async fn download_pr_attachment_bridge(
         attachment_id_for_download,
     );
 
-    client_guard
+    let file = client_guard
         .git()
         .download_pull_request_attachment(params)
-        .await
-        .map_err(Error::from)
+        .await?;
+    access_control
+        .policy()
+        .check_download_size(file.bytes.len() as u64)?;
+    Ok(file)
 }
 
 pub(crate) async fn get_pull_request_comment_list_tool(
@@ -176,13 +194,44 @@ pub(crate) async fn add_pull_request_comment_bridge(
     access_control: &AccessControl,
 ) -> Result<PullRequestComment> {
     let project_id_or_key = req.project_id_or_key.parse::<ProjectIdOrKey>()?;
+    if let Some(user_ids) = &req.notified_user_ids {
+        access_control.policy().check_notified(user_ids)?;
+    }
     let params = AddPullRequestCommentParams::try_from(req)?;
 
     let client_guard = client.lock().await;
 
     access_control
-        .check_project_access_id_or_key_async(&project_id_or_key, &client_guard)
+        .check_project_write_access_id_or_key_async(&project_id_or_key, &client_guard)
         .await?;
 
     Ok(client_guard.git().add_pull_request_comment(params).await?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{forbid_secret_project_data, mock_projects, restricted_access};
+    use wiremock::MockServer;
+
+    #[tokio::test]
+    async fn test_repository_list_of_disallowed_project_is_denied() {
+        let mock_server = MockServer::start().await;
+        mock_projects(&mock_server).await;
+        forbid_secret_project_data(&mock_server).await;
+        let client = Arc::new(Mutex::new(
+            BacklogApiClient::new(&mock_server.uri()).unwrap(),
+        ));
+
+        for project in ["SECRET", "2"] {
+            let request = GetRepositoryListRequest {
+                project_id_or_key: project.to_string(),
+            };
+            let result = get_repository_list(client.clone(), request, &restricted_access()).await;
+            assert!(
+                matches!(result, Err(Error::ProjectAccessDenied { .. })),
+                "{project}"
+            );
+        }
+    }
+}
@@ -1,6 +1,8 @@
+use crate::web_url::IdOrUrl;
 #[cfg(feature = "git_writable")]
 use backlog_api_client::AddPullRequestCommentParams;
-use backlog_api_client::{ApiError, GetPullRequestCommentListParams, PrCommentOrder};
+use backlog_api_client::{ApiError, GetPullRequestCommentListParams};
+use backlog_core::Order;
 #[cfg(feature = "git_writable")]
 use backlog_core::identifier::UserId;
 use rmcp::schemars::{self, JsonSchema}; // rmcp::schemars を使用
@@ -12,11 +14,13 @@ pub struct ListPullRequestsRequest {
     #[schemars(
         description = "The project ID (numeric) or project key (string). Examples: 'MYPROJECTKEY' or '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     /// The repository ID (as a string) or repository name.
     #[schemars(
         description = "The repository ID (numeric as string) or repository name. Examples: '456' or 'my-repo'."
     )]
+    #[serde(deserialize_with = "crate::web_url::repo_id_or_name")]
     pub repo_id_or_name: String,
     // Note: Additional optional parameters (status, assignee_id, issue_id, created_by_id, offset, count) are supported by the underlying API
 }
@@ -27,15 +31,19 @@ pub struct GetPullRequestDetailsRequest {
     #[schemars(
         description = "The project ID (numeric) or project key (string). Examples: 'MYPROJECTKEY' or '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     /// The repository ID (as a string) or repository name.
     #[schemars(
         description = "The repository ID (numeric as string) or repository name. Examples: '456' or 'my-repo'."
     )]
+    #[serde(deserialize_with = "crate::web_url::repo_id_or_name")]
     pub repo_id_or_name: String,
     /// The pull request number.
-    #[schemars(description = "The pull request number. Example: 42")]
-    pub pr_number: u64,
+    #[schemars(
+        description = "The pull request number, or a pull request URL. Examples: 42, 'https://example.backlog.com/git/PROJ/repo/pullRequests/42'."
+    )]
+    pub pr_number: IdOrUrl,
 }
 
 #[derive(Deserialize, JsonSchema, Debug)]
@@ -44,15 +52,19 @@ pub struct DownloadPullRequestAttachmentRequest {
     #[schemars(
         description = "The project ID (numeric) or project key (string). Examples: 'MYPROJECTKEY' or '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     /// The repository ID (as a string) or repository name.
     #[schemars(
         description = "The repository ID (numeric as string) or repository name. Examples: '456' or 'my-repo'."
     )]
+    #[serde(deserialize_with = "crate::web_url::repo_id_or_name")]
     pub repo_id_or_name: String,
     /// The pull request number.
-    #[schemars(description = "The pull request number. Example: 42")]
-    pub pr_number: u64,
+    #[schemars(
+        description = "The pull request number, or a pull request URL. Examples: 42, 'https://example.backlog.com/git/PROJ/repo/pullRequests/42'."
+    )]
+    pub pr_number: IdOrUrl,
     /// The numeric ID of the attachment to download.
     #[schemars(description = "The numeric ID of the attachment to download.")]
     pub attachment_id: u32,
@@ -67,17 +79,20 @@ pub struct GetRepositoryDetailsRequest {
     #[schemars(
         description = "The project ID (numeric) or project key (string). Examples: 'MYPROJECTKEY' or '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     /// The repository ID (as a string) or repository name.
     #[schemars(
         description = "The repository ID (numeric as string) or repository name. Examples: '456' or 'my-repo'."
     )]
+    #[serde(deserialize_with = "crate::web_url::repo_id_or_name")]
     pub repo_id_or_name: String,
 }
 
 #[derive(Deserialize, JsonSchema, Debug)]
 pub struct GetRepositoryListRequest {
     /// The project ID or project key to retrieve repositories for. Examples: "MYPROJECTKEY", "123".
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
 }
 
@@ -87,15 +102,19 @@ pub struct GetPullRequestAttachmentListRequest {
     #[schemars(
         description = "The project ID (numeric) or project key (string). Examples: 'MYPROJECTKEY' or '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     /// The repository ID (as a string) or repository name.
     #[schemars(
         description = "The repository ID (numeric as string) or repository name. Examples: '456' or 'my-repo'."
     )]
+    #[serde(deserialize_with = "crate::web_url::repo_id_or_name")]
     pub repo_id_or_name: String,
     /// The pull request number.
-    #[schemars(description = "The pull request number. Example: 42")]
-    pub pr_number: u64,
+    #[schemars(
+        description = "The pull request number, or a pull request URL. Examples: 42, 'https://example.backlog.com/git/PROJ/repo/pullRequests/42'."
+    )]
+    pub pr_number: IdOrUrl,
 }
 
 #[derive(Deserialize, JsonSchema, Debug)]
@@ -104,15 +123,19 @@ pub struct GetPullRequestCommentListRequest {
     #[schemars(
         description = "The project ID (numeric) or project key (string). Examples: 'MYPROJECTKEY' or '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     /// The repository ID (as a string) or repository name.
     #[schemars(
         description = "The repository ID (numeric as string) or repository name. Examples: '456' or 'my-repo'."
     )]
+    #[serde(deserialize_with = "crate::web_url::repo_id_or_name")]
     pub repo_id_or_name: String,
     /// The pull request number.
-    #[schemars(description = "The pull request number. Example: 42")]
-    pub pr_number: u64,
+    #[schemars(
+        description = "The pull request number, or a pull request URL. Examples: 42, 'https://example.backlog.com/git/PROJ/repo/pullRequests/42'."
+    )]
+    pub pr_number: IdOrUrl,
     /// The minimum comment ID to retrieve.
     #[serde(default)]
     #[schemars(description = "The minimum comment ID to retrieve.")]
@@ -128,25 +151,19 @@ pub struct GetPullRequestCommentListRequest {
     /// The sort order: 'asc' or 'desc'.
     #[serde(default)]
     #[schemars(description = "The sort order: 'asc' or 'desc'.")]
-    pub order: Option<String>,
+    pub order: Option<Order>,
 }
 
 impl TryFrom<GetPullRequestCommentListRequest> for GetPullRequestCommentListParams {
     type Error = ApiError;
 
     fn try_from(req: GetPullRequestCommentListRequest) -> Result<Self, Self::Error> {
-        use backlog_api_client::{ProjectIdOrKey, PullRequestNumber, RepositoryIdOrName};
+        use backlog_api_client::{ProjectIdOrKey, RepositoryIdOrName};
         use std::str::FromStr;
 
         let project_id_or_key = req.project_id_or_key.parse::<ProjectIdOrKey>()?;
         let repo_id_or_name = RepositoryIdOrName::from_str(req.repo_id_or_name.trim())?;
-        let pr_number = PullRequestNumber::from(req.pr_number);
-
-        let order = req
-            .order
-            .as_deref()
-            .map(PrCommentOrder::from_str)
-            .transpose()?;
+        let pr_number = req.pr_number.pull_request_number()?;
 
         let mut params =
             GetPullRequestCommentListParams::new(project_id_or_key, repo_id_or_name, pr_number);
@@ -160,7 +177,7 @@ impl TryFrom<GetPullRequestCommentListRequest> for GetPullRequestCommentListPara
         if let Some(count) = req.count {
             params = params.count(count);
         }
-        if let Some(order) = order {
+        if let Some(order) = req.order {
             params = params.order(order);
         }
 
@@ -175,15 +192,19 @@ pub struct AddPullRequestCommentRequest {
     #[schemars(
         description = "The project ID (numeric) or project key (string). Examples: 'MYPROJECTKEY' or '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     /// The repository ID (as a string) or repository name.
     #[schemars(
         description = "The repository ID (numeric as string) or repository name. Examples: '456' or 'my-repo'."
     )]
+    #[serde(deserialize_with = "crate::web_url::repo_id_or_name")]
     pub repo_id_or_name: String,
     /// The pull request number.
-    #[schemars(description = "The pull request number. Example: 42")]
-    pub pr_number: u64,
+    #[schemars(
+        description = "The pull request number, or a pull request URL. Examples: 42, 'https://example.backlog.com/git/PROJ/repo/pullRequests/42'."
+    )]
+    pub pr_number: IdOrUrl,
     /// The content of the comment.
     #[schemars(description = "The content of the comment to add.")]
     pub content: String,
@@ -198,12 +219,12 @@ impl TryFrom<AddPullRequestCommentRequest> for AddPullRequestCommentParams {
     type Error = ApiError;
 
     fn try_from(req: AddPullRequestCommentRequest) -> Result<Self, Self::Error> {
-        use backlog_api_client::{ProjectIdOrKey, PullRequestNumber, RepositoryIdOrName};
+        use backlog_api_client::{ProjectIdOrKey, RepositoryIdOrName};
         use std::str::FromStr;
 
         let project_id_or_key = req.project_id_or_key.parse::<ProjectIdOrKey>()?;
         let repo_id_or_name = RepositoryIdOrName::from_str(req.repo_id_or_name.trim())?;
-        let pr_number = PullRequestNumber::from(req.pr_number);
+        let pr_number = req.pr_number.pull_request_number()?;
 
         let notified_user_ids = req
             .notified_user_ids
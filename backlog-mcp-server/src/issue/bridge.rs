@@ -1,23 +1,28 @@
 use super::request::{
     AddCommentRequest, DownloadAttachmentRequest, GetAttachmentListRequest,
-    GetIssueCommentsRequest, GetIssueDetailsRequest, GetIssueSharedFilesRequest,
-    GetIssuesByMilestoneNameRequest, GetVersionMilestoneListRequest, UpdateIssueRequest,
+    GetIssueCommentsRequest, GetIssueDetailsRequest, GetIssueHistoryRequest,
+    GetIssueSharedFilesRequest, GetIssuesByMilestoneNameRequest, GetIssuesByQueryRequest,
+    GetVersionMilestoneListRequest, SearchIssuesRequest, UpdateIssueRequest,
 };
 #[cfg(feature = "issue_writable")]
 use super::request::{AddIssueRequest, UpdateCommentRequest};
+use super::response_transformer::{IssueSearchResponse, IssueSummary};
 use crate::access_control::AccessControl;
 use crate::error::{Error as McpError, Result};
+use crate::notation;
 use crate::util::{MatchResult, find_by_name_from_array};
 #[cfg(feature = "issue_writable")]
 use backlog_api_client::backlog_issue::AddIssueParamsBuilder;
+use backlog_api_client::backlog_issue::history::IssueHistory;
+use backlog_api_client::backlog_issue::query::Field;
 use backlog_api_client::client::BacklogApiClient;
 use backlog_api_client::{
     AddCommentParams, Attachment, AttachmentId, Comment, DownloadedFile, GetCommentListParams,
-    GetIssueListParamsBuilder, Issue, IssueIdOrKey, IssueKey, IssueSharedFile, Milestone,
-    ProjectIdOrKey, UpdateIssueParams, backlog_issue, backlog_project,
+    GetIssueListParams, GetIssueListParamsBuilder, Issue, IssueIdOrKey, IssueKey, IssueQuery,
+    IssueSharedFile, Milestone, ProjectIdOrKey, UpdateIssueParams, backlog_issue, backlog_project,
 };
 #[cfg(feature = "issue_writable")]
-use backlog_core::identifier::{IssueTypeId, PriorityId, ProjectId};
+use backlog_core::identifier::{IssueTypeId, PriorityId, ProjectId, UserId};
 use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -29,7 +34,7 @@ pub(crate) async fn get_issue_details(
 ) -> Result<Issue> {
     let client_guard = client.lock().await;
     let parsed_issue_key = IssueKey::from_str(req.issue_key.trim())?;
-    let issue = client_guard
+    let mut issue = client_guard
         .issue()
         .get_issue(backlog_issue::GetIssueParams::new(parsed_issue_key.clone()))
         .await?;
@@ -38,6 +43,8 @@ pub(crate) async fn get_issue_details(
         .check_project_access_by_id_async(&issue.project_id, &client_guard)
         .await?;
 
+    let rule = notation::formatting_rule(access_control, &client_guard, &issue.project_id).await?;
+    issue.description = rule.native_to_markdown(&issue.description);
     Ok(issue)
 }
 
@@ -81,16 +88,98 @@ pub(crate) async fn get_issues_by_milestone_name(
 
     let milestone =
         find_milestone_by_name(&all_project_milestones, &req.milestone_name, proj_id_or_key)?;
-    let params = GetIssueListParamsBuilder::default()
+    let mut builder = GetIssueListParamsBuilder::default();
+    builder
         .project_id(vec![milestone.project_id])
-        .milestone_id(vec![milestone.id])
-        .build()?;
+        .milestone_id(vec![milestone.id]);
+    if let Some(sort) = req.sort {
+        builder.sort(sort);
+    }
+    if let Some(order) = req.order {
+        builder.order(order);
+    }
+    let params = builder.build()?;
+
+    let issues = client_guard.issue().get_issue_list(params).await?;
+    Ok(issues)
+}
 
+pub(crate) async fn get_issues_by_query(
+    client: Arc<Mutex<BacklogApiClient>>,
+    req: GetIssuesByQueryRequest,
+    access_control: &AccessControl,
+) -> Result<Vec<Issue>> {
+    let query = IssueQuery::parse(&req.query).map_err(|e| McpError::Parameter(e.to_string()))?;
+
+    let client_guard = client.lock().await;
+    let params = compile_query(&client_guard, &query, access_control).await?;
     let issues = client_guard.issue().get_issue_list(params).await?;
     Ok(issues)
 }
 
-fn find_milestone_by_name(
+pub(crate) async fn search_issues(
+    client: Arc<Mutex<BacklogApiClient>>,
+    req: SearchIssuesRequest,
+    access_control: &AccessControl,
+) -> Result<IssueSearchResponse> {
+    let query = req.to_query();
+
+    let client_guard = client.lock().await;
+    let params = compile_query(&client_guard, &query, access_control).await?;
+    let total = client_guard
+        .issue()
+        .count_issue(params.clone())
+        .await?
+        .count;
+    let issues = client_guard.issue().get_issue_list(params).await?;
+    Ok(IssueSearchResponse {
+        total,
+        offset: req.offset.unwrap_or(0),
+        issues: issues.into_iter().map(IssueSummary::from).collect(),
+    })
+}
+
+/// Resolves the names in `query` to the parameters of an issue list request.
+async fn compile_query(
+    client: &BacklogApiClient,
+    query: &IssueQuery,
+    access_control: &AccessControl,
+) -> Result<GetIssueListParams> {
+    // Resolve projects among the allowed ones before any project data is loaded,
+    // so names from other projects never show up in results or error messages.
+    if access_control.is_enabled() {
+        if !query.uses(Field::Project) {
+            return Err(McpError::Parameter(
+                "Project access is restricted; limit the search to a project (`project:KEY` in a query, or `projects`)."
+                    .to_string(),
+            ));
+        }
+        let mut projects = client
+            .project()
+            .get_project_list(backlog_project::GetProjectListParams::default())
+            .await?;
+        projects.retain(|project| {
+            access_control
+                .check_project_access_by_key(&project.project_key)
+                .is_ok()
+        });
+        query
+            .resolve_projects(&projects)
+            .map_err(|e| McpError::Parameter(e.to_string()))?;
+    }
+
+    let context = client.load_query_context(query).await?;
+    for data in &context.projects {
+        access_control
+            .check_project_access_by_key_async(&data.project.project_key)
+            .await?;
+    }
+    query
+        .compile(&context)
+        .map_err(|e| McpError::Parameter(e.to_string()))
+}
+
+pub(crate) fn find_milestone_by_name(
     milestones: &[Milestone],
     milestone_name: &str,
     project_id_or_key: ProjectIdOrKey,
@@ -131,16 +220,22 @@ pub(crate) async fn update_issue_impl(
         .await?;
 
     access_control
-        .check_project_access_by_id_async(&issue.project_id, &client_guard)
+        .check_project_write_access_by_id_async(&issue.project_id, &client_guard)
         .await?;
 
+    let rule = notation::formatting_rule(access_control, &client_guard, &issue.project_id).await?;
     let mut update_params = UpdateIssueParams::try_from(req.clone())?;
+    update_params.description = update_params
+        .description
+        .map(|description| rule.markdown_to_native(&description));
 
     if let Some(custom_fields_by_name) = req.custom_fields {
         let project_id_or_key = ProjectIdOrKey::from(issue.project_id);
         let custom_fields = crate::issue::custom_field_converter::resolve_custom_fields(
             &client_guard,
             &project_id_or_key,
+            issue.issue_type.id,
+            false,
             custom_fields_by_name,
         )
         .await?;
@@ -148,7 +243,8 @@ pub(crate) async fn update_issue_impl(
         update_params.custom_fields = Some(custom_fields);
     }
 
-    let updated_issue = client_guard.issue().update_issue(update_params).await?;
+    let mut updated_issue = client_guard.issue().update_issue(update_params).await?;
+    updated_issue.description = rule.native_to_markdown(&updated_issue.description);
     Ok(updated_issue)
 }
 
@@ -171,10 +267,17 @@ pub(crate) async fn get_issue_comments_impl(
         .check_project_access_by_id_async(&issue.project_id, &client_guard)
         .await?;
 
-    let comments = client_guard
+    let rule = notation::formatting_rule(access_control, &client_guard, &issue.project_id).await?;
+    let mut comments = client_guard
         .issue()
         .get_comment_list(comment_params)
         .await?;
+    for comment in &mut comments {
+        comment.content = comment
+            .content
+            .as_deref()
+            .map(|content| rule.native_to_markdown(content));
+    }
     Ok(comments)
 }
 
@@ -207,6 +310,35 @@ pub(crate) async fn get_attachment_list_impl(
     Ok(attachments)
 }
 
+pub(crate) async fn get_issue_history_impl(
+    client: Arc<Mutex<BacklogApiClient>>,
+    req: GetIssueHistoryRequest,
+    access_control: &AccessControl,
+) -> Result<IssueHistory> {
+    let parsed_issue_id_or_key = IssueIdOrKey::from_str(req.issue_id_or_key.trim())?;
+
+    let client_guard = client.lock().await;
+
+    let issue = client_guard
+        .issue()
+        .get_issue(backlog_issue::GetIssueParams::new(
+            parsed_issue_id_or_key.clone(),
+        ))
+        .await?;
+
+    access_control
+        .check_project_access_by_id_async(&issue.project_id, &client_guard)
+        .await?;
+
+    let mut history = client_guard.issue().get_issue_history(issue.id).await?;
+    if let Some(field) = req.field.as_deref() {
+        history
+            .transitions
+            .retain(|t| t.field.name().eq_ignore_ascii_case(field.trim()));
+    }
+    Ok(history)
+}
+
 pub(crate) async fn download_issue_attachment_file(
     client: Arc<Mutex<BacklogApiClient>>,
     req: DownloadAttachmentRequest,
@@ -228,9 +360,20 @@ pub(crate) async fn download_issue_attachment_file(
         .check_project_access_by_id_async(&issue.project_id, &client_guard)
         .await?;
 
+    if let Some(metadata) = issue
+        .attachments
+        .iter()
+        .find(|attachment| attachment.id == parsed_attachment_id)
+    {
+        access_control.policy().check_download_size(metadata.size)?;
+    }
+
     let params =
         backlog_issue::GetAttachmentFileParams::new(parsed_issue_id_or_key, parsed_attachment_id);
     let attachment = client_guard.issue().get_attachment_file(params).await?;
+    access_control
+        .policy()
+        .check_download_size(attachment.bytes.len() as u64)?;
     Ok(attachment)
 }
 
@@ -240,7 +383,10 @@ pub(crate) async fn add_comment_impl(
     req: AddCommentRequest,
     access_control: &AccessControl,
 ) -> Result<Comment> {
-    let add_comment_params = AddCommentParams::try_from(req.clone())?;
+    if let Some(user_ids) = &req.notified_user_ids {
+        access_control.policy().check_notified(user_ids)?;
+    }
+    let mut add_comment_params = AddCommentParams::try_from(req.clone())?;
 
     let client_guard = client.lock().await;
 
@@ -251,10 +397,17 @@ pub(crate) async fn add_comment_impl(
         .await?;
 
     access_control
-        .check_project_access_by_id_async(&issue.project_id, &client_guard)
+        .check_project_write_access_by_id_async(&issue.project_id, &client_guard)
         .await?;
 
-    let comment = client_guard.issue().add_comment(add_comment_params).await?;
+    let rule = notation::formatting_rule(access_control, &client_guard, &issue.project_id).await?;
+    add_comment_params.content = rule.markdown_to_native(&add_comment_params.content);
+
+    let mut comment = client_guard.issue().add_comment(add_comment_params).await?;
+    comment.content = comment
+        .content
+        .as_deref()
+        .map(|content| rule.native_to_markdown(content));
     Ok(comment)
 }
 
@@ -268,7 +421,7 @@ pub(crate) async fn update_comment_impl(
     use backlog_core::identifier::CommentId;
 
     let parsed_issue_id_or_key = IssueIdOrKey::from_str(req.issue_id_or_key.trim())?;
-    let comment_id = CommentId::new(req.comment_id);
+    let comment_id = req.comment_id.comment_id()?;
 
     let client_guard = client.lock().await;
 
@@ -280,16 +433,21 @@ pub(crate) async fn update_comment_impl(
         .await?;
 
     access_control
-        .check_project_access_by_id_async(&issue.project_id, &client_guard)
+        .check_project_write_access_by_id_async(&issue.project_id, &client_guard)
         .await?;
 
+    let rule = notation::formatting_rule(access_control, &client_guard, &issue.project_id).await?;
     let params = UpdateCommentParams {
         issue_id_or_key: parsed_issue_id_or_key,
         comment_id,
-        content: req.content,
+        content: rule.markdown_to_native(&req.content),
     };
 
-    let comment = client_guard.issue().update_comment(params).await?;
+    let mut comment = client_guard.issue().update_comment(params).await?;
+    comment.content = comment
+        .content
+        .as_deref()
+        .map(|content| rule.native_to_markdown(content));
     Ok(comment)
 }
 
@@ -331,6 +489,9 @@ pub(crate) async fn add_issue_impl(
     let project_id_or_key = ProjectIdOrKey::from_str(req.project_id_or_key.trim())?;
     let issue_type_id = IssueTypeId::new(req.issue_type_id);
     let priority_id = PriorityId::new(req.priority_id);
+    if let Some(assignee_id) = req.assignee_id {
+        access_control.policy().check_assignee(assignee_id)?;
+    }
 
     let client_guard = client.lock().await;
 
@@ -347,7 +508,7 @@ pub(crate) async fn add_issue_impl(
     };
 
     access_control
-        .check_project_access_by_id_async(&project_id, &client_guard)
+        .check_project_write_access_by_id_async(&project_id, &client_guard)
         .await?;
 
     let mut builder = AddIssueParamsBuilder::default();
@@ -356,25 +517,71 @@ pub(crate) async fn add_issue_impl(
         .summary(req.summary)
         .issue_type_id(issue_type_id)
         .priority_id(priority_id);
+    if let Some(assignee_id) = req.assignee_id {
+        builder.assignee_id(UserId::new(assignee_id));
+    }
 
+    let rule = notation::formatting_rule(access_control, &client_guard, &project_id).await?;
     if let Some(description) = req.description {
-        builder.description(description);
+        builder.description(rule.markdown_to_native(&description));
     }
 
-    if let Some(custom_fields_by_name) = req.custom_fields {
-        let project_id_or_key = ProjectIdOrKey::from(project_id);
-        let custom_fields = crate::issue::custom_field_converter::resolve_custom_fields(
-            &client_guard,
-            &project_id_or_key,
-            custom_fields_by_name,
-        )
-        .await?;
-
+    // Resolved even without values, so missing required fields are reported
+    // before the issue is created.
+    let custom_fields = crate::issue::custom_field_converter::resolve_custom_fields(
+        &client_guard,
+        &ProjectIdOrKey::from(project_id),
+        issue_type_id,
+        true,
+        req.custom_fields.unwrap_or_default(),
+    )
+    .await?;
+    if !custom_fields.is_empty() {
         builder.custom_fields(custom_fields);
     }
 
     let params = builder.build()?;
 
-    let issue = client_guard.issue().add_issue(params).await?;
+    let mut issue = client_guard.issue().add_issue(params).await?;
+    issue.description = rule.native_to_markdown(&issue.description);
     Ok(issue)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{forbid_secret_project_data, mock_projects, restricted_access};
+    use serde_json::json;
+    use wiremock::{Mock, MockServer, ResponseTemplate, matchers};
+
+    #[tokio::test]
+    async fn test_search_of_disallowed_project_is_denied() {
+        let mock_server = MockServer::start().await;
+        mock_projects(&mock_server).await;
+        forbid_secret_project_data(&mock_server).await;
+        Mock::given(matchers::path_regex("^/api/v2/issues"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let client = Arc::new(Mutex::new(
+            BacklogApiClient::new(&mock_server.uri()).unwrap(),
+        ));
+
+        for projects in [
+            None,
+            Some(vec!["SECRET".to_string()]),
+            Some(vec!["2".to_string()]),
+        ] {
+            let request = SearchIssuesRequest {
+                projects: projects.clone(),
+                ..Default::default()
+            };
+            let result = search_issues(client.clone(), request, &restricted_access()).await;
+            assert!(
+                matches!(result, Err(McpError::Parameter(_))),
+                "{projects:?}"
+            );
+        }
+    }
+}
@@ -1,6 +1,11 @@
+#[cfg(feature = "issue_writable")]
+use crate::web_url::IdOrUrl;
+use backlog_api_client::IssueQuery;
+use backlog_api_client::backlog_issue::query::{Field, Operator, Term};
 use backlog_api_client::{
-    AddCommentParams, AddCommentParamsBuilder, ApiError, CommentOrder, GetCommentListParams,
-    GetCommentListParamsBuilder, IssueIdOrKey, UpdateIssueParams, UpdateIssueParamsBuilder,
+    AddCommentParams, AddCommentParamsBuilder, ApiError, GetCommentListParams,
+    GetCommentListParamsBuilder, IssueIdOrKey, IssueSort, Order, UpdateIssueParams,
+    UpdateIssueParamsBuilder,
 };
 use rmcp::schemars;
 use std::str::FromStr;
@@ -10,6 +15,7 @@ pub(crate) struct GetIssueDetailsRequest {
     #[schemars(
         description = "The issue key to retrieve details for. \n    This should be in the format 'PROJECT-123', where 'PROJECT' is the project key and '123' is the issue number. \n    Ensure there are no leading or trailing spaces."
     )]
+    #[serde(deserialize_with = "crate::web_url::issue_id_or_key")]
     pub issue_key: String,
 }
 
@@ -18,6 +24,7 @@ pub(crate) struct GetVersionMilestoneListRequest {
     #[schemars(
         description = "The project ID or project key to retrieve versions (milestones) for. \n    Examples: 'MYPROJECTKEY', '123'. \n    Ensure there are no leading or trailing spaces."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
 }
 
@@ -26,9 +33,197 @@ pub(crate) struct GetIssuesByMilestoneNameRequest {
     #[schemars(
         description = "The project ID or project key where the milestone belongs. Examples: 'MYPROJECTKEY', '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     #[schemars(description = "The name of the milestone to retrieve issues for.")]
     pub milestone_name: String,
+    #[schemars(description = "Sort key, such as 'dueDate', 'updated' or 'customField_12'.")]
+    pub sort: Option<IssueSort>,
+    #[schemars(description = "Sort order: 'asc' or 'desc'.")]
+    pub order: Option<Order>,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub(crate) struct GetIssuesByQueryRequest {
+    #[schemars(
+        description = "Issue search query. Conditions are `field:value` separated by spaces, quoted when they contain spaces; commas list alternatives and a leading `-` excludes values. Fields: project, status, type, category, milestone, version (names within the given projects), assignee and created_by (`@me`, user ID or name), priority, resolution, parent, id, is (parent, child, standalone), has (attachment, file), created, updated, start and due (compared with :, <, <=, >, >= against YYYY-MM-DD, today or offsets such as -7d), sort, order, count, offset. Other words are searched in the summary and description. Example: `project:WEB status:\"In Progress\" assignee:@me type:Bug due<2026-11-01 updated>-7d login`."
+    )]
+    pub query: String,
+}
+
+#[derive(Debug, Default, serde::Deserialize, schemars::JsonSchema)]
+pub(crate) struct SearchIssuesRequest {
+    #[schemars(
+        description = "Projects to search, by key, name or ID. Required when project access is restricted, and for status, type, category, milestone and version names."
+    )]
+    pub projects: Option<Vec<String>>,
+    #[schemars(description = "Status names or IDs to include, e.g. ['Open', 'In Progress'].")]
+    pub statuses: Option<Vec<String>>,
+    #[schemars(
+        description = "Status names or IDs to exclude, e.g. ['Closed'] for unfinished issues."
+    )]
+    pub exclude_statuses: Option<Vec<String>>,
+    #[schemars(description = "Issue type names or IDs, e.g. ['Bug'].")]
+    pub issue_types: Option<Vec<String>>,
+    #[schemars(description = "Category names or IDs.")]
+    pub categories: Option<Vec<String>>,
+    #[schemars(description = "Milestone names or IDs.")]
+    pub milestones: Option<Vec<String>>,
+    #[schemars(description = "Version (affected version) names or IDs.")]
+    pub versions: Option<Vec<String>>,
+    #[schemars(description = "Priority names or IDs, e.g. ['High'].")]
+    pub priorities: Option<Vec<String>>,
+    #[schemars(description = "Resolution names or IDs.")]
+    pub resolutions: Option<Vec<String>>,
+    #[schemars(description = "Assignees: '@me', user IDs (login names), names or numeric IDs.")]
+    pub assignees: Option<Vec<String>>,
+    #[schemars(
+        description = "Issue creators: '@me', user IDs (login names), names or numeric IDs."
+    )]
+    pub created_by: Option<Vec<String>>,
+    #[schemars(description = "Numeric IDs of parent issues, to list their child issues.")]
+    pub parent_issue_ids: Option<Vec<u32>>,
+    #[schemars(
+        description = "Parent/child condition: 'parent', 'child', 'not_child' or 'standalone'."
+    )]
+    pub parent_child: Option<String>,
+    #[schemars(description = "Only issues with attachments.")]
+    pub has_attachment: Option<bool>,
+    #[schemars(description = "Only issues with linked shared files.")]
+    pub has_shared_file: Option<bool>,
+    #[schemars(
+        description = "Created on or after this date: YYYY-MM-DD, 'today', 'yesterday', 'tomorrow' or an offset such as '-7d', '+2w' or '-1m'."
+    )]
+    pub created_since: Option<String>,
+    #[schemars(
+        description = "Created on or before this date: YYYY-MM-DD, 'today', 'yesterday', 'tomorrow' or an offset such as '-7d', '+2w' or '-1m'."
+    )]
+    pub created_until: Option<String>,
+    #[schemars(
+        description = "Updated on or after this date: YYYY-MM-DD, 'today', 'yesterday', 'tomorrow' or an offset such as '-7d', '+2w' or '-1m'."
+    )]
+    pub updated_since: Option<String>,
+    #[schemars(
+        description = "Updated on or before this date: YYYY-MM-DD, 'today', 'yesterday', 'tomorrow' or an offset such as '-7d', '+2w' or '-1m'."
+    )]
+    pub updated_until: Option<String>,
+    #[schemars(
+        description = "Start date on or after this date: YYYY-MM-DD, 'today', 'yesterday', 'tomorrow' or an offset such as '-7d', '+2w' or '-1m'."
+    )]
+    pub start_date_since: Option<String>,
+    #[schemars(
+        description = "Start date on or before this date: YYYY-MM-DD, 'today', 'yesterday', 'tomorrow' or an offset such as '-7d', '+2w' or '-1m'."
+    )]
+    pub start_date_until: Option<String>,
+    #[schemars(
+        description = "Due date on or after this date: YYYY-MM-DD, 'today', 'yesterday', 'tomorrow' or an offset such as '-7d', '+2w' or '-1m'."
+    )]
+    pub due_date_since: Option<String>,
+    #[schemars(
+        description = "Due date on or before this date: YYYY-MM-DD, 'today', 'yesterday', 'tomorrow' or an offset such as '-7d', '+2w' or '-1m'."
+    )]
+    pub due_date_until: Option<String>,
+    #[schemars(description = "Text searched in the summary and description.")]
+    pub keyword: Option<String>,
+    #[schemars(
+        description = "Sort key, such as 'updated', 'dueDate', 'priority' or 'customField_12'."
+    )]
+    pub sort: Option<IssueSort>,
+    #[schemars(description = "Sort order: 'asc' or 'desc'.")]
+    pub order: Option<Order>,
+    #[schemars(description = "Number of issues to skip, for paging.")]
+    pub offset: Option<u32>,
+    #[schemars(description = "Number of issues to return (1-100, default 20).")]
+    pub count: Option<u32>,
+}
+
+impl SearchIssuesRequest {
+    /// Expresses the search as an issue query, so names are resolved the same way
+    /// as in `issue_list_by_query_get`.
+    pub(crate) fn to_query(&self) -> IssueQuery {
+        let mut terms = Vec::new();
+        let mut push = |field: Field, operator: Operator, values: Vec<String>, negated: bool| {
+            terms.push(Term {
+                field,
+                operator,
+                values,
+                negated,
+                column: 0,
+            });
+        };
+
+        let lists = [
+            (Field::Project, &self.projects, false),
+            (Field::Status, &self.statuses, false),
+            (Field::Status, &self.exclude_statuses, true),
+            (Field::IssueType, &self.issue_types, false),
+            (Field::Category, &self.categories, false),
+            (Field::Milestone, &self.milestones, false),
+            (Field::Version, &self.versions, false),
+            (Field::Priority, &self.priorities, false),
+            (Field::Resolution, &self.resolutions, false),
+            (Field::Assignee, &self.assignees, false),
+            (Field::CreatedBy, &self.created_by, false),
+        ];
+        for (field, values, negated) in lists {
+            if let Some(values) = values.as_ref().filter(|values| !values.is_empty()) {
+                push(field, Operator::Eq, values.clone(), negated);
+            }
+        }
+        if let Some(ids) = self.parent_issue_ids.as_ref().filter(|ids| !ids.is_empty()) {
+            let ids = ids.iter().map(u32::to_string).collect();
+            push(Field::Parent, Operator::Eq, ids, false);
+        }
+        match self.parent_child.as_deref() {
+            Some("not_child") => push(Field::Is, Operator::Eq, vec!["child".to_string()], true),
+            Some(condition) => push(Field::Is, Operator::Eq, vec![condition.to_string()], false),
+            None => {}
+        }
+        if self.has_attachment == Some(true) {
+            push(
+                Field::Has,
+                Operator::Eq,
+                vec!["attachment".to_string()],
+                false,
+            );
+        }
+        if self.has_shared_file == Some(true) {
+            push(Field::Has, Operator::Eq, vec!["file".to_string()], false);
+        }
+
+        let dates = [
+            (Field::Created, &self.created_since, &self.created_until),
+            (Field::Updated, &self.updated_since, &self.updated_until),
+            (
+                Field::StartDate,
+                &self.start_date_since,
+                &self.start_date_until,
+            ),
+            (Field::DueDate, &self.due_date_since, &self.due_date_until),
+        ];
+        for (field, since, until) in dates {
+            if let Some(since) = since {
+                push(field, Operator::Ge, vec![since.clone()], false);
+            }
+            if let Some(until) = until {
+                push(field, Operator::Le, vec![until.clone()], false);
+            }
+        }
+
+        let single = [
+            (Field::Keyword, self.keyword.clone()),
+            (Field::Sort, self.sort.map(|sort| sort.to_string())),
+            (Field::Order, self.order.map(|order| order.to_string())),
+            (Field::Offset, self.offset.map(|offset| offset.to_string())),
+            (Field::Count, self.count.map(|count| count.to_string())),
+        ];
+        for (field, value) in single {
+            if let Some(value) = value.filter(|value| !value.trim().is_empty()) {
+                push(field, Operator::Eq, vec![value], false);
+            }
+        }
+        IssueQuery::from(terms)
+    }
 }
 
 #[derive(Debug, Clone, serde::Deserialize, schemars::JsonSchema)]
@@ -36,13 +231,14 @@ pub(crate) struct UpdateIssueRequest {
     #[schemars(
         description = "The issue ID or issue key to update. Example: 'MYPROJECTKEY-123' or '12345'."
     )]
+    #[serde(deserialize_with = "crate::web_url::issue_id_or_key")]
     pub issue_id_or_key: String,
     #[schemars(
         description = "The new summary for the issue. Set to null or omit to keep unchanged."
     )]
     pub summary: Option<String>,
     #[schemars(
-        description = "The new description for the issue. Set to null or omit to keep unchanged."
+        description = "The new description for the issue, in Markdown. Set to null or omit to keep unchanged."
     )]
     pub description: Option<String>,
     #[schemars(
@@ -75,12 +271,27 @@ pub(crate) struct GetAttachmentListRequest {
     #[schemars(
         description = "The issue ID or issue key for which to retrieve attachments. Examples: 'MYPROJECTKEY-123', '12345'."
     )]
+    #[serde(deserialize_with = "crate::web_url::issue_id_or_key")]
     pub issue_id_or_key: String,
 }
 
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub(crate) struct GetIssueHistoryRequest {
+    #[schemars(
+        description = "The issue ID or issue key whose change history to retrieve. Examples: 'MYPROJECTKEY-123', '12345'."
+    )]
+    #[serde(deserialize_with = "crate::web_url::issue_id_or_key")]
+    pub issue_id_or_key: String,
+    #[schemars(
+        description = "Only return changes to this field, compared case-insensitively. Examples: 'status', 'assignee', 'dueDate', 'attachment', or a custom field name."
+    )]
+    pub field: Option<String>,
+}
+
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub(crate) struct DownloadAttachmentRequest {
     #[schemars(description = "The issue ID or issue key. Examples: 'MYPROJECTKEY-123', '12345'.")]
+    #[serde(deserialize_with = "crate::web_url::issue_id_or_key")]
     pub issue_id_or_key: String,
     #[schemars(description = "The numeric ID of the attachment to download.")]
     pub attachment_id: u32,
@@ -94,6 +305,7 @@ pub(crate) struct DownloadAttachmentRequest {
 #[derive(Debug, Clone, serde::Deserialize, schemars::JsonSchema)]
 pub(crate) struct GetIssueCommentsRequest {
     #[schemars(description = "Issue ID or key (e.g., 'PROJECT-123').")]
+    #[serde(deserialize_with = "crate::web_url::issue_id_or_key")]
     pub issue_id_or_key: String,
     #[schemars(description = "Min comment ID.")]
     pub min_id: Option<u64>,
@@ -102,7 +314,7 @@ pub(crate) struct GetIssueCommentsRequest {
     #[schemars(description = "Number of comments to retrieve (1-100).")]
     pub count: Option<u8>,
     #[schemars(description = "Sort order: 'asc' or 'desc'.")]
-    pub order: Option<String>,
+    pub order: Option<Order>,
 }
 
 impl TryFrom<GetIssueCommentsRequest> for GetCommentListParams {
@@ -121,12 +333,7 @@ impl TryFrom<GetIssueCommentsRequest> for GetCommentListParams {
         if let Some(count) = req.count {
             params_builder.count(count);
         }
-        let parsed_order: Option<CommentOrder> = req
-            .order
-            .as_deref()
-            .map(CommentOrder::from_str)
-            .transpose()?;
-        if let Some(order) = parsed_order {
+        if let Some(order) = req.order {
             params_builder.order(order);
         }
         params_builder.build()
@@ -138,8 +345,9 @@ pub(crate) struct AddCommentRequest {
     #[schemars(
         description = "The issue ID or issue key to add the comment to. Examples: 'MYPROJECTKEY-123', '12345'."
     )]
+    #[serde(deserialize_with = "crate::web_url::issue_id_or_key")]
     pub issue_id_or_key: String,
-    #[schemars(description = "The content of the comment to add.")]
+    #[schemars(description = "The content of the comment to add, in Markdown.")]
     pub content: String,
     #[schemars(description = "User IDs to notify about this comment (optional).")]
     pub notified_user_ids: Option<Vec<u32>>,
@@ -179,10 +387,13 @@ pub(crate) struct UpdateCommentRequest {
     #[schemars(
         description = "The issue ID or issue key containing the comment. Examples: 'MYPROJECTKEY-123', '12345'."
     )]
+    #[serde(deserialize_with = "crate::web_url::issue_id_or_key")]
     pub issue_id_or_key: String,
-    #[schemars(description = "The ID of the comment to update.")]
-    pub comment_id: u32,
-    #[schemars(description = "The new content for the comment.")]
+    #[schemars(
+        description = "The ID of the comment to update, or an issue URL ending in '#comment-{id}'."
+    )]
+    pub comment_id: IdOrUrl,
+    #[schemars(description = "The new content for the comment, in Markdown.")]
     pub content: String,
 }
 
@@ -191,6 +402,7 @@ pub(crate) struct GetIssueSharedFilesRequest {
     #[schemars(
         description = "The issue ID or issue key for which to retrieve shared files. Examples: 'MYPROJECTKEY-123', '12345'."
     )]
+    #[serde(deserialize_with = "crate::web_url::issue_id_or_key")]
     pub issue_id_or_key: String,
 }
 
@@ -200,6 +412,7 @@ pub(crate) struct AddIssueRequest {
     #[schemars(
         description = "The project ID or project key to create the issue in. Examples: 'MYPROJECTKEY', '123'."
     )]
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
     #[schemars(description = "The title/summary of the issue.")]
     pub summary: String,
@@ -211,10 +424,45 @@ pub(crate) struct AddIssueRequest {
         description = "The ID of the priority. get_priorities tool can be used to retrieve valid IDs."
     )]
     pub priority_id: u32,
-    #[schemars(description = "Optional description of the issue.")]
+    #[schemars(description = "Optional description of the issue, in Markdown.")]
     pub description: Option<String>,
+    #[schemars(description = "Optional ID of the user to assign the issue to.")]
+    pub assignee_id: Option<u32>,
     #[schemars(
         description = "Optional custom fields as a JSON object mapping field names to values. Use get_custom_field_list to see available fields and their expected formats."
     )]
     pub custom_fields: Option<std::collections::HashMap<String, serde_json::Value>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conditions(query: &IssueQuery) -> Vec<(Field, Operator, Vec<String>, bool)> {
+        query
+            .terms()
+            .iter()
+            .map(|t| (t.field, t.operator, t.values.clone(), t.negated))
+            .collect()
+    }
+
+    #[test]
+    fn test_search_request_to_query() {
+        let request = SearchIssuesRequest {
+            projects: Some(vec!["WEB".to_string()]),
+            exclude_statuses: Some(vec!["Closed".to_string()]),
+            issue_types: Some(vec!["Bug".to_string()]),
+            assignees: Some(vec!["@me".to_string()]),
+            parent_child: Some("not_child".to_string()),
+            updated_since: Some("-7d".to_string()),
+            keyword: Some("login page".to_string()),
+            count: Some(50),
+            ..Default::default()
+        };
+        let expected = IssueQuery::parse(
+            r#"project:WEB -status:Closed type:Bug assignee:@me -is:child updated>=-7d keyword:"login page" count:50"#,
+        )
+        .unwrap();
+        assert_eq!(conditions(&request.to_query()), conditions(&expected));
+    }
+}
@@ -106,6 +106,50 @@ impl From<Issue> for IssueResponse {
     }
 }
 
+/// One line of a search result, without descriptions or custom fields
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSummary {
+    pub issue_key: IssueKey,
+    pub summary: String,
+    pub issue_type: String,
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub milestones: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    pub updated: String,
+}
+
+impl From<Issue> for IssueSummary {
+    fn from(issue: Issue) -> Self {
+        IssueSummary {
+            issue_key: issue.issue_key,
+            summary: issue.summary,
+            issue_type: issue.issue_type.name,
+            status: issue.status.name,
+            priority: issue.priority.map(|priority| priority.name),
+            assignee: issue.assignee.map(|user| user.name),
+            milestones: issue.milestone.into_iter().map(|m| m.name).collect(),
+            due_date: issue.due_date,
+            updated: issue.updated,
+        }
+    }
+}
+
+/// A page of search results with the number of issues matching in total
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchResponse {
+    pub total: u32,
+    pub offset: u32,
+    pub issues: Vec<IssueSummary>,
+}
+
 #[cfg(test)]
 #[path = "response_transformer_test.rs"]
 mod tests;
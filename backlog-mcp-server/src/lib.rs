@@ -1,16 +1,28 @@
 #![allow(unused_imports, dead_code)]
 
 pub mod access_control;
+pub mod audit;
 pub mod document;
 mod error;
 pub mod file;
 pub mod git;
 pub mod issue;
+mod notation;
+pub mod output;
+pub mod policy;
 pub mod project;
 pub(crate) mod project_cache;
+mod prompt;
+mod resource;
 mod server;
+pub mod shaping;
+#[cfg(test)]
+mod test_utils;
+pub mod toolset;
+pub mod transport;
 pub mod user;
 mod util;
+pub mod web_url;
 pub mod wiki;
 
 mod file_utils;
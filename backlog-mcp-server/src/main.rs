@@ -1,9 +1,31 @@
+use clap::Parser;
 use rmcp::{ServiceExt, transport::stdio};
+use std::net::SocketAddr;
+use tokio_util::sync::CancellationToken;
 
 use mcp_backlog_server::Server;
+use mcp_backlog_server::transport::{self, HttpOptions, TransportMode};
+
+#[derive(Parser)]
+#[command(version, about = "MCP server for Backlog")]
+struct Args {
+    /// Transport to serve the MCP protocol over
+    #[arg(
+        long,
+        value_enum,
+        env = "BACKLOG_MCP_TRANSPORT",
+        default_value = "stdio"
+    )]
+    transport: TransportMode,
+
+    /// Address to listen on with the http and sse transports
+    #[arg(long, env = "BACKLOG_MCP_BIND", default_value = "127.0.0.1:8080")]
+    bind: SocketAddr,
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
     eprintln!("Backlog MCP Server starting...");
 
     let server = match Server::new() {
@@ -17,10 +39,37 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
-    let service = server.serve(stdio()).await.inspect_err(|e| {
-        eprintln!("Error starting server: {e}");
-    })?;
-    service.waiting().await?;
+    match args.transport {
+        TransportMode::Stdio => {
+            let service = server.serve(stdio()).await.inspect_err(|e| {
+                eprintln!("Error starting server: {e}");
+            })?;
+            service.waiting().await?;
+        }
+        TransportMode::Http | TransportMode::Sse => {
+            // Read from the environment only, so the token never shows up in process lists
+            let options = HttpOptions {
+                bind: args.bind,
+                auth_token: std::env::var("BACKLOG_MCP_AUTH_TOKEN").ok(),
+            };
+            let shutdown = CancellationToken::new();
+            tokio::spawn({
+                let shutdown = shutdown.clone();
+                async move {
+                    transport::shutdown_signal().await;
+                    eprintln!("Shutting down...");
+                    shutdown.cancel();
+                }
+            });
+
+            let result = if args.transport == TransportMode::Http {
+                transport::serve_streamable_http(server, options, shutdown).await
+            } else {
+                transport::serve_sse(server, options, shutdown).await
+            };
+            result.inspect_err(|e| eprintln!("Error serving over HTTP: {e}"))?;
+        }
+    }
 
     eprintln!("Backlog MCP Server finished.");
     Ok(())
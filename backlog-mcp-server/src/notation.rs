@@ -0,0 +1,22 @@
+//! Tools exchange text with the client as Markdown. Projects that use Backlog
+//! notation get their descriptions, comments and wiki pages converted on the way
+//! in and out, so the client never has to know which rule a project uses.
+
+use crate::access_control::AccessControl;
+use crate::error::Result;
+use backlog_api_client::client::BacklogApiClient;
+use backlog_core::TextFormattingRule;
+use backlog_core::identifier::ProjectId;
+
+/// Returns the text formatting rule of a project, using the project cache.
+pub(crate) async fn formatting_rule(
+    access_control: &AccessControl,
+    client: &BacklogApiClient,
+    project_id: &ProjectId,
+) -> Result<TextFormattingRule> {
+    let project = access_control
+        .project_cache()
+        .get_by_id(project_id, client)
+        .await?;
+    Ok(project.text_formatting_rule)
+}
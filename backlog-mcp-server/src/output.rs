@@ -0,0 +1,614 @@
+use crate::error::{Error, Result};
+use rmcp::model::{CallToolResult, JsonObject, RawContent};
+use serde_json::{Map, Value, json};
+use std::env;
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+/// Descriptions in lists are cut to this many characters.
+const EXCERPT_CHARS: usize = 280;
+/// The argument that picks the output format of one call.
+const FORMAT_KEY: &str = "output_format";
+
+/// How tools return issues, comments, pull requests, wikis and milestones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// The serialized result, as returned by the Backlog API.
+    #[default]
+    Json,
+    /// A concise rendering that leaves out IDs, nested objects and empty fields.
+    Markdown,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(OutputFormat::Json),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            _ => Err(format!("Unknown output format '{s}'. Use json or markdown")),
+        }
+    }
+}
+
+impl OutputFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            OutputFormat::Json => "json",
+            OutputFormat::Markdown => "markdown",
+        }
+    }
+
+    /// Reads `BACKLOG_OUTPUT_FORMAT`, which defaults to `json`.
+    pub fn from_env() -> std::result::Result<Self, String> {
+        match env::var("BACKLOG_OUTPUT_FORMAT") {
+            Ok(value) if !value.trim().is_empty() => value
+                .parse()
+                .map_err(|e| format!("Invalid BACKLOG_OUTPUT_FORMAT: {e}")),
+            _ => Ok(OutputFormat::default()),
+        }
+    }
+
+    /// Removes the format argument from a call, returning it if it was given.
+    pub fn take(arguments: &mut Option<JsonObject>) -> Result<Option<Self>> {
+        match arguments.as_mut().and_then(|args| args.remove(FORMAT_KEY)) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(s)) => s.parse().map(Some).map_err(Error::Parameter),
+            Some(other) => Err(Error::Parameter(format!(
+                "{FORMAT_KEY} must be 'json' or 'markdown', not {other}"
+            ))),
+        }
+    }
+}
+
+/// Adds the format argument to the input schema of a tool.
+pub(crate) fn add_format_property(schema: &mut JsonObject, default: OutputFormat) {
+    let properties = schema
+        .entry("properties")
+        .or_insert_with(|| Value::Object(Map::new()));
+    let Value::Object(properties) = properties else {
+        return;
+    };
+    properties.insert(
+        FORMAT_KEY.to_string(),
+        json!({
+            "type": "string",
+            "enum": ["json", "markdown"],
+            "description": format!("'markdown' returns a concise summary with keys, status, assignee, dates and a description excerpt; 'json' returns every field and is needed for fields. Default: {}.", default.name())
+        }),
+    );
+}
+
+/// The kind of Backlog object a tool returns, which decides how it is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rendering {
+    Issue,
+    Comment,
+    PullRequest,
+    Wiki,
+    Milestone,
+}
+
+impl Rendering {
+    pub(crate) const TOOLS: [(&'static str, Rendering); 17] = [
+        ("issue_details_get", Rendering::Issue),
+        ("issue_list_by_milestone_get", Rendering::Issue),
+        ("issue_list_by_query_get", Rendering::Issue),
+        ("issue_search", Rendering::Issue),
+        ("issue_update", Rendering::Issue),
+        ("issue_add", Rendering::Issue),
+        ("issue_comment_list_get", Rendering::Comment),
+        ("issue_comment_add", Rendering::Comment),
+        ("issue_comment_update", Rendering::Comment),
+        ("git_pr_comment_list_get", Rendering::Comment),
+        ("git_pr_comment_add", Rendering::Comment),
+        ("git_pr_list_get", Rendering::PullRequest),
+        ("git_pr_details_get", Rendering::PullRequest),
+        ("wiki_details_get", Rendering::Wiki),
+        ("wiki_list_get", Rendering::Wiki),
+        ("wiki_update", Rendering::Wiki),
+        ("issue_milestone_list_get", Rendering::Milestone),
+    ];
+
+    /// Returns how the results of a tool, with or without the name prefix, are rendered.
+    pub fn of_tool(tool_name: &str) -> Option<Self> {
+        Self::TOOLS
+            .iter()
+            .find(|(name, _)| {
+                tool_name
+                    .strip_suffix(name)
+                    .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with('_'))
+            })
+            .map(|(_, rendering)| *rendering)
+    }
+
+    fn plural(self) -> &'static str {
+        match self {
+            Rendering::Issue => "issues",
+            Rendering::Comment => "comments",
+            Rendering::PullRequest => "pull requests",
+            Rendering::Wiki => "wiki pages",
+            Rendering::Milestone => "milestones",
+        }
+    }
+
+    /// Renders one object; `full` keeps the whole description rather than an excerpt.
+    fn item(self, value: &Value, full: bool) -> String {
+        match self {
+            Rendering::Issue => issue(value, full),
+            Rendering::Comment => comment(value),
+            Rendering::PullRequest => pull_request(value, full),
+            Rendering::Wiki => wiki(value, full),
+            Rendering::Milestone => milestone(value),
+        }
+    }
+
+    fn render(self, value: &Value) -> Option<String> {
+        match value {
+            Value::Array(items) => Some(self.list(items)),
+            Value::Object(object) => match object.get("issues") {
+                Some(Value::Array(issues)) => {
+                    let mut out = String::new();
+                    if let Some(total) = object.get("total").and_then(Value::as_u64) {
+                        let offset = object.get("offset").and_then(Value::as_u64).unwrap_or(0);
+                        let last = offset + issues.len() as u64;
+                        let _ = writeln!(
+                            out,
+                            "{total} issues found, showing {} to {last}.\n",
+                            offset + 1
+                        );
+                    }
+                    out.push_str(&self.list(issues));
+                    Some(out)
+                }
+                _ => Some(self.item(value, true)),
+            },
+            _ => None,
+        }
+    }
+
+    fn list(self, items: &[Value]) -> String {
+        if items.is_empty() {
+            return format!("No {}.", self.plural());
+        }
+        let separator = if self == Rendering::Milestone {
+            "\n"
+        } else {
+            "\n\n"
+        };
+        items
+            .iter()
+            .map(|item| self.item(item, false))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+}
+
+/// Returns true if the first content of a result holds a list, rendered one excerpt per item.
+pub fn is_list(result: &CallToolResult) -> bool {
+    let Some(RawContent::Text(text)) = result.content.first().map(|c| &c.raw) else {
+        return false;
+    };
+    match serde_json::from_str::<Value>(&text.text) {
+        Ok(Value::Array(_)) => true,
+        Ok(Value::Object(object)) => matches!(object.get("issues"), Some(Value::Array(_))),
+        _ => false,
+    }
+}
+
+/// Replaces the first content of a result, which holds the data, with its Markdown rendering.
+///
+/// Contents that are not JSON, such as an error message, are left as they are, and so is
+/// anything after the first, like the summary of a shaped result.
+pub fn render(mut result: CallToolResult, rendering: Rendering) -> CallToolResult {
+    if result.is_error == Some(true) {
+        return result;
+    }
+    if let Some(RawContent::Text(text)) = result.content.first_mut().map(|c| &mut c.raw)
+        && let Ok(value) = serde_json::from_str::<Value>(&text.text)
+        && let Some(markdown) = rendering.render(&value)
+    {
+        text.text = markdown;
+    }
+    result
+}
+
+fn issue(value: &Value, full: bool) -> String {
+    let mut out = format!(
+        "### {} {}\n",
+        text(value, "issueKey").unwrap_or_default(),
+        text(value, "summary").unwrap_or_default()
+    );
+    fields_line(
+        &mut out,
+        &[
+            ("Status", name(value.get("status"))),
+            ("Type", name(value.get("issueType"))),
+            ("Priority", name(value.get("priority"))),
+            ("Assignee", name(value.get("assignee"))),
+        ],
+    );
+    fields_line(
+        &mut out,
+        &[
+            (
+                "Milestone",
+                names(value.get("milestone").or(value.get("milestones"))),
+            ),
+            ("Category", names(value.get("category"))),
+            ("Start", date(value.get("startDate"))),
+            ("Due", date(value.get("dueDate"))),
+        ],
+    );
+    fields_line(
+        &mut out,
+        &[
+            (
+                "Created",
+                by(date(value.get("created")), value.get("createdUser")),
+            ),
+            (
+                "Updated",
+                by(date(value.get("updated")), value.get("updatedUser")),
+            ),
+        ],
+    );
+    if full && let Some(Value::Object(custom_fields)) = value.get("customFields") {
+        let values: Vec<_> = custom_fields
+            .iter()
+            .filter_map(|(field, info)| Some((field.as_str(), display(info.get("value")?)?)))
+            .collect();
+        fields_line(
+            &mut out,
+            &values
+                .into_iter()
+                .map(|(field, value)| (field, Some(value)))
+                .collect::<Vec<_>>(),
+        );
+    }
+    body(&mut out, text(value, "description"), full);
+    out.trim_end().to_string()
+}
+
+fn comment(value: &Value) -> String {
+    let mut out = format!(
+        "#### Comment {}",
+        value.get("id").and_then(display).unwrap_or_default()
+    );
+    if let Some(user) = name(value.get("createdUser")) {
+        let _ = write!(out, " by {user}");
+    }
+    if let Some(created) = date_time(value.get("created")) {
+        let _ = write!(out, " on {created}");
+    }
+    out.push('\n');
+    if let Some(Value::Array(changes)) = value.get("changeLog") {
+        for change in changes {
+            let field = text(change, "field").unwrap_or_default();
+            let from = text(change, "originalValue").filter(|s| !s.is_empty());
+            let to = text(change, "newValue").filter(|s| !s.is_empty());
+            let _ = writeln!(
+                out,
+                "- {field}: {} → {}",
+                from.unwrap_or("(none)"),
+                to.unwrap_or("(none)")
+            );
+        }
+    }
+    body(&mut out, text(value, "content"), true);
+    out.trim_end().to_string()
+}
+
+fn pull_request(value: &Value, full: bool) -> String {
+    let mut out = format!(
+        "### #{} {}",
+        value.get("number").and_then(display).unwrap_or_default(),
+        text(value, "summary").unwrap_or_default()
+    );
+    if let (Some(branch), Some(base)) = (text(value, "branch"), text(value, "base")) {
+        let _ = write!(out, " ({branch} → {base})");
+    }
+    out.push('\n');
+    fields_line(
+        &mut out,
+        &[
+            ("Status", name(value.get("status"))),
+            ("Assignee", name(value.get("assignee"))),
+            ("Merged", date(value.get("mergeAt"))),
+            ("Closed", date(value.get("closeAt"))),
+        ],
+    );
+    fields_line(
+        &mut out,
+        &[
+            (
+                "Created",
+                by(date(value.get("created")), value.get("createdUser")),
+            ),
+            (
+                "Updated",
+                by(date(value.get("updated")), value.get("updatedUser")),
+            ),
+        ],
+    );
+    body(&mut out, text(value, "description"), full);
+    out.trim_end().to_string()
+}
+
+fn wiki(value: &Value, full: bool) -> String {
+    let mut out = format!("### {}\n", text(value, "name").unwrap_or_default());
+    fields_line(
+        &mut out,
+        &[
+            ("Tags", names(value.get("tags"))),
+            (
+                "Created",
+                by(date(value.get("created")), value.get("createdUser")),
+            ),
+            (
+                "Updated",
+                by(date(value.get("updated")), value.get("updatedUser")),
+            ),
+        ],
+    );
+    fields_line(
+        &mut out,
+        &[("Attachments", names(value.get("attachments")))],
+    );
+    body(&mut out, text(value, "content"), full);
+    out.trim_end().to_string()
+}
+
+fn milestone(value: &Value) -> String {
+    let mut out = format!("- **{}**", text(value, "name").unwrap_or_default());
+    let dates = [
+        date(value.get("startDate")),
+        date(value.get("releaseDueDate")),
+    ];
+    match dates {
+        [Some(start), Some(due)] => {
+            let _ = write!(out, " ({start} → {due})");
+        }
+        [None, Some(due)] => {
+            let _ = write!(out, " (due {due})");
+        }
+        [Some(start), None] => {
+            let _ = write!(out, " (from {start})");
+        }
+        [None, None] => {}
+    }
+    if value.get("archived").and_then(Value::as_bool) == Some(true) {
+        out.push_str(" [archived]");
+    }
+    if let Some(description) = text(value, "description").filter(|s| !s.trim().is_empty()) {
+        let _ = write!(out, ": {}", excerpt(description));
+    }
+    out
+}
+
+/// Writes the fields that have a value as one `- A: x | B: y` line.
+fn fields_line(out: &mut String, fields: &[(&str, Option<String>)]) {
+    let parts: Vec<_> = fields
+        .iter()
+        .filter_map(|(label, value)| Some(format!("{label}: {}", value.as_ref()?)))
+        .collect();
+    if !parts.is_empty() {
+        let _ = writeln!(out, "- {}", parts.join(" | "));
+    }
+}
+
+fn body(out: &mut String, text: Option<&str>, full: bool) {
+    let Some(text) = text.map(str::trim).filter(|s| !s.is_empty()) else {
+        return;
+    };
+    if full {
+        let _ = write!(out, "\n{text}\n");
+    } else {
+        let _ = writeln!(out, "\n> {}", excerpt(text));
+    }
+}
+
+/// The start of a text on one line, for lists.
+fn excerpt(text: &str) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= EXCERPT_CHARS {
+        return line;
+    }
+    let head: String = line.chars().take(EXCERPT_CHARS).collect();
+    format!("{}…", head.trim_end())
+}
+
+fn text<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value.get(key)?.as_str()
+}
+
+/// The name of a nested object such as a status or user, which search results give as a string.
+fn name(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(object) => object.get("name")?.as_str().map(str::to_string),
+        _ => None,
+    }
+}
+
+fn names(value: Option<&Value>) -> Option<String> {
+    let Value::Array(items) = value? else {
+        return None;
+    };
+    let names: Vec<_> = items.iter().filter_map(|item| name(Some(item))).collect();
+    (!names.is_empty()).then(|| names.join(", "))
+}
+
+/// A custom field value, which may be a string, number, date, item or list of items.
+fn display(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) if s.is_empty() => None,
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Array(_) => names(Some(value)),
+        Value::Object(_) => name(Some(value)),
+    }
+}
+
+fn date(value: Option<&Value>) -> Option<String> {
+    let s = value?.as_str()?;
+    Some(s.get(..10).unwrap_or(s).to_string())
+}
+
+fn date_time(value: Option<&Value>) -> Option<String> {
+    let s = value?.as_str()?;
+    Some(
+        s.get(..16)
+            .map_or(s.to_string(), |s| s.replacen('T', " ", 1)),
+    )
+}
+
+fn by(date: Option<String>, user: Option<&Value>) -> Option<String> {
+    match (date, name(user)) {
+        (Some(date), Some(user)) => Some(format!("{date} by {user}")),
+        (date, _) => date,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::Content;
+
+    fn rendered(rendering: Rendering, value: Value) -> String {
+        let result = render(
+            CallToolResult::success(vec![Content::json(value).unwrap()]),
+            rendering,
+        );
+        result.content[0].as_text().unwrap().text.clone()
+    }
+
+    #[test]
+    fn test_of_tool() {
+        assert_eq!(
+            Rendering::of_tool("issue_details_get"),
+            Some(Rendering::Issue)
+        );
+        assert_eq!(
+            Rendering::of_tool("backlog_git_pr_comment_list_get"),
+            Some(Rendering::Comment)
+        );
+        assert_eq!(
+            Rendering::of_tool("backlog_issue_attachment_list_get"),
+            None
+        );
+        assert_eq!(Rendering::of_tool("myissue_search"), None);
+    }
+
+    #[test]
+    fn test_take() {
+        let mut arguments = json!({ "issue_id_or_key": "WEB-1", "output_format": "Markdown" })
+            .as_object()
+            .cloned();
+        assert_eq!(
+            OutputFormat::take(&mut arguments).unwrap(),
+            Some(OutputFormat::Markdown)
+        );
+        assert_eq!(
+            Value::Object(arguments.clone().unwrap()),
+            json!({ "issue_id_or_key": "WEB-1" })
+        );
+        assert_eq!(OutputFormat::take(&mut arguments).unwrap(), None);
+        assert!(
+            OutputFormat::take(&mut json!({ "output_format": "xml" }).as_object().cloned())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_issue() {
+        let issue = json!({
+            "id": 1, "projectId": 2, "issueKey": "WEB-12", "keyId": 12,
+            "issueType": { "id": 3, "name": "Bug" },
+            "summary": "Login fails",
+            "description": "Steps:\n1. Open the page",
+            "priority": { "id": 2, "name": "High" },
+            "status": { "id": 1, "name": "Open" },
+            "assignee": { "id": 5, "name": "Alice" },
+            "category": [], "versions": [],
+            "milestone": [{ "id": 9, "name": "v1.2" }],
+            "startDate": null, "dueDate": "2026-11-01T00:00:00Z",
+            "createdUser": { "id": 6, "name": "Bob" },
+            "created": "2026-10-01T09:00:00Z",
+            "updatedUser": null,
+            "updated": "2026-10-10T09:00:00Z",
+            "customFields": {
+                "Severity": { "id": 1, "fieldTypeId": "6", "value": { "id": 4, "name": "Major" } },
+                "Notes": { "id": 2, "fieldTypeId": "1", "value": null }
+            }
+        });
+        assert_eq!(
+            rendered(Rendering::Issue, issue.clone()),
+            "### WEB-12 Login fails\n\
+             - Status: Open | Type: Bug | Priority: High | Assignee: Alice\n\
+             - Milestone: v1.2 | Due: 2026-11-01\n\
+             - Created: 2026-10-01 by Bob | Updated: 2026-10-10\n\
+             - Severity: Major\n\
+             \n\
+             Steps:\n1. Open the page"
+        );
+        assert_eq!(
+            rendered(Rendering::Issue, json!([issue])),
+            "### WEB-12 Login fails\n\
+             - Status: Open | Type: Bug | Priority: High | Assignee: Alice\n\
+             - Milestone: v1.2 | Due: 2026-11-01\n\
+             - Created: 2026-10-01 by Bob | Updated: 2026-10-10\n\
+             \n\
+             > Steps: 1. Open the page"
+        );
+    }
+
+    #[test]
+    fn test_search_result() {
+        let search = json!({
+            "total": 31, "offset": 30,
+            "issues": [{ "issueKey": "WEB-1", "summary": "Fix", "issueType": "Task", "status": "Open", "updated": "2026-10-10T09:00:00Z" }]
+        });
+        assert_eq!(
+            rendered(Rendering::Issue, search),
+            "31 issues found, showing 31 to 31.\n\n\
+             ### WEB-1 Fix\n\
+             - Status: Open | Type: Task\n\
+             - Updated: 2026-10-10"
+        );
+        assert_eq!(rendered(Rendering::Issue, json!([])), "No issues.");
+    }
+
+    #[test]
+    fn test_comment_and_milestone() {
+        let comment = json!({
+            "id": 77, "content": "Fixed in #42",
+            "changeLog": [{ "field": "status", "newValue": "Closed", "originalValue": "Open" }],
+            "createdUser": { "id": 5, "name": "Alice" },
+            "created": "2026-10-10T09:30:00Z", "updated": "2026-10-10T09:30:00Z",
+            "stars": [], "notifications": []
+        });
+        assert_eq!(
+            rendered(Rendering::Comment, json!([comment])),
+            "#### Comment 77 by Alice on 2026-10-10 09:30\n- status: Open → Closed\n\nFixed in #42"
+        );
+
+        let milestones = json!([
+            { "id": 1, "projectId": 2, "name": "v1.2", "description": "", "startDate": "2026-10-01T00:00:00Z", "releaseDueDate": "2026-11-01T00:00:00Z", "archived": false, "displayOrder": 0 },
+            { "id": 2, "projectId": 2, "name": "v1.1", "description": "Hotfixes", "startDate": null, "releaseDueDate": null, "archived": true, "displayOrder": 1 }
+        ]);
+        assert_eq!(
+            rendered(Rendering::Milestone, milestones),
+            "- **v1.2** (2026-10-01 → 2026-11-01)\n- **v1.1** [archived]: Hotfixes"
+        );
+    }
+
+    #[test]
+    fn test_non_json_is_unchanged() {
+        let result = CallToolResult::success(vec![Content::text("not json")]);
+        assert_eq!(render(result.clone(), Rendering::Wiki), result);
+    }
+}
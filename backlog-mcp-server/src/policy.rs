@@ -0,0 +1,310 @@
+use crate::error::Error;
+use backlog_core::ProjectKey;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::str::FromStr;
+
+/// What the server may do in a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectAccess {
+    None,
+    Read,
+    Write,
+}
+
+/// Access policy loaded from the JSON file named by `BACKLOG_POLICY_FILE`.
+///
+/// The default policy allows everything, so the server behaves as if no file was given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Policy {
+    /// Access by project key, checked to be valid keys.
+    projects: BTreeMap<String, ProjectAccess>,
+    default_project_access: ProjectAccess,
+    allowed_tools: Option<Vec<String>>,
+    denied_tools: Vec<String>,
+    assignable_user_ids: Option<BTreeSet<u32>>,
+    notifiable_user_ids: Option<BTreeSet<u32>>,
+    max_download_bytes: Option<u64>,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            projects: BTreeMap::new(),
+            default_project_access: ProjectAccess::Write,
+            allowed_tools: None,
+            denied_tools: Vec::new(),
+            assignable_user_ids: None,
+            notifiable_user_ids: None,
+            max_download_bytes: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyFile {
+    #[serde(default)]
+    projects: BTreeMap<String, ProjectAccess>,
+    default_project_access: Option<ProjectAccess>,
+    #[serde(default)]
+    tools: ToolRules,
+    #[serde(default)]
+    users: UserRules,
+    max_download_bytes: Option<u64>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ToolRules {
+    allow: Option<Vec<String>>,
+    #[serde(default)]
+    deny: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UserRules {
+    assignable: Option<BTreeSet<u32>>,
+    notifiable: Option<BTreeSet<u32>>,
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let file: PolicyFile =
+            serde_json::from_str(s).map_err(|e| format!("Invalid access policy: {e}"))?;
+
+        let projects = file
+            .projects
+            .into_iter()
+            .map(|(key, access)| {
+                ProjectKey::from_str(key.trim())
+                    .map(|key| (String::from(key), access))
+                    .map_err(|e| format!("Invalid project key '{key}' in access policy: {e}"))
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+        // Listing projects is taken as an allowlist unless a default says otherwise.
+        let default_project_access =
+            file.default_project_access
+                .unwrap_or(if projects.is_empty() {
+                    ProjectAccess::Write
+                } else {
+                    ProjectAccess::None
+                });
+
+        Ok(Self {
+            projects,
+            default_project_access,
+            allowed_tools: file.tools.allow,
+            denied_tools: file.tools.deny,
+            assignable_user_ids: file.users.assignable,
+            notifiable_user_ids: file.users.notifiable,
+            max_download_bytes: file.max_download_bytes,
+        })
+    }
+}
+
+impl Policy {
+    /// Loads the file named by `BACKLOG_POLICY_FILE`, or returns the default policy if unset.
+    pub fn from_env() -> Result<Self, String> {
+        match env::var("BACKLOG_POLICY_FILE") {
+            Ok(path) if !path.trim().is_empty() => Self::load(path.trim()),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read access policy file '{path}': {e}"))?;
+        content
+            .parse()
+            .map_err(|e| format!("{e} (in policy file '{path}')"))
+    }
+
+    pub fn project_access(&self, project_key: &ProjectKey) -> ProjectAccess {
+        self.projects
+            .get(project_key.as_str())
+            .copied()
+            .unwrap_or(self.default_project_access)
+    }
+
+    /// Returns true if some project is given less than `access`.
+    pub fn restricts(&self, access: ProjectAccess) -> bool {
+        self.default_project_access < access || self.projects.values().any(|a| *a < access)
+    }
+
+    /// Projects listed in the policy with at least `access`.
+    pub fn listed_projects(&self, access: ProjectAccess) -> impl Iterator<Item = &str> {
+        self.projects
+            .iter()
+            .filter(move |(_, a)| **a >= access)
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// Returns true if the tool, named without the configured prefix, may be served.
+    /// Deny patterns win over allow patterns; `*` matches any run of characters.
+    pub fn allows_tool(&self, tool_name: &str) -> bool {
+        if self
+            .denied_tools
+            .iter()
+            .any(|pattern| glob_match(pattern, tool_name))
+        {
+            return false;
+        }
+        match &self.allowed_tools {
+            Some(patterns) => patterns
+                .iter()
+                .any(|pattern| glob_match(pattern, tool_name)),
+            None => true,
+        }
+    }
+
+    pub fn check_assignee(&self, user_id: u32) -> Result<(), Error> {
+        check_user(&self.assignable_user_ids, &[user_id], "assigned")
+    }
+
+    pub fn check_notified(&self, user_ids: &[u32]) -> Result<(), Error> {
+        check_user(&self.notifiable_user_ids, user_ids, "notified")
+    }
+
+    /// Checks a file size against the download cap. Call it with the size from the
+    /// attachment metadata before downloading, and again with the downloaded length.
+    pub fn check_download_size(&self, size: u64) -> Result<(), Error> {
+        match self.max_download_bytes {
+            Some(limit) if size > limit => Err(Error::DownloadTooLarge { size, limit }),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn check_user(
+    allowed: &Option<BTreeSet<u32>>,
+    user_ids: &[u32],
+    action: &'static str,
+) -> Result<(), Error> {
+    let Some(allowed) = allowed else {
+        return Ok(());
+    };
+    match user_ids.iter().find(|id| !allowed.contains(id)) {
+        Some(&user_id) => Err(Error::UserNotAllowed {
+            user_id,
+            action,
+            allowed_user_ids: allowed.iter().copied().collect(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<_> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No `*` in the pattern
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(s: &str) -> ProjectKey {
+        ProjectKey::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let policy: Policy = r#"{
+            "projects": { "WEB": "write", "DOCS": "read", "HR": "none" },
+            "default_project_access": "read",
+            "tools": { "allow": ["issue_*", "wiki_*"], "deny": ["*_update"] },
+            "users": { "assignable": [1, 2], "notifiable": [1] },
+            "max_download_bytes": 1024
+        }"#
+        .parse()
+        .unwrap();
+
+        assert_eq!(policy.project_access(&key("WEB")), ProjectAccess::Write);
+        assert_eq!(policy.project_access(&key("DOCS")), ProjectAccess::Read);
+        assert_eq!(policy.project_access(&key("HR")), ProjectAccess::None);
+        assert_eq!(policy.project_access(&key("OTHER")), ProjectAccess::Read);
+        assert!(policy.restricts(ProjectAccess::Read));
+        assert_eq!(
+            policy
+                .listed_projects(ProjectAccess::Read)
+                .collect::<Vec<_>>(),
+            ["DOCS", "WEB"]
+        );
+
+        assert!(policy.check_assignee(2).is_ok());
+        assert!(matches!(
+            policy.check_assignee(3),
+            Err(Error::UserNotAllowed { user_id: 3, .. })
+        ));
+        assert!(policy.check_notified(&[]).is_ok());
+        assert!(policy.check_notified(&[1, 2]).is_err());
+        assert!(policy.check_download_size(1024).is_ok());
+        assert!(matches!(
+            policy.check_download_size(1025),
+            Err(Error::DownloadTooLarge {
+                size: 1025,
+                limit: 1024
+            })
+        ));
+
+        // Listed projects without a default act as an allowlist.
+        let policy: Policy = r#"{ "projects": { "WEB": "read" } }"#.parse().unwrap();
+        assert_eq!(policy.project_access(&key("OTHER")), ProjectAccess::None);
+
+        let policy: Policy = "{}".parse().unwrap();
+        assert_eq!(policy, Policy::default());
+        assert!(!policy.restricts(ProjectAccess::Write));
+
+        assert!("{ \"project\": {} }".parse::<Policy>().is_err());
+        assert!(r#"{ "projects": { "WEB": "admin" } }"#.parse::<Policy>().is_err());
+        assert!(r#"{ "projects": { "web-1": "read" } }"#.parse::<Policy>().is_err());
+    }
+
+    #[test]
+    fn test_allows_tool() {
+        let policy: Policy = r#"{ "tools": { "allow": ["issue_*", "wiki_*"], "deny": ["*_update", "issue_*_download"] } }"#
+            .parse()
+            .unwrap();
+        assert!(policy.allows_tool("issue_details_get"));
+        assert!(policy.allows_tool("wiki_attachment_download"));
+        assert!(!policy.allows_tool("issue_update"));
+        assert!(!policy.allows_tool("issue_attachment_download"));
+        assert!(!policy.allows_tool("git_pr_list_get"));
+
+        let policy: Policy = r#"{ "tools": { "deny": ["issue_comment_add"] } }"#.parse().unwrap();
+        assert!(policy.allows_tool("issue_comment_update"));
+        assert!(!policy.allows_tool("issue_comment_add"));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("issue_get", "issue_get"));
+        assert!(!glob_match("issue_get", "issue_get_more"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(!glob_match("a*b*c", "axxbyy"));
+        assert!(!glob_match("ab*ba", "aba"));
+    }
+}
@@ -54,6 +54,8 @@ pub(crate) async fn get_project_issue_types_tool(
 }
 
 /// Helper function to implement the get_priorities tool.
+/// Priorities are defined for the whole space and hold no project data, so no
+/// project access check applies.
 pub(crate) async fn get_priorities_tool(
     client: Arc<Mutex<BacklogApiClient>>,
     _req: GetPrioritiesRequest,
@@ -217,3 +219,62 @@ fn generate_custom_field_info(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::test_utils::{forbid_secret_project_data, mock_projects, restricted_access};
+    use wiremock::MockServer;
+
+    #[tokio::test]
+    async fn test_project_lists_of_disallowed_project_are_denied() {
+        let mock_server = MockServer::start().await;
+        mock_projects(&mock_server).await;
+        forbid_secret_project_data(&mock_server).await;
+        let client = Arc::new(Mutex::new(
+            BacklogApiClient::new(&mock_server.uri()).unwrap(),
+        ));
+        let access_control = restricted_access();
+
+        for project in ["SECRET", "2"] {
+            let project_id_or_key = project.to_string();
+            let statuses = get_project_status_list_tool(
+                client.clone(),
+                GetProjectStatusListRequest {
+                    project_id_or_key: project_id_or_key.clone(),
+                },
+                &access_control,
+            )
+            .await;
+            assert!(
+                matches!(statuses, Err(Error::ProjectAccessDenied { .. })),
+                "{project}"
+            );
+
+            let issue_types = get_project_issue_types_tool(
+                client.clone(),
+                GetProjectIssueTypesRequest {
+                    project_id_or_key: project_id_or_key.clone(),
+                },
+                &access_control,
+            )
+            .await;
+            assert!(
+                matches!(issue_types, Err(Error::ProjectAccessDenied { .. })),
+                "{project}"
+            );
+
+            let custom_fields = get_custom_field_list_tool(
+                client.clone(),
+                GetCustomFieldListRequest { project_id_or_key },
+                &access_control,
+            )
+            .await;
+            assert!(
+                matches!(custom_fields, Err(Error::ProjectAccessDenied { .. })),
+                "{project}"
+            );
+        }
+    }
+}
@@ -6,6 +6,7 @@ pub struct GetProjectStatusListRequest {
     /// The project ID or project key to retrieve statuses for.
     /// Examples: "MYPROJECTKEY", "123".
     /// Ensure there are no leading or trailing spaces.
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
 }
 
@@ -14,6 +15,7 @@ pub struct GetProjectIssueTypesRequest {
     /// The project ID or project key to retrieve issue types for.
     /// Examples: "MYPROJECTKEY", "123".
     /// Ensure there are no leading or trailing spaces.
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
 }
 
@@ -27,5 +29,6 @@ pub struct GetCustomFieldListRequest {
     /// The project ID or project key to retrieve custom fields for.
     /// Examples: "MYPROJECTKEY", "123".
     /// Ensure there are no leading or trailing spaces.
+    #[serde(deserialize_with = "crate::web_url::project_id_or_key")]
     pub project_id_or_key: String,
 }
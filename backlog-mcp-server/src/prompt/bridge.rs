@@ -0,0 +1,751 @@
+use crate::access_control::AccessControl;
+use crate::error::{Error as McpError, Result};
+use crate::git::{
+    self,
+    request::{GetPullRequestCommentListRequest, GetPullRequestDetailsRequest},
+};
+use crate::issue::{
+    self,
+    request::{GetIssueCommentsRequest, GetIssueDetailsRequest, GetVersionMilestoneListRequest},
+    response_transformer::IssueResponse,
+};
+use crate::policy::Policy;
+use crate::project::{
+    self,
+    request::{GetPrioritiesRequest, GetProjectIssueTypesRequest},
+};
+use crate::toolset::ToolsetConfig;
+use crate::web_url::IdOrUrl;
+use backlog_api_client::client::BacklogApiClient;
+use backlog_api_client::{GetIssueListParamsBuilder, Issue, IssueKey, backlog_issue};
+use backlog_core::Order;
+use backlog_core::activity::Activity;
+use backlog_core::identifier::{Identifier, ProjectId};
+use backlog_core::mention::extract_issue_keys;
+use backlog_user::{GetOwnUserParams, GetUserRecentUpdatesParams};
+use chrono::{Duration, Utc};
+use rmcp::model::{
+    GetPromptResult, JsonObject, Prompt, PromptArgument, PromptMessage, PromptMessageRole,
+};
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+const TRIAGE_ISSUE: &str = "triage_issue";
+const SUMMARIZE_PULL_REQUEST: &str = "summarize_pull_request";
+const RELEASE_NOTES: &str = "release_notes";
+const DAILY_STANDUP: &str = "daily_standup";
+
+/// Tools whose data each prompt reads. A prompt is only offered while all of them are
+/// served. The activity feed of the standup has no tool of its own; it belongs with the
+/// users and mostly reports issue updates.
+const PROMPT_TOOLS: [(&str, &[&str]); 4] = [
+    (
+        TRIAGE_ISSUE,
+        &[
+            "issue_details_get",
+            "issue_comment_list_get",
+            "project_issue_type_list_get",
+            "issue_priority_list_get",
+        ],
+    ),
+    (
+        SUMMARIZE_PULL_REQUEST,
+        &[
+            "git_pr_details_get",
+            "git_pr_comment_list_get",
+            "issue_details_get",
+        ],
+    ),
+    (
+        RELEASE_NOTES,
+        &["issue_milestone_list_get", "issue_list_by_milestone_get"],
+    ),
+    (DAILY_STANDUP, &["user_list_get", "issue_details_get"]),
+];
+
+/// Linked issues beyond this many are left out of a pull request summary.
+const MAX_LINKED_ISSUES: usize = 10;
+/// Issues are fetched a page at a time, the most the API returns.
+const ISSUE_PAGE: u32 = 100;
+/// Activities are fetched a page at a time, the most the API returns.
+const ACTIVITY_PAGE: u32 = 100;
+/// The standup stops paging back after this many activities.
+const MAX_STANDUP_ACTIVITIES: usize = 1000;
+
+/// Returns the tools a prompt reads through, or `None` for an unknown prompt.
+pub(crate) fn prompt_tools(name: &str) -> Option<&'static [&'static str]> {
+    PROMPT_TOOLS
+        .iter()
+        .find(|(prompt, _)| *prompt == name)
+        .map(|(_, tools)| *tools)
+}
+
+/// The prompts whose tools are served.
+pub(crate) fn prompts(toolsets: &ToolsetConfig, policy: &Policy) -> Vec<Prompt> {
+    let argument = |name: &str, description: &str, required: bool| PromptArgument {
+        name: name.to_string(),
+        title: None,
+        description: Some(description.to_string()),
+        required: Some(required),
+    };
+    [
+        Prompt::new(
+            TRIAGE_ISSUE,
+            Some("Triage an issue: suggest its type, priority and next steps"),
+            Some(vec![argument(
+                "issue_key",
+                "The issue key, e.g. PROJ-12",
+                true,
+            )]),
+        ),
+        Prompt::new(
+            SUMMARIZE_PULL_REQUEST,
+            Some("Summarize a pull request, its review discussion and the issues it links to"),
+            Some(vec![
+                argument("project_id_or_key", "The project ID or key", true),
+                argument("repo_id_or_name", "The repository ID or name", true),
+                argument("pr_number", "The pull request number or URL", true),
+            ]),
+        ),
+        Prompt::new(
+            RELEASE_NOTES,
+            Some("Write release notes from the issues of a milestone"),
+            Some(vec![
+                argument("project_id_or_key", "The project ID or key", true),
+                argument("milestone_name", "The milestone name", true),
+            ]),
+        ),
+        Prompt::new(
+            DAILY_STANDUP,
+            Some("Draft a daily standup update from your recent Backlog activity"),
+            Some(vec![argument(
+                "days",
+                "How many days of activity to cover (default: 1)",
+                false,
+            )]),
+        ),
+    ]
+    .into_iter()
+    .filter(|prompt| {
+        prompt_tools(&prompt.name).is_some_and(|tools| toolsets.check_served(tools, policy).is_ok())
+    })
+    .collect()
+}
+
+pub(crate) async fn get_prompt(
+    client: Arc<Mutex<BacklogApiClient>>,
+    name: &str,
+    arguments: Option<JsonObject>,
+    access_control: &AccessControl,
+    toolsets: &ToolsetConfig,
+) -> Result<GetPromptResult> {
+    let tools =
+        prompt_tools(name).ok_or_else(|| McpError::Parameter(format!("Unknown prompt: {name}")))?;
+    toolsets.check_served(tools, access_control.policy())?;
+    let arguments = arguments.unwrap_or_default();
+    let (description, text) = match name {
+        TRIAGE_ISSUE => {
+            let issue_key = required(&arguments, "issue_key")?;
+            let text = triage_issue(client, issue_key, access_control).await?;
+            (format!("Triage {issue_key}"), text)
+        }
+        SUMMARIZE_PULL_REQUEST => {
+            let request = GetPullRequestDetailsRequest {
+                project_id_or_key: required(&arguments, "project_id_or_key")?.to_string(),
+                repo_id_or_name: required(&arguments, "repo_id_or_name")?.to_string(),
+                pr_number: IdOrUrl::Url(required(&arguments, "pr_number")?.to_string()),
+            };
+            let text = summarize_pull_request(client, request, access_control).await?;
+            ("Summarize a pull request".to_string(), text)
+        }
+        RELEASE_NOTES => {
+            let project_id_or_key = required(&arguments, "project_id_or_key")?;
+            let milestone_name = required(&arguments, "milestone_name")?;
+            let text =
+                release_notes(client, project_id_or_key, milestone_name, access_control).await?;
+            (format!("Release notes for {milestone_name}"), text)
+        }
+        DAILY_STANDUP => {
+            let days = match optional(&arguments, "days") {
+                Some(days) => days
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|days| *days > 0)
+                    .ok_or_else(|| {
+                        McpError::Parameter(format!("days must be a positive number: {days}"))
+                    })?,
+                None => 1,
+            };
+            let text = daily_standup(client, days, access_control).await?;
+            ("Daily standup".to_string(), text)
+        }
+        _ => return Err(McpError::Parameter(format!("Unknown prompt: {name}"))),
+    };
+    Ok(GetPromptResult {
+        description: Some(description),
+        messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+    })
+}
+
+fn optional<'a>(arguments: &'a JsonObject, name: &str) -> Option<&'a str> {
+    arguments
+        .get(name)
+        .and_then(|value| value.as_str())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn required<'a>(arguments: &'a JsonObject, name: &str) -> Result<&'a str> {
+    optional(arguments, name)
+        .ok_or_else(|| McpError::Parameter(format!("Missing prompt argument: {name}")))
+}
+
+async fn triage_issue(
+    client: Arc<Mutex<BacklogApiClient>>,
+    issue_key: &str,
+    access_control: &AccessControl,
+) -> Result<String> {
+    let issue = issue::bridge::get_issue_details(
+        client.clone(),
+        GetIssueDetailsRequest {
+            issue_key: issue_key.to_string(),
+        },
+        access_control,
+    )
+    .await?;
+    let mut comments = issue::bridge::get_issue_comments_impl(
+        client.clone(),
+        GetIssueCommentsRequest {
+            issue_id_or_key: issue_key.to_string(),
+            min_id: None,
+            max_id: None,
+            count: Some(100),
+            order: Some(Order::Desc),
+        },
+        access_control,
+    )
+    .await?;
+    comments.reverse();
+    let issue_types = project::bridge::get_project_issue_types_tool(
+        client.clone(),
+        GetProjectIssueTypesRequest {
+            project_id_or_key: issue.project_id.value().to_string(),
+        },
+        access_control,
+    )
+    .await?;
+    let priorities = project::bridge::get_priorities_tool(client, GetPrioritiesRequest {}).await?;
+
+    let mut text = format!(
+        "Triage the Backlog issue {issue_key} below.\n\n\
+         1. Summarize the problem or request in two or three sentences.\n\
+         2. Suggest an issue type and a priority from the available ones, with a short reason.\n\
+         3. List any information that is missing to act on it, as questions for the reporter.\n\
+         4. Point out likely duplicates or related work mentioned in the discussion.\n\
+         5. Propose concrete next steps.\n\n\
+         Do not change the issue; only make suggestions.\n"
+    );
+    let names = |names: Vec<&str>| names.join(", ");
+    let _ = write!(
+        text,
+        "\nAvailable issue types: {}\nAvailable priorities: {}\n",
+        names(issue_types.iter().map(|t| t.name.as_str()).collect()),
+        names(priorities.iter().map(|p| p.name.as_str()).collect()),
+    );
+    push_json(&mut text, "Issue", &IssueResponse::from(issue))?;
+    text.push_str("\n## Comments\n");
+    if comments.is_empty() {
+        text.push_str("\nNo comments yet.\n");
+    }
+    for comment in &comments {
+        push_comment(
+            &mut text,
+            &comment.created_user.name,
+            &comment.created.to_rfc3339(),
+            comment.content.as_deref(),
+            comment.change_log.iter().map(|change| {
+                (
+                    change.field.as_str(),
+                    change.original_value.as_deref(),
+                    change.new_value.as_deref(),
+                )
+            }),
+        );
+    }
+    Ok(text)
+}
+
+/// Issue keys mentioned in `text` whose projects the server may read.
+fn readable_issue_keys(text: &str, access_control: &AccessControl) -> Vec<IssueKey> {
+    extract_issue_keys(text)
+        .into_iter()
+        .map(|mention| mention.issue_key)
+        .filter(|issue_key| {
+            access_control
+                .check_project_access_by_key(issue_key.project_key())
+                .is_ok()
+        })
+        .collect()
+}
+
+async fn summarize_pull_request(
+    client: Arc<Mutex<BacklogApiClient>>,
+    request: GetPullRequestDetailsRequest,
+    access_control: &AccessControl,
+) -> Result<String> {
+    let comments_request = GetPullRequestCommentListRequest {
+        project_id_or_key: request.project_id_or_key.clone(),
+        repo_id_or_name: request.repo_id_or_name.clone(),
+        pr_number: request.pr_number.clone(),
+        min_id: None,
+        max_id: None,
+        count: Some(100),
+        order: Some(Order::Asc),
+    };
+    let pull_request =
+        git::bridge::get_pull_request(client.clone(), request, access_control).await?;
+    let comments = git::bridge::get_pull_request_comment_list_tool(
+        client.clone(),
+        comments_request,
+        access_control,
+    )
+    .await?;
+
+    // The related issue, then every issue key mentioned in the title, branch or description.
+    // Issues of projects the server may not read are left out without a trace.
+    let mut issue_keys: Vec<IssueKey> = Vec::new();
+    if let Some(related) = &pull_request.related_issue {
+        let client_guard = client.lock().await;
+        if let Ok(related) = client_guard
+            .issue()
+            .get_issue(backlog_issue::GetIssueParams::new(related.id))
+            .await
+            && access_control
+                .check_project_access_by_id_async(&related.project_id, &client_guard)
+                .await
+                .is_ok()
+        {
+            issue_keys.push(related.issue_key);
+        }
+    }
+    let mentioned = format!(
+        "{}\n{}\n{}",
+        pull_request.summary,
+        pull_request.branch,
+        pull_request.description.as_deref().unwrap_or_default()
+    );
+    for issue_key in readable_issue_keys(&mentioned, access_control) {
+        if !issue_keys.contains(&issue_key) {
+            issue_keys.push(issue_key);
+        }
+    }
+    let mut linked_issues = Vec::new();
+    let mut unavailable = Vec::new();
+    for issue_key in issue_keys.into_iter().take(MAX_LINKED_ISSUES) {
+        match issue::bridge::get_issue_details(
+            client.clone(),
+            GetIssueDetailsRequest {
+                issue_key: issue_key.to_string(),
+            },
+            access_control,
+        )
+        .await
+        {
+            Ok(issue) => linked_issues.push(IssueResponse::from(issue)),
+            Err(_) => unavailable.push(issue_key.to_string()),
+        }
+    }
+
+    let mut text = format!(
+        "Summarize the Backlog pull request #{} below for a reviewer who has not read it.\n\n\
+         1. Explain what the change does and why, based on the description and the linked issues.\n\
+         2. Summarize the review discussion: decisions made, open questions and requested changes.\n\
+         3. Say whether the linked issues look fully addressed, and what remains.\n\
+         4. Point out risks worth a closer look.\n",
+        pull_request.number.value()
+    );
+    push_json(&mut text, "Pull request", &pull_request)?;
+    text.push_str("\n## Review comments\n");
+    if comments.is_empty() {
+        text.push_str("\nNo comments yet.\n");
+    }
+    for comment in &comments {
+        push_comment(
+            &mut text,
+            &comment.created_user.name,
+            &comment.created.to_rfc3339(),
+            Some(comment.content.as_str()),
+            comment.change_log.iter().map(|change| {
+                (
+                    change.field.as_str(),
+                    change.original_value.as_deref(),
+                    change.new_value.as_deref(),
+                )
+            }),
+        );
+    }
+    push_json(&mut text, "Linked issues", &linked_issues)?;
+    if !unavailable.is_empty() {
+        let _ = writeln!(
+            text,
+            "\nAlso mentioned, but not available: {}",
+            unavailable.join(", ")
+        );
+    }
+    Ok(text)
+}
+
+async fn release_notes(
+    client: Arc<Mutex<BacklogApiClient>>,
+    project_id_or_key: &str,
+    milestone_name: &str,
+    access_control: &AccessControl,
+) -> Result<String> {
+    let milestones = issue::bridge::get_version_milestone_list(
+        client.clone(),
+        GetVersionMilestoneListRequest {
+            project_id_or_key: project_id_or_key.to_string(),
+        },
+        access_control,
+    )
+    .await?;
+    let milestone = issue::bridge::find_milestone_by_name(
+        &milestones,
+        milestone_name,
+        project_id_or_key.parse()?,
+    )?;
+
+    // The project access was checked when listing its milestones
+    let client_guard = client.lock().await;
+    let mut issues: Vec<Issue> = Vec::new();
+    loop {
+        let params = GetIssueListParamsBuilder::default()
+            .project_id(vec![milestone.project_id])
+            .milestone_id(vec![milestone.id])
+            .offset(issues.len() as u32)
+            .count(ISSUE_PAGE)
+            .build()?;
+        let page = client_guard.issue().get_issue_list(params).await?;
+        let last_page = page.len() < ISSUE_PAGE as usize;
+        issues.extend(page);
+        if last_page {
+            break;
+        }
+    }
+    drop(client_guard);
+
+    let mut text = format!(
+        "Write release notes for milestone \"{}\" from the issues below.\n\n\
+         Group the changes by kind (new features, improvements, bug fixes and so on), \
+         one line per change written for users rather than developers, with the issue key \
+         in parentheses. Leave out internal tasks that users would not notice. \
+         After the notes, list separately the issues that are not closed yet, \
+         as they may not be part of the release.\n",
+        milestone.name
+    );
+    if let Some(description) = milestone.description.as_deref().filter(|d| !d.is_empty()) {
+        let _ = writeln!(text, "\nMilestone description: {description}");
+    }
+    if let Some(due) = milestone.release_due_date {
+        let _ = writeln!(text, "Release due date: {}", due.date_naive());
+    }
+    let _ = writeln!(text, "\n## Issues ({})\n", issues.len());
+    for issue in &issues {
+        let _ = write!(
+            text,
+            "- {} [{}] {} (status: {}",
+            issue.issue_key, issue.issue_type.name, issue.summary, issue.status.name
+        );
+        if let Some(resolution) = &issue.resolution {
+            let _ = write!(text, ", resolution: {}", resolution.name);
+        }
+        text.push_str(")\n");
+    }
+    Ok(text)
+}
+
+/// An activity as shown in the prompt, with the project reduced to its key.
+#[derive(Serialize)]
+struct StandupActivity {
+    created: String,
+    project: Option<String>,
+    #[serde(rename = "type")]
+    activity_type: String,
+    content: serde_json::Value,
+}
+
+async fn daily_standup(
+    client: Arc<Mutex<BacklogApiClient>>,
+    days: u32,
+    access_control: &AccessControl,
+) -> Result<String> {
+    let since = Utc::now() - Duration::days(days.into());
+    let client_guard = client.lock().await;
+    let myself = client_guard
+        .user()
+        .get_own_user(GetOwnUserParams::new())
+        .await?;
+    // Activities come newest first; page back until one is older than the period
+    let mut recent: Vec<Activity> = Vec::new();
+    let mut truncated = false;
+    loop {
+        let page = client_guard
+            .user()
+            .get_user_recent_updates(GetUserRecentUpdatesParams {
+                user_id: myself.id,
+                activity_type_ids: None,
+                min_id: None,
+                max_id: recent.last().map(|a| a.id.value() as i64),
+                count: Some(ACTIVITY_PAGE),
+                order: Some(Order::Desc),
+            })
+            .await?;
+        let last_page =
+            page.len() < ACTIVITY_PAGE as usize || page.last().is_some_and(|a| a.created < since);
+        recent.extend(page);
+        if last_page {
+            break;
+        }
+        if recent.len() >= MAX_STANDUP_ACTIVITIES {
+            truncated = true;
+            break;
+        }
+    }
+
+    let mut activities = Vec::new();
+    for activity in recent.into_iter().filter(|a| a.created >= since) {
+        // Activities without a project, such as space-wide ones, say nothing about the work
+        let Some(project_id) = activity.project_id() else {
+            continue;
+        };
+        if access_control
+            .check_project_access_by_id_async(&ProjectId::new(project_id), &client_guard)
+            .await
+            .is_err()
+        {
+            continue;
+        }
+        let json = serde_json::to_value(&activity)?;
+        activities.push(StandupActivity {
+            created: activity.created.to_rfc3339(),
+            project: json["project"]["projectKey"].as_str().map(str::to_string),
+            activity_type: activity
+                .activity_type()
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| activity.type_id.to_string()),
+            content: json["content"].clone(),
+        });
+    }
+    drop(client_guard);
+    activities.reverse();
+
+    let period = if days == 1 {
+        "the last day".to_string()
+    } else {
+        format!("the last {days} days")
+    };
+    let mut text = format!(
+        "Draft a daily standup update for {} from their Backlog activity over {period}, \
+         listed below oldest first.\n\n\
+         Use three short sections: what I did, what I plan to do next, and blockers. \
+         Mention issue keys and pull request numbers, merge related activities into one line, \
+         and infer next steps and blockers only from what the activity shows. \
+         If there is no activity, say so instead of making anything up.\n",
+        myself.name
+    );
+    if truncated {
+        let _ = writeln!(
+            text,
+            "\nOnly the latest {MAX_STANDUP_ACTIVITIES} activities were fetched, so the start of \
+             the period is missing; say so in the update."
+        );
+    }
+    push_json(&mut text, "Activity", &activities)?;
+    Ok(text)
+}
+
+fn push_json<T: Serialize>(text: &mut String, heading: &str, value: &T) -> Result<()> {
+    let _ = write!(
+        text,
+        "\n## {heading}\n\n```json\n{}\n```\n",
+        serde_json::to_string_pretty(value)?
+    );
+    Ok(())
+}
+
+fn push_comment<'a>(
+    text: &mut String,
+    author: &str,
+    created: &str,
+    content: Option<&str>,
+    changes: impl Iterator<Item = (&'a str, Option<&'a str>, Option<&'a str>)>,
+) {
+    let _ = writeln!(text, "\n### {author} at {created}\n");
+    for (field, from, to) in changes {
+        let _ = writeln!(
+            text,
+            "- changed {field}: {} -> {}",
+            from.unwrap_or("(none)"),
+            to.unwrap_or("(none)")
+        );
+    }
+    if let Some(content) = content.filter(|c| !c.trim().is_empty()) {
+        let _ = writeln!(text, "{content}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{ALLOWED, project_json};
+    use backlog_core::ProjectKey;
+    use serde_json::json;
+    use wiremock::{Mock, MockServer, ResponseTemplate, matchers};
+
+    fn user_json() -> serde_json::Value {
+        json!({
+            "id": 1,
+            "userId": "admin",
+            "name": "admin",
+            "roleType": 1,
+            "lang": "ja",
+            "mailAddress": "admin@example.com",
+            "lastLoginTime": "2024-06-20T06:35:39Z"
+        })
+    }
+
+    /// Issue-created activities with the given IDs, newest first, created `hours_ago`.
+    fn activities_json(ids: impl Iterator<Item = u32>, hours_ago: i64) -> serde_json::Value {
+        let created = (Utc::now() - Duration::hours(hours_ago)).to_rfc3339();
+        ids.map(|id| {
+            json!({
+                "id": id,
+                "project": project_json(ALLOWED.0, ALLOWED.1),
+                "type": 1,
+                "content": { "id": id, "key_id": id, "summary": "Work", "description": "" },
+                "notifications": [],
+                "createdUser": user_json(),
+                "created": created
+            })
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_prompt_arguments() {
+        let arguments: JsonObject = serde_json::from_value(serde_json::json!({
+            "issue_key": " PROJ-1 ",
+            "days": "",
+        }))
+        .unwrap();
+        assert_eq!(required(&arguments, "issue_key").unwrap(), "PROJ-1");
+        assert_eq!(optional(&arguments, "days"), None);
+        assert!(required(&arguments, "milestone_name").is_err());
+
+        let names: Vec<String> = prompts(&ToolsetConfig::default(), &Policy::default())
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                TRIAGE_ISSUE,
+                SUMMARIZE_PULL_REQUEST,
+                RELEASE_NOTES,
+                DAILY_STANDUP
+            ]
+        );
+    }
+
+    #[test]
+    fn test_readable_issue_keys_skip_disallowed_projects() {
+        let access_control = AccessControl::with_config(
+            Some(vec![ProjectKey::from_str("PROJ").unwrap()]),
+            Default::default(),
+        );
+        let keys = readable_issue_keys("Fixes PROJ-1, see SECRET-2 and PROJ-3", &access_control);
+        let keys: Vec<String> = keys.iter().map(ToString::to_string).collect();
+        assert_eq!(keys, ["PROJ-1", "PROJ-3"]);
+    }
+
+    #[test]
+    fn test_prompts_follow_served_tools() {
+        let toolsets = ToolsetConfig::parse(Some("issue,project,user"), None).unwrap();
+        let policy: Policy = r#"{ "tools": { "deny": ["issue_milestone_*"] } }"#.parse().unwrap();
+        let names: Vec<String> = prompts(&toolsets, &policy)
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(names, [TRIAGE_ISSUE, DAILY_STANDUP]);
+    }
+
+    #[tokio::test]
+    async fn test_get_prompt_checks_served_tools() {
+        let client = Arc::new(Mutex::new(
+            BacklogApiClient::new("http://127.0.0.1:1").unwrap(),
+        ));
+        let toolsets = ToolsetConfig::parse(Some("issue"), None).unwrap();
+        let result = get_prompt(
+            client,
+            SUMMARIZE_PULL_REQUEST,
+            None,
+            &AccessControl::with_config(None, Policy::default()),
+            &toolsets,
+        )
+        .await;
+        assert!(
+            matches!(result, Err(McpError::ToolNotServed(tool)) if tool == "git_pr_details_get")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_daily_standup_pages_back_to_the_start_of_the_period() {
+        let mock_server = MockServer::start().await;
+        Mock::given(matchers::path("/api/v2/users/myself"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(user_json()))
+            .mount(&mock_server)
+            .await;
+        let activities = "/api/v2/users/1/activities";
+        Mock::given(matchers::path(activities))
+            .and(matchers::query_param_is_missing("maxId"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(activities_json((201..=300).rev(), 1)),
+            )
+            .mount(&mock_server)
+            .await;
+        let mut older = activities_json((151..=200).rev(), 2);
+        let expired = activities_json((101..=150).rev(), 72);
+        older
+            .as_array_mut()
+            .unwrap()
+            .extend(expired.as_array().unwrap().iter().cloned());
+        Mock::given(matchers::path(activities))
+            .and(matchers::query_param("maxId", "201"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(older))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(matchers::path(activities))
+            .and(matchers::query_param("maxId", "101"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let client = Arc::new(Mutex::new(
+            BacklogApiClient::new(&mock_server.uri()).unwrap(),
+        ));
+
+        let text = daily_standup(
+            client,
+            1,
+            &AccessControl::with_config(None, Policy::default()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(text.matches("\"created\"").count(), 150);
+        assert!(!text.contains("Only the latest"));
+    }
+}
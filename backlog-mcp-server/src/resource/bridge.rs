@@ -0,0 +1,536 @@
+use super::uri::{
+    DIRECTORY_TOOLS, DOCUMENT_TEMPLATE, DOCUMENT_TOOLS, FILE_TEMPLATE, ISSUE_TEMPLATE, ISSUE_TOOLS,
+    ResourceUri, WIKI_TEMPLATE, WIKI_TOOLS,
+};
+use crate::access_control::AccessControl;
+use crate::document::{self, request::GetDocumentDetailsRequest};
+use crate::error::{Error as McpError, Result};
+use crate::file::{
+    self,
+    request::{DownloadSharedFileRequest, GetSharedFilesListRequest},
+};
+use crate::file_utils::{FileFormat, FormatDetector};
+use crate::issue::{self, request::GetIssueDetailsRequest};
+use crate::policy::Policy;
+use crate::toolset::ToolsetConfig;
+use crate::web_url::IdOrUrl;
+use crate::wiki::{self, request::GetWikiDetailRequest};
+use backlog_api_client::client::BacklogApiClient;
+use backlog_api_client::{DocumentTreeNode, backlog_project};
+use backlog_core::ProjectKey;
+use backlog_core::identifier::Identifier;
+use backlog_file::{FileContent, SharedFile};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
+use rmcp::model::{
+    AnnotateAble, RawResource, RawResourceTemplate, Resource, ResourceContents, ResourceTemplate,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+const MARKDOWN: &str = "text/markdown";
+const JSON: &str = "application/json";
+/// Shared files are listed a page at a time, the most the API returns.
+const SHARED_FILE_PAGE: u32 = 100;
+
+/// Templates of the resources whose tools are served.
+pub(crate) fn resource_templates(
+    toolsets: &ToolsetConfig,
+    policy: &Policy,
+) -> Vec<ResourceTemplate> {
+    let template = |uri_template: &str, name: &str, description: &str, mime_type: Option<&str>| {
+        RawResourceTemplate {
+            uri_template: uri_template.to_string(),
+            name: name.to_string(),
+            title: None,
+            description: Some(description.to_string()),
+            mime_type: mime_type.map(str::to_string),
+        }
+        .no_annotation()
+    };
+    [
+        (
+            ISSUE_TOOLS,
+            template(
+                ISSUE_TEMPLATE,
+                "issue",
+                "An issue with its description as Markdown, e.g. backlog://issue/PROJ-12",
+                Some(JSON),
+            ),
+        ),
+        (
+            WIKI_TOOLS,
+            template(
+                WIKI_TEMPLATE,
+                "wiki",
+                "A wiki page as Markdown, by its numeric ID",
+                Some(MARKDOWN),
+            ),
+        ),
+        (
+            DOCUMENT_TOOLS,
+            template(
+                DOCUMENT_TEMPLATE,
+                "document",
+                "A document as Markdown",
+                Some(MARKDOWN),
+            ),
+        ),
+        (
+            DIRECTORY_TOOLS,
+            template(
+                FILE_TEMPLATE,
+                "shared file",
+                "A shared file by its path, or a directory listing if the path ends with '/'",
+                None,
+            ),
+        ),
+    ]
+    .into_iter()
+    .filter(|(tools, _)| toolsets.check_served(tools, policy).is_ok())
+    .map(|(_, template)| template)
+    .collect()
+}
+
+/// Lists the documents and top-level shared files of one accessible project per page,
+/// each only while its tools are served. The cursor is the index of the project in key order.
+pub(crate) async fn list_resources(
+    client: Arc<Mutex<BacklogApiClient>>,
+    cursor: Option<&str>,
+    access_control: &AccessControl,
+    toolsets: &ToolsetConfig,
+) -> Result<(Vec<Resource>, Option<String>)> {
+    let index = match cursor {
+        Some(cursor) => cursor
+            .parse::<usize>()
+            .map_err(|_| McpError::Parameter(format!("Invalid cursor: {cursor}")))?,
+        None => 0,
+    };
+
+    let client_guard = client.lock().await;
+    let mut projects: Vec<ProjectKey> = client_guard
+        .project()
+        .get_project_list(backlog_project::GetProjectListParams::default())
+        .await?
+        .into_iter()
+        .map(|project| project.project_key)
+        .filter(|key| access_control.check_project_access_by_key(key).is_ok())
+        .collect();
+    projects.sort_by_key(|key| key.to_string());
+
+    let Some(project_key) = projects.get(index) else {
+        return Ok((Vec::new(), None));
+    };
+    let next_cursor = (index + 1 < projects.len()).then(|| (index + 1).to_string());
+
+    let served = |tools| {
+        toolsets
+            .check_served(tools, access_control.policy())
+            .is_ok()
+    };
+    let mut resources = Vec::new();
+    // Projects without documents or shared files report errors; list what there is.
+    if served(DOCUMENT_TOOLS)
+        && let Ok(tree) = client_guard
+            .document()
+            .get_document_tree(backlog_api_client::GetDocumentTreeParams {
+                project_id_or_key: project_key.clone().into(),
+            })
+            .await
+    {
+        collect_documents(project_key, &tree.active_tree.children, &mut resources);
+    }
+    drop(client_guard);
+    if served(DIRECTORY_TOOLS)
+        && let Ok(files) = list_directory(client, project_key, "", access_control).await
+    {
+        for shared_file in &files {
+            resources.push(shared_file_resource(project_key, shared_file));
+        }
+    }
+
+    Ok((resources, next_cursor))
+}
+
+fn collect_documents(
+    project_key: &ProjectKey,
+    nodes: &[DocumentTreeNode],
+    resources: &mut Vec<Resource>,
+) {
+    for node in nodes {
+        let uri = ResourceUri::Document {
+            project_key: project_key.clone(),
+            document_id: node.id.clone(),
+        };
+        let mut resource = RawResource::new(uri.to_string(), node.name.clone());
+        resource.mime_type = Some(MARKDOWN.to_string());
+        resources.push(resource.no_annotation());
+        collect_documents(project_key, &node.children, resources);
+    }
+}
+
+fn shared_file_resource(project_key: &ProjectKey, shared_file: &SharedFile) -> Resource {
+    let mut path = format!("{}/{}", shared_file.dir.trim_matches('/'), shared_file.name);
+    path = path.trim_start_matches('/').to_string();
+    let (size, mime_type) = match shared_file.content {
+        FileContent::File { size } => (u32::try_from(size).ok(), None),
+        FileContent::Directory => {
+            path.push('/');
+            (None, Some(JSON.to_string()))
+        }
+    };
+    let uri = ResourceUri::File {
+        project_key: project_key.clone(),
+        path,
+    };
+    let mut resource = RawResource::new(uri.to_string(), shared_file.name.clone());
+    resource.size = size;
+    resource.mime_type = mime_type;
+    resource.no_annotation()
+}
+
+pub(crate) async fn read_resource(
+    client: Arc<Mutex<BacklogApiClient>>,
+    uri: &str,
+    access_control: &AccessControl,
+    toolsets: &ToolsetConfig,
+) -> Result<ResourceContents> {
+    let resource = ResourceUri::from_str(uri).map_err(McpError::Parameter)?;
+    toolsets.check_served(resource.tool_names(), access_control.policy())?;
+    // Check the project in the URI first, so nothing is fetched from other projects
+    if let Some(project_key) = resource.project_key() {
+        access_control
+            .check_project_access_by_key_async(project_key)
+            .await?;
+    }
+
+    match resource {
+        ResourceUri::Issue(issue_key) => {
+            let issue = issue::bridge::get_issue_details(
+                client,
+                GetIssueDetailsRequest {
+                    issue_key: issue_key.to_string(),
+                },
+                access_control,
+            )
+            .await?;
+            Ok(text_contents(
+                uri,
+                JSON,
+                serde_json::to_string_pretty(&issue)?,
+            ))
+        }
+        ResourceUri::Wiki(wiki_id) => {
+            let client_guard = client.lock().await;
+            let wiki = wiki::bridge::get_wiki_detail(
+                &client_guard,
+                GetWikiDetailRequest {
+                    wiki_id: IdOrUrl::Id(wiki_id.value().into()),
+                },
+                access_control,
+            )
+            .await?;
+            let name = wiki["name"].as_str().unwrap_or_default();
+            let content = wiki["content"].as_str().unwrap_or_default();
+            Ok(text_contents(
+                uri,
+                MARKDOWN,
+                format!("# {name}\n\n{content}"),
+            ))
+        }
+        ResourceUri::Document {
+            project_key,
+            document_id,
+        } => {
+            let document = document::bridge::get_document_details(
+                client.clone(),
+                GetDocumentDetailsRequest {
+                    document_id: document_id.to_string(),
+                },
+                access_control,
+            )
+            .await?;
+            let client_guard = client.lock().await;
+            let project = access_control
+                .project_cache()
+                .get_by_id(&document.project_id, &client_guard)
+                .await
+                .map_err(|e| McpError::Server(e.to_string()))?;
+            if project.project_key != project_key {
+                return Err(McpError::Parameter(format!(
+                    "Document {document_id} not found in project {project_key}"
+                )));
+            }
+            let markdown = format!("# {}\n\n{}", document.title, document.to_markdown());
+            Ok(text_contents(uri, MARKDOWN, markdown))
+        }
+        ResourceUri::File { project_key, path } => {
+            read_shared_file(client, uri, &project_key, &path, access_control).await
+        }
+    }
+}
+
+async fn read_shared_file(
+    client: Arc<Mutex<BacklogApiClient>>,
+    uri: &str,
+    project_key: &ProjectKey,
+    path: &str,
+    access_control: &AccessControl,
+) -> Result<ResourceContents> {
+    if path.is_empty() || path.ends_with('/') {
+        let files = list_directory(client, project_key, path, access_control).await?;
+        let listing: Vec<_> = files
+            .iter()
+            .map(|shared_file| {
+                let resource = shared_file_resource(project_key, shared_file);
+                serde_json::json!({
+                    "uri": resource.uri,
+                    "name": shared_file.name,
+                    "content": shared_file.content,
+                    "updated": shared_file.updated.unwrap_or(shared_file.created),
+                })
+            })
+            .collect();
+        return Ok(text_contents(
+            uri,
+            JSON,
+            serde_json::to_string_pretty(&listing)?,
+        ));
+    }
+
+    let (directory, name) = path.rsplit_once('/').unwrap_or(("", path));
+    let shared_file = list_directory(client.clone(), project_key, directory, access_control)
+        .await?
+        .into_iter()
+        .find(|f| f.name == name && matches!(f.content, FileContent::File { .. }))
+        .ok_or_else(|| {
+            McpError::Parameter(format!(
+                "Shared file '{path}' not found in project {project_key}"
+            ))
+        })?;
+
+    // The listing gives the size, so an oversized file is refused before downloading it
+    if let FileContent::File { size } = shared_file.content {
+        access_control.policy().check_download_size(size)?;
+    }
+
+    let file = file::bridge::download_shared_file_bridge(
+        client,
+        DownloadSharedFileRequest {
+            project_id_or_key: project_key.to_string(),
+            shared_file_id: shared_file.id.value(),
+            format: None,
+        },
+        access_control,
+    )
+    .await?;
+
+    let mime_type = Some(file.content_type.clone());
+    let format = FormatDetector::detect_format(&file, None)
+        .map_err(|e| McpError::Server(e.message.into_owned()))?;
+    Ok(match format {
+        FileFormat::Text => ResourceContents::TextResourceContents {
+            uri: uri.to_string(),
+            mime_type,
+            text: String::from_utf8_lossy(&file.bytes).into_owned(),
+            meta: None,
+        },
+        FileFormat::Image | FileFormat::Raw => ResourceContents::BlobResourceContents {
+            uri: uri.to_string(),
+            mime_type,
+            blob: BASE64_STANDARD.encode(&file.bytes),
+            meta: None,
+        },
+    })
+}
+
+/// Lists every entry of a shared file directory, following pages.
+async fn list_directory(
+    client: Arc<Mutex<BacklogApiClient>>,
+    project_key: &ProjectKey,
+    directory: &str,
+    access_control: &AccessControl,
+) -> Result<Vec<SharedFile>> {
+    let mut files = Vec::new();
+    loop {
+        let page = file::bridge::get_shared_files_list_tool(
+            client.clone(),
+            GetSharedFilesListRequest {
+                project_id_or_key: project_key.to_string(),
+                path: directory.trim_end_matches('/').to_string(),
+                order: None,
+                offset: Some(files.len() as u32),
+                count: Some(SHARED_FILE_PAGE),
+            },
+            access_control,
+        )
+        .await?;
+        let last_page = page.len() < SHARED_FILE_PAGE as usize;
+        files.extend(page);
+        if last_page {
+            return Ok(files);
+        }
+    }
+}
+
+fn text_contents(uri: &str, mime_type: &str, text: String) -> ResourceContents {
+    ResourceContents::TextResourceContents {
+        uri: uri.to_string(),
+        mime_type: Some(mime_type.to_string()),
+        text,
+        meta: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{forbid_secret_project_data, mock_projects, restricted_access};
+    use serde_json::json;
+    use wiremock::{Mock, MockServer, ResponseTemplate, matchers};
+
+    #[tokio::test]
+    async fn test_resource_list_has_only_allowed_projects() {
+        let mock_server = MockServer::start().await;
+        mock_projects(&mock_server).await;
+        forbid_secret_project_data(&mock_server).await;
+        Mock::given(matchers::path("/api/v2/documents/tree"))
+            .and(matchers::query_param("projectIdOrKey", "ALLOWED"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "projectId": 1,
+                "activeTree": {
+                    "id": "Active",
+                    "children": [{
+                        "id": "doc1",
+                        "name": "Guide",
+                        "updated": "2024-01-01T12:00:00Z",
+                        "children": []
+                    }]
+                },
+                "trashTree": { "id": "Trash", "children": [] }
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(matchers::path("/api/v2/documents/tree"))
+            .and(matchers::query_param("projectIdOrKey", "SECRET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(matchers::path_regex(
+            "^/api/v2/projects/ALLOWED/files/metadata/",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&mock_server)
+        .await;
+        let client = Arc::new(Mutex::new(
+            BacklogApiClient::new(&mock_server.uri()).unwrap(),
+        ));
+
+        let (resources, next_cursor) = list_resources(
+            client,
+            None,
+            &restricted_access(),
+            &ToolsetConfig::default(),
+        )
+        .await
+        .unwrap();
+        let uris: Vec<_> = resources
+            .iter()
+            .map(|resource| resource.uri.as_str())
+            .collect();
+        assert_eq!(uris, ["backlog://document/ALLOWED/doc1"]);
+        assert_eq!(next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_resources_follow_served_tools() {
+        let mock_server = MockServer::start().await;
+        Mock::given(matchers::any())
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let client = Arc::new(Mutex::new(
+            BacklogApiClient::new(&mock_server.uri()).unwrap(),
+        ));
+        let access_control = AccessControl::with_config(None, Policy::default());
+        let toolsets = ToolsetConfig::parse(Some("issue,file"), None).unwrap();
+        let policy: Policy = r#"{ "tools": { "deny": ["file_shared_download"] } }"#
+            .parse()
+            .unwrap();
+
+        let templates: Vec<_> = resource_templates(&toolsets, &policy)
+            .into_iter()
+            .map(|template| template.raw.name)
+            .collect();
+        assert_eq!(templates, ["issue", "shared file"]);
+
+        let access_control = access_control.with_policy(policy);
+        for uri in ["backlog://wiki/34", "backlog://file/PROJ/docs/a.txt"] {
+            let result = read_resource(client.clone(), uri, &access_control, &toolsets).await;
+            assert!(
+                matches!(result, Err(McpError::ToolNotServed(_))),
+                "{uri}: {result:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oversized_shared_file_is_not_downloaded() {
+        let mock_server = MockServer::start().await;
+        mock_projects(&mock_server).await;
+        let user = json!({
+            "id": 1,
+            "userId": "admin",
+            "name": "admin",
+            "roleType": 1,
+            "lang": "ja",
+            "mailAddress": "admin@example.com",
+            "lastLoginTime": "2024-06-20T06:35:39Z"
+        });
+        Mock::given(matchers::path(
+            "/api/v2/projects/ALLOWED/files/metadata/docs",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": 5,
+            "projectId": 1,
+            "type": "file",
+            "dir": "/docs/",
+            "name": "big.bin",
+            "size": 2048,
+            "createdUser": user,
+            "created": "2024-01-01T12:00:00Z"
+        }])))
+        .mount(&mock_server)
+        .await;
+        Mock::given(matchers::path("/api/v2/projects/ALLOWED/files/5"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0; 2048]))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let client = Arc::new(Mutex::new(
+            BacklogApiClient::new(&mock_server.uri()).unwrap(),
+        ));
+        let policy: Policy = r#"{ "max_download_bytes": 1024 }"#.parse().unwrap();
+        let access_control = AccessControl::with_config(None, policy);
+
+        let result = read_resource(
+            client,
+            "backlog://file/ALLOWED/docs/big.bin",
+            &access_control,
+            &ToolsetConfig::default(),
+        )
+        .await;
+        assert!(
+            matches!(
+                result,
+                Err(McpError::DownloadTooLarge {
+                    size: 2048,
+                    limit: 1024
+                })
+            ),
+            "{result:?}"
+        );
+    }
+}
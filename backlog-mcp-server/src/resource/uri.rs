@@ -0,0 +1,188 @@
+use backlog_core::identifier::{DocumentId, Identifier, WikiId};
+use backlog_core::{IssueKey, ProjectKey};
+use std::fmt;
+use std::str::FromStr;
+
+pub(crate) const SCHEME: &str = "backlog://";
+
+/// URI templates of the resources, as advertised to clients.
+pub(crate) const ISSUE_TEMPLATE: &str = "backlog://issue/{issueKey}";
+pub(crate) const WIKI_TEMPLATE: &str = "backlog://wiki/{wikiId}";
+pub(crate) const DOCUMENT_TEMPLATE: &str = "backlog://document/{projectKey}/{documentId}";
+pub(crate) const FILE_TEMPLATE: &str = "backlog://file/{projectKey}/{path}";
+
+/// A `backlog://` resource URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ResourceUri {
+    Issue(IssueKey),
+    Wiki(WikiId),
+    Document {
+        project_key: ProjectKey,
+        document_id: DocumentId,
+    },
+    /// A shared file, or a directory if `path` is empty or ends with `/`
+    File {
+        project_key: ProjectKey,
+        path: String,
+    },
+}
+
+/// Tools whose data the resources read, so a resource is served only with them.
+pub(crate) const ISSUE_TOOLS: &[&str] = &["issue_details_get"];
+pub(crate) const WIKI_TOOLS: &[&str] = &["wiki_details_get"];
+pub(crate) const DOCUMENT_TOOLS: &[&str] = &["document_details_get"];
+pub(crate) const DIRECTORY_TOOLS: &[&str] = &["file_shared_list_get"];
+/// A file is found by listing its directory, then downloaded.
+pub(crate) const FILE_TOOLS: &[&str] = &["file_shared_list_get", "file_shared_download"];
+
+impl ResourceUri {
+    pub(crate) fn tool_names(&self) -> &'static [&'static str] {
+        match self {
+            ResourceUri::Issue(_) => ISSUE_TOOLS,
+            ResourceUri::Wiki(_) => WIKI_TOOLS,
+            ResourceUri::Document { .. } => DOCUMENT_TOOLS,
+            ResourceUri::File { path, .. } if path.is_empty() || path.ends_with('/') => {
+                DIRECTORY_TOOLS
+            }
+            ResourceUri::File { .. } => FILE_TOOLS,
+        }
+    }
+
+    pub(crate) fn project_key(&self) -> Option<&ProjectKey> {
+        match self {
+            ResourceUri::Issue(key) => Some(key.project_key()),
+            ResourceUri::Wiki(_) => None,
+            ResourceUri::Document { project_key, .. } | ResourceUri::File { project_key, .. } => {
+                Some(project_key)
+            }
+        }
+    }
+}
+
+impl FromStr for ResourceUri {
+    type Err = String;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Unknown resource URI: {uri}");
+        let rest = uri.strip_prefix(SCHEME).ok_or_else(invalid)?;
+        let (kind, rest) = rest.split_once('/').ok_or_else(invalid)?;
+
+        match kind {
+            "issue" => rest.parse().map(ResourceUri::Issue).map_err(|_| invalid()),
+            "wiki" => rest
+                .parse::<u32>()
+                .map(|id| ResourceUri::Wiki(WikiId::new(id)))
+                .map_err(|_| invalid()),
+            "document" => {
+                let (project_key, document_id) = rest.split_once('/').ok_or_else(invalid)?;
+                Ok(ResourceUri::Document {
+                    project_key: project_key.parse().map_err(|_| invalid())?,
+                    document_id: document_id.parse().map_err(|_| invalid())?,
+                })
+            }
+            "file" => {
+                let (project_key, path) = rest.split_once('/').unwrap_or((rest, ""));
+                Ok(ResourceUri::File {
+                    project_key: project_key.parse().map_err(|_| invalid())?,
+                    path: decode(path).ok_or_else(invalid)?,
+                })
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for ResourceUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceUri::Issue(key) => write!(f, "{SCHEME}issue/{key}"),
+            ResourceUri::Wiki(id) => write!(f, "{SCHEME}wiki/{}", id.value()),
+            ResourceUri::Document {
+                project_key,
+                document_id,
+            } => write!(f, "{SCHEME}document/{project_key}/{document_id}"),
+            ResourceUri::File { project_key, path } => {
+                write!(f, "{SCHEME}file/{project_key}/{}", encode(path))
+            }
+        }
+    }
+}
+
+/// Percent-encodes a path, keeping the `/` separators.
+fn encode(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~' | b'/') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+fn decode(path: &str) -> Option<String> {
+    let bytes = path.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = path.get(i + 1..i + 3)?;
+            out.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(out).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for uri in [
+            "backlog://issue/PROJ-12",
+            "backlog://wiki/34",
+            "backlog://document/PROJ/0195faa11fcb7aaab4c4005a7ada4b6f",
+            "backlog://file/PROJ/",
+            "backlog://file/PROJ/docs/",
+            "backlog://file/PROJ/docs/Meeting%20notes%20%E8%AD%B0%E4%BA%8B%E9%8C%B2.txt",
+        ] {
+            let parsed: ResourceUri = uri.parse().unwrap();
+            assert_eq!(parsed.to_string(), uri);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "backlog://file/PROJ/docs/a%20b.txt".parse::<ResourceUri>(),
+            Ok(ResourceUri::File {
+                project_key: "PROJ".parse().unwrap(),
+                path: "docs/a b.txt".to_string()
+            })
+        );
+        assert_eq!(
+            "backlog://file/PROJ"
+                .parse::<ResourceUri>()
+                .unwrap()
+                .project_key(),
+            Some(&"PROJ".parse().unwrap())
+        );
+        for invalid in [
+            "https://example.backlog.com/view/PROJ-1",
+            "backlog://issue/not a key",
+            "backlog://issue/PROJ-99999999999",
+            "backlog://wiki/Home",
+            "backlog://document/PROJ",
+            "backlog://pr/PROJ/repo/1",
+            "backlog://file/PROJ/%zz",
+        ] {
+            assert!(invalid.parse::<ResourceUri>().is_err(), "{invalid}");
+        }
+    }
+}
@@ -30,8 +30,8 @@ use crate::{
         self,
         request::{
             AddCommentRequest, DownloadAttachmentRequest, GetAttachmentListRequest,
-            GetIssueDetailsRequest, GetIssuesByMilestoneNameRequest,
-            GetVersionMilestoneListRequest,
+            GetIssueDetailsRequest, GetIssueHistoryRequest, GetIssuesByMilestoneNameRequest,
+            GetIssuesByQueryRequest, GetVersionMilestoneListRequest, SearchIssuesRequest,
         },
     },
     project::{
@@ -41,6 +41,7 @@ use crate::{
             GetProjectStatusListRequest,
         },
     },
+    prompt, resource,
     user::{self, request::GetUserListRequest},
     wiki::{
         self,
@@ -55,15 +56,29 @@ use crate::{
 use crate::wiki::request::UpdateWikiRequest;
 
 use crate::access_control::AccessControl;
+use crate::audit::{AuditConfig, AuditEntry, AuditLog};
 #[cfg(feature = "git_writable")]
 use crate::git::request::AddPullRequestCommentRequest;
+use crate::output::{self, OutputFormat, Rendering};
+use crate::policy::Policy;
+use crate::shaping::{self, ShapeHints, ShapingConfig};
+use crate::toolset::ToolsetConfig;
 use backlog_api_client::client::BacklogApiClient;
 use rmcp::handler::server::router::tool;
 use rmcp::{
-    ErrorData as McpError,
-    handler::server::{tool::ToolRouter, wrapper::Parameters},
-    model::{CallToolResult, Content, ServerCapabilities, ServerInfo},
-    tool, tool_handler, tool_router,
+    ErrorData as McpError, RoleServer,
+    handler::server::{
+        tool::{ToolCallContext, ToolRouter},
+        wrapper::Parameters,
+    },
+    model::{
+        CallToolRequestParam, CallToolResult, Content, GetPromptRequestParam, GetPromptResult,
+        ListPromptsResult, ListResourceTemplatesResult, ListResourcesResult, ListToolsResult,
+        PaginatedRequestParam, ReadResourceRequestParam, ReadResourceResult, ServerCapabilities,
+        ServerInfo,
+    },
+    service::RequestContext,
+    tool, tool_router,
 };
 use std::env;
 use std::sync::Arc;
@@ -73,6 +88,10 @@ use tokio::sync::Mutex;
 pub struct Server {
     client: Arc<Mutex<BacklogApiClient>>,
     access_control: AccessControl,
+    toolsets: ToolsetConfig,
+    audit: Option<AuditLog>,
+    shaping: ShapingConfig,
+    output_format: OutputFormat,
     pub tool_router: ToolRouter<Self>,
 }
 
@@ -91,16 +110,47 @@ impl Server {
 
         let client = BacklogApiClient::new(&base_url)?.with_api_key(api_key);
         let access_control = AccessControl::new()?;
+        let toolsets = ToolsetConfig::from_env()?;
+        eprintln!("{}", toolsets.describe());
+        let audit = AuditConfig::from_env()?.map(AuditLog::open).transpose()?;
+        let shaping = ShapingConfig::from_env()?;
+        let output_format = OutputFormat::from_env()?;
 
         Ok(Self {
             client: Arc::new(Mutex::new(client)),
+            tool_router: Self::create_tool_router(
+                &prefix,
+                &toolsets,
+                access_control.policy(),
+                output_format,
+            ),
             access_control,
-            tool_router: Self::create_tool_router(&prefix),
+            toolsets,
+            audit,
+            shaping,
+            output_format,
         })
     }
 
-    fn create_tool_router(prefix: &str) -> ToolRouter<Self> {
+    fn create_tool_router(
+        prefix: &str,
+        toolsets: &ToolsetConfig,
+        policy: &Policy,
+        output_format: OutputFormat,
+    ) -> ToolRouter<Self> {
         let mut tool_router = Self::tool_router();
+        tool_router
+            .map
+            .retain(|name, _| toolsets.serves(name, policy));
+        for (name, route) in tool_router.map.iter_mut() {
+            let schema = Arc::make_mut(&mut route.attr.input_schema);
+            if shaping::is_shaped_tool(name) {
+                shaping::add_hint_properties(schema);
+            }
+            if Rendering::of_tool(name).is_some() {
+                output::add_format_property(schema, output_format);
+            }
+        }
 
         if prefix.is_empty() {
             return tool_router;
@@ -184,7 +234,7 @@ impl Server {
     }
 
     #[tool(
-        description = "Get details for a specific Backlog document. Returns document title, content as both Markdown ('plain') and ProseMirror JSON ('json'), and metadata. Requires project_id_or_key and document_id."
+        description = "Get details for a specific Backlog document. Returns the document metadata as JSON (including the raw ProseMirror 'json' and 'plain' text), followed by the body rendered as Markdown with tables, checklists, code languages and attachment links preserved. Requires document_id."
     )]
     async fn document_details_get(
         &self,
@@ -196,8 +246,12 @@ impl Server {
             &self.access_control,
         )
         .await?;
+        let markdown = document.to_markdown();
 
-        Ok(CallToolResult::success(vec![Content::json(document)?]))
+        Ok(CallToolResult::success(vec![
+            Content::json(document)?,
+            Content::text(markdown),
+        ]))
     }
 
     #[tool(
@@ -276,6 +330,36 @@ impl Server {
         )?]))
     }
 
+    #[tool(
+        description = "Search issues with a query string such as `project:WEB status:\"In Progress\" assignee:@me type:Bug due<2026-11-01 updated>-7d login`. Names are resolved against the project's master data; unknown names are reported with suggestions."
+    )]
+    async fn issue_list_by_query_get(
+        &self,
+        request: Parameters<GetIssuesByQueryRequest>,
+    ) -> McpResult {
+        let issues = issue::bridge::get_issues_by_query(
+            self.client.clone(),
+            request.0,
+            &self.access_control,
+        )
+        .await?;
+        let issue_responses: Vec<IssueResponse> =
+            issues.into_iter().map(IssueResponse::from).collect();
+        Ok(CallToolResult::success(vec![Content::json(
+            issue_responses,
+        )?]))
+    }
+
+    #[tool(
+        description = "Search issues by project, status, type, category, milestone, version, priority, resolution, assignee, creator, parent/child, attachments, date ranges and keyword. Names or IDs are accepted and resolved against the project's master data; 'assignees': ['@me'] means yourself. Returns a compact list (key, summary, type, status, priority, assignee, milestones, due date, updated) and the total number of matches, for paging with offset and count."
+    )]
+    async fn issue_search(&self, request: Parameters<SearchIssuesRequest>) -> McpResult {
+        let result =
+            issue::bridge::search_issues(self.client.clone(), request.0, &self.access_control)
+                .await?;
+        Ok(CallToolResult::success(vec![Content::json(result)?]))
+    }
+
     #[cfg(feature = "issue_writable")]
     #[tool(
         description = "Update a Backlog issue. Requires issue_id_or_key. Optional: summary, description, status_id, assignee_id, priority_id, due_date, custom fields, etc."
@@ -363,6 +447,20 @@ impl Server {
         Ok(CallToolResult::success(vec![Content::json(attachments)?]))
     }
 
+    #[tool(
+        name = "issue_history_get",
+        description = "Get the chronological field change history of an issue (status, assignee, due date, custom fields, attachments, ...), rebuilt from the change logs of all its comments. Requires issue_id_or_key. Optional: field to only return changes to one field."
+    )]
+    async fn issue_history_get(&self, request: Parameters<GetIssueHistoryRequest>) -> McpResult {
+        let history = issue::bridge::get_issue_history_impl(
+            self.client.clone(),
+            request.0,
+            &self.access_control,
+        )
+        .await?;
+        Ok(CallToolResult::success(vec![Content::json(history)?]))
+    }
+
     #[tool(
         name = "issue_shared_file_list_get",
         description = "Get a list of shared files linked to a specified issue. Requires issue_id_or_key parameter. Returns linked shared file information."
@@ -381,10 +479,15 @@ impl Server {
     }
 
     #[tool(
-        description = "Get a list of users in the space. No required parameters. Returns user information including ID, name, email, and role."
+        description = "Get a list of users in the space. No required parameters. Returns user information including ID, name, email, and role. When project access is restricted, only members of the allowed projects are returned."
     )]
     async fn user_list_get(&self, request: Parameters<GetUserListRequest>) -> McpResult {
-        let users = user::bridge::get_user_list_bridge(self.client.clone(), request.0).await?;
+        let users = user::bridge::get_user_list_bridge(
+            self.client.clone(),
+            request.0,
+            &self.access_control,
+        )
+        .await?;
         Ok(CallToolResult::success(vec![Content::json(users)?]))
     }
 
@@ -583,7 +686,7 @@ impl Server {
     }
 
     #[tool(
-        description = "Get a list of wiki pages. Optional: project_id_or_key (filter by project), keyword (search term). Returns wiki page summaries."
+        description = "Get a list of wiki pages. Optional: project_id_or_key (filter by project), keyword (search term). Returns wiki page summaries from allowed projects only."
     )]
     async fn wiki_list_get(&self, request: Parameters<GetWikiListRequest>) -> McpResult {
         let client = self.client.lock().await;
@@ -657,17 +760,302 @@ impl Server {
     }
 }
 
-#[tool_handler]
+impl Server {
+    /// Routes a call to its tool and shapes the result as the call asks, returning how it
+    /// should be rendered.
+    async fn call_routed(
+        &self,
+        mut request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(CallToolResult, Option<ShapeHints>, Option<Rendering>), McpError> {
+        let hints = shaping::is_shaped_tool(&request.name)
+            .then(|| ShapeHints::take(&mut request.arguments))
+            .transpose()?;
+        let rendering = match Rendering::of_tool(&request.name) {
+            Some(rendering) => OutputFormat::take(&mut request.arguments)?
+                .unwrap_or(self.output_format)
+                .eq(&OutputFormat::Markdown)
+                .then_some(rendering),
+            None => None,
+        };
+        if rendering.is_some() && hints.as_ref().is_some_and(|hints| !hints.fields.is_empty()) {
+            return Err(crate::error::Error::Parameter(
+                "fields only applies to JSON results; set output_format to 'json' to use it."
+                    .to_string(),
+            )
+            .into());
+        }
+
+        let tcc = ToolCallContext::new(self, request, context);
+        let result = self.tool_router.call(tcc).await?;
+        Ok((result, hints, rendering))
+    }
+}
+
 impl rmcp::ServerHandler for Server {
     fn get_info(&self) -> ServerInfo {
-        let instructions = "Backlog MCP Server\n\n\
+        let instructions = format!(
+            "Backlog MCP Server\n\n\
 This server provides tools to interact with Backlog, a project management service.
-"
-        .to_string();
+Issue descriptions, comments and wiki content are exchanged as Markdown, even for projects that use Backlog notation.
+Parameters that take an ID or key also accept a Backlog web URL, such as https://example.backlog.com/view/PROJ-12.
+Read tools accept fields, max_items, max_tokens and cursor to keep large results small; a shaped result ends with a summary giving the next_cursor to continue.
+Tools returning issues, comments, pull requests, wikis or milestones accept output_format 'markdown' for a concise rendering (default: {}).
+{}
+",
+            self.output_format.name(),
+            self.toolsets.describe()
+        );
         ServerInfo {
             instructions: Some(instructions),
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_prompts()
+                .build(),
             ..Default::default()
         }
     }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let audit = self
+            .audit
+            .as_ref()
+            .filter(|audit| audit.covers(&request.name))
+            .map(|audit| (audit, AuditEntry::begin(&request)));
+
+        let (result, hints, rendering) = match self.call_routed(request, context).await {
+            Ok((result, hints, rendering)) => (Ok(result), hints, rendering),
+            Err(e) => (Err(e), None, None),
+        };
+
+        if let Some((audit, entry)) = audit {
+            audit.record(&entry.finish(&result));
+        }
+        // Shaped and rendered after auditing, so the log still picks IDs from the whole JSON result
+        result.map(|result| present(result, hints, rendering, &self.shaping))
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult::with_all_items(self.tool_router.list_all()))
+    }
+
+    async fn list_resources(
+        &self,
+        request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let cursor = request.and_then(|request| request.cursor);
+        let (resources, next_cursor) = resource::bridge::list_resources(
+            self.client.clone(),
+            cursor.as_deref(),
+            &self.access_control,
+            &self.toolsets,
+        )
+        .await?;
+        Ok(ListResourcesResult {
+            resources,
+            next_cursor,
+        })
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult {
+            resource_templates: resource::bridge::resource_templates(
+                &self.toolsets,
+                self.access_control.policy(),
+            ),
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let contents = resource::bridge::read_resource(
+            self.client.clone(),
+            &request.uri,
+            &self.access_control,
+            &self.toolsets,
+        )
+        .await?;
+        Ok(ReadResourceResult {
+            contents: vec![contents],
+        })
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult {
+            prompts: prompt::bridge::prompts(&self.toolsets, self.access_control.policy()),
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        Ok(prompt::bridge::get_prompt(
+            self.client.clone(),
+            &request.name,
+            request.arguments,
+            &self.access_control,
+            &self.toolsets,
+        )
+        .await?)
+    }
+}
+
+/// Shapes and renders a result. Lists are paged before they are rendered, while a single
+/// object is rendered first, so a text cursor moves through the Markdown as a whole.
+fn present(
+    mut result: CallToolResult,
+    hints: Option<ShapeHints>,
+    rendering: Option<Rendering>,
+    config: &ShapingConfig,
+) -> CallToolResult {
+    if let Some(rendering) = rendering
+        && !output::is_list(&result)
+    {
+        result = output::render(result, rendering);
+        return match hints {
+            Some(hints) => shaping::shape(result, &hints, config),
+            None => result,
+        };
+    }
+    if let Some(hints) = hints {
+        result = shaping::shape(result, &hints, config);
+    }
+    match rendering {
+        Some(rendering) => output::render(result, rendering),
+        None => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::toolset::Toolset;
+
+    fn texts(result: &CallToolResult) -> Vec<String> {
+        result
+            .content
+            .iter()
+            .map(|content| content.as_text().unwrap().text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_single_markdown_result_is_rendered_before_shaping() {
+        let wiki = serde_json::json!({ "name": "Setup", "content": "a".repeat(30) });
+        let result = CallToolResult::success(vec![Content::json(wiki).unwrap()]);
+        let markdown = texts(&output::render(result.clone(), Rendering::Wiki)).remove(0);
+        let hints = ShapeHints {
+            cursor: shaping::Cursor::Text(20),
+            ..ShapeHints::default()
+        };
+        let config = ShapingConfig {
+            max_tokens: None,
+            max_text_chars: 20,
+        };
+
+        let shaped = texts(&present(
+            result,
+            Some(hints),
+            Some(Rendering::Wiki),
+            &config,
+        ));
+        let window: String = markdown.chars().skip(20).take(20).collect();
+        assert!(
+            shaped[0].starts_with(&format!("…{window}")),
+            "{}",
+            shaped[0]
+        );
+        assert!(shaped[1].contains("summary"));
+    }
+
+    #[test]
+    fn test_markdown_list_is_paged_before_rendering() {
+        let comments: Vec<_> = (1..=3)
+            .map(|id| serde_json::json!({ "id": id, "content": format!("Comment {id}") }))
+            .collect();
+        let result = CallToolResult::success(vec![Content::json(comments).unwrap()]);
+        let hints = ShapeHints {
+            max_items: Some(2),
+            ..ShapeHints::default()
+        };
+
+        let shaped = present(
+            result,
+            Some(hints),
+            Some(Rendering::Comment),
+            &ShapingConfig::default(),
+        );
+        let texts = texts(&shaped);
+        assert!(texts[0].contains("Comment 2") && !texts[0].contains("Comment 3"));
+        assert!(texts[1].contains("\"next_cursor\":\"item:2\""));
+    }
+
+    #[test]
+    fn test_resource_and_prompt_tools_exist() {
+        use crate::resource::uri::{
+            DIRECTORY_TOOLS, DOCUMENT_TOOLS, FILE_TOOLS, ISSUE_TOOLS, WIKI_TOOLS,
+        };
+
+        let router = Server::tool_router();
+        let prompts = prompt::bridge::prompts(&ToolsetConfig::default(), &Policy::default());
+        let prompt_tools = prompts
+            .iter()
+            .flat_map(|prompt| prompt::bridge::prompt_tools(&prompt.name).unwrap());
+        let resource_tools = [
+            ISSUE_TOOLS,
+            WIKI_TOOLS,
+            DOCUMENT_TOOLS,
+            DIRECTORY_TOOLS,
+            FILE_TOOLS,
+        ]
+        .concat();
+        for name in prompt_tools.chain(resource_tools.iter()) {
+            assert!(router.map.contains_key(*name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_every_tool_belongs_to_a_toolset() {
+        for name in Server::tool_router().map.keys() {
+            assert!(Toolset::of_tool(name).is_some(), "{name}");
+        }
+    }
+
+    #[cfg(all(
+        feature = "issue_writable",
+        feature = "git_writable",
+        feature = "wiki_writable"
+    ))]
+    #[test]
+    fn test_rendered_tools_exist() {
+        let router = Server::tool_router();
+        for (name, _) in Rendering::TOOLS {
+            assert!(router.map.contains_key(name), "{name}");
+        }
+    }
 }
@@ -0,0 +1,718 @@
+use crate::error::{Error, Result};
+use crate::toolset::is_read_tool;
+use crate::util::parse_number;
+use rmcp::model::{CallToolResult, Content, JsonObject, RawContent};
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeSet;
+
+const DEFAULT_MAX_TEXT_CHARS: usize = 2000;
+/// Rough size of a token, which is close for English and errs low for Japanese.
+const BYTES_PER_TOKEN: usize = 4;
+/// Fields of an object result that hold the list to page through, looked up in nested
+/// objects too, such as the `activeTree` of a document tree.
+const LIST_KEYS: [&str; 2] = ["issues", "children"];
+/// Field holding the child items of a tree item; trees are paged in depth-first order.
+const CHILDREN_KEY: &str = "children";
+/// Arguments read by the shaping layer rather than by the tool.
+const HINT_KEYS: [&str; 4] = ["fields", "max_items", "max_tokens", "cursor"];
+
+/// Limits applied to tool results, chosen at startup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapingConfig {
+    /// Token budget for results when a call does not set `max_tokens`; `None` leaves
+    /// results as they are unless a call asks for shaping.
+    pub max_tokens: Option<usize>,
+    /// Longer strings, such as issue descriptions, are cut when a result is shaped.
+    pub max_text_chars: usize,
+}
+
+impl Default for ShapingConfig {
+    fn default() -> Self {
+        Self {
+            max_tokens: None,
+            max_text_chars: DEFAULT_MAX_TEXT_CHARS,
+        }
+    }
+}
+
+impl ShapingConfig {
+    /// Reads `BACKLOG_MAX_RESPONSE_TOKENS` and `BACKLOG_MAX_TEXT_CHARS`.
+    pub fn from_env() -> std::result::Result<Self, String> {
+        let max_tokens = parse_number("BACKLOG_MAX_RESPONSE_TOKENS", 0)?;
+        Ok(Self {
+            max_tokens: (max_tokens > 0).then_some(max_tokens),
+            max_text_chars: parse_number("BACKLOG_MAX_TEXT_CHARS", DEFAULT_MAX_TEXT_CHARS)?.max(1),
+        })
+    }
+}
+
+/// Returns true if the results of the tool can be shaped: reads other than downloads.
+pub(crate) fn is_shaped_tool(tool_name: &str) -> bool {
+    is_read_tool(tool_name) && !tool_name.ends_with("_download")
+}
+
+/// Adds the shaping arguments to the input schema of a tool.
+pub(crate) fn add_hint_properties(schema: &mut JsonObject) {
+    let properties = schema
+        .entry("properties")
+        .or_insert_with(|| Value::Object(Map::new()));
+    let Value::Object(properties) = properties else {
+        return;
+    };
+    properties.insert(
+        "fields".to_string(),
+        json!({
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Only return these fields of each item, e.g. ['issueKey', 'summary', 'status.name']."
+        }),
+    );
+    properties.insert(
+        "max_items".to_string(),
+        json!({
+            "type": "integer",
+            "minimum": 1,
+            "description": "Return at most this many items; the rest can be read with the returned next_cursor. Items of a tree, such as a document tree, are counted in depth-first order and returned with their parents."
+        }),
+    );
+    properties.insert(
+        "max_tokens".to_string(),
+        json!({
+            "type": "integer",
+            "minimum": 1,
+            "description": "Approximate token budget for the result. Items beyond it are left for the next_cursor."
+        }),
+    );
+    properties.insert(
+        "cursor".to_string(),
+        json!({
+            "type": "string",
+            "description": "The next_cursor of a previous call with the same arguments, to continue where it stopped. The result is fetched again and sliced, so it shifts if the data changed in between. A text cursor moves every cut text of a single result by the same offset; text cut inside list items is not continued, so read such an item with its details tool."
+        }),
+    );
+}
+
+/// Where a shaped result continues: at a list item, or at a character of long texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Cursor {
+    #[default]
+    Start,
+    Item(usize),
+    Text(usize),
+}
+
+impl Cursor {
+    fn parse(value: &str) -> Result<Self> {
+        let invalid = || Error::Parameter(format!("Invalid cursor: {value}"));
+        let (kind, index) = value.split_once(':').ok_or_else(invalid)?;
+        let index = index.parse().map_err(|_| invalid())?;
+        match kind {
+            "item" => Ok(Cursor::Item(index)),
+            "text" => Ok(Cursor::Text(index)),
+            _ => Err(invalid()),
+        }
+    }
+
+    fn item(self) -> usize {
+        match self {
+            Cursor::Item(index) => index,
+            _ => 0,
+        }
+    }
+
+    fn text(self) -> usize {
+        match self {
+            Cursor::Text(index) => index,
+            _ => 0,
+        }
+    }
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cursor::Start => f.write_str("item:0"),
+            Cursor::Item(index) => write!(f, "item:{index}"),
+            Cursor::Text(index) => write!(f, "text:{index}"),
+        }
+    }
+}
+
+/// Shaping asked for by one tool call.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ShapeHints {
+    pub fields: Vec<String>,
+    pub max_items: Option<usize>,
+    pub max_tokens: Option<usize>,
+    pub cursor: Cursor,
+}
+
+impl ShapeHints {
+    /// Removes the shaping arguments from a call, leaving those of the tool itself.
+    pub fn take(arguments: &mut Option<JsonObject>) -> Result<Self> {
+        let mut hints = Self::default();
+        let Some(arguments) = arguments else {
+            return Ok(hints);
+        };
+        let mut taken: Map<String, Value> = HINT_KEYS
+            .iter()
+            .filter_map(|key| Some((key.to_string(), arguments.remove(*key)?)))
+            .filter(|(_, value)| !value.is_null())
+            .collect();
+
+        if let Some(fields) = taken.remove("fields") {
+            hints.fields = serde_json::from_value(fields).map_err(|_| {
+                Error::Parameter("fields must be an array of field names".to_string())
+            })?;
+        }
+        hints.max_items = taken
+            .remove("max_items")
+            .map(|v| positive("max_items", v))
+            .transpose()?;
+        hints.max_tokens = taken
+            .remove("max_tokens")
+            .map(|v| positive("max_tokens", v))
+            .transpose()?;
+        if let Some(cursor) = taken.remove("cursor") {
+            let cursor = cursor
+                .as_str()
+                .ok_or_else(|| Error::Parameter("cursor must be a string".to_string()))?;
+            hints.cursor = Cursor::parse(cursor)?;
+        }
+        Ok(hints)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn positive(name: &str, value: Value) -> Result<usize> {
+    value
+        .as_u64()
+        .filter(|n| *n > 0)
+        .map(|n| n as usize)
+        .ok_or_else(|| Error::Parameter(format!("{name} must be a positive integer")))
+}
+
+/// Describes a shaped result, appended as its last content.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Summary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_items: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_item: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub returned_items: Option<usize>,
+    /// Names of the fields whose text was cut.
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub truncated_fields: BTreeSet<String>,
+    pub estimated_tokens: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// Applies field projection, item and token limits and text truncation to a tool result.
+///
+/// A result is returned unchanged when the call gave no hints and it fits the server's
+/// default budget. Otherwise JSON contents are shaped in place and a summary is appended.
+pub fn shape(
+    mut result: CallToolResult,
+    hints: &ShapeHints,
+    config: &ShapingConfig,
+) -> CallToolResult {
+    if result.is_error == Some(true) {
+        return result;
+    }
+    let max_tokens = hints.max_tokens.or(config.max_tokens);
+    if hints.is_empty() && max_tokens.is_none_or(|max| estimate_result(&result) <= max) {
+        return result;
+    }
+
+    let mut shaper = Shaper {
+        hints,
+        max_tokens,
+        // Two characters per token keeps one long body within the budget for most languages.
+        text_limit: max_tokens.map_or(config.max_text_chars, |max| {
+            config.max_text_chars.min(max.saturating_mul(2).max(1))
+        }),
+        summary: Summary::default(),
+        more_text: false,
+    };
+    for content in &mut result.content {
+        let RawContent::Text(text) = &mut content.raw else {
+            continue;
+        };
+        text.text = match serde_json::from_str::<Value>(&text.text) {
+            Ok(value @ (Value::Array(_) | Value::Object(_))) => {
+                shaper.shape_json(value).to_string()
+            }
+            _ => shaper.cut_text("text", &text.text),
+        };
+    }
+
+    let mut summary = shaper.summary;
+    // A text cursor restarts lists at their first item, so it is only offered for single results.
+    if summary.total_items.is_none() && shaper.more_text {
+        let next = hints.cursor.text() + shaper.text_limit;
+        summary.next_cursor = Some(Cursor::Text(next).to_string());
+    }
+    summary.estimated_tokens = estimate_result(&result);
+    if let Ok(content) = Content::json(json!({ "summary": summary })) {
+        result.content.push(content);
+    }
+    result
+}
+
+struct Shaper<'a> {
+    hints: &'a ShapeHints,
+    max_tokens: Option<usize>,
+    text_limit: usize,
+    summary: Summary,
+    /// Some cut text continues past the current window.
+    more_text: bool,
+}
+
+impl Shaper<'_> {
+    fn shape_json(&mut self, value: Value) -> Value {
+        match value {
+            Value::Array(items) => Value::Array(self.shape_list(items, false)),
+            Value::Object(mut object) => {
+                if self.shape_list_in(&mut object) {
+                    Value::Object(object)
+                } else {
+                    let value = project(Value::Object(object), &self.hints.fields);
+                    self.cut_strings(None, value)
+                }
+            }
+            other => other,
+        }
+    }
+
+    /// Pages the first list held under one of [`LIST_KEYS`] in the object or its nested
+    /// objects. Returns false if there is none.
+    fn shape_list_in(&mut self, object: &mut Map<String, Value>) -> bool {
+        let list_key = LIST_KEYS
+            .iter()
+            .find(|key| matches!(object.get(**key), Some(Value::Array(_))));
+        if let Some(key) = list_key {
+            let Some(Value::Array(items)) = object.remove(*key) else {
+                unreachable!("checked above");
+            };
+            let items = self.shape_list(items, *key == CHILDREN_KEY);
+            object.insert(key.to_string(), Value::Array(items));
+            return true;
+        }
+        object.values_mut().any(|value| match value {
+            Value::Object(nested) => self.shape_list_in(nested),
+            _ => false,
+        })
+    }
+
+    /// Keeps the items from the cursor on that fit the item and token limits, always at least one.
+    fn shape_list(&mut self, items: Vec<Value>, tree: bool) -> Vec<Value> {
+        let total = if tree {
+            count_nodes(&items)
+        } else {
+            items.len()
+        };
+        let mut page = Page {
+            first: self.hints.cursor.item().min(total),
+            ..Page::default()
+        };
+        let shaped = self.shape_items(items, tree, &mut page);
+
+        let next = page.first + page.returned;
+        if self.summary.total_items.is_none() {
+            self.summary.total_items = Some(total);
+            self.summary.first_item = Some(page.first);
+            self.summary.returned_items = Some(page.returned);
+            self.summary.next_cursor = (next < total).then(|| Cursor::Item(next).to_string());
+        }
+        shaped
+    }
+
+    /// Walks the items of a list, and of a tree in depth-first order, keeping those of the page.
+    /// Parents of kept tree items are kept as well, so the result stays a tree.
+    fn shape_items(&mut self, items: Vec<Value>, tree: bool, page: &mut Page) -> Vec<Value> {
+        let mut shaped = Vec::new();
+        for mut item in items {
+            if page.full {
+                break;
+            }
+            let children = if tree { take_children(&mut item) } else { None };
+            let index = page.index;
+            page.index += 1;
+
+            // Items before the page are only walked for their children.
+            let kept = index >= page.first;
+            let item = if kept {
+                if self.hints.max_items.is_some_and(|max| page.returned >= max) {
+                    page.full = true;
+                    break;
+                }
+                let item = self.cut_strings(None, project(item, &self.hints.fields));
+                let tokens = estimate(&item.to_string());
+                if page.returned > 0 && self.max_tokens.is_some_and(|max| page.used + tokens > max)
+                {
+                    page.full = true;
+                    break;
+                }
+                page.used += tokens;
+                page.returned += 1;
+                item
+            } else {
+                item
+            };
+
+            let Some(children) = children else {
+                if kept {
+                    shaped.push(item);
+                }
+                continue;
+            };
+            let children = self.shape_items(children, tree, page);
+            let item = match kept {
+                true => item,
+                false if children.is_empty() => continue,
+                false => self.cut_strings(None, project(item, &self.hints.fields)),
+            };
+            shaped.push(with_children(item, children));
+        }
+        shaped
+    }
+
+    fn cut_strings(&mut self, key: Option<&str>, value: Value) -> Value {
+        match value {
+            Value::String(s) => Value::String(self.cut_text(key.unwrap_or("text"), &s)),
+            Value::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|item| self.cut_strings(key, item))
+                    .collect(),
+            ),
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(k, v)| {
+                        let v = self.cut_strings(Some(&k), v);
+                        (k, v)
+                    })
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    /// Returns the window of a long text selected by the text cursor; short texts are kept.
+    fn cut_text(&mut self, field: &str, text: &str) -> String {
+        let len = text.chars().count();
+        if len <= self.text_limit {
+            return text.to_string();
+        }
+        let start = self.hints.cursor.text().min(len);
+        let end = (start + self.text_limit).min(len);
+        let mut window: String = text.chars().skip(start).take(end - start).collect();
+        if start > 0 {
+            window.insert(0, '…');
+        }
+        if end < len {
+            window.push_str(&format!("… ({} more chars)", len - end));
+            self.more_text = true;
+        }
+        self.summary.truncated_fields.insert(field.to_string());
+        window
+    }
+}
+
+/// Position while paging through the items of a list or tree.
+#[derive(Debug, Default)]
+struct Page {
+    /// Index of the first item to return.
+    first: usize,
+    /// Index of the next item visited.
+    index: usize,
+    returned: usize,
+    used: usize,
+    /// The item or token limit is reached.
+    full: bool,
+}
+
+fn take_children(item: &mut Value) -> Option<Vec<Value>> {
+    let Value::Object(object) = item else {
+        return None;
+    };
+    match object.remove(CHILDREN_KEY) {
+        Some(Value::Array(children)) => Some(children),
+        Some(other) => {
+            object.insert(CHILDREN_KEY.to_string(), other);
+            None
+        }
+        None => None,
+    }
+}
+
+fn with_children(mut item: Value, children: Vec<Value>) -> Value {
+    if let Value::Object(object) = &mut item {
+        object.insert(CHILDREN_KEY.to_string(), Value::Array(children));
+    }
+    item
+}
+
+fn count_nodes(items: &[Value]) -> usize {
+    items
+        .iter()
+        .map(|item| match item.get(CHILDREN_KEY) {
+            Some(Value::Array(children)) => 1 + count_nodes(children),
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Keeps only the given dotted field paths of an object, or of each object in an array.
+fn project(value: Value, fields: &[String]) -> Value {
+    if fields.is_empty() {
+        return value;
+    }
+    let paths: Vec<Vec<&str>> = fields.iter().map(|f| f.split('.').collect()).collect();
+    project_paths(value, &paths)
+}
+
+fn project_paths(value: Value, paths: &[Vec<&str>]) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| project_paths(item, paths))
+                .collect(),
+        ),
+        Value::Object(mut object) => {
+            let mut projected = Map::new();
+            for path in paths {
+                let Some((head, _)) = path.split_first() else {
+                    continue;
+                };
+                if projected.contains_key(*head) {
+                    continue;
+                }
+                let Some(field) = object.remove(*head) else {
+                    continue;
+                };
+                let rest: Vec<Vec<&str>> = paths
+                    .iter()
+                    .filter(|p| p.first() == Some(head))
+                    .map(|p| p[1..].to_vec())
+                    .collect();
+                let field = if rest.iter().any(Vec::is_empty) {
+                    field
+                } else {
+                    project_paths(field, &rest)
+                };
+                projected.insert(head.to_string(), field);
+            }
+            Value::Object(projected)
+        }
+        other => other,
+    }
+}
+
+fn estimate(text: &str) -> usize {
+    text.len().div_ceil(BYTES_PER_TOKEN)
+}
+
+fn estimate_result(result: &CallToolResult) -> usize {
+    result
+        .content
+        .iter()
+        .filter_map(|content| content.as_text())
+        .map(|text| estimate(&text.text))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(arguments: Value) -> ShapeHints {
+        ShapeHints::take(&mut arguments.as_object().cloned()).unwrap()
+    }
+
+    fn contents(result: &CallToolResult) -> Vec<Value> {
+        result
+            .content
+            .iter()
+            .map(|content| serde_json::from_str(&content.as_text().unwrap().text).unwrap())
+            .collect()
+    }
+
+    fn issues(count: usize) -> Value {
+        (0..count)
+            .map(|i| json!({ "issueKey": format!("WEB-{i}"), "summary": "Fix", "status": { "id": 1, "name": "Open" } }))
+            .collect()
+    }
+
+    #[test]
+    fn test_take_hints() {
+        let mut arguments = json!({
+            "project_id_or_key": "WEB",
+            "fields": ["issueKey"],
+            "max_items": 10,
+            "cursor": "item:20",
+        })
+        .as_object()
+        .cloned();
+        let hints = ShapeHints::take(&mut arguments).unwrap();
+        assert_eq!(hints.fields, ["issueKey"]);
+        assert_eq!(hints.max_items, Some(10));
+        assert_eq!(hints.max_tokens, None);
+        assert_eq!(hints.cursor, Cursor::Item(20));
+        assert_eq!(
+            Value::Object(arguments.unwrap()),
+            json!({ "project_id_or_key": "WEB" })
+        );
+
+        assert!(call(json!({ "max_tokens": null })).is_empty());
+        assert!(ShapeHints::take(&mut json!({ "max_items": 0 }).as_object().cloned()).is_err());
+        assert!(ShapeHints::take(&mut json!({ "cursor": "page:2" }).as_object().cloned()).is_err());
+    }
+
+    #[test]
+    fn test_unshaped_result_is_unchanged() {
+        let result = CallToolResult::success(vec![Content::json(issues(3)).unwrap()]);
+        let config = ShapingConfig::default();
+        assert_eq!(
+            shape(result.clone(), &ShapeHints::default(), &config),
+            result
+        );
+
+        let config = ShapingConfig {
+            max_tokens: Some(10_000),
+            ..config
+        };
+        assert_eq!(
+            shape(result.clone(), &ShapeHints::default(), &config),
+            result
+        );
+    }
+
+    #[test]
+    fn test_list_paging_and_projection() {
+        let result = CallToolResult::success(vec![Content::json(issues(5)).unwrap()]);
+        let hints = call(
+            json!({ "fields": ["issueKey", "status.name"], "max_items": 2, "cursor": "item:2" }),
+        );
+        let shaped = contents(&shape(result, &hints, &ShapingConfig::default()));
+        assert_eq!(
+            shaped[0],
+            json!([
+                { "issueKey": "WEB-2", "status": { "name": "Open" } },
+                { "issueKey": "WEB-3", "status": { "name": "Open" } },
+            ])
+        );
+        let summary = &shaped[1]["summary"];
+        assert_eq!(summary["total_items"], 5);
+        assert_eq!(summary["first_item"], 2);
+        assert_eq!(summary["returned_items"], 2);
+        assert_eq!(summary["next_cursor"], "item:4");
+    }
+
+    #[test]
+    fn test_token_budget_over_search_result() {
+        let search = json!({ "total": 40, "offset": 0, "issues": issues(40) });
+        let result = CallToolResult::success(vec![Content::json(search).unwrap()]);
+        let config = ShapingConfig {
+            max_tokens: Some(100),
+            ..ShapingConfig::default()
+        };
+        let shaped = contents(&shape(result, &ShapeHints::default(), &config));
+        let returned = shaped[0]["issues"].as_array().unwrap().len();
+        assert!(returned > 0 && returned < 40);
+        assert_eq!(shaped[0]["total"], 40);
+        assert_eq!(
+            shaped[1]["summary"]["next_cursor"],
+            format!("item:{returned}")
+        );
+    }
+
+    #[test]
+    fn test_nested_tree_is_paged_depth_first() {
+        let node =
+            |id: &str, children: Value| json!({ "id": id, "name": id, "children": children });
+        let tree = json!({
+            "projectId": 1,
+            "activeTree": {
+                "id": "Active",
+                "children": [
+                    node("a", json!([node("a1", json!([])), node("a2", json!([]))])),
+                    node("b", json!([node("b1", json!([]))])),
+                ]
+            }
+        });
+        let result = CallToolResult::success(vec![Content::json(tree).unwrap()]);
+        let hints = call(json!({ "fields": ["id"], "max_items": 2, "cursor": "item:2" }));
+        let shaped = contents(&shape(result, &hints, &ShapingConfig::default()));
+        assert_eq!(
+            shaped[0]["activeTree"]["children"],
+            json!([
+                { "id": "a", "children": [{ "id": "a2", "children": [] }] },
+                { "id": "b", "children": [] },
+            ])
+        );
+        let summary = &shaped[1]["summary"];
+        assert_eq!(summary["total_items"], 5);
+        assert_eq!(summary["returned_items"], 2);
+        assert_eq!(summary["next_cursor"], "item:4");
+    }
+
+    #[test]
+    fn test_text_cut_in_list_items_has_no_text_cursor() {
+        let comments = json!([{ "id": 1, "content": "a".repeat(25) }]);
+        let result = CallToolResult::success(vec![Content::json(comments).unwrap()]);
+        let config = ShapingConfig {
+            max_tokens: None,
+            max_text_chars: 10,
+        };
+        let shaped = contents(&shape(result, &call(json!({ "max_items": 5 })), &config));
+        assert_eq!(shaped[1]["summary"]["truncated_fields"], json!(["content"]));
+        assert!(shaped[1]["summary"].get("next_cursor").is_none());
+    }
+
+    #[test]
+    fn test_long_text_continues_with_cursor() {
+        let body = "a".repeat(25);
+        let wiki = json!({ "id": 7, "name": "Setup", "content": body });
+        let result = CallToolResult::success(vec![Content::json(wiki).unwrap()]);
+        let config = ShapingConfig {
+            max_tokens: None,
+            max_text_chars: 10,
+        };
+
+        let shaped = contents(&shape(
+            result.clone(),
+            &call(json!({ "fields": [] })),
+            &config,
+        ));
+        assert!(shaped.len() == 1, "empty hints leave the result unchanged");
+
+        let shaped = contents(&shape(
+            result.clone(),
+            &call(json!({ "cursor": "text:0" })),
+            &config,
+        ));
+        assert_eq!(shaped[0]["name"], "Setup");
+        assert_eq!(
+            shaped[0]["content"],
+            format!("{}… (15 more chars)", "a".repeat(10))
+        );
+        assert_eq!(shaped[1]["summary"]["truncated_fields"], json!(["content"]));
+        assert_eq!(shaped[1]["summary"]["next_cursor"], "text:10");
+
+        let shaped = contents(&shape(
+            result,
+            &call(json!({ "cursor": "text:20" })),
+            &config,
+        ));
+        assert_eq!(shaped[0]["content"], format!("…{}", "a".repeat(5)));
+        assert!(shaped[1]["summary"].get("next_cursor").is_none());
+    }
+}
@@ -0,0 +1,68 @@
+//! Fixtures shared by the bridge tests that check project access.
+
+use crate::access_control::AccessControl;
+use crate::policy::Policy;
+use serde_json::json;
+use wiremock::{Mock, MockServer, ResponseTemplate, matchers};
+
+/// The project the access policy of [`restricted_access`] allows.
+pub(crate) const ALLOWED: (u32, &str) = (1, "ALLOWED");
+/// A project the access policy of [`restricted_access`] does not allow.
+pub(crate) const SECRET: (u32, &str) = (2, "SECRET");
+
+pub(crate) fn project_json(id: u32, key: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "projectKey": key,
+        "name": key,
+        "chartEnabled": false,
+        "subtaskingEnabled": false,
+        "projectLeaderCanEditProjectLeader": false,
+        "useWiki": true,
+        "useFileSharing": false,
+        "useWikiTreeView": false,
+        "useOriginalImageSizeAtWiki": false,
+        "textFormattingRule": "markdown",
+        "archived": false,
+        "displayOrder": 0,
+        "useDevAttributes": false
+    })
+}
+
+/// Serves the [`ALLOWED`] and [`SECRET`] projects, as a list and one by one.
+pub(crate) async fn mock_projects(mock_server: &MockServer) {
+    Mock::given(matchers::path("/api/v2/projects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            project_json(ALLOWED.0, ALLOWED.1),
+            project_json(SECRET.0, SECRET.1)
+        ])))
+        .mount(mock_server)
+        .await;
+    for (id, key) in [ALLOWED, SECRET] {
+        for id_or_key in [id.to_string(), key.to_string()] {
+            Mock::given(matchers::path(format!("/api/v2/projects/{id_or_key}")))
+                .respond_with(ResponseTemplate::new(200).set_body_json(project_json(id, key)))
+                .mount(mock_server)
+                .await;
+        }
+    }
+}
+
+/// Mounts a handler that fails the test if any path under the [`SECRET`] project is requested.
+pub(crate) async fn forbid_secret_project_data(mock_server: &MockServer) {
+    for id_or_key in [SECRET.0.to_string(), SECRET.1.to_string()] {
+        Mock::given(matchers::path_regex(format!(
+            "^/api/v2/projects/{id_or_key}/.+"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(0)
+        .mount(mock_server)
+        .await;
+    }
+}
+
+/// Access control that only allows the [`ALLOWED`] project.
+pub(crate) fn restricted_access() -> AccessControl {
+    let policy: Policy = r#"{ "projects": { "ALLOWED": "read" } }"#.parse().unwrap();
+    AccessControl::with_config(None, policy)
+}
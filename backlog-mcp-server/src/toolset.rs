@@ -0,0 +1,224 @@
+use crate::error::Error;
+use crate::policy::Policy;
+use crate::util::parse_flag;
+use std::collections::BTreeSet;
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+
+/// A group of tools, named after the first segment of the tool names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Toolset {
+    Document,
+    File,
+    Git,
+    Issue,
+    Project,
+    User,
+    Wiki,
+}
+
+impl Toolset {
+    pub const ALL: [Toolset; 7] = [
+        Toolset::Document,
+        Toolset::File,
+        Toolset::Git,
+        Toolset::Issue,
+        Toolset::Project,
+        Toolset::User,
+        Toolset::Wiki,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Toolset::Document => "document",
+            Toolset::File => "file",
+            Toolset::Git => "git",
+            Toolset::Issue => "issue",
+            Toolset::Project => "project",
+            Toolset::User => "user",
+            Toolset::Wiki => "wiki",
+        }
+    }
+
+    /// Returns the toolset of an unprefixed tool name such as `issue_details_get`.
+    pub fn of_tool(tool_name: &str) -> Option<Toolset> {
+        let category = tool_name.split('_').next()?;
+        category.parse().ok()
+    }
+}
+
+impl fmt::Display for Toolset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Toolset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Toolset::ALL
+            .into_iter()
+            .find(|toolset| toolset.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = Toolset::ALL.iter().map(|t| t.name()).collect();
+                format!(
+                    "Unknown toolset '{s}'. Available toolsets: {}",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// Which tools are served, chosen at startup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolsetConfig {
+    toolsets: BTreeSet<Toolset>,
+    read_only: bool,
+}
+
+impl Default for ToolsetConfig {
+    fn default() -> Self {
+        Self {
+            toolsets: Toolset::ALL.into_iter().collect(),
+            read_only: false,
+        }
+    }
+}
+
+impl ToolsetConfig {
+    /// Reads `BACKLOG_TOOLSETS` (comma-separated toolsets, or `all`) and `BACKLOG_READ_ONLY`.
+    pub fn from_env() -> Result<Self, String> {
+        Self::parse(
+            env::var("BACKLOG_TOOLSETS").ok().as_deref(),
+            env::var("BACKLOG_READ_ONLY").ok().as_deref(),
+        )
+    }
+
+    pub fn parse(toolsets: Option<&str>, read_only: Option<&str>) -> Result<Self, String> {
+        let mut config = Self::default();
+        if let Some(value) = toolsets.map(str::trim).filter(|v| !v.is_empty())
+            && !value.eq_ignore_ascii_case("all")
+        {
+            config.toolsets = value
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(Toolset::from_str)
+                .collect::<Result<_, _>>()?;
+        }
+        config.read_only = parse_flag("BACKLOG_READ_ONLY", read_only)?;
+        Ok(config)
+    }
+
+    pub fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn toolsets(&self) -> impl Iterator<Item = Toolset> + '_ {
+        self.toolsets.iter().copied()
+    }
+
+    /// Returns true if the tool, named without the configured prefix, is served.
+    ///
+    /// In read-only mode only tools whose action is `get`, `download` or `search` are
+    /// kept, so a new tool that changes data is left out unless it is named as a read.
+    pub fn allows(&self, tool_name: &str) -> bool {
+        let Some(toolset) = Toolset::of_tool(tool_name) else {
+            return false;
+        };
+        if !self.toolsets.contains(&toolset) {
+            return false;
+        }
+        !self.read_only || is_read_tool(tool_name)
+    }
+
+    /// Returns true if the tool is served: its toolset is selected and the policy allows it.
+    pub fn serves(&self, tool_name: &str, policy: &Policy) -> bool {
+        self.allows(tool_name) && policy.allows_tool(tool_name)
+    }
+
+    /// Fails unless every tool is served. Resources and prompts read the same data as
+    /// these tools, so they are only available while the tools are.
+    pub(crate) fn check_served(&self, tool_names: &[&str], policy: &Policy) -> Result<(), Error> {
+        match tool_names.iter().find(|name| !self.serves(name, policy)) {
+            Some(name) => Err(Error::ToolNotServed(name.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    /// Describes the selection for the server instructions.
+    pub fn describe(&self) -> String {
+        let names: Vec<_> = self.toolsets().map(Toolset::name).collect();
+        let mode = if self.read_only {
+            "read-only"
+        } else {
+            "read-write"
+        };
+        format!("Enabled toolsets: {} ({mode}).", names.join(", "))
+    }
+}
+
+/// Returns true if the tool only reads data, judged by the last part of its name.
+pub(crate) fn is_read_tool(tool_name: &str) -> bool {
+    matches!(
+        tool_name.rsplit('_').next(),
+        Some("get" | "download" | "search")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let all = ToolsetConfig::parse(None, None).unwrap();
+        assert_eq!(all, ToolsetConfig::default());
+        assert_eq!(ToolsetConfig::parse(Some(" all "), Some("")).unwrap(), all);
+
+        let config = ToolsetConfig::parse(Some("Issue, wiki,"), Some("1")).unwrap();
+        assert_eq!(
+            config.toolsets().collect::<Vec<_>>(),
+            [Toolset::Issue, Toolset::Wiki]
+        );
+        assert!(config.read_only());
+        assert_eq!(
+            config.describe(),
+            "Enabled toolsets: issue, wiki (read-only)."
+        );
+
+        assert!(ToolsetConfig::parse(Some("issue,calendar"), None).is_err());
+        assert!(ToolsetConfig::parse(None, Some("maybe")).is_err());
+    }
+
+    #[test]
+    fn test_allows() {
+        let config = ToolsetConfig::parse(Some("issue,git"), Some("true")).unwrap();
+        assert!(config.allows("issue_details_get"));
+        assert!(config.allows("issue_search"));
+        assert!(config.allows("git_pr_attachment_download"));
+        assert!(!config.allows("issue_update"));
+        assert!(!config.allows("git_pr_comment_add"));
+        assert!(!config.allows("wiki_list_get"));
+
+        let config = ToolsetConfig::parse(Some("wiki"), None).unwrap();
+        assert!(config.allows("wiki_update"));
+        assert!(!config.allows("issue_details_get"));
+    }
+
+    #[test]
+    fn test_check_served() {
+        let config = ToolsetConfig::parse(Some("issue,wiki"), None).unwrap();
+        let policy: Policy = r#"{ "tools": { "deny": ["wiki_*"] } }"#.parse().unwrap();
+        assert!(config.serves("issue_details_get", &policy));
+        assert!(!config.serves("wiki_details_get", &policy));
+        assert!(!config.serves("git_pr_details_get", &policy));
+        assert!(config.check_served(&["issue_details_get"], &policy).is_ok());
+        assert!(matches!(
+            config.check_served(&["issue_details_get", "wiki_details_get"], &policy),
+            Err(Error::ToolNotServed(tool)) if tool == "wiki_details_get"
+        ));
+    }
+}
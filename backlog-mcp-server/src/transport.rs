@@ -0,0 +1,249 @@
+//! Serving the MCP server over HTTP, for a shared deployment used by many clients.
+//!
+//! Two transports are available: streamable HTTP at `/mcp`, and the legacy
+//! HTTP+SSE transport (`GET /sse`, `POST /message`) for older clients. Both can
+//! require clients to send `Authorization: Bearer <token>`, and both stop accepting
+//! requests when the shutdown token is cancelled, giving open streams a short grace
+//! period to finish.
+
+use crate::Server;
+use axum::{
+    Router,
+    extract::{Request, State},
+    http::{StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+};
+use rmcp::transport::{
+    sse_server::{SseServer, SseServerConfig},
+    streamable_http_server::{
+        StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager,
+    },
+};
+use std::{io, net::SocketAddr, sync::Arc, time::Duration};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+/// How long open streams may keep running once shutdown has started.
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(10);
+const KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Transport the MCP protocol is served over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum TransportMode {
+    /// Standard input and output, for a server launched by the MCP client
+    #[default]
+    Stdio,
+    /// Streamable HTTP at `/mcp`
+    Http,
+    /// Legacy HTTP+SSE at `/sse` and `/message`
+    Sse,
+}
+
+/// Settings of the HTTP transports.
+#[derive(Debug, Clone)]
+pub struct HttpOptions {
+    pub bind: SocketAddr,
+    /// Token clients must present as `Authorization: Bearer <token>`
+    pub auth_token: Option<String>,
+}
+
+impl HttpOptions {
+    /// Refuses to serve without authentication on anything but a loopback address,
+    /// as every client would act with the server's Backlog API key.
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.auth_token {
+            Some(token) if token.is_empty() => Err("The auth token must not be empty".to_string()),
+            None if !self.bind.ip().is_loopback() => Err(format!(
+                "Refusing to listen on {} without authentication; set BACKLOG_MCP_AUTH_TOKEN",
+                self.bind
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Serves streamable HTTP until `shutdown` is cancelled.
+pub async fn serve_streamable_http(
+    server: Server,
+    options: HttpOptions,
+    shutdown: CancellationToken,
+) -> io::Result<()> {
+    options.validate().map_err(io::Error::other)?;
+
+    let service = StreamableHttpService::new(
+        move || Ok(server.clone()),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig {
+            sse_keep_alive: Some(KEEP_ALIVE),
+            stateful_mode: true,
+        },
+    );
+    let router = Router::new().nest_service("/mcp", service);
+    serve_router(router, &options, shutdown).await
+}
+
+/// Serves the legacy HTTP+SSE transport until `shutdown` is cancelled.
+pub async fn serve_sse(
+    server: Server,
+    options: HttpOptions,
+    shutdown: CancellationToken,
+) -> io::Result<()> {
+    options.validate().map_err(io::Error::other)?;
+
+    let (sse_server, router) = SseServer::new(SseServerConfig {
+        bind: options.bind,
+        sse_path: "/sse".to_string(),
+        post_path: "/message".to_string(),
+        ct: shutdown.child_token(),
+        sse_keep_alive: Some(KEEP_ALIVE),
+    });
+    sse_server.with_service(move || server.clone());
+    serve_router(router, &options, shutdown).await
+}
+
+async fn serve_router(
+    router: Router,
+    options: &HttpOptions,
+    shutdown: CancellationToken,
+) -> io::Result<()> {
+    let router = with_auth(router, options.auth_token.as_deref());
+    let listener = TcpListener::bind(options.bind).await?;
+    eprintln!("Listening on http://{}", listener.local_addr()?);
+
+    let graceful = axum::serve(listener, router).with_graceful_shutdown({
+        let shutdown = shutdown.clone();
+        async move { shutdown.cancelled().await }
+    });
+    tokio::select! {
+        result = graceful => result,
+        _ = async {
+            shutdown.cancelled().await;
+            tokio::time::sleep(SHUTDOWN_GRACE_PERIOD).await;
+        } => {
+            eprintln!("Closing connections still open after the grace period");
+            Ok(())
+        }
+    }
+}
+
+/// Adds bearer token authentication to every route, if a token is configured.
+fn with_auth(router: Router, token: Option<&str>) -> Router {
+    match token {
+        Some(token) => router.layer(middleware::from_fn_with_state(
+            Arc::<str>::from(token),
+            require_bearer_token,
+        )),
+        None => router,
+    }
+}
+
+async fn require_bearer_token(
+    State(token): State<Arc<str>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let presented = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match presented {
+        Some(presented) if constant_time_eq(presented.as_bytes(), token.as_bytes()) => {
+            next.run(request).await
+        }
+        _ => (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "Missing or invalid bearer token",
+        )
+            .into_response(),
+    }
+}
+
+/// Compares without returning early, so the time taken does not reveal how much of
+/// the token was guessed right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Completes on Ctrl-C, or on SIGTERM where available.
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+
+    fn options(bind: &str, token: Option<&str>) -> HttpOptions {
+        HttpOptions {
+            bind: bind.parse().unwrap(),
+            auth_token: token.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_validate_requires_token_off_loopback() {
+        assert!(options("127.0.0.1:8080", None).validate().is_ok());
+        assert!(options("[::1]:8080", None).validate().is_ok());
+        assert!(options("0.0.0.0:8080", None).validate().is_err());
+        assert!(options("0.0.0.0:8080", Some("secret")).validate().is_ok());
+        assert!(options("127.0.0.1:8080", Some("")).validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bearer_token_is_required() {
+        let router = with_auth(
+            Router::new().route("/mcp", get(|| async { "ok" })),
+            Some("secret"),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let shutdown = CancellationToken::new();
+        let server = tokio::spawn({
+            let shutdown = shutdown.clone();
+            async move {
+                axum::serve(listener, router)
+                    .with_graceful_shutdown(async move { shutdown.cancelled().await })
+                    .await
+            }
+        });
+
+        let client = reqwest::Client::new();
+        let url = format!("http://{addr}/mcp");
+        let status = |auth: Option<&'static str>| {
+            let mut request = client.get(&url);
+            if let Some(auth) = auth {
+                request = request.header("Authorization", auth);
+            }
+            async move { request.send().await.unwrap().status() }
+        };
+        assert_eq!(status(None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(Some("Bearer wrong")).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(Some("secret")).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(Some("Bearer secret")).await, StatusCode::OK);
+
+        shutdown.cancel();
+        server.await.unwrap().unwrap();
+    }
+}
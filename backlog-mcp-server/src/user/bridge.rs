@@ -1,16 +1,103 @@
+use crate::access_control::AccessControl;
 use crate::error::Result;
 use crate::user::request::GetUserListRequest;
 use backlog_api_client::client::BacklogApiClient;
 use backlog_core::User;
+use backlog_project::{GetProjectListParams, GetProjectUserListParams};
 use backlog_user::GetUserListParams;
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+/// Lists the users of the space, or only members of allowed projects when
+/// project access is restricted.
 pub(crate) async fn get_user_list_bridge(
     client: Arc<Mutex<BacklogApiClient>>,
     _req: GetUserListRequest,
+    access_control: &AccessControl,
 ) -> Result<Vec<User>> {
     let client_guard = client.lock().await;
-    let param = GetUserListParams::new();
-    Ok(client_guard.user().get_user_list(param).await?)
+    if !access_control.is_enabled() {
+        let param = GetUserListParams::new();
+        return Ok(client_guard.user().get_user_list(param).await?);
+    }
+
+    let projects = client_guard
+        .project()
+        .get_project_list(GetProjectListParams::default())
+        .await?;
+    let mut seen = HashSet::new();
+    let mut users = Vec::new();
+    for project in projects {
+        if access_control
+            .check_project_access_by_key(&project.project_key)
+            .is_err()
+        {
+            continue;
+        }
+        let members = client_guard
+            .project()
+            .get_project_user_list(GetProjectUserListParams::new(project.id))
+            .await?;
+        users.extend(members.into_iter().filter(|user| seen.insert(user.id)));
+    }
+    Ok(users)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_projects, restricted_access};
+    use serde_json::json;
+    use wiremock::{Mock, MockServer, ResponseTemplate, matchers};
+
+    fn user_json(id: u32, name: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "userId": name,
+            "name": name,
+            "roleType": 2,
+            "lang": "ja",
+            "mailAddress": format!("{name}@example.com"),
+            "lastLoginTime": "2024-06-20T06:35:39Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_user_list_only_has_members_of_allowed_projects() {
+        let mock_server = MockServer::start().await;
+        mock_projects(&mock_server).await;
+        Mock::given(matchers::path("/api/v2/projects/1/users"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([user_json(10, "alice"), user_json(11, "bob")])),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(matchers::path("/api/v2/projects/2/users"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([user_json(20, "eve")])))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(matchers::path("/api/v2/users"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([user_json(10, "alice"), user_json(20, "eve")])),
+            )
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = BacklogApiClient::new(&mock_server.uri()).unwrap();
+
+        let users = get_user_list_bridge(
+            Arc::new(Mutex::new(client)),
+            GetUserListRequest::default(),
+            &restricted_access(),
+        )
+        .await
+        .unwrap();
+        let names: Vec<_> = users.iter().map(|user| user.name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob"]);
+    }
 }
@@ -1,4 +1,27 @@
 use std::cmp::Ordering;
+use std::env;
+
+/// Parses an on/off environment variable, which is off when unset or empty.
+pub(crate) fn parse_flag(name: &str, value: Option<&str>) -> Result<bool, String> {
+    match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+        None | Some("" | "0" | "false" | "no" | "off") => Ok(false),
+        Some("1" | "true" | "yes" | "on") => Ok(true),
+        Some(other) => Err(format!(
+            "Invalid {name} value '{other}'. Use 1/true or 0/false"
+        )),
+    }
+}
+
+/// Reads a whole-number environment variable, falling back to `default` when unset or empty.
+pub(crate) fn parse_number<T: std::str::FromStr>(name: &str, default: T) -> Result<T, String> {
+    match env::var(name) {
+        Ok(value) if !value.trim().is_empty() => value
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid {name} value '{value}'. Use a whole number")),
+        _ => Ok(default),
+    }
+}
 
 pub(crate) enum MatchResult<T> {
     Exact(T),
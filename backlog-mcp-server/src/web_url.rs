@@ -0,0 +1,215 @@
+use backlog_core::identifier::{CommentId, PullRequestNumber, WikiId};
+use backlog_core::{BacklogResource, BacklogUrl, Error as CoreError, Result, WikiPage, web_url};
+use rmcp::schemars;
+use serde::{Deserialize, Deserializer};
+use std::str::FromStr;
+
+/// A numeric ID, or a Backlog web URL the ID is taken from.
+///
+/// String parameters (issue keys, project keys, repository names, document IDs)
+/// take URLs through the `deserialize_with` functions below, so the identifier
+/// types in `backlog-core` only ever see bare keys.
+#[derive(Debug, Clone, serde::Deserialize, schemars::JsonSchema)]
+#[serde(untagged)]
+pub enum IdOrUrl {
+    Id(u64),
+    Url(String),
+}
+
+impl IdOrUrl {
+    fn resolve<T>(
+        &self,
+        what: &str,
+        from_id: impl FnOnce(u64) -> Option<T>,
+        component: impl FnOnce(&BacklogResource) -> Option<T>,
+    ) -> Result<T> {
+        let id = match self {
+            IdOrUrl::Id(id) => Some(*id),
+            IdOrUrl::Url(s) => s.trim().parse::<u64>().ok(),
+        };
+        if let Some(id) = id {
+            return from_id(id)
+                .ok_or_else(|| CoreError::InvalidParameter(format!("Invalid {what}: {id}")));
+        }
+        let IdOrUrl::Url(s) = self else {
+            unreachable!("numeric IDs are handled above")
+        };
+        let url = BacklogUrl::from_str(s)?;
+        component(url.resource()).ok_or_else(|| {
+            CoreError::InvalidParameter(format!("URL does not contain a {what}: {s}"))
+        })
+    }
+
+    pub fn pull_request_number(&self) -> Result<PullRequestNumber> {
+        self.resolve(
+            "pull request number",
+            |id| Some(PullRequestNumber::new(id)),
+            BacklogResource::pull_request_number,
+        )
+    }
+
+    pub fn comment_id(&self) -> Result<CommentId> {
+        self.resolve(
+            "comment ID",
+            |id| u32::try_from(id).ok().map(CommentId::new),
+            BacklogResource::comment_id,
+        )
+    }
+
+    /// The wiki page, which may still have to be looked up by name.
+    pub fn wiki_page(&self) -> Result<WikiPage> {
+        self.resolve(
+            "wiki ID",
+            |id| {
+                u32::try_from(id)
+                    .ok()
+                    .map(|id| WikiPage::Id(WikiId::new(id)))
+            },
+            |resource| resource.wiki_page().cloned(),
+        )
+    }
+}
+
+/// Returns `s` unchanged, or the key `component` picks out of it if `s` is a URL.
+fn key_or_url(
+    s: String,
+    what: &str,
+    component: impl FnOnce(&BacklogResource) -> Option<String>,
+) -> std::result::Result<String, String> {
+    if !web_url::is_url(&s) {
+        return Ok(s);
+    }
+    let url = BacklogUrl::from_str(&s).map_err(|e| e.to_string())?;
+    component(url.resource()).ok_or_else(|| format!("URL does not contain a {what}: {s}"))
+}
+
+fn deserialize_key<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    what: &str,
+    component: impl FnOnce(&BacklogResource) -> Option<String>,
+) -> std::result::Result<String, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    key_or_url(s, what, component).map_err(serde::de::Error::custom)
+}
+
+/// Deserializes a project ID or key, taking the key from any URL that names a project.
+pub(crate) fn project_id_or_key<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<String, D::Error> {
+    deserialize_key(deserializer, "project key", |resource| {
+        resource.project_key().map(ToString::to_string)
+    })
+}
+
+/// Like [`project_id_or_key`], for optional parameters.
+pub(crate) fn optional_project_id_or_key<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|s| {
+            key_or_url(s, "project key", |resource| {
+                resource.project_key().map(ToString::to_string)
+            })
+        })
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
+/// Deserializes an issue ID or key, taking the key from an issue URL.
+pub(crate) fn issue_id_or_key<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<String, D::Error> {
+    deserialize_key(deserializer, "issue key", |resource| {
+        resource.issue_key().map(ToString::to_string)
+    })
+}
+
+/// Deserializes a repository ID or name, taking the name from a repository or pull request URL.
+pub(crate) fn repo_id_or_name<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<String, D::Error> {
+    deserialize_key(deserializer, "repository name", |resource| {
+        resource.repository_name().map(ToString::to_string)
+    })
+}
+
+/// Deserializes a document ID, taking it from a document URL.
+pub(crate) fn document_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<String, D::Error> {
+    deserialize_key(deserializer, "document ID", |resource| {
+        resource.document_id().map(ToString::to_string)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers_and_numeric_strings() {
+        let number: IdOrUrl = serde_json::from_value(serde_json::json!(42)).unwrap();
+        assert_eq!(
+            number.pull_request_number().unwrap(),
+            PullRequestNumber::new(42)
+        );
+        let string: IdOrUrl = serde_json::from_value(serde_json::json!("42")).unwrap();
+        assert_eq!(string.comment_id().unwrap(), CommentId::new(42));
+    }
+
+    #[test]
+    fn test_urls() {
+        let url = IdOrUrl::Url("https://acme.backlog.com/view/PROJ-1#comment-7".to_string());
+        assert_eq!(url.comment_id().unwrap(), CommentId::new(7));
+        assert!(url.pull_request_number().is_err());
+
+        let url = IdOrUrl::Url("https://acme.backlog.com/wiki/PROJ/Home".to_string());
+        assert!(matches!(url.wiki_page().unwrap(), WikiPage::Name { name, .. } if name == "Home"));
+
+        let url = IdOrUrl::Url("https://acme.backlog.com/view/PROJ-99999999999#comment-7".into());
+        assert!(url.comment_id().is_err());
+    }
+
+    #[derive(Deserialize)]
+    struct Keys {
+        #[serde(deserialize_with = "issue_id_or_key")]
+        issue_id_or_key: String,
+        #[serde(deserialize_with = "project_id_or_key")]
+        project_id_or_key: String,
+        #[serde(default, deserialize_with = "optional_project_id_or_key")]
+        project: Option<String>,
+    }
+
+    #[test]
+    fn test_keys_from_urls() {
+        let keys: Keys = serde_json::from_value(serde_json::json!({
+            "issue_id_or_key": "https://acme.backlog.com/view/PROJ-1#comment-7",
+            "project_id_or_key": "https://acme.backlog.com/git/PROJ/repo/pullRequests/3",
+        }))
+        .unwrap();
+        assert_eq!(keys.issue_id_or_key, "PROJ-1");
+        assert_eq!(keys.project_id_or_key, "PROJ");
+        assert_eq!(keys.project, None);
+
+        let keys: Keys = serde_json::from_value(serde_json::json!({
+            "issue_id_or_key": "123",
+            "project_id_or_key": "PROJ",
+            "project": "https://acme.backlog.com/projects/OTHER",
+        }))
+        .unwrap();
+        assert_eq!(keys.issue_id_or_key, "123");
+        assert_eq!(keys.project.as_deref(), Some("OTHER"));
+
+        let wrong = serde_json::from_value::<Keys>(serde_json::json!({
+            "issue_id_or_key": "https://acme.backlog.com/projects/PROJ",
+            "project_id_or_key": "PROJ",
+        }));
+        assert!(wrong.is_err());
+
+        let oversized = serde_json::from_value::<Keys>(serde_json::json!({
+            "issue_id_or_key": "https://acme.backlog.com/view/PROJ-99999999999",
+            "project_id_or_key": "PROJ",
+        }));
+        assert!(oversized.is_err());
+    }
+}
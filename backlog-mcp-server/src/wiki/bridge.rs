@@ -1,5 +1,6 @@
 use crate::access_control::AccessControl;
 use crate::error::{Error as McpError, Result};
+use crate::notation;
 use crate::wiki::request::{
     DownloadWikiAttachmentRequest, GetWikiAttachmentListRequest, GetWikiDetailRequest,
     GetWikiListRequest,
@@ -14,8 +15,9 @@ use backlog_wiki::{
     DownloadWikiAttachmentParams, GetWikiAttachmentListParams, GetWikiDetailParams,
 };
 
+use crate::web_url::IdOrUrl;
 use backlog_core::{
-    ProjectKey,
+    ProjectKey, WikiPage,
     identifier::{ProjectId, WikiAttachmentId, WikiId},
 };
 #[cfg(feature = "wiki_writable")]
@@ -42,6 +44,9 @@ pub(crate) async fn get_wiki_list(
                 "Invalid project ID or key: {project_str}. Must be a numeric ID or valid project key.",
             )));
         };
+        access_control
+            .check_project_access_id_or_key_async(&project_id_or_key, client)
+            .await?;
         params = params.project_id_or_key(project_id_or_key);
     }
 
@@ -52,14 +57,51 @@ pub(crate) async fn get_wiki_list(
 
     let wikis = wiki_api.get_wiki_list(params).await?;
 
-    // Check project access for each wiki in the response
-    for wiki in &wikis {
-        access_control
+    // Without a project the list spans the space, so drop pages of projects
+    // that are not allowed rather than failing on them.
+    let mut allowed_wikis = Vec::with_capacity(wikis.len());
+    for wiki in wikis {
+        if access_control
             .check_project_access_by_id_async(&wiki.project_id, client)
-            .await?;
+            .await
+            .is_ok()
+        {
+            allowed_wikis.push(wiki);
+        }
     }
 
-    Ok(serde_json::to_value(wikis)?)
+    Ok(serde_json::to_value(allowed_wikis)?)
+}
+
+/// Resolves a wiki page given by ID or URL to its ID, looking it up by name if needed.
+async fn resolve_wiki_id(
+    client: &BacklogApiClient,
+    wiki: &IdOrUrl,
+    access_control: &AccessControl,
+) -> Result<WikiId> {
+    match wiki.wiki_page()? {
+        WikiPage::Id(id) => Ok(id),
+        WikiPage::Name { project_key, name } => {
+            access_control
+                .check_project_access_by_key_async(&project_key)
+                .await?;
+            let params = GetWikiListParams::new()
+                .project_id_or_key(project_key.clone())
+                .keyword(name.clone());
+            client
+                .wiki()
+                .get_wiki_list(params)
+                .await?
+                .into_iter()
+                .find(|wiki| wiki.name == name)
+                .map(|wiki| wiki.id)
+                .ok_or_else(|| {
+                    McpError::Parameter(format!(
+                        "Wiki page '{name}' not found in project {project_key}."
+                    ))
+                })
+        }
+    }
 }
 
 pub(crate) async fn get_wiki_detail(
@@ -68,9 +110,9 @@ pub(crate) async fn get_wiki_detail(
     access_control: &AccessControl,
 ) -> Result<serde_json::Value> {
     let wiki_api = client.wiki();
-    let wiki_id = WikiId::new(request.wiki_id);
+    let wiki_id = resolve_wiki_id(client, &request.wiki_id, access_control).await?;
 
-    let wiki_detail = wiki_api
+    let mut wiki_detail = wiki_api
         .get_wiki_detail(GetWikiDetailParams::new(wiki_id))
         .await?;
 
@@ -79,6 +121,8 @@ pub(crate) async fn get_wiki_detail(
         .check_project_access_by_id_async(&wiki_detail.project_id, client)
         .await?;
 
+    let rule = notation::formatting_rule(access_control, client, &wiki_detail.project_id).await?;
+    wiki_detail.content = rule.native_to_markdown(&wiki_detail.content);
     Ok(serde_json::to_value(wiki_detail)?)
 }
 
@@ -88,7 +132,7 @@ pub(crate) async fn get_wiki_attachment_list(
     access_control: &AccessControl,
 ) -> Result<serde_json::Value> {
     let wiki_api = client.wiki();
-    let wiki_id = WikiId::new(request.wiki_id);
+    let wiki_id = resolve_wiki_id(client, &request.wiki_id, access_control).await?;
 
     // First get wiki details to check project access
     let wiki_detail = wiki_api
@@ -112,7 +156,7 @@ pub(crate) async fn download_wiki_attachment(
     access_control: &AccessControl,
 ) -> Result<DownloadedFile> {
     let wiki_api = client.wiki();
-    let wiki_id = WikiId::new(request.wiki_id);
+    let wiki_id = resolve_wiki_id(client, &request.wiki_id, access_control).await?;
 
     // First get wiki details to check project access
     let wiki_detail = wiki_api
@@ -124,9 +168,20 @@ pub(crate) async fn download_wiki_attachment(
         .await?;
 
     let attachment_id = WikiAttachmentId::new(request.attachment_id);
+    if let Some(metadata) = wiki_detail
+        .attachments
+        .iter()
+        .find(|attachment| attachment.id == attachment_id)
+    {
+        access_control.policy().check_download_size(metadata.size)?;
+    }
+
     let downloaded_file = wiki_api
         .download_wiki_attachment(DownloadWikiAttachmentParams::new(wiki_id, attachment_id))
         .await?;
+    access_control
+        .policy()
+        .check_download_size(downloaded_file.bytes.len() as u64)?;
 
     Ok(downloaded_file)
 }
@@ -138,7 +193,7 @@ pub(crate) async fn update_wiki(
     access_control: &AccessControl,
 ) -> Result<serde_json::Value> {
     let wiki_api = client.wiki();
-    let wiki_id = WikiId::new(request.wiki_id);
+    let wiki_id = resolve_wiki_id(client, &request.wiki_id, access_control).await?;
 
     // First get wiki details to check project access
     let wiki_detail_before = wiki_api
@@ -146,7 +201,7 @@ pub(crate) async fn update_wiki(
         .await?;
 
     access_control
-        .check_project_access_by_id_async(&wiki_detail_before.project_id, client)
+        .check_project_write_access_by_id_async(&wiki_detail_before.project_id, client)
         .await?;
 
     // Build UpdateWikiParams from request
@@ -156,15 +211,101 @@ pub(crate) async fn update_wiki(
         params = params.name(name);
     }
 
+    let rule =
+        notation::formatting_rule(access_control, client, &wiki_detail_before.project_id).await?;
     if let Some(content) = request.content {
-        params = params.content(content);
+        params = params.content(rule.markdown_to_native(&content));
     }
 
     if let Some(mail_notify) = request.mail_notify {
         params = params.mail_notify(mail_notify);
     }
 
-    let wiki_detail = wiki_api.update_wiki(params).await?;
+    let mut wiki_detail = wiki_api.update_wiki(params).await?;
+    wiki_detail.content = rule.native_to_markdown(&wiki_detail.content);
 
     Ok(serde_json::to_value(wiki_detail)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::test_utils::{mock_projects, restricted_access};
+    use serde_json::json;
+    use wiremock::{Mock, MockServer, ResponseTemplate, matchers};
+
+    fn wiki_json(id: u32, project_id: u32, name: &str) -> serde_json::Value {
+        let user = json!({
+            "id": 1,
+            "userId": "admin",
+            "name": "admin",
+            "roleType": 1,
+            "lang": "ja",
+            "mailAddress": "admin@example.com",
+            "lastLoginTime": "2024-06-20T06:35:39Z"
+        });
+        json!({
+            "id": id,
+            "projectId": project_id,
+            "name": name,
+            "tags": [],
+            "createdUser": user,
+            "created": "2024-01-01T12:00:00Z",
+            "updatedUser": user,
+            "updated": "2024-01-01T12:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_wiki_list_drops_pages_of_disallowed_projects() {
+        let mock_server = MockServer::start().await;
+        mock_projects(&mock_server).await;
+        Mock::given(matchers::path("/api/v2/wikis"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                wiki_json(10, 1, "Home"),
+                wiki_json(20, 2, "Salaries"),
+                wiki_json(11, 1, "Guide")
+            ])))
+            .mount(&mock_server)
+            .await;
+        let client = BacklogApiClient::new(&mock_server.uri()).unwrap();
+
+        let request = GetWikiListRequest {
+            project_id_or_key: None,
+            keyword: None,
+        };
+        let wikis = get_wiki_list(&client, request, &restricted_access())
+            .await
+            .unwrap();
+        let wikis = wikis.as_array().unwrap();
+        assert_eq!(wikis.len(), 2);
+        assert!(wikis.iter().all(|wiki| wiki["projectId"] == 1));
+    }
+
+    #[tokio::test]
+    async fn test_wiki_list_of_disallowed_project_is_denied() {
+        let mock_server = MockServer::start().await;
+        mock_projects(&mock_server).await;
+        Mock::given(matchers::path("/api/v2/wikis"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!([wiki_json(20, 2, "Salaries")])),
+            )
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let client = BacklogApiClient::new(&mock_server.uri()).unwrap();
+
+        for project in ["SECRET", "2"] {
+            let request = GetWikiListRequest {
+                project_id_or_key: Some(project.to_string()),
+                keyword: None,
+            };
+            let result = get_wiki_list(&client, request, &restricted_access()).await;
+            assert!(
+                matches!(result, Err(Error::ProjectAccessDenied { .. })),
+                "{project}"
+            );
+        }
+    }
+}
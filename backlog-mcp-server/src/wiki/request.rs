@@ -1,9 +1,12 @@
+use crate::web_url::IdOrUrl;
 use rmcp::schemars;
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub(crate) struct GetWikiDetailRequest {
-    #[schemars(description = "Wiki page ID to retrieve details for. Must be a positive integer.")]
-    pub wiki_id: u32,
+    #[schemars(
+        description = "Wiki page ID to retrieve details for, or a wiki page URL such as 'https://example.backlog.com/wiki/PROJ/Home'."
+    )]
+    pub wiki_id: IdOrUrl,
 }
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
@@ -12,6 +15,10 @@ pub(crate) struct GetWikiListRequest {
         description = "Optional project ID or project key to filter wiki pages. Examples: \"MYPROJECTKEY\", \"123\"."
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::web_url::optional_project_id_or_key"
+    )]
     pub project_id_or_key: Option<String>,
     #[schemars(description = "Optional keyword to search for in wiki page names or content.")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -21,17 +28,17 @@ pub(crate) struct GetWikiListRequest {
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub(crate) struct GetWikiAttachmentListRequest {
     #[schemars(
-        description = "Wiki page ID to retrieve attachments for. Must be a positive integer."
+        description = "Wiki page ID to retrieve attachments for, or a wiki page URL such as 'https://example.backlog.com/wiki/PROJ/Home'."
     )]
-    pub wiki_id: u32,
+    pub wiki_id: IdOrUrl,
 }
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub(crate) struct DownloadWikiAttachmentRequest {
     #[schemars(
-        description = "Wiki page ID to download attachment from. Must be a positive integer."
+        description = "Wiki page ID to download attachment from, or a wiki page URL such as 'https://example.backlog.com/wiki/PROJ/Home'."
     )]
-    pub wiki_id: u32,
+    pub wiki_id: IdOrUrl,
     #[schemars(description = "Attachment ID to download. Must be a positive integer.")]
     pub attachment_id: u32,
     #[schemars(
@@ -44,12 +51,14 @@ pub(crate) struct DownloadWikiAttachmentRequest {
 #[cfg(feature = "wiki_writable")]
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub(crate) struct UpdateWikiRequest {
-    #[schemars(description = "Wiki page ID to update. Must be a positive integer.")]
-    pub wiki_id: u32,
+    #[schemars(
+        description = "Wiki page ID to update, or a wiki page URL such as 'https://example.backlog.com/wiki/PROJ/Home'."
+    )]
+    pub wiki_id: IdOrUrl,
     #[schemars(description = "Optional new page name.")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[schemars(description = "Optional new page content.")]
+    #[schemars(description = "Optional new page content, in Markdown.")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
     #[schemars(description = "Optional whether to send email notification of update.")]
@@ -1,3 +1,4 @@
+use backlog_core::Order;
 use clap::Parser;
 
 #[cfg(feature = "project")]
@@ -25,8 +26,8 @@ pub enum ActivityCommands {
         count: Option<u32>,
 
         /// Sort order (asc or desc)
-        #[clap(long)]
-        order: Option<String>,
+        #[clap(long, value_enum)]
+        order: Option<Order>,
     },
     #[cfg(feature = "space")]
     /// Get recent activities in the space
@@ -40,7 +41,7 @@ pub enum ActivityCommands {
         count: Option<u32>,
 
         /// Sort order (asc or desc)
-        #[clap(long)]
-        order: Option<String>,
+        #[clap(long, value_enum)]
+        order: Option<Order>,
     },
 }
@@ -0,0 +1,82 @@
+use backlog_api_client::{GetDocumentParams, client::BacklogApiClient, prosemirror};
+use backlog_core::identifier::DocumentId;
+use blg::url_args::parse_document_id;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::error::Error;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Parser)]
+pub struct DocumentArgs {
+    #[clap(subcommand)]
+    pub command: DocumentCommands,
+}
+
+#[derive(Subcommand)]
+pub enum DocumentCommands {
+    /// Export a document body as Markdown, HTML, plain text or ProseMirror JSON
+    Export {
+        /// Document ID
+        #[arg(value_parser = parse_document_id)]
+        document_id: String,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "markdown")]
+        format: ExportFormat,
+
+        /// Write to a file instead of stdout
+        #[arg(short, long, value_name = "FILE_PATH")]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+    Plain,
+    Json,
+}
+
+pub async fn execute(client: &BacklogApiClient, args: DocumentArgs) -> Result<(), Box<dyn Error>> {
+    match args.command {
+        DocumentCommands::Export {
+            document_id,
+            format,
+            output,
+        } => {
+            let document_id = DocumentId::from_str(document_id.trim())?;
+            let document = client
+                .document()
+                .get_document(GetDocumentParams::new(document_id))
+                .await?;
+
+            // Attachment links point at the API download URL of the configured space.
+            let mut options = document.render_options();
+            if let Ok(base_url) = std::env::var("BACKLOG_BASE_URL") {
+                options = options.base_url(base_url);
+            }
+            let body = match format {
+                ExportFormat::Markdown => document
+                    .content()
+                    .map(|node| prosemirror::to_markdown(&node, &options))
+                    .unwrap_or_else(|_| document.plain.clone()),
+                ExportFormat::Html => document
+                    .content()
+                    .map(|node| prosemirror::to_html(&node, &options))
+                    .unwrap_or_else(|_| document.to_html()),
+                ExportFormat::Plain => document.plain.clone(),
+                ExportFormat::Json => serde_json::to_string_pretty(&document.json)?,
+            };
+
+            match output {
+                Some(path) => {
+                    tokio::fs::write(&path, body).await?;
+                    eprintln!("Exported '{}' to {}", document.title, path.display());
+                }
+                None => println!("{body}"),
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,9 @@
+#[cfg(feature = "document")]
+pub mod document;
 #[cfg(feature = "rate-limit")]
 pub mod rate_limit;
+#[cfg(all(feature = "issue", feature = "project", feature = "user"))]
+pub mod report;
 #[cfg(feature = "star")]
 pub mod star;
 #[cfg(feature = "team")]
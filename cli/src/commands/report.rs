@@ -0,0 +1,102 @@
+use backlog_api_client::backlog_issue::analytics::{DEFAULT_MAX_ISSUES, FlowOptions, FlowReport};
+use backlog_api_client::client::BacklogApiClient;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::error::Error;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+pub struct ReportArgs {
+    #[clap(subcommand)]
+    pub command: ReportCommands,
+}
+
+#[derive(Subcommand)]
+pub enum ReportCommands {
+    /// Flow metrics from status changes: time in status, lead time, cycle time
+    /// and weekly throughput
+    Flow {
+        /// Issue search query selecting the issues, e.g. 'project:WEB milestone:"Sprint 3"'
+        query: String,
+
+        /// Status that marks work as started (repeatable) [default: "In Progress", 処理中]
+        #[arg(long = "started-status")]
+        started_statuses: Vec<String>,
+
+        /// Status that marks an issue as closed (repeatable) [default: Closed, 完了]
+        #[arg(long = "closed-status")]
+        closed_statuses: Vec<String>,
+
+        /// Analyze at most this many matching issues, as each costs a history request
+        #[arg(long, default_value_t = DEFAULT_MAX_ISSUES)]
+        max_issues: usize,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "json")]
+        format: FlowFormat,
+
+        /// Write the report to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum FlowFormat {
+    /// The full report
+    Json,
+    /// One row per issue
+    Csv,
+    /// Issues closed per week
+    ThroughputCsv,
+}
+
+pub async fn execute(client: &BacklogApiClient, args: ReportArgs) -> Result<(), Box<dyn Error>> {
+    match args.command {
+        ReportCommands::Flow {
+            query,
+            started_statuses,
+            closed_statuses,
+            max_issues,
+            format,
+            output,
+        } => {
+            let mut options = FlowOptions::default();
+            if !started_statuses.is_empty() {
+                options.started_statuses = started_statuses;
+            }
+            if !closed_statuses.is_empty() {
+                options.closed_statuses = closed_statuses;
+            }
+            options.max_issues = Some(max_issues);
+
+            let params = client.compile_issue_query(&query).await?;
+            let report = client.issue().get_flow_report(params, &options).await?;
+            if report.truncated {
+                eprintln!(
+                    "Warning: more than {max_issues} issues matched; only the first {max_issues} were analyzed (raise --max-issues to include more)"
+                );
+            }
+            let text = format_flow_report(&report, format)?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, text)?;
+                    eprintln!(
+                        "Wrote flow report for {} issues to {}",
+                        report.total_issues,
+                        path.display()
+                    );
+                }
+                None => print!("{text}"),
+            }
+            Ok(())
+        }
+    }
+}
+
+fn format_flow_report(report: &FlowReport, format: FlowFormat) -> serde_json::Result<String> {
+    Ok(match format {
+        FlowFormat::Json => serde_json::to_string_pretty(report)? + "\n",
+        FlowFormat::Csv => report.issues_csv(),
+        FlowFormat::ThroughputCsv => report.throughput_csv(),
+    })
+}
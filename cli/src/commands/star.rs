@@ -1,7 +1,9 @@
 #[cfg(all(feature = "star", feature = "star_writable"))]
 use backlog_api_client::AddStarParams;
 #[cfg(feature = "star")]
-use backlog_api_client::StarApi;
+use backlog_api_client::client::BacklogApiClient;
+#[cfg(feature = "star_writable")]
+use blg::url_args::{parse_comment_id, parse_issue_id_or_key, parse_pr_comment_id, parse_wiki_id};
 use clap::{Args, Subcommand};
 
 #[derive(Args)]
@@ -25,19 +27,23 @@ pub enum StarCommands {
 pub enum StarTarget {
     /// Add star to an issue
     Issue {
-        /// Issue ID
-        issue_id: u32,
+        /// Issue ID, key or URL
+        #[clap(value_parser = parse_issue_id_or_key)]
+        issue_id: String,
     },
     /// Add star to a comment
     Comment {
-        /// Issue ID
-        issue_id: u32,
-        /// Comment ID
+        /// Issue ID, key or URL
+        #[clap(value_parser = parse_issue_id_or_key)]
+        issue_id: String,
+        /// Comment ID or URL
+        #[clap(value_parser = parse_comment_id)]
         comment_id: u32,
     },
     /// Add star to a wiki page
     Wiki {
-        /// Wiki ID
+        /// Wiki ID or URL
+        #[clap(value_parser = parse_wiki_id)]
         wiki_id: u32,
     },
     /// Add star to a pull request
@@ -47,27 +53,36 @@ pub enum StarTarget {
     },
     /// Add star to a pull request comment
     PrComment {
-        /// Pull request comment ID
+        /// Pull request comment ID or URL
+        #[clap(value_parser = parse_pr_comment_id)]
         pr_comment_id: u32,
     },
 }
 
 #[cfg(feature = "star")]
-pub async fn handle_star_command(api: &StarApi, command: &StarCommands) -> anyhow::Result<()> {
+pub async fn handle_star_command(
+    client: &BacklogApiClient,
+    command: &StarCommands,
+) -> anyhow::Result<()> {
     match command {
         #[cfg(feature = "star_writable")]
-        StarCommands::Add { target } => handle_add_star(api, target).await,
+        StarCommands::Add { target } => handle_add_star(client, target).await,
     }
 }
 
 #[cfg(feature = "star_writable")]
-async fn handle_add_star(api: &StarApi, target: &StarTarget) -> anyhow::Result<()> {
+async fn handle_add_star(client: &BacklogApiClient, target: &StarTarget) -> anyhow::Result<()> {
+    let resolve_issue_id = async |issue_id: &str| {
+        crate::resolve_issue_id(client, issue_id)
+            .await
+            .map_err(|e| anyhow::anyhow!("{e}"))
+    };
     let params = match target {
-        StarTarget::Issue { issue_id } => AddStarParams::issue(*issue_id),
+        StarTarget::Issue { issue_id } => AddStarParams::issue(resolve_issue_id(issue_id).await?),
         StarTarget::Comment {
             issue_id,
             comment_id,
-        } => AddStarParams::comment(*issue_id, *comment_id),
+        } => AddStarParams::comment(resolve_issue_id(issue_id).await?, *comment_id),
         StarTarget::Wiki { wiki_id } => AddStarParams::wiki(*wiki_id),
         StarTarget::Pr { pr_id } => AddStarParams::pull_request(*pr_id),
         StarTarget::PrComment { pr_comment_id } => {
@@ -75,7 +90,7 @@ async fn handle_add_star(api: &StarApi, target: &StarTarget) -> anyhow::Result<(
         }
     };
 
-    api.add_star(params).await?;
+    client.star().add_star(params).await?;
     println!("Star added successfully");
     Ok(())
 }
@@ -1,4 +1,5 @@
-use backlog_api_client::{ListTeamsOrder, ListTeamsParams, ListTeamsResponse, TeamApi};
+use backlog_api_client::{ListTeamsParams, ListTeamsResponse, TeamApi};
+use backlog_core::Order;
 use backlog_core::{id::TeamId, identifier::Identifier};
 use backlog_team::api::{GetTeamIconParams, GetTeamParams};
 use clap::{Parser, ValueEnum};
@@ -23,7 +24,7 @@ pub enum TeamCommands {
     List {
         /// Sort order
         #[clap(short, long, value_enum)]
-        order: Option<CliListTeamsOrder>,
+        order: Option<Order>,
 
         /// Number of items to skip
         #[clap(short = 's', long)]
@@ -49,12 +50,6 @@ pub enum TeamCommands {
     },
 }
 
-#[derive(Debug, Clone, Copy, ValueEnum)]
-pub enum CliListTeamsOrder {
-    Asc,
-    Desc,
-}
-
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum OutputFormat {
     Table,
@@ -104,10 +99,7 @@ pub async fn handle_team_command(api: TeamApi, args: TeamArgs) {
             format,
         } => {
             let params = ListTeamsParams {
-                order: order.map(|o| match o {
-                    CliListTeamsOrder::Asc => ListTeamsOrder::Asc,
-                    CliListTeamsOrder::Desc => ListTeamsOrder::Desc,
-                }),
+                order,
                 offset,
                 count,
             };
@@ -1,5 +1,6 @@
 use backlog_core::IssueIdOrKey;
 use backlog_core::identifier::WatchingId;
+use blg::url_args;
 use clap::{Args, Subcommand};
 
 #[derive(Args, Debug, Clone)]
@@ -40,6 +41,7 @@ pub struct GetWatchingArgs {
 #[derive(Args, Debug, Clone)]
 pub struct AddWatchingArgs {
     /// The ID or key of the issue to watch
+    #[arg(value_parser = url_args::parse_issue_id_or_key)]
     pub issue: String,
 
     /// Optional note for the watching
@@ -3,6 +3,7 @@ use backlog_core::{
     ProjectIdOrKey,
     id::{ActivityTypeId, WebhookId},
 };
+use blg::url_args;
 use clap::{Parser, Subcommand, ValueEnum};
 use prettytable::{Cell, Row, Table, row};
 use std::error::Error;
@@ -19,7 +20,7 @@ pub enum WebhookCommands {
     #[clap(alias = "ls")]
     List {
         /// Project ID or key
-        #[arg(short, long)]
+        #[arg(short, long, value_parser = url_args::parse_project_id_or_key)]
         project: String,
 
         /// Output format
@@ -29,7 +30,7 @@ pub enum WebhookCommands {
     /// Get a specific webhook
     Get {
         /// Project ID or key
-        #[arg(short, long)]
+        #[arg(short, long, value_parser = url_args::parse_project_id_or_key)]
         project: String,
 
         /// Webhook ID
@@ -44,7 +45,7 @@ pub enum WebhookCommands {
     #[cfg(feature = "webhook_writable")]
     Add {
         /// Project ID or key
-        #[arg(short, long)]
+        #[arg(short, long, value_parser = url_args::parse_project_id_or_key)]
         project: String,
 
         /// Webhook name
@@ -71,7 +72,7 @@ pub enum WebhookCommands {
     #[cfg(feature = "webhook_writable")]
     Update {
         /// Project ID or key
-        #[arg(short, long)]
+        #[arg(short, long, value_parser = url_args::parse_project_id_or_key)]
         project: String,
 
         /// Webhook ID to update
@@ -103,7 +104,7 @@ pub enum WebhookCommands {
     #[clap(alias = "rm")]
     Delete {
         /// Project ID or key
-        #[arg(short, long)]
+        #[arg(short, long, value_parser = url_args::parse_project_id_or_key)]
         project: String,
 
         /// Webhook ID to delete
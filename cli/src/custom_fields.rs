@@ -1,7 +1,10 @@
 use backlog_core::identifier::{CustomFieldId, CustomFieldItemId};
+use backlog_domain_models::CustomFieldType;
+use backlog_issue::custom_field_names::{CustomFieldNameError, resolve_custom_field_names};
 use backlog_issue::models::CustomFieldInput;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
 use thiserror::Error;
@@ -51,6 +54,17 @@ pub enum CustomFieldError {
 
     #[error("Failed to read file: {0}")]
     FileReadError(#[from] std::io::Error),
+
+    #[error(
+        "{0}\nRun `blg project custom-field-schema PROJECT` to see the field and option names."
+    )]
+    Name(#[from] CustomFieldNameError),
+
+    #[error(
+        "Custom fields given by name need the project's definitions: {}",
+        names.join(", ")
+    )]
+    UnresolvedNames { names: Vec<String> },
 }
 
 /// Custom field specification in JSON
@@ -234,20 +248,65 @@ pub fn parse_custom_field_arg(
     Ok((CustomFieldId::new(id), input))
 }
 
-/// Parse custom fields from JSON file
-/// Expected format:
+/// Custom field values from the command line or a JSON file.
+///
+/// Values keyed by field ID are ready to send. Values keyed by field name need
+/// the project's definitions, see [`CustomFieldValues::resolve`].
+#[derive(Debug, Clone, Default)]
+pub struct CustomFieldValues {
+    pub by_id: HashMap<CustomFieldId, CustomFieldInput>,
+    pub by_name: HashMap<String, Value>,
+}
+
+impl CustomFieldValues {
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty() && self.by_name.is_empty()
+    }
+
+    /// Resolve the values keyed by name against the project's definitions.
+    pub fn resolve(
+        self,
+        definitions: &[CustomFieldType],
+    ) -> Result<HashMap<CustomFieldId, CustomFieldInput>, CustomFieldError> {
+        let mut result = self.by_id;
+        result.extend(resolve_custom_field_names(definitions, self.by_name)?);
+        Ok(result)
+    }
+
+    /// Get the values, failing if any of them still needs resolving by name.
+    pub fn into_ids(self) -> Result<HashMap<CustomFieldId, CustomFieldInput>, CustomFieldError> {
+        if self.by_name.is_empty() {
+            return Ok(self.by_id);
+        }
+        let mut names: Vec<String> = self.by_name.into_keys().collect();
+        names.sort();
+        Err(CustomFieldError::UnresolvedNames { names })
+    }
+}
+
+impl From<HashMap<CustomFieldId, CustomFieldInput>> for CustomFieldValues {
+    fn from(by_id: HashMap<CustomFieldId, CustomFieldInput>) -> Self {
+        CustomFieldValues {
+            by_id,
+            by_name: HashMap::new(),
+        }
+    }
+}
+
+/// Read custom fields from a JSON file
+///
+/// Entries with a `type` are keyed by field ID and use item IDs; any other
+/// entry is keyed by field name and uses option names. Both can be mixed, and
+/// a `$schema` key is ignored.
 /// {
+///   "$schema": "./custom_fields.schema.json",
 ///   "1": {"type": "text", "value": "Sample text"},
-///   "2": {"type": "numeric", "value": 123.45},
-///   "3": {"type": "date", "value": "2024-06-24"},
 ///   "4": {"type": "single_list", "id": 100, "other_value": "Other"},
-///   "5": {"type": "multiple_list", "ids": [100, 200], "other_value": "Other"},
-///   "6": {"type": "checkbox", "ids": [10, 20, 30]},
-///   "7": {"type": "radio", "id": 400, "other_value": "Other"}
+///   "Severity": "High",
+///   "Components": ["Backend", "Frontend"],
+///   "Due": "2024-06-24"
 /// }
-pub fn parse_custom_fields_json(
-    path: &str,
-) -> Result<HashMap<CustomFieldId, CustomFieldInput>, CustomFieldError> {
+pub fn read_custom_fields_json(path: &str) -> Result<CustomFieldValues, CustomFieldError> {
     let content = fs::read_to_string(path).map_err(|e| {
         if e.kind() == std::io::ErrorKind::NotFound {
             CustomFieldError::FileNotFound {
@@ -257,27 +316,52 @@ pub fn parse_custom_fields_json(
             CustomFieldError::FileReadError(e)
         }
     })?;
+    let invalid_json = |e: serde_json::Error| CustomFieldError::InvalidJson {
+        path: path.to_string(),
+        error: e.to_string(),
+    };
 
-    let specs: HashMap<String, CustomFieldSpec> =
-        serde_json::from_str(&content).map_err(|e| CustomFieldError::InvalidJson {
-            path: path.to_string(),
-            error: e.to_string(),
-        })?;
+    let entries: serde_json::Map<String, Value> =
+        serde_json::from_str(&content).map_err(invalid_json)?;
 
-    let mut result = HashMap::new();
-    for (id_str, spec) in specs {
-        let id = id_str
+    let mut result = CustomFieldValues::default();
+    for (key, value) in entries {
+        if key == "$schema" {
+            continue;
+        }
+        if value.get("type").is_none() {
+            result.by_name.insert(key, value);
+            continue;
+        }
+        let spec: CustomFieldSpec = serde_json::from_value(value).map_err(invalid_json)?;
+        let id = key
             .parse::<u32>()
-            .map_err(|_| CustomFieldError::InvalidId {
-                input: id_str.clone(),
-            })?;
-        let input = spec.to_input()?;
-        result.insert(CustomFieldId::new(id), input);
+            .map_err(|_| CustomFieldError::InvalidId { input: key.clone() })?;
+        result
+            .by_id
+            .insert(CustomFieldId::new(id), spec.to_input()?);
     }
 
     Ok(result)
 }
 
+/// Parse custom fields keyed by field ID from a JSON file
+/// Expected format:
+/// {
+///   "1": {"type": "text", "value": "Sample text"},
+///   "2": {"type": "numeric", "value": 123.45},
+///   "3": {"type": "date", "value": "2024-06-24"},
+///   "4": {"type": "single_list", "id": 100, "other_value": "Other"},
+///   "5": {"type": "multiple_list", "ids": [100, 200], "other_value": "Other"},
+///   "6": {"type": "checkbox", "ids": [10, 20, 30]},
+///   "7": {"type": "radio", "id": 400, "other_value": "Other"}
+/// }
+pub fn parse_custom_fields_json(
+    path: &str,
+) -> Result<HashMap<CustomFieldId, CustomFieldInput>, CustomFieldError> {
+    read_custom_fields_json(path)?.into_ids()
+}
+
 /// Parse multiple custom field arguments
 pub fn parse_custom_field_args(
     args: &[String],
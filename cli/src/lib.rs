@@ -1 +1,2 @@
 pub mod custom_fields;
+pub mod url_args;
@@ -1,7 +1,12 @@
 use blg::custom_fields;
+use blg::url_args::{
+    parse_comment_id, parse_issue_id_or_key, parse_pr_comment_id, parse_pr_number,
+    parse_project_id_or_key, parse_repo_id_or_name, parse_wiki_page,
+};
 
 mod activity_commands;
 #[cfg(any(
+    feature = "document",
     feature = "team",
     feature = "star",
     feature = "rate-limit",
@@ -33,12 +38,14 @@ use backlog_api_client::UpdatePullRequestCommentParams;
 #[allow(unused_imports)]
 use backlog_api_client::UpdatePullRequestParams;
 use backlog_api_client::{
-    AttachmentId, GetCommentNotificationsParams, GetIssueListParamsBuilder,
-    GetPullRequestCountParams, IssueIdOrKey, ProjectId, ProjectIdOrKey, PullRequestAttachmentId,
-    PullRequestCommentId, PullRequestNumber, RepositoryIdOrName, StatusId, UserId, WikiId,
-    backlog_issue, client::BacklogApiClient,
+    AttachmentId, GetCommentNotificationsParams, GetIssueListParams, GetIssueListParamsBuilder,
+    GetPullRequestCountParams, IssueIdOrKey, IssueSort, ProjectId, ProjectIdOrKey,
+    PullRequestAttachmentId, PullRequestCommentId, PullRequestNumber, RepositoryIdOrName, StatusId,
+    UserId, WikiId, backlog_issue, client::BacklogApiClient,
 };
 use backlog_core::ApiDate;
+use backlog_core::Order;
+use backlog_core::WikiPage;
 #[cfg(feature = "project")]
 use backlog_core::identifier::ActivityTypeId;
 use backlog_core::identifier::IssueId;
@@ -66,9 +73,11 @@ use backlog_issue::DeleteCommentParams;
 use backlog_issue::GetRecentlyViewedIssuesParamsBuilder;
 #[cfg(feature = "issue_writable")]
 use backlog_issue::UnlinkSharedFileParams;
+#[cfg(all(feature = "issue_writable", feature = "project"))]
+use backlog_issue::custom_field_validation::CustomFieldValidator;
 #[cfg(feature = "issue_writable")]
 use backlog_issue::{
-    AddIssueParamsBuilder, AddRecentlyViewedIssueParams, UpdateIssueParamsBuilder,
+    AddIssueParamsBuilder, AddRecentlyViewedIssueParams, CustomFieldInput, UpdateIssueParamsBuilder,
 };
 #[cfg(feature = "project")]
 use backlog_project::GetProjectRecentUpdatesParams;
@@ -96,8 +105,7 @@ use backlog_space::{UpdateSpaceNotificationParams, UploadAttachmentParams};
 use backlog_user::{
     GetNotificationCountParams, GetNotificationsParams, GetOwnUserParams, GetUserIconParams,
     GetUserListParams, GetUserParams, GetUserStarCountParams, GetUserStarsParams,
-    GetWatchingCountParams, GetWatchingListParams, NotificationOrder,
-    api::{Order as WatchingOrder, StarOrder, WatchingSort},
+    GetWatchingCountParams, GetWatchingListParams, api::WatchingSort,
 };
 #[cfg(feature = "wiki")]
 use backlog_wiki::GetRecentlyViewedWikisParamsBuilder;
@@ -140,9 +148,15 @@ enum Commands {
     /// Manage wikis
     #[cfg(feature = "wiki")]
     Wiki(WikiArgs),
+    /// Export documents
+    #[cfg(feature = "document")]
+    Document(commands::document::DocumentArgs),
     /// View activities
     #[cfg(feature = "project")]
     Activity(ActivityArgs),
+    /// Generate reports
+    #[cfg(all(feature = "issue", feature = "project", feature = "user"))]
+    Report(commands::report::ReportArgs),
     /// Manage teams
     #[cfg(feature = "team")]
     Team(TeamArgs),
@@ -185,16 +199,16 @@ enum RepoCommands {
     /// List repositories in a project
     List {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
     },
     /// Show details of a specific repository
     Show {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
         /// Repository ID or Name
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_repo_id_or_name)]
         repo_id: String,
     },
 }
@@ -210,22 +224,22 @@ enum PrCommands {
     /// List pull requests in a repository
     List {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
         /// Repository ID or Name
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_repo_id_or_name)]
         repo_id: String,
     },
     /// Show details of a specific pull request
     Show {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
         /// Repository ID or Name
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_repo_id_or_name)]
         repo_id: String,
-        /// Pull Request number
-        #[clap(short = 'n', long)]
+        /// Pull Request number or URL
+        #[clap(short = 'n', long, value_parser = parse_pr_number)]
         pr_number: u64,
     },
     /// Download a pull request attachment
@@ -239,13 +253,13 @@ enum PrCommands {
     #[cfg(feature = "git_writable")]
     Update {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
         /// Repository ID or Name
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_repo_id_or_name)]
         repo_id: String,
-        /// Pull Request number
-        #[clap(long)]
+        /// Pull Request number or URL
+        #[clap(long, value_parser = parse_pr_number)]
         pr_number: u64,
         /// Update summary (title)
         #[clap(long)]
@@ -253,9 +267,9 @@ enum PrCommands {
         /// Update description
         #[clap(long)]
         description: Option<String>,
-        /// Related issue ID
-        #[clap(long)]
-        issue_id: Option<u32>,
+        /// Related issue ID, key or URL
+        #[clap(long, value_parser = parse_issue_id_or_key)]
+        issue_id: Option<String>,
         /// Assignee user ID
         #[clap(long)]
         assignee_id: Option<u32>,
@@ -270,16 +284,16 @@ enum PrCommands {
     #[cfg(feature = "git_writable")]
     CommentUpdate {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
         /// Repository ID or Name
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_repo_id_or_name)]
         repo_id: String,
-        /// Pull Request number
-        #[clap(long)]
+        /// Pull Request number or URL
+        #[clap(long, value_parser = parse_pr_number)]
         pr_number: u64,
-        /// Comment ID to update
-        #[clap(long)]
+        /// Comment ID or URL to update
+        #[clap(long, value_parser = parse_pr_comment_id)]
         comment_id: u32,
         /// New content for the comment
         #[clap(short, long)]
@@ -288,22 +302,22 @@ enum PrCommands {
     /// Get the number of comments on a pull request
     CommentCount {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
         /// Repository ID or Name
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_repo_id_or_name)]
         repo_id: String,
-        /// Pull Request number
-        #[clap(long)]
+        /// Pull Request number or URL
+        #[clap(long, value_parser = parse_pr_number)]
         pr_number: u64,
     },
     /// Get the number of pull requests in a repository
     Count {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
         /// Repository ID or Name
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_repo_id_or_name)]
         repo_id: String,
         /// Filter by status IDs (comma-separated, e.g., "1,2,3")
         #[clap(long)]
@@ -328,10 +342,10 @@ enum PrCommands {
     #[cfg(feature = "git_writable")]
     Create {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
         /// Repository ID or Name
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_repo_id_or_name)]
         repo_id: String,
         /// Pull request title
         #[clap(short, long)]
@@ -345,9 +359,9 @@ enum PrCommands {
         /// Source branch to be merged
         #[clap(short = 'B', long)]
         branch: String,
-        /// Related issue ID
-        #[clap(long)]
-        issue_id: Option<u32>,
+        /// Related issue ID, key or URL
+        #[clap(long, value_parser = parse_issue_id_or_key)]
+        issue_id: Option<String>,
         /// Assignee user ID
         #[clap(long)]
         assignee_id: Option<u32>,
@@ -363,13 +377,13 @@ enum PrCommands {
 #[derive(Args, Debug)]
 struct DownloadPrAttachmentArgs {
     /// Project ID or Key
-    #[clap(short = 'p', long)]
+    #[clap(short = 'p', long, value_parser = parse_project_id_or_key)]
     project_id: String,
     /// Repository ID or Name
-    #[clap(short = 'r', long)]
+    #[clap(short = 'r', long, value_parser = parse_repo_id_or_name)]
     repo_id: String,
-    /// Pull Request number
-    #[clap(short = 'n', long)]
+    /// Pull Request number or URL
+    #[clap(short = 'n', long, value_parser = parse_pr_number)]
     pr_number: u64,
     /// The numeric ID of the attachment to download
     #[clap(short = 'a', long)]
@@ -383,13 +397,13 @@ struct DownloadPrAttachmentArgs {
 #[derive(Args, Debug)]
 struct DeletePrAttachmentArgs {
     /// Project ID or Key
-    #[clap(short = 'p', long)]
+    #[clap(short = 'p', long, value_parser = parse_project_id_or_key)]
     project_id: String,
     /// Repository ID or Name
-    #[clap(short = 'r', long)]
+    #[clap(short = 'r', long, value_parser = parse_repo_id_or_name)]
     repo_id: String,
-    /// Pull Request number
-    #[clap(short = 'n', long)]
+    /// Pull Request number or URL
+    #[clap(short = 'n', long, value_parser = parse_pr_number)]
     pr_number: u64,
     /// The numeric ID of the attachment to delete
     #[clap(short = 'a', long)]
@@ -412,7 +426,7 @@ enum IssueCommands {
     /// Show details of a specific issue
     Show {
         /// Issue ID or Key (e.g., "PROJECT-123" or "12345")
-        #[clap(name = "ISSUE_ID_OR_KEY")]
+        #[clap(name = "ISSUE_ID_OR_KEY", value_parser = parse_issue_id_or_key)]
         issue_id_or_key: String,
     },
     /// Download an issue attachment
@@ -463,22 +477,35 @@ enum IssueCommands {
     #[command(about = "List participants in an issue")]
     ListParticipants {
         /// Issue ID or Key (e.g., "PROJECT-123" or "12345")
-        #[clap(name = "ISSUE_ID_OR_KEY")]
+        #[clap(name = "ISSUE_ID_OR_KEY", value_parser = parse_issue_id_or_key)]
         issue_id_or_key: String,
     },
     /// List shared files linked to an issue
     #[command(about = "List shared files linked to an issue")]
     ListSharedFiles {
         /// Issue ID or Key (e.g., "PROJECT-123" or "12345")
-        #[clap(name = "ISSUE_ID_OR_KEY")]
+        #[clap(name = "ISSUE_ID_OR_KEY", value_parser = parse_issue_id_or_key)]
+        issue_id_or_key: String,
+    },
+    /// Show the field change history of an issue
+    #[command(about = "Show the field change history of an issue")]
+    History {
+        /// Issue ID or Key (e.g., "PROJECT-123" or "12345")
+        #[clap(name = "ISSUE_ID_OR_KEY", value_parser = parse_issue_id_or_key)]
         issue_id_or_key: String,
+        /// Only show changes to this field (e.g., status, assignee, dueDate, or a custom field name)
+        #[clap(short, long)]
+        field: Option<String>,
+        /// Print the history as JSON
+        #[clap(long)]
+        json: bool,
     },
     /// Link shared files to an issue
     #[cfg(feature = "issue_writable")]
     #[command(about = "Link shared files to an issue")]
     LinkSharedFiles {
         /// Issue ID or Key (e.g., "PROJECT-123" or "12345")
-        #[clap(name = "ISSUE_ID_OR_KEY")]
+        #[clap(name = "ISSUE_ID_OR_KEY", value_parser = parse_issue_id_or_key)]
         issue_id_or_key: String,
         /// Shared file IDs to link (comma-separated)
         #[clap(short, long, value_delimiter = ',')]
@@ -489,7 +516,7 @@ enum IssueCommands {
     #[command(about = "Unlink a shared file from an issue")]
     UnlinkSharedFile {
         /// Issue ID or Key (e.g., "PROJECT-123" or "12345")
-        #[clap(name = "ISSUE_ID_OR_KEY")]
+        #[clap(name = "ISSUE_ID_OR_KEY", value_parser = parse_issue_id_or_key)]
         issue_id_or_key: String,
         /// Shared file ID to unlink
         #[clap(short, long)]
@@ -498,9 +525,9 @@ enum IssueCommands {
     /// Get recently viewed issues
     #[command(about = "Get recently viewed issues for the current user")]
     RecentlyViewed {
-        /// Sort order (asc or desc, default: desc)
-        #[clap(long, default_value = "desc")]
-        order: String,
+        /// Sort order (default: desc)
+        #[clap(long, value_enum, default_value_t = Order::Desc)]
+        order: Order,
         /// Number of issues to retrieve (1-100, default: 20)
         #[clap(long, default_value_t = 20)]
         count: u32,
@@ -513,7 +540,7 @@ enum IssueCommands {
     #[command(about = "Add an issue to recently viewed list")]
     AddRecentlyViewed {
         /// Issue ID or Key (e.g., "PROJECT-123" or "12345")
-        #[clap(name = "ISSUE_ID_OR_KEY")]
+        #[clap(name = "ISSUE_ID_OR_KEY", value_parser = parse_issue_id_or_key)]
         issue_id_or_key: String,
     },
 }
@@ -521,6 +548,7 @@ enum IssueCommands {
 #[derive(Args, Debug)]
 struct DownloadAttachmentArgs {
     /// The ID or key of the issue (e.g., "PROJECT-123" or "12345")
+    #[arg(value_parser = parse_issue_id_or_key)]
     issue_id_or_key: String,
 
     /// The numeric ID of the attachment to download
@@ -534,6 +562,7 @@ struct DownloadAttachmentArgs {
 #[derive(Args, Debug)]
 struct AddCommentArgs {
     /// The ID or key of the issue (e.g., "PROJECT-123" or "12345")
+    #[arg(value_parser = parse_issue_id_or_key)]
     issue_id_or_key: String,
 
     /// The comment content
@@ -547,33 +576,41 @@ struct AddCommentArgs {
     /// Attachment IDs to include (comma-separated, e.g., "789,101112")
     #[arg(short, long)]
     attachments: Option<String>,
+
+    /// Send the text as-is instead of converting Markdown to the project's text formatting rule
+    #[arg(long)]
+    raw: bool,
 }
 
 #[cfg(feature = "issue_writable")]
 #[derive(Args, Debug)]
 struct UpdateCommentArgs {
     /// Issue ID or key (e.g., 'PROJECT-123')
-    #[clap(short, long)]
+    #[clap(short, long, value_parser = parse_issue_id_or_key)]
     issue_id: String,
 
-    /// Comment ID to update
-    #[clap(short = 'c', long)]
+    /// Comment ID or URL to update
+    #[clap(short = 'c', long, value_parser = parse_comment_id)]
     comment_id: u32,
 
     /// New content for the comment
     #[clap(short = 'n', long)]
     content: String,
+
+    /// Send the text as-is instead of converting Markdown to the project's text formatting rule
+    #[arg(long)]
+    raw: bool,
 }
 
 #[cfg(feature = "issue_writable")]
 #[derive(Args, Debug)]
 struct DeleteCommentArgs {
     /// Issue ID or key (e.g., 'PROJECT-123')
-    #[clap(short, long)]
+    #[clap(short, long, value_parser = parse_issue_id_or_key)]
     issue_id: String,
 
-    /// Comment ID to delete
-    #[clap(short = 'c', long)]
+    /// Comment ID or URL to delete
+    #[clap(short = 'c', long, value_parser = parse_comment_id)]
     comment_id: u32,
 }
 
@@ -581,7 +618,7 @@ struct DeleteCommentArgs {
 #[derive(Args, Debug)]
 struct DeleteAttachmentArgs {
     /// Issue ID or key (e.g., 'PROJECT-123')
-    #[clap(short, long)]
+    #[clap(short, long, value_parser = parse_issue_id_or_key)]
     issue_id: String,
 
     /// Attachment ID to delete
@@ -592,7 +629,7 @@ struct DeleteAttachmentArgs {
 #[derive(Args, Debug)]
 struct CreateIssueArgs {
     /// Project ID or Key
-    #[arg(short, long)]
+    #[arg(short, long, value_parser = parse_project_id_or_key)]
     project_id: String,
 
     /// Issue summary (title)
@@ -611,6 +648,10 @@ struct CreateIssueArgs {
     #[arg(short, long)]
     description: Option<String>,
 
+    /// Send the text as-is instead of converting Markdown to the project's text formatting rule
+    #[arg(long)]
+    raw: bool,
+
     /// Assignee user ID
     #[arg(short, long)]
     assignee_id: Option<u32>,
@@ -636,12 +677,14 @@ struct CreateIssueArgs {
     #[arg(long = "custom-field", value_name = "FIELD")]
     custom_fields: Vec<String>,
 
-    /// Custom fields JSON file path
+    /// Custom fields JSON file path, keyed by field ID or field name
     /// Expected format:
     /// {
     ///   "1": {"type": "text", "value": "Sample text"},
-    ///   "2": {"type": "numeric", "value": 123.45}
+    ///   "Severity": "High",
+    ///   "Components": ["Backend", "Frontend"]
     /// }
+    /// See `blg project custom-field-schema` for a schema of the names.
     #[arg(
         long = "custom-fields-json",
         value_name = "FILE",
@@ -653,6 +696,7 @@ struct CreateIssueArgs {
 #[derive(Args, Debug)]
 struct UpdateIssueArgs {
     /// Issue ID or Key
+    #[arg(value_parser = parse_issue_id_or_key)]
     issue_id_or_key: String,
 
     /// Issue summary (title)
@@ -663,6 +707,10 @@ struct UpdateIssueArgs {
     #[arg(short, long)]
     description: Option<String>,
 
+    /// Send the text as-is instead of converting Markdown to the project's text formatting rule
+    #[arg(long)]
+    raw: bool,
+
     /// Issue type ID
     #[arg(short = 't', long)]
     issue_type_id: Option<u32>,
@@ -695,7 +743,7 @@ struct UpdateIssueArgs {
     #[arg(long = "custom-field", value_name = "FIELD")]
     custom_fields: Vec<String>,
 
-    /// Custom fields JSON file path
+    /// Custom fields JSON file path, keyed by field ID or field name
     #[arg(
         long = "custom-fields-json",
         value_name = "FILE",
@@ -707,20 +755,24 @@ struct UpdateIssueArgs {
 #[derive(Args, Debug)]
 struct DeleteIssueArgs {
     /// Issue Key (e.g., "PROJECT-123")
+    #[arg(value_parser = parse_issue_id_or_key)]
     issue_key: String,
 }
 
 #[derive(Args, Debug)]
 struct CountCommentArgs {
     /// The ID or key of the issue (e.g., "PROJECT-123" or "12345")
+    #[arg(value_parser = parse_issue_id_or_key)]
     issue_id_or_key: String,
 }
 
 #[derive(Args, Debug)]
 struct GetCommentArgs {
     /// The ID or key of the issue (e.g., "PROJECT-123" or "12345")
+    #[arg(value_parser = parse_issue_id_or_key)]
     issue_id_or_key: String,
-    /// The ID of the comment
+    /// The ID or URL of the comment
+    #[clap(value_parser = parse_comment_id)]
     comment_id: u32,
 }
 
@@ -728,8 +780,10 @@ struct GetCommentArgs {
 #[derive(Args, Debug)]
 struct AddCommentNotificationArgs {
     /// The ID or key of the issue (e.g., "PROJECT-123" or "12345")
+    #[arg(value_parser = parse_issue_id_or_key)]
     issue_id_or_key: String,
-    /// The ID of the comment
+    /// The ID or URL of the comment
+    #[clap(value_parser = parse_comment_id)]
     comment_id: u32,
     /// User IDs to notify (comma-separated, e.g., "123,456")
     #[arg(short, long)]
@@ -791,7 +845,7 @@ enum ProjectCommands {
     /// Show details of a specific project
     Show {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
     /// Add a new project
@@ -844,7 +898,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     Update {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Update project name
         #[clap(long)]
@@ -896,14 +950,14 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     Delete {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
     /// List recently viewed projects
     RecentlyViewed {
         /// Sort order ("asc" or "desc", default: "desc")
-        #[clap(long)]
-        order: Option<String>,
+        #[clap(long, value_enum)]
+        order: Option<Order>,
         /// Number of results to return (1-100, default: 20)
         #[clap(long)]
         count: Option<u32>,
@@ -914,31 +968,31 @@ enum ProjectCommands {
     /// List statuses for a project
     StatusList {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
     /// List milestones for a project
     MilestoneList {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
     /// List issue types for a project
     IssueTypeList {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
     /// List categories for a project
     CategoryList {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
     /// Get disk usage for a project
     DiskUsage {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Show sizes in human-readable format (e.g., 1.2GB)
         #[clap(short = 'H', long)]
@@ -947,20 +1001,20 @@ enum ProjectCommands {
     /// List users for a project
     UserList {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
     /// List administrators for a project
     AdminList {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
     /// Add a user as a project administrator
     #[cfg(feature = "project_writable")]
     AdminAdd {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// User ID to add as administrator
         #[clap(short, long)]
@@ -970,7 +1024,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     AdminRemove {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// User ID to remove as administrator
         #[clap(short, long)]
@@ -980,7 +1034,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     UserAdd {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// User ID to add
         #[clap(short, long)]
@@ -990,7 +1044,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     UserRemove {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// User ID to remove
         #[clap(short, long)]
@@ -999,14 +1053,23 @@ enum ProjectCommands {
     /// List custom fields for a project
     CustomFieldList {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
+    /// Print a JSON Schema of the name-based `--custom-fields-json` format for a project
+    CustomFieldSchema {
+        /// Project ID or Key
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
+        project_id_or_key: String,
+        /// Write the schema to this file instead of stdout
+        #[clap(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
     /// Update a custom field in a project
     #[cfg(feature = "project_writable")]
     CustomFieldUpdate {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Custom field ID
         #[clap(long)]
@@ -1044,7 +1107,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     CustomFieldAdd {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Field type (text, textarea, numeric, date, single-list, multiple-list, checkbox, radio)
         #[clap(short = 't', long)]
@@ -1105,7 +1168,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     CustomFieldDelete {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Custom Field ID
         #[clap(short, long)]
@@ -1115,7 +1178,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     CustomFieldAddItem {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Custom Field ID
         #[clap(short, long)]
@@ -1128,7 +1191,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     CustomFieldUpdateItem {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Custom Field ID
         #[clap(short, long)]
@@ -1144,7 +1207,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     CustomFieldDeleteItem {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Custom Field ID
         #[clap(short, long)]
@@ -1157,7 +1220,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     CategoryAdd {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Category name
         #[clap(short, long)]
@@ -1167,7 +1230,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     CategoryUpdate {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Category ID
         #[clap(short, long)]
@@ -1180,7 +1243,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     CategoryDelete {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Category ID
         #[clap(short, long)]
@@ -1190,7 +1253,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     IssueTypeAdd {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Issue type name
         #[clap(short, long)]
@@ -1209,7 +1272,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     IssueTypeDelete {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Issue type ID to delete
         #[clap(long)]
@@ -1222,7 +1285,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     IssueTypeUpdate {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Issue type ID to update
         #[clap(long)]
@@ -1248,7 +1311,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     VersionAdd {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Version name
         #[clap(short, long)]
@@ -1267,7 +1330,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     VersionUpdate {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Version ID to update
         #[clap(long)]
@@ -1292,7 +1355,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     VersionDelete {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Version ID to delete
         #[clap(long)]
@@ -1302,7 +1365,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     StatusAdd {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Status name
         #[clap(short, long)]
@@ -1315,7 +1378,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     StatusUpdate {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Status ID to update
         #[clap(long)]
@@ -1331,7 +1394,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     StatusDelete {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Status ID to delete
         #[clap(long)]
@@ -1344,7 +1407,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     StatusOrderUpdate {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Status IDs in desired display order (comma-separated)
         #[clap(long)]
@@ -1353,7 +1416,7 @@ enum ProjectCommands {
     /// Download project icon
     Icon {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Output file path to save the icon
         #[clap(short, long, value_name = "FILE_PATH")]
@@ -1362,14 +1425,14 @@ enum ProjectCommands {
     /// List teams for a project
     TeamList {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
     },
     /// Add a team to a project
     #[cfg(feature = "project_writable")]
     TeamAdd {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Team ID to add
         #[clap(short, long)]
@@ -1379,7 +1442,7 @@ enum ProjectCommands {
     #[cfg(feature = "project_writable")]
     TeamDelete {
         /// Project ID or Key
-        #[clap(name = "PROJECT_ID_OR_KEY")]
+        #[clap(name = "PROJECT_ID_OR_KEY", value_parser = parse_project_id_or_key)]
         project_id_or_key: String,
         /// Team ID to remove
         #[clap(short, long)]
@@ -1443,8 +1506,8 @@ enum UserCommands {
         #[clap(long)]
         count: Option<u32>,
         /// Sort order (asc or desc)
-        #[clap(long)]
-        order: Option<String>,
+        #[clap(long, value_enum)]
+        order: Option<Order>,
     },
     /// Get notification count for authenticated user
     NotificationCount {
@@ -1468,8 +1531,8 @@ enum UserCommands {
         #[clap(long, short = 'n')]
         count: Option<u8>,
         /// Sort order (asc or desc)
-        #[clap(long, short = 'o')]
-        order: Option<String>,
+        #[clap(long, short = 'o', value_enum)]
+        order: Option<Order>,
         /// Filter by sender user ID
         #[clap(long)]
         sender_id: Option<u32>,
@@ -1489,11 +1552,11 @@ enum UserCommands {
         #[clap(name = "USER_ID")]
         user_id: u32,
         /// Sort order (asc or desc)
-        #[clap(long)]
-        order: Option<String>,
-        /// Sort by (created, updated, issueUpdated)
-        #[clap(long)]
-        sort: Option<String>,
+        #[clap(long, value_enum)]
+        order: Option<Order>,
+        /// Sort by
+        #[clap(long, value_enum)]
+        sort: Option<WatchingSort>,
         /// Maximum number of results to return (1-100)
         #[clap(long)]
         count: Option<u8>,
@@ -1534,8 +1597,8 @@ enum WikiCommands {
     /// List recently viewed wikis
     RecentlyViewed {
         /// Sort order (asc or desc)
-        #[clap(short, long)]
-        order: Option<String>,
+        #[clap(short, long, value_enum)]
+        order: Option<Order>,
         /// Number of items to retrieve (1-100)
         #[clap(short, long)]
         count: Option<u32>,
@@ -1545,28 +1608,28 @@ enum WikiCommands {
     },
     /// List attachments for a wiki page
     ListAttachments {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
     },
     /// List shared files linked to a wiki page
     ListSharedFiles {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
     },
     /// List stars for a wiki page
     Stars {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
     },
     /// Link shared files to a wiki page
     #[cfg(feature = "wiki_writable")]
     LinkSharedFiles {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
         /// Shared file IDs (comma-separated)
         #[clap(name = "FILE_IDS", value_delimiter = ',')]
         file_ids: Vec<u32>,
@@ -1574,18 +1637,18 @@ enum WikiCommands {
     /// Unlink a shared file from a wiki page
     #[cfg(feature = "wiki_writable")]
     UnlinkSharedFile {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
         /// Shared file ID
         #[clap(name = "FILE_ID")]
         file_id: u32,
     },
     /// Download an attachment from a wiki page
     DownloadAttachment {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
         /// Attachment ID
         #[clap(name = "ATTACHMENT_ID")]
         attachment_id: u32,
@@ -1596,8 +1659,8 @@ enum WikiCommands {
     /// Create a new wiki page
     #[cfg(feature = "wiki_writable")]
     Create {
-        /// Project ID
-        #[clap(long)]
+        /// Project ID, key or URL
+        #[clap(long, value_parser = parse_project_id_or_key)]
         project_id: String,
         /// Wiki page name
         #[clap(long)]
@@ -1605,6 +1668,9 @@ enum WikiCommands {
         /// Wiki page content
         #[clap(long)]
         content: String,
+        /// Send the content as-is instead of converting Markdown to the project's text formatting rule
+        #[clap(long)]
+        raw: bool,
         /// Send email notification
         #[clap(long)]
         mail_notify: Option<bool>,
@@ -1612,15 +1678,18 @@ enum WikiCommands {
     /// Update a wiki page
     #[cfg(feature = "wiki_writable")]
     Update {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
         /// New wiki page name
         #[clap(long)]
         name: Option<String>,
         /// New wiki page content
         #[clap(long)]
         content: Option<String>,
+        /// Send the content as-is instead of converting Markdown to the project's text formatting rule
+        #[clap(long)]
+        raw: bool,
         /// Send email notification of update
         #[clap(long)]
         mail_notify: Option<bool>,
@@ -1628,9 +1697,9 @@ enum WikiCommands {
     /// Delete a wiki page
     #[cfg(feature = "wiki_writable")]
     Delete {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
         /// Send email notification of deletion
         #[clap(long)]
         mail_notify: Option<bool>,
@@ -1638,9 +1707,9 @@ enum WikiCommands {
     /// Attach file to a wiki page
     #[cfg(feature = "wiki_writable")]
     AttachFile {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
         /// File path to attach
         #[clap(long)]
         file_path: PathBuf,
@@ -1648,9 +1717,9 @@ enum WikiCommands {
     /// Delete an attachment from a wiki page
     #[cfg(feature = "wiki_writable")]
     DeleteAttachment {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
         /// Attachment ID to delete
         #[clap(name = "ATTACHMENT_ID")]
         attachment_id: u32,
@@ -1661,14 +1730,14 @@ enum WikiCommands {
     /// List tags used in wiki pages
     ListTags {
         /// Project ID or Key
-        #[clap(short, long)]
+        #[clap(short, long, value_parser = parse_project_id_or_key)]
         project_id: String,
     },
     /// Get history of a wiki page
     History {
-        /// Wiki ID
-        #[clap(name = "WIKI_ID")]
-        wiki_id: u32,
+        /// Wiki ID or URL
+        #[clap(name = "WIKI_ID", value_parser = parse_wiki_page)]
+        wiki_id: WikiPage,
         /// Minimum ID for history entries
         #[clap(long)]
         min_id: Option<u32>,
@@ -1680,21 +1749,20 @@ enum WikiCommands {
         count: Option<u32>,
         /// Sort order for history entries
         #[clap(long, value_enum)]
-        order: Option<HistoryOrderCli>,
+        order: Option<Order>,
     },
 }
 
-#[cfg(feature = "wiki")]
-#[derive(Clone, clap::ValueEnum)]
-enum HistoryOrderCli {
-    Asc,
-    Desc,
-}
-
 #[derive(Parser, Debug, Default)]
 struct IssueListCliParams {
-    /// Filter by project ID(s)
-    #[clap(long)]
+    /// Search query, e.g. `project:WEB status:"In Progress" assignee:@me due<2026-11-01`
+    #[clap(long, conflicts_with_all = [
+        "project_id", "assignee_id", "status_id", "keyword",
+        "start_date_since", "start_date_until", "due_date_since", "due_date_until",
+    ])]
+    query: Option<String>,
+    /// Filter by project ID(s), keys or URLs
+    #[clap(long, value_parser = parse_project_id_or_key)]
     project_id: Option<Vec<String>>,
     /// Filter by assignee ID(s)
     #[clap(long)]
@@ -1720,7 +1788,15 @@ struct IssueListCliParams {
     /// Filter by due date (until). Format: YYYY-MM-DD
     #[clap(long)]
     due_date_until: Option<String>,
-    // TODO: Add more filters like sort, order, offset, issue_type_id, etc.
+    /// Sort key: issueType, category, version, milestone, summary, status, priority,
+    /// attachment, sharedFile, created, createdUser, updated, updatedUser, assignee,
+    /// startDate, dueDate, estimatedHours, actualHours, childIssue or customField_<ID>
+    #[clap(long, value_parser = <IssueSort as std::str::FromStr>::from_str)]
+    sort: Option<IssueSort>,
+    /// Sort order (default: desc)
+    #[clap(long, value_enum)]
+    order: Option<Order>,
+    // TODO: Add more filters like offset, issue_type_id, etc.
 }
 
 /// Truncates a string to a maximum length, ensuring UTF-8 character boundary safety
@@ -1753,6 +1829,252 @@ fn format_bytes(bytes: u64) -> String {
     }
 }
 
+/// Builds issue list parameters from the individual filter flags.
+async fn issue_list_params(
+    client: &BacklogApiClient,
+    params: IssueListCliParams,
+) -> Result<GetIssueListParams, Box<dyn std::error::Error>> {
+    let mut builder = GetIssueListParamsBuilder::default();
+
+    if let Some(p_ids) = params.project_id {
+        let mut parsed_ids = Vec::with_capacity(p_ids.len());
+        for project_id_or_key in &p_ids {
+            parsed_ids.push(resolve_project_id(client, project_id_or_key).await?);
+        }
+        builder.project_id(parsed_ids);
+    }
+    if let Some(a_ids) = params.assignee_id {
+        let parsed_ids: std::result::Result<Vec<UserId>, _> = a_ids
+            .iter()
+            .map(|s| s.parse::<u32>().map(UserId::from))
+            .collect();
+        builder.assignee_id(parsed_ids?);
+    }
+    if let Some(s_ids) = params.status_id {
+        let parsed_ids: std::result::Result<Vec<StatusId>, _> = s_ids
+            .iter()
+            .map(|s| s.parse::<u32>().map(StatusId::from))
+            .collect();
+        builder.status_id(parsed_ids?);
+    }
+    if let Some(keyword) = params.keyword {
+        builder.keyword(keyword);
+    }
+    builder.count(params.count); // count has a default_value_t
+    if let Some(sort) = params.sort {
+        builder.sort(sort);
+    }
+    if let Some(order) = params.order {
+        builder.order(order);
+    }
+
+    // Handle date range parameters
+    if let Some(start_date_since) = params.start_date_since {
+        let date = NaiveDate::parse_from_str(&start_date_since, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid start-date-since format: {start_date_since}"))?;
+        let datetime = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        builder.start_date_since(ApiDate::from(datetime));
+    }
+    if let Some(start_date_until) = params.start_date_until {
+        let date = NaiveDate::parse_from_str(&start_date_until, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid start-date-until format: {start_date_until}"))?;
+        let datetime = date.and_hms_opt(23, 59, 59).unwrap().and_utc();
+        builder.start_date_until(ApiDate::from(datetime));
+    }
+    if let Some(due_date_since) = params.due_date_since {
+        let date = NaiveDate::parse_from_str(&due_date_since, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid due-date-since format: {due_date_since}"))?;
+        let datetime = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        builder.due_date_since(ApiDate::from(datetime));
+    }
+    if let Some(due_date_until) = params.due_date_until {
+        let date = NaiveDate::parse_from_str(&due_date_until, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid due-date-until format: {due_date_until}"))?;
+        let datetime = date.and_hms_opt(23, 59, 59).unwrap().and_utc();
+        builder.due_date_until(ApiDate::from(datetime));
+    }
+
+    Ok(builder.build()?)
+}
+
+/// Resolves a `--query` search string; `--count`, `--sort` and `--order` apply
+/// unless the query sets them.
+#[cfg(all(feature = "project", feature = "user"))]
+async fn issue_query_params(
+    client: &BacklogApiClient,
+    query: &str,
+    cli_params: &IssueListCliParams,
+) -> Result<GetIssueListParams, Box<dyn std::error::Error>> {
+    let mut params = client.compile_issue_query(query).await?;
+    params.count.get_or_insert(cli_params.count);
+    params.sort = params.sort.or(cli_params.sort);
+    params.order = params.order.or(cli_params.order);
+    Ok(params)
+}
+
+#[cfg(not(all(feature = "project", feature = "user")))]
+async fn issue_query_params(
+    _client: &BacklogApiClient,
+    _query: &str,
+    _cli_params: &IssueListCliParams,
+) -> Result<GetIssueListParams, Box<dyn std::error::Error>> {
+    Err("--query requires the project and user features".into())
+}
+
+/// Converts Markdown into the text formatting rule of the project the text is stored in.
+#[cfg(feature = "project")]
+async fn markdown_for_project(
+    client: &BacklogApiClient,
+    project_id_or_key: ProjectIdOrKey,
+    markdown: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let project = client
+        .project()
+        .get_project(backlog_project::GetProjectDetailParams::new(
+            project_id_or_key,
+        ))
+        .await?;
+    Ok(project.text_formatting_rule.markdown_to_native(markdown))
+}
+
+/// Without the project API the text formatting rule is unknown, so the text is sent as-is.
+#[cfg(not(feature = "project"))]
+async fn markdown_for_project(
+    _client: &BacklogApiClient,
+    _project_id_or_key: ProjectIdOrKey,
+    markdown: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    Ok(markdown.to_string())
+}
+
+/// Resolves custom fields given by name and checks every value against the project's
+/// definitions, reporting all violations before the issue is written.
+#[cfg(all(feature = "issue_writable", feature = "project"))]
+async fn resolve_custom_fields(
+    client: &BacklogApiClient,
+    project_id: ProjectId,
+    issue_type_id: IssueTypeId,
+    check_required: bool,
+    values: custom_fields::CustomFieldValues,
+) -> Result<std::collections::HashMap<CustomFieldId, CustomFieldInput>, Box<dyn std::error::Error>>
+{
+    let definitions = client
+        .project()
+        .get_custom_field_list(backlog_project::GetCustomFieldListParams::new(project_id))
+        .await?;
+    let values = values.resolve(&definitions)?;
+    CustomFieldValidator::new(&definitions)
+        .issue_type(issue_type_id)
+        .check_required(check_required)
+        .validate(&values)?;
+    Ok(values)
+}
+
+/// Without the project API the definitions are unavailable; only values keyed by ID
+/// can be sent, and Backlog validates them on write.
+#[cfg(all(feature = "issue_writable", not(feature = "project")))]
+async fn resolve_custom_fields(
+    _client: &BacklogApiClient,
+    _project_id: ProjectId,
+    _issue_type_id: IssueTypeId,
+    _check_required: bool,
+    values: custom_fields::CustomFieldValues,
+) -> Result<std::collections::HashMap<CustomFieldId, CustomFieldInput>, Box<dyn std::error::Error>>
+{
+    Ok(values.into_ids()?)
+}
+
+/// Converts Markdown into the text formatting rule of the project an issue belongs to.
+#[cfg(feature = "issue_writable")]
+async fn markdown_for_issue(
+    client: &BacklogApiClient,
+    issue_id_or_key: IssueIdOrKey,
+    markdown: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let issue = client
+        .issue()
+        .get_issue(backlog_issue::GetIssueParams::new(issue_id_or_key))
+        .await?;
+    markdown_for_project(client, issue.project_id.into(), markdown).await
+}
+
+/// Resolves a project ID or key, as given on the command line, to the project's ID.
+#[cfg(feature = "project")]
+async fn resolve_project_id(
+    client: &BacklogApiClient,
+    project_id_or_key: &str,
+) -> Result<ProjectId, Box<dyn std::error::Error>> {
+    if let Ok(id) = project_id_or_key.trim().parse::<u32>() {
+        return Ok(ProjectId::new(id));
+    }
+    let project = client
+        .project()
+        .get_project(backlog_project::GetProjectDetailParams::new(
+            ProjectIdOrKey::from_str(project_id_or_key)?,
+        ))
+        .await?;
+    Ok(project.id)
+}
+
+/// Without the project API a project key can't be looked up, so only IDs are accepted.
+#[cfg(not(feature = "project"))]
+async fn resolve_project_id(
+    _client: &BacklogApiClient,
+    project_id_or_key: &str,
+) -> Result<ProjectId, Box<dyn std::error::Error>> {
+    Ok(ProjectId::from_str(project_id_or_key.trim())?)
+}
+
+/// Resolves an issue ID or key, as given on the command line, to the issue's ID.
+#[cfg(feature = "issue")]
+async fn resolve_issue_id(
+    client: &BacklogApiClient,
+    issue_id_or_key: &str,
+) -> Result<IssueId, Box<dyn std::error::Error>> {
+    if let Ok(id) = issue_id_or_key.trim().parse::<u32>() {
+        return Ok(IssueId::new(id));
+    }
+    let issue = client
+        .issue()
+        .get_issue(backlog_issue::GetIssueParams::new(IssueIdOrKey::from_str(
+            issue_id_or_key,
+        )?))
+        .await?;
+    Ok(issue.id)
+}
+
+/// Without the issue API an issue key can't be looked up, so only IDs are accepted.
+#[cfg(not(feature = "issue"))]
+async fn resolve_issue_id(
+    _client: &BacklogApiClient,
+    issue_id_or_key: &str,
+) -> Result<IssueId, Box<dyn std::error::Error>> {
+    Ok(IssueId::from_str(issue_id_or_key.trim())?)
+}
+
+/// Resolves a wiki page given by ID or URL to its ID, looking it up by name if needed.
+#[cfg(feature = "wiki")]
+async fn resolve_wiki_id(
+    client: &BacklogApiClient,
+    page: &WikiPage,
+) -> Result<WikiId, Box<dyn std::error::Error>> {
+    match page {
+        WikiPage::Id(id) => Ok(*id),
+        WikiPage::Name { project_key, name } => client
+            .wiki()
+            .get_wiki_list(
+                backlog_wiki::GetWikiListParams::new()
+                    .project_id_or_key(project_key.clone())
+                    .keyword(name.clone()),
+            )
+            .await?
+            .into_iter()
+            .find(|wiki| wiki.name == *name)
+            .map(|wiki| wiki.id)
+            .ok_or_else(|| format!("Wiki page '{name}' not found in project {project_key}").into()),
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let base_url = env::var("BACKLOG_BASE_URL")?;
@@ -1945,7 +2267,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
 
                 if let Some(issue_id) = issue_id {
-                    params = params.issue_id(IssueId::new(issue_id));
+                    params = params.issue_id(resolve_issue_id(&client, &issue_id).await?);
                 }
 
                 if let Some(assignee_id) = assignee_id {
@@ -2184,7 +2506,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 // Parse optional issue ID
                 if let Some(issue_id) = issue_id {
-                    params = params.issue_id(backlog_core::identifier::IssueId::new(issue_id));
+                    params = params.issue_id(resolve_issue_id(&client, &issue_id).await?);
                 }
 
                 // Parse optional assignee ID
@@ -2260,65 +2582,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             IssueCommands::List { params } => {
                 println!("Listing issues with params: {params:?}");
-                let mut builder = GetIssueListParamsBuilder::default();
-
-                if let Some(p_ids) = params.project_id {
-                    let parsed_ids: std::result::Result<Vec<ProjectId>, _> = p_ids
-                        .iter()
-                        .map(|s| s.parse::<u32>().map(ProjectId::from))
-                        .collect();
-                    builder.project_id(parsed_ids?);
-                }
-                if let Some(a_ids) = params.assignee_id {
-                    let parsed_ids: std::result::Result<Vec<UserId>, _> = a_ids
-                        .iter()
-                        .map(|s| s.parse::<u32>().map(UserId::from))
-                        .collect();
-                    builder.assignee_id(parsed_ids?);
-                }
-                if let Some(s_ids) = params.status_id {
-                    let parsed_ids: std::result::Result<Vec<StatusId>, _> = s_ids
-                        .iter()
-                        .map(|s| s.parse::<u32>().map(StatusId::from))
-                        .collect();
-                    builder.status_id(parsed_ids?);
-                }
-                if let Some(keyword) = params.keyword {
-                    builder.keyword(keyword);
-                }
-                builder.count(params.count); // count has a default_value_t
-
-                // Handle date range parameters
-                if let Some(start_date_since) = params.start_date_since {
-                    let date =
-                        NaiveDate::parse_from_str(&start_date_since, "%Y-%m-%d").map_err(|_| {
-                            format!("Invalid start-date-since format: {start_date_since}")
-                        })?;
-                    let datetime = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
-                    builder.start_date_since(ApiDate::from(datetime));
-                }
-                if let Some(start_date_until) = params.start_date_until {
-                    let date =
-                        NaiveDate::parse_from_str(&start_date_until, "%Y-%m-%d").map_err(|_| {
-                            format!("Invalid start-date-until format: {start_date_until}")
-                        })?;
-                    let datetime = date.and_hms_opt(23, 59, 59).unwrap().and_utc();
-                    builder.start_date_until(ApiDate::from(datetime));
-                }
-                if let Some(due_date_since) = params.due_date_since {
-                    let date = NaiveDate::parse_from_str(&due_date_since, "%Y-%m-%d")
-                        .map_err(|_| format!("Invalid due-date-since format: {due_date_since}"))?;
-                    let datetime = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
-                    builder.due_date_since(ApiDate::from(datetime));
-                }
-                if let Some(due_date_until) = params.due_date_until {
-                    let date = NaiveDate::parse_from_str(&due_date_until, "%Y-%m-%d")
-                        .map_err(|_| format!("Invalid due-date-until format: {due_date_until}"))?;
-                    let datetime = date.and_hms_opt(23, 59, 59).unwrap().and_utc();
-                    builder.due_date_until(ApiDate::from(datetime));
-                }
-
-                let list_params = builder.build()?;
+                let list_params = match &params.query {
+                    Some(query) => issue_query_params(&client, query, &params).await?,
+                    None => issue_list_params(&client, params).await?,
+                };
                 let issues = client.issue().get_issue_list(list_params).await?;
                 // TODO: Pretty print issues
                 println!("{issues:?}");
@@ -2380,9 +2647,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         )
                     })?;
 
+                let content = if !add_args.raw {
+                    markdown_for_issue(&client, parsed_issue_id_or_key.clone(), &add_args.content)
+                        .await?
+                } else {
+                    add_args.content.clone()
+                };
+
                 let mut builder = AddCommentParamsBuilder::default();
                 builder.issue_id_or_key(parsed_issue_id_or_key);
-                builder.content(&add_args.content);
+                builder.content(content);
 
                 // Parse notify_users if provided
                 if let Some(notify_str) = &add_args.notify_users {
@@ -2436,10 +2710,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 use backlog_core::identifier::CommentId;
                 use backlog_issue::UpdateCommentParams;
 
+                let issue_id_or_key: IssueIdOrKey = args.issue_id.parse::<IssueKey>()?.into();
+                let content = if !args.raw {
+                    markdown_for_issue(&client, issue_id_or_key.clone(), &args.content).await?
+                } else {
+                    args.content
+                };
                 let params = UpdateCommentParams {
-                    issue_id_or_key: args.issue_id.parse::<IssueKey>()?.into(),
+                    issue_id_or_key,
                     comment_id: CommentId::new(args.comment_id),
-                    content: args.content,
+                    content,
                 };
 
                 match client.issue().update_comment(params).await {
@@ -2523,7 +2803,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     .priority_id(PriorityId::new(create_args.priority_id));
 
                 if let Some(description) = &create_args.description {
-                    builder.description(description);
+                    if !create_args.raw {
+                        builder.description(
+                            markdown_for_project(&client, project_id.into(), description).await?,
+                        );
+                    } else {
+                        builder.description(description);
+                    }
                 }
 
                 if let Some(assignee_id) = create_args.assignee_id {
@@ -2557,7 +2843,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 // Handle custom fields
                 let custom_fields_map = if let Some(json_path) = &create_args.custom_fields_json {
-                    match custom_fields::parse_custom_fields_json(json_path.to_str().unwrap()) {
+                    match custom_fields::read_custom_fields_json(json_path.to_str().unwrap()) {
                         Ok(fields) => Some(fields),
                         Err(e) => {
                             eprintln!("Error parsing custom fields JSON: {e}");
@@ -2566,7 +2852,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 } else if !create_args.custom_fields.is_empty() {
                     match custom_fields::parse_custom_field_args(&create_args.custom_fields) {
-                        Ok(fields) => Some(fields),
+                        Ok(fields) => Some(fields.into()),
                         Err(e) => {
                             eprintln!("Error parsing custom fields: {e}");
                             return Ok(());
@@ -2576,8 +2862,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     None
                 };
 
-                if let Some(fields) = custom_fields_map {
-                    builder.custom_fields(fields);
+                let custom_fields = match resolve_custom_fields(
+                    &client,
+                    project_id,
+                    IssueTypeId::new(create_args.issue_type_id),
+                    true,
+                    custom_fields_map.unwrap_or_default(),
+                )
+                .await
+                {
+                    Ok(fields) => fields,
+                    Err(e) => {
+                        eprintln!("Error: {e}");
+                        return Ok(());
+                    }
+                };
+                if !custom_fields.is_empty() {
+                    builder.custom_fields(custom_fields);
                 }
 
                 let params = builder.build()?;
@@ -2603,14 +2904,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let issue_id_or_key = update_args.issue_id_or_key.parse::<IssueIdOrKey>()?;
 
                 let mut builder = UpdateIssueParamsBuilder::default();
-                builder.issue_id_or_key(issue_id_or_key);
+                builder.issue_id_or_key(issue_id_or_key.clone());
 
                 if let Some(summary) = &update_args.summary {
                     builder.summary(summary);
                 }
 
                 if let Some(description) = &update_args.description {
-                    builder.description(description);
+                    if !update_args.raw {
+                        builder.description(
+                            markdown_for_issue(&client, issue_id_or_key.clone(), description)
+                                .await?,
+                        );
+                    } else {
+                        builder.description(description);
+                    }
                 }
 
                 if let Some(issue_type_id) = update_args.issue_type_id {
@@ -2639,7 +2947,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 // Handle custom fields
                 let custom_fields_map = if let Some(json_path) = &update_args.custom_fields_json {
-                    match custom_fields::parse_custom_fields_json(json_path.to_str().unwrap()) {
+                    match custom_fields::read_custom_fields_json(json_path.to_str().unwrap()) {
                         Ok(fields) => Some(fields),
                         Err(e) => {
                             eprintln!("Error parsing custom fields JSON: {e}");
@@ -2648,7 +2956,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 } else if !update_args.custom_fields.is_empty() {
                     match custom_fields::parse_custom_field_args(&update_args.custom_fields) {
-                        Ok(fields) => Some(fields),
+                        Ok(fields) => Some(fields.into()),
                         Err(e) => {
                             eprintln!("Error parsing custom fields: {e}");
                             return Ok(());
@@ -2659,6 +2967,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 };
 
                 if let Some(fields) = custom_fields_map {
+                    let issue = client
+                        .issue()
+                        .get_issue(backlog_issue::GetIssueParams::new(issue_id_or_key.clone()))
+                        .await?;
+                    let issue_type_id = update_args
+                        .issue_type_id
+                        .map(IssueTypeId::new)
+                        .unwrap_or(issue.issue_type.id);
+                    let fields = match resolve_custom_fields(
+                        &client,
+                        issue.project_id,
+                        issue_type_id,
+                        false,
+                        fields,
+                    )
+                    .await
+                    {
+                        Ok(fields) => fields,
+                        Err(e) => {
+                            eprintln!("Error: {e}");
+                            return Ok(());
+                        }
+                    };
                     builder.custom_fields(fields);
                 }
 
@@ -2895,6 +3226,49 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
+            IssueCommands::History {
+                issue_id_or_key,
+                field,
+                json,
+            } => {
+                let parsed_issue_id_or_key =
+                    IssueIdOrKey::from_str(&issue_id_or_key).map_err(|e| {
+                        format!("Failed to parse issue_id_or_key '{issue_id_or_key}': {e}")
+                    })?;
+
+                match client
+                    .issue()
+                    .get_issue_history(parsed_issue_id_or_key)
+                    .await
+                {
+                    Ok(mut history) => {
+                        if let Some(field) = &field {
+                            history
+                                .transitions
+                                .retain(|t| t.field.name().eq_ignore_ascii_case(field));
+                        }
+                        if json {
+                            println!("{}", serde_json::to_string_pretty(&history)?);
+                        } else if history.is_empty() {
+                            println!("No field changes found for issue: {issue_id_or_key}");
+                        } else {
+                            for transition in &history.transitions {
+                                println!(
+                                    "{}  {}  {}: {} -> {}",
+                                    transition.at.format("%Y-%m-%d %H:%M:%S"),
+                                    transition.changed_by.name,
+                                    transition.field,
+                                    transition.from.as_deref().unwrap_or("(none)"),
+                                    transition.to.as_deref().unwrap_or("(none)")
+                                );
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("Error getting issue history: {e}");
+                    }
+                }
+            }
             IssueCommands::ListParticipants { issue_id_or_key } => {
                 println!("Listing participants for issue: {issue_id_or_key}");
 
@@ -3281,7 +3655,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                         (detail.project_id.value(), total)
                                     })
                                     .collect();
-                                project_usages.sort_by(|a, b| b.1.cmp(&a.1));
+                                project_usages.sort_by_key(|b| std::cmp::Reverse(b.1));
 
                                 for (i, (project_id, usage)) in
                                     project_usages.iter().take(10).enumerate()
@@ -4170,6 +4544,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
+            ProjectCommands::CustomFieldSchema {
+                project_id_or_key,
+                output,
+            } => {
+                let proj_id_or_key = project_id_or_key.parse::<ProjectIdOrKey>()?;
+                let params = backlog_project::GetCustomFieldListParams::new(proj_id_or_key);
+                match client.project().get_custom_field_list(params).await {
+                    Ok(custom_fields) => {
+                        let schema = backlog_issue::custom_field_names::custom_fields_json_schema(
+                            &custom_fields,
+                        );
+                        let json = serde_json::to_string_pretty(&schema)?;
+                        match output {
+                            Some(path) => {
+                                if let Err(e) = fs::write(&path, json).await {
+                                    eprintln!("Error writing schema: {e}");
+                                } else {
+                                    println!("Schema written to: {}", path.display());
+                                }
+                            }
+                            None => println!("{json}"),
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("Error listing custom fields: {e}");
+                    }
+                }
+            }
             #[cfg(feature = "project_writable")]
             ProjectCommands::CustomFieldUpdate {
                 project_id_or_key,
@@ -5504,16 +5906,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     params = params.with_count(count);
                 }
 
-                if let Some(order_str) = order {
-                    let order_enum = match order_str.to_lowercase().as_str() {
-                        "asc" => StarOrder::Asc,
-                        "desc" => StarOrder::Desc,
-                        _ => {
-                            eprintln!("Invalid order: '{order_str}'. Must be 'asc' or 'desc'");
-                            return Ok(());
-                        }
-                    };
-                    params = params.with_order(order_enum);
+                if let Some(order) = order {
+                    params = params.with_order(order);
                 }
 
                 match client.user().get_user_stars(params).await {
@@ -5596,16 +5990,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     params = params.with_count(count);
                 }
 
-                if let Some(order_str) = order {
-                    let notification_order = match order_str.to_lowercase().as_str() {
-                        "asc" => NotificationOrder::Asc,
-                        "desc" => NotificationOrder::Desc,
-                        _ => {
-                            eprintln!("❌ Invalid order. Use 'asc' or 'desc'");
-                            std::process::exit(1);
-                        }
-                    };
-                    params = params.with_order(notification_order);
+                if let Some(order) = order {
+                    params = params.with_order(order);
                 }
 
                 if let Some(sender_id) = sender_id {
@@ -5715,31 +6101,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 let mut params = GetWatchingListParams::builder();
 
-                if let Some(order_str) = order {
-                    let order_enum = match order_str.to_lowercase().as_str() {
-                        "asc" => WatchingOrder::Asc,
-                        "desc" => WatchingOrder::Desc,
-                        _ => {
-                            eprintln!("Invalid order: {order_str}. Use 'asc' or 'desc'");
-                            std::process::exit(1);
-                        }
-                    };
-                    params = params.order(order_enum);
+                if let Some(order) = order {
+                    params = params.order(order);
                 }
 
-                if let Some(sort_str) = sort {
-                    let sort_enum = match sort_str.to_lowercase().as_str() {
-                        "created" => WatchingSort::Created,
-                        "updated" => WatchingSort::Updated,
-                        "issueupdated" => WatchingSort::IssueUpdated,
-                        _ => {
-                            eprintln!(
-                                "Invalid sort: {sort_str}. Use 'created', 'updated', or 'issueUpdated'"
-                            );
-                            std::process::exit(1);
-                        }
-                    };
-                    params = params.sort(sort_enum);
+                if let Some(sort) = sort {
+                    params = params.sort(sort);
                 }
 
                 if let Some(c) = count {
@@ -5917,12 +6284,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
             WikiCommands::ListAttachments { wiki_id } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!("Listing attachments for wiki ID: {wiki_id}");
 
                 match client
                     .wiki()
                     .get_wiki_attachment_list(backlog_wiki::GetWikiAttachmentListParams::new(
-                        WikiId::new(wiki_id),
+                        wiki_id,
                     ))
                     .await
                 {
@@ -5953,12 +6321,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
             WikiCommands::ListSharedFiles { wiki_id } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!("Listing shared files for wiki ID: {wiki_id}");
 
                 match client
                     .wiki()
                     .get_wiki_shared_file_list(backlog_wiki::GetWikiSharedFileListParams::new(
-                        WikiId::new(wiki_id),
+                        wiki_id,
                     ))
                     .await
                 {
@@ -6002,11 +6371,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
             WikiCommands::Stars { wiki_id } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!("Getting stars for wiki ID: {wiki_id}");
 
                 match client
                     .wiki()
-                    .get_wiki_stars(backlog_wiki::GetWikiStarsParams::new(WikiId::new(wiki_id)))
+                    .get_wiki_stars(backlog_wiki::GetWikiStarsParams::new(wiki_id))
                     .await
                 {
                     Ok(stars) => {
@@ -6042,6 +6412,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             #[cfg(feature = "wiki_writable")]
             WikiCommands::LinkSharedFiles { wiki_id, file_ids } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!(
                     "Linking {} shared file(s) to wiki ID: {}",
                     file_ids.len(),
@@ -6053,10 +6424,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     .map(|&id| backlog_core::identifier::SharedFileId::new(id))
                     .collect();
 
-                let params = backlog_wiki::LinkSharedFilesToWikiParams::new(
-                    WikiId::new(wiki_id),
-                    shared_file_ids,
-                );
+                let params =
+                    backlog_wiki::LinkSharedFilesToWikiParams::new(wiki_id, shared_file_ids);
 
                 match client.wiki().link_shared_files_to_wiki(params).await {
                     Ok(shared_files) => {
@@ -6098,10 +6467,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             #[cfg(feature = "wiki_writable")]
             WikiCommands::UnlinkSharedFile { wiki_id, file_id } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!("Unlinking shared file {file_id} from wiki ID: {wiki_id}");
 
                 let params = backlog_wiki::UnlinkSharedFileFromWikiParams::new(
-                    WikiId::new(wiki_id),
+                    wiki_id,
                     backlog_core::identifier::SharedFileId::new(file_id),
                 );
 
@@ -6134,12 +6504,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 attachment_id,
                 output,
             } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!("Downloading attachment {attachment_id} from wiki ID: {wiki_id}");
 
                 match client
                     .wiki()
                     .download_wiki_attachment(backlog_wiki::DownloadWikiAttachmentParams::new(
-                        WikiId::new(wiki_id),
+                        wiki_id,
                         WikiAttachmentId::new(attachment_id),
                     ))
                     .await
@@ -6170,11 +6541,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 project_id,
                 name,
                 content,
+                raw,
                 mail_notify,
             } => {
                 println!("Creating new wiki page in project: {project_id}");
 
-                let params = AddWikiParams::new(ProjectId::from_str(&project_id)?, name, content);
+                let project_id = resolve_project_id(&client, &project_id).await?;
+                let content = if !raw {
+                    markdown_for_project(&client, project_id.into(), &content).await?
+                } else {
+                    content
+                };
+                let params = AddWikiParams::new(project_id, name, content);
 
                 let params = if let Some(mail_notify) = mail_notify {
                     params.mail_notify(mail_notify)
@@ -6205,18 +6583,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 wiki_id,
                 name,
                 content,
+                raw,
                 mail_notify,
             } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!("Updating wiki ID: {wiki_id}");
 
                 // Create params with provided options
-                let mut params = UpdateWikiParams::new(WikiId::new(wiki_id));
+                let mut params = UpdateWikiParams::new(wiki_id);
 
                 if let Some(name) = name {
                     params = params.name(name);
                 }
 
                 if let Some(content) = content {
+                    let content = if !raw {
+                        let wiki = client
+                            .wiki()
+                            .get_wiki_detail(backlog_wiki::GetWikiDetailParams::new(wiki_id))
+                            .await?;
+                        markdown_for_project(&client, wiki.project_id.into(), &content).await?
+                    } else {
+                        content
+                    };
                     params = params.content(content);
                 }
 
@@ -6256,9 +6645,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 wiki_id,
                 mail_notify,
             } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!("Deleting wiki ID: {wiki_id}");
 
-                let mut params = DeleteWikiParams::new(WikiId::new(wiki_id));
+                let mut params = DeleteWikiParams::new(wiki_id);
 
                 if let Some(mail_notify) = mail_notify {
                     params = params.mail_notify(mail_notify);
@@ -6289,6 +6679,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             #[cfg(feature = "wiki_writable")]
             WikiCommands::AttachFile { wiki_id, file_path } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!("Attaching file to wiki ID: {wiki_id}");
 
                 // Step 1: Upload file to space to get attachment ID
@@ -6311,10 +6702,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 // Step 2: Attach the uploaded file to the wiki page
                 println!("🔗 Attaching file to wiki page...");
-                let attach_params = AttachFilesToWikiParams::new(
-                    WikiId::new(wiki_id),
-                    vec![AttachmentId::new(attachment.id)],
-                );
+                let attach_params =
+                    AttachFilesToWikiParams::new(wiki_id, vec![AttachmentId::new(attachment.id)]);
 
                 match client.wiki().attach_files_to_wiki(attach_params).await {
                     Ok(wiki_attachments) => {
@@ -6342,6 +6731,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 attachment_id,
                 force,
             } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 // Get attachment details before deletion for confirmation
                 if !force {
                     print!(
@@ -6362,10 +6752,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 println!("🗑️ Deleting attachment {attachment_id} from wiki {wiki_id}...");
 
-                let delete_params = DeleteWikiAttachmentParams::new(
-                    WikiId::new(wiki_id),
-                    WikiAttachmentId::new(attachment_id),
-                );
+                let delete_params =
+                    DeleteWikiAttachmentParams::new(wiki_id, WikiAttachmentId::new(attachment_id));
 
                 match client.wiki().delete_wiki_attachment(delete_params).await {
                     Ok(deleted_attachment) => {
@@ -6414,10 +6802,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 count,
                 order,
             } => {
+                let wiki_id = resolve_wiki_id(&client, &wiki_id).await?;
                 println!("Getting history for wiki ID: {wiki_id}");
 
-                use backlog_wiki::{GetWikiHistoryParams, HistoryOrder};
-                let mut params = GetWikiHistoryParams::new(WikiId::new(wiki_id));
+                use backlog_wiki::GetWikiHistoryParams;
+                let mut params = GetWikiHistoryParams::new(wiki_id);
 
                 if let Some(min_id) = min_id {
                     params = params.min_id(min_id);
@@ -6429,10 +6818,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     params = params.count(count);
                 }
                 if let Some(order) = order {
-                    let order = match order {
-                        HistoryOrderCli::Asc => HistoryOrder::Asc,
-                        HistoryOrderCli::Desc => HistoryOrder::Desc,
-                    };
                     params = params.order(order);
                 }
 
@@ -6664,7 +7049,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         #[cfg(feature = "star")]
         Commands::Star(star_args) => {
-            handle_star_command(&client.star(), &star_args.command).await?;
+            handle_star_command(&client, &star_args.command).await?;
         }
         #[cfg(feature = "rate-limit")]
         Commands::RateLimit(rate_limit_args) => {
@@ -6681,6 +7066,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Webhook(webhook_args) => {
             commands::webhook::execute(&client, webhook_args).await?;
         }
+        #[cfg(feature = "document")]
+        Commands::Document(document_args) => {
+            commands::document::execute(&client, document_args).await?;
+        }
+        #[cfg(all(feature = "issue", feature = "project", feature = "user"))]
+        Commands::Report(report_args) => {
+            commands::report::execute(&client, report_args).await?;
+        }
     }
 
     Ok(())
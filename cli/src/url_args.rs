@@ -0,0 +1,108 @@
+//! Clap value parsers for arguments that also accept Backlog web URLs.
+//!
+//! The identifier types in `backlog-core` parse bare keys and IDs only, so the
+//! parsers here pick the key or ID out of a pasted URL before the argument is
+//! parsed further. Anything that is not a URL is passed through unchanged.
+
+use backlog_core::identifier::{Identifier, WikiId};
+use backlog_core::{BacklogResource, BacklogUrl, WikiPage, web_url};
+use std::str::FromStr;
+
+fn parse_number_or_url<T: FromStr>(
+    s: &str,
+    what: &str,
+    component: impl FnOnce(&BacklogResource) -> Option<T>,
+) -> Result<T, String> {
+    match s.trim().parse::<T>() {
+        Ok(value) => Ok(value),
+        Err(_) => from_url(s, what, component),
+    }
+}
+
+fn from_url<T>(
+    s: &str,
+    what: &str,
+    component: impl FnOnce(&BacklogResource) -> Option<T>,
+) -> Result<T, String> {
+    let url = BacklogUrl::from_str(s).map_err(|e| format!("expected a {what} or URL: {e}"))?;
+    component(url.resource()).ok_or_else(|| format!("URL does not contain a {what}: {s}"))
+}
+
+/// Parses a pull request number, or takes it from a pull request URL.
+pub fn parse_pr_number(s: &str) -> Result<u64, String> {
+    parse_number_or_url(s, "pull request number", |resource| {
+        resource.pull_request_number().map(|number| number.value())
+    })
+}
+
+/// Parses an issue comment ID, or takes it from an issue URL ending in `#comment-{id}`.
+pub fn parse_comment_id(s: &str) -> Result<u32, String> {
+    parse_number_or_url(s, "comment ID", |resource| {
+        resource.comment_id().map(|id| id.value())
+    })
+}
+
+/// Parses a pull request comment ID, or takes it from a pull request URL ending in `#comment-{id}`.
+pub fn parse_pr_comment_id(s: &str) -> Result<u32, String> {
+    parse_number_or_url(s, "pull request comment ID", |resource| {
+        resource.pull_request_comment_id().map(|id| id.value())
+    })
+}
+
+/// Parses a wiki ID, or a wiki URL addressing the page by ID or by name.
+///
+/// Pages given by name have to be looked up before use.
+pub fn parse_wiki_page(s: &str) -> Result<WikiPage, String> {
+    match s.trim().parse::<u32>() {
+        Ok(id) => Ok(WikiPage::Id(WikiId::new(id))),
+        Err(_) => from_url(s, "wiki page", |resource| resource.wiki_page().cloned()),
+    }
+}
+
+/// Parses a wiki ID, or takes it from a `/alias/wiki/{id}` URL.
+pub fn parse_wiki_id(s: &str) -> Result<u32, String> {
+    parse_number_or_url(s, "wiki ID", |resource| match resource.wiki_page() {
+        Some(WikiPage::Id(id)) => Some(id.value()),
+        _ => None,
+    })
+}
+
+fn key_or_url(
+    s: &str,
+    what: &str,
+    component: impl FnOnce(&BacklogResource) -> Option<String>,
+) -> Result<String, String> {
+    if web_url::is_url(s) {
+        from_url(s, what, component)
+    } else {
+        Ok(s.to_string())
+    }
+}
+
+/// Passes a project ID or key through, or takes the key from any URL that names a project.
+pub fn parse_project_id_or_key(s: &str) -> Result<String, String> {
+    key_or_url(s, "project key", |resource| {
+        resource.project_key().map(ToString::to_string)
+    })
+}
+
+/// Passes an issue ID or key through, or takes the key from an issue URL.
+pub fn parse_issue_id_or_key(s: &str) -> Result<String, String> {
+    key_or_url(s, "issue key", |resource| {
+        resource.issue_key().map(ToString::to_string)
+    })
+}
+
+/// Passes a repository ID or name through, or takes the name from a repository or pull request URL.
+pub fn parse_repo_id_or_name(s: &str) -> Result<String, String> {
+    key_or_url(s, "repository name", |resource| {
+        resource.repository_name().map(ToString::to_string)
+    })
+}
+
+/// Passes a document ID through, or takes it from a document URL.
+pub fn parse_document_id(s: &str) -> Result<String, String> {
+    key_or_url(s, "document ID", |resource| {
+        resource.document_id().map(ToString::to_string)
+    })
+}
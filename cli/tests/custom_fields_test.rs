@@ -64,4 +64,37 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_custom_fields_json_by_id_and_name() {
+        use backlog_core::identifier::CustomFieldId;
+        use blg::custom_fields::{CustomFieldError, read_custom_fields_json};
+
+        let temp_dir = TempDir::new().unwrap();
+        let json_path = temp_dir.path().join("custom_fields.json");
+        fs::write(
+            &json_path,
+            r#"{
+                "$schema": "./custom_fields.schema.json",
+                "1": {"type": "text", "value": "Sample text"},
+                "Severity": "High",
+                "Components": {"items": ["Backend"], "other": "CLI"}
+            }"#,
+        )
+        .unwrap();
+
+        let values = read_custom_fields_json(json_path.to_str().unwrap()).unwrap();
+        assert_eq!(values.by_id.len(), 1);
+        assert!(values.by_id.contains_key(&CustomFieldId::new(1)));
+        assert_eq!(values.by_name.len(), 2);
+        assert_eq!(values.by_name["Severity"], "High");
+
+        // Names cannot be sent without the project's definitions
+        match values.into_ids().unwrap_err() {
+            CustomFieldError::UnresolvedNames { names } => {
+                assert_eq!(names, ["Components", "Severity"]);
+            }
+            e => panic!("Expected UnresolvedNames error, got {e}"),
+        }
+    }
 }
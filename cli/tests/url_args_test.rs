@@ -0,0 +1,80 @@
+use backlog_core::WikiPage;
+use backlog_core::identifier::WikiId;
+use blg::url_args::{
+    parse_comment_id, parse_issue_id_or_key, parse_pr_comment_id, parse_pr_number,
+    parse_project_id_or_key, parse_repo_id_or_name, parse_wiki_id, parse_wiki_page,
+};
+
+#[test]
+fn test_numbers_are_accepted_as_is() {
+    assert_eq!(parse_pr_number("7"), Ok(7));
+    assert_eq!(parse_comment_id(" 345 "), Ok(345));
+    assert_eq!(parse_wiki_id("12"), Ok(12));
+    assert_eq!(parse_wiki_page("12"), Ok(WikiPage::Id(WikiId::new(12))));
+}
+
+#[test]
+fn test_ids_are_taken_from_urls() {
+    assert_eq!(
+        parse_pr_number("https://acme.backlog.com/git/PROJ/repo/pullRequests/7"),
+        Ok(7)
+    );
+    assert_eq!(
+        parse_pr_comment_id("https://acme.backlog.com/git/PROJ/repo/pullRequests/7#comment-9"),
+        Ok(9)
+    );
+    assert_eq!(
+        parse_comment_id("https://acme.backlog.com/view/PROJ-12#comment-345"),
+        Ok(345)
+    );
+    assert_eq!(
+        parse_wiki_id("https://acme.backlog.com/alias/wiki/55"),
+        Ok(55)
+    );
+    assert!(matches!(
+        parse_wiki_page("https://acme.backlog.com/wiki/PROJ/Page%2FSub"),
+        Ok(WikiPage::Name { name, .. }) if name == "Page/Sub"
+    ));
+}
+
+#[test]
+fn test_urls_without_the_id_are_rejected() {
+    let err = parse_comment_id("https://acme.backlog.com/view/PROJ-12").unwrap_err();
+    assert!(err.contains("does not contain a comment ID"), "{err}");
+    assert!(parse_wiki_id("https://acme.backlog.com/wiki/PROJ/Home").is_err());
+    assert!(parse_pr_number("not-a-number").is_err());
+}
+
+#[test]
+fn test_keys_are_taken_from_urls() {
+    assert_eq!(parse_issue_id_or_key("PROJ-12"), Ok("PROJ-12".to_string()));
+    assert_eq!(
+        parse_issue_id_or_key("https://acme.backlog.com/view/PROJ-12#comment-3"),
+        Ok("PROJ-12".to_string())
+    );
+    assert_eq!(
+        parse_project_id_or_key("https://acme.backlog.com/git/PROJ/repo/pullRequests/7"),
+        Ok("PROJ".to_string())
+    );
+    assert_eq!(
+        parse_repo_id_or_name("https://acme.backlog.com/git/PROJ/repo/pullRequests/7"),
+        Ok("repo".to_string())
+    );
+    assert!(parse_issue_id_or_key("https://acme.backlog.com/projects/PROJ").is_err());
+}
+
+#[test]
+fn test_ids_resolved_later_are_passed_through() {
+    // `pr create --issue-id`, `star add issue` and `wiki create --project-id` look keys up by ID.
+    assert_eq!(parse_issue_id_or_key("67890"), Ok("67890".to_string()));
+    assert_eq!(parse_project_id_or_key("123"), Ok("123".to_string()));
+    assert_eq!(
+        parse_project_id_or_key("https://acme.backlog.com/wiki/PROJ/Home"),
+        Ok("PROJ".to_string())
+    );
+    assert_eq!(
+        parse_project_id_or_key("https://acme.backlog.com/projects/PROJ"),
+        Ok("PROJ".to_string())
+    );
+    assert!(parse_issue_id_or_key("https://acme.backlog.com/view/PROJ-99999999999").is_err());
+}
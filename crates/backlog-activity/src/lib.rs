@@ -1,6 +1,10 @@
 pub mod api;
+#[cfg(feature = "watcher")]
+pub mod watcher;
 
 pub use api::ActivityApi;
 
 // Re-export API types
 pub use api::GetActivityParams;
+#[cfg(feature = "watcher")]
+pub use watcher::{ActivitySource, ActivityWatcher, CheckpointStore, FileCheckpointStore};
@@ -0,0 +1,178 @@
+use backlog_core::identifier::{ActivityId, Identifier};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Persists the ID of the last activity a watcher has handed out.
+///
+/// Checkpoints are keyed so that one store can serve several watchers
+/// (for example one per project).
+///
+/// A store may hold saved checkpoints back and write them later in one go; the
+/// watcher calls [`flush`](Self::flush) before it waits for the next poll.
+/// Checkpoints not yet written when the process dies are delivered again after
+/// a restart.
+pub trait CheckpointStore: Send + Sync {
+    /// Returns the stored checkpoint for `key`, or `None` if the watcher has never run.
+    fn load(&self, key: &str) -> io::Result<Option<ActivityId>>;
+
+    /// Stores `activity_id` as the checkpoint for `key`.
+    fn save(&self, key: &str, activity_id: ActivityId) -> io::Result<()>;
+
+    /// Writes checkpoints held back by [`save`](Self::save). Does nothing by default.
+    fn flush(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Default delay between two writes of a [`FileCheckpointStore`].
+pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Keeps checkpoints in a JSON file mapping keys to activity IDs.
+///
+/// Saves update an in-memory copy, and the file is rewritten at most once per
+/// [`flush_interval`](Self::flush_interval), on [`flush`](CheckpointStore::flush),
+/// and when the store is dropped. The file is rewritten through a temporary file
+/// and a rename, so an interrupted write never leaves a truncated checkpoint behind.
+///
+/// The file is read once, so it must not be shared with another running store.
+#[derive(Debug)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+    flush_interval: Duration,
+    state: Mutex<FileState>,
+}
+
+#[derive(Debug, Default)]
+struct FileState {
+    /// Checkpoints read from the file, plus those saved since.
+    checkpoints: Option<BTreeMap<String, u32>>,
+    dirty: bool,
+    last_write: Option<Instant>,
+}
+
+impl FileCheckpointStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            flush_interval: DEFAULT_FLUSH_INTERVAL,
+            state: Mutex::new(FileState::default()),
+        }
+    }
+
+    /// Sets the least delay between two writes. `Duration::ZERO` writes on every save.
+    pub fn flush_interval(mut self, interval: Duration) -> Self {
+        self.flush_interval = interval;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read_all(&self) -> io::Result<BTreeMap<String, u32>> {
+        match fs::read(&self.path) {
+            Ok(bytes) if bytes.is_empty() => Ok(BTreeMap::new()),
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn checkpoints<'a>(
+        &self,
+        state: &'a mut FileState,
+    ) -> io::Result<&'a mut BTreeMap<String, u32>> {
+        if state.checkpoints.is_none() {
+            state.checkpoints = Some(self.read_all()?);
+        }
+        Ok(state.checkpoints.get_or_insert_default())
+    }
+
+    fn write(&self, state: &mut FileState) -> io::Result<()> {
+        let checkpoints = self.checkpoints(state)?;
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        let json = serde_json::to_vec_pretty(checkpoints)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, &self.path)?;
+        state.dirty = false;
+        state.last_write = Some(Instant::now());
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FileState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn load(&self, key: &str) -> io::Result<Option<ActivityId>> {
+        let mut state = self.lock();
+        Ok(self
+            .checkpoints(&mut state)?
+            .get(key)
+            .copied()
+            .map(ActivityId::new))
+    }
+
+    fn save(&self, key: &str, activity_id: ActivityId) -> io::Result<()> {
+        let mut state = self.lock();
+        self.checkpoints(&mut state)?
+            .insert(key.to_string(), activity_id.value());
+        state.dirty = true;
+        let due = state
+            .last_write
+            .is_none_or(|at| at.elapsed() >= self.flush_interval);
+        if due { self.write(&mut state) } else { Ok(()) }
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        let mut state = self.lock();
+        if state.dirty {
+            self.write(&mut state)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for FileCheckpointStore {
+    fn drop(&mut self) {
+        // Nothing can be reported from here; a lost write only means a replay.
+        let _ = self.flush();
+    }
+}
+
+/// Keeps checkpoints in memory only; restarting the process starts over.
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoints: Mutex<BTreeMap<String, ActivityId>>,
+}
+
+impl MemoryCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load(&self, key: &str) -> io::Result<Option<ActivityId>> {
+        let checkpoints = self.checkpoints.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(checkpoints.get(key).copied())
+    }
+
+    fn save(&self, key: &str, activity_id: ActivityId) -> io::Result<()> {
+        let mut checkpoints = self.checkpoints.lock().unwrap_or_else(|e| e.into_inner());
+        checkpoints.insert(key.to_string(), activity_id);
+        Ok(())
+    }
+}
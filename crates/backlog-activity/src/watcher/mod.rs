@@ -0,0 +1,330 @@
+//! Polling watcher that turns recent-update endpoints into a stream of new activities.
+
+mod checkpoint;
+
+pub use checkpoint::{
+    CheckpointStore, DEFAULT_FLUSH_INTERVAL, FileCheckpointStore, MemoryCheckpointStore,
+};
+
+use backlog_api_core::Error as ApiError;
+use backlog_core::activity::Activity;
+use backlog_core::identifier::{ActivityId, ActivityTypeId, Identifier, ProjectId};
+use backlog_core::{Order, ProjectIdOrKey};
+use backlog_project::GetProjectRecentUpdatesParams;
+use backlog_space::GetSpaceRecentUpdatesParams;
+use client::Client;
+use futures::Stream;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Largest page size accepted by the recent-updates endpoints.
+const PAGE_SIZE: u32 = 100;
+
+/// Default delay between two polls.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Errors yielded by an [`ActivityWatcher`] stream.
+///
+/// Errors do not end the stream; the watcher retries on the next poll.
+#[derive(Debug, thiserror::Error)]
+pub enum WatchError {
+    #[error("Failed to fetch activities: {0}")]
+    Api(#[from] ApiError),
+
+    #[error("Failed to access activity checkpoint: {0}")]
+    Checkpoint(#[from] std::io::Error),
+}
+
+/// Where a watcher reads activities from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActivitySource {
+    /// `GET /api/v2/space/activities`
+    Space,
+    /// `GET /api/v2/projects/:projectIdOrKey/activities`
+    Project(ProjectIdOrKey),
+}
+
+impl ActivitySource {
+    fn checkpoint_key(&self) -> String {
+        match self {
+            ActivitySource::Space => "space".to_string(),
+            ActivitySource::Project(project) => format!("project:{project}"),
+        }
+    }
+}
+
+/// Yields activities that appeared since the last run, as a [`Stream`].
+///
+/// The watcher polls the recent-updates endpoint, oldest first, starting after
+/// the stored checkpoint. An activity's ID is saved to the checkpoint store
+/// when the next item is requested, i.e. once the caller is done with it.
+///
+/// Delivery is at-least-once: a restart never skips an activity, but the one in
+/// flight is delivered again, and so are any whose checkpoint the store had not
+/// yet written (see [`FileCheckpointStore`]). Handlers should be idempotent, for
+/// example by remembering the activity IDs they have acted on.
+///
+/// On the very first run (no checkpoint stored) the watcher starts from the most
+/// recent activity instead of replaying history, unless
+/// [`replay_history`](Self::replay_history) is set.
+///
+/// ```no_run
+/// use backlog_activity::watcher::{ActivityWatcher, FileCheckpointStore};
+/// use backlog_core::activity::ActivityType;
+/// use futures::StreamExt;
+/// use std::time::Duration;
+///
+/// # async fn run(client: client::Client) {
+/// let mut activities = Box::pin(
+///     ActivityWatcher::project(
+///         client,
+///         "PROJ".parse().unwrap(),
+///         FileCheckpointStore::new("/var/lib/backlog-bot/checkpoints.json"),
+///     )
+///         .interval(Duration::from_secs(30))
+///         .activity_types([ActivityType::IssueCreated, ActivityType::IssueCommented])
+///         .stream(),
+/// );
+/// while let Some(activity) = activities.next().await {
+///     match activity {
+///         Ok(activity) => println!("{} {:?}", activity.id, activity.activity_type()),
+///         Err(e) => eprintln!("{e}"),
+///     }
+/// }
+/// # }
+/// ```
+pub struct ActivityWatcher {
+    client: Client,
+    source: ActivitySource,
+    interval: Duration,
+    activity_type_ids: Vec<ActivityTypeId>,
+    project_ids: Vec<ProjectId>,
+    store: Arc<dyn CheckpointStore>,
+    checkpoint_key: String,
+    replay_history: bool,
+}
+
+impl ActivityWatcher {
+    /// Watches activities across the whole space.
+    pub fn space(client: Client, store: impl CheckpointStore + 'static) -> Self {
+        Self::new(client, ActivitySource::Space, store)
+    }
+
+    /// Watches activities of a single project.
+    pub fn project(
+        client: Client,
+        project_id_or_key: ProjectIdOrKey,
+        store: impl CheckpointStore + 'static,
+    ) -> Self {
+        Self::new(client, ActivitySource::Project(project_id_or_key), store)
+    }
+
+    /// Creates a watcher that keeps its checkpoint in `store`.
+    pub fn new(
+        client: Client,
+        source: ActivitySource,
+        store: impl CheckpointStore + 'static,
+    ) -> Self {
+        let checkpoint_key = source.checkpoint_key();
+        Self {
+            client,
+            source,
+            interval: DEFAULT_POLL_INTERVAL,
+            activity_type_ids: Vec::new(),
+            project_ids: Vec::new(),
+            store: Arc::new(store),
+            checkpoint_key,
+            replay_history: false,
+        }
+    }
+
+    /// Sets the delay between polls.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Only yields activities of the given types. Filtering happens server-side.
+    pub fn activity_types<I, T>(mut self, activity_types: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<ActivityTypeId>,
+    {
+        self.activity_type_ids = activity_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Only yields activities belonging to one of the given projects.
+    ///
+    /// Mostly useful with [`ActivitySource::Space`] to follow a handful of projects
+    /// with a single poll.
+    pub fn projects<I, T>(mut self, project_ids: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<ProjectId>,
+    {
+        self.project_ids = project_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the key the checkpoint is stored under. Defaults to `space` or `project:<key>`.
+    pub fn checkpoint_key(mut self, key: impl Into<String>) -> Self {
+        self.checkpoint_key = key.into();
+        self
+    }
+
+    /// Yields the available history on the first run instead of starting from the latest activity.
+    pub fn replay_history(mut self, replay: bool) -> Self {
+        self.replay_history = replay;
+        self
+    }
+
+    /// Starts watching. The stream never ends on its own.
+    pub fn stream(self) -> impl Stream<Item = Result<Activity, WatchError>> + Send + 'static {
+        let state = WatchState {
+            watcher: self,
+            last_id: None,
+            loaded: false,
+            buffer: VecDeque::new(),
+            uncommitted: None,
+            batch_end: None,
+            poll_now: true,
+        };
+        futures::stream::unfold(state, |mut state| async move {
+            let item = state.next().await;
+            Some((item, state))
+        })
+    }
+
+    async fn fetch(
+        &self,
+        min_id: Option<ActivityId>,
+        count: u32,
+        order: Order,
+    ) -> Result<Vec<Activity>, ApiError> {
+        let activity_type_ids =
+            (!self.activity_type_ids.is_empty()).then(|| self.activity_type_ids.clone());
+        match &self.source {
+            ActivitySource::Space => {
+                let params = GetSpaceRecentUpdatesParams {
+                    activity_type_ids,
+                    min_id,
+                    count: Some(count),
+                    order: Some(order),
+                    ..Default::default()
+                };
+                self.client.execute(params).await
+            }
+            ActivitySource::Project(project) => {
+                let mut params = GetProjectRecentUpdatesParams::new(project.clone());
+                params.activity_type_ids = activity_type_ids;
+                params.min_id = min_id;
+                params.count = Some(count);
+                params.order = Some(order);
+                self.client.execute(params).await
+            }
+        }
+    }
+
+    fn matches_project(&self, activity: &Activity) -> bool {
+        self.project_ids.is_empty()
+            || activity
+                .project_id()
+                .is_some_and(|id| self.project_ids.iter().any(|p| p.value() == id))
+    }
+}
+
+impl std::fmt::Debug for ActivityWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ActivityWatcher")
+            .field("source", &self.source)
+            .field("interval", &self.interval)
+            .field("activity_type_ids", &self.activity_type_ids)
+            .field("project_ids", &self.project_ids)
+            .field("checkpoint_key", &self.checkpoint_key)
+            .field("replay_history", &self.replay_history)
+            .finish()
+    }
+}
+
+struct WatchState {
+    watcher: ActivityWatcher,
+    /// Highest activity ID fetched so far.
+    last_id: Option<ActivityId>,
+    loaded: bool,
+    buffer: VecDeque<Activity>,
+    /// Checkpoint to persist before handing out the next item.
+    uncommitted: Option<ActivityId>,
+    /// Newest ID of the current batch, persisted once the batch has been handed out.
+    batch_end: Option<ActivityId>,
+    /// Skip the interval before the next poll (first poll or a full page).
+    poll_now: bool,
+}
+
+impl WatchState {
+    async fn next(&mut self) -> Result<Activity, WatchError> {
+        loop {
+            if let Some(id) = self.uncommitted.take() {
+                self.save(id)?;
+            }
+            if let Some(activity) = self.buffer.pop_front() {
+                self.uncommitted = Some(activity.id);
+                return Ok(activity);
+            }
+            if let Some(id) = self.batch_end.take() {
+                self.save(id)?;
+            }
+            if !self.loaded {
+                self.load().await?;
+                continue;
+            }
+            if !std::mem::replace(&mut self.poll_now, false) {
+                self.watcher.store.flush()?;
+                tokio::time::sleep(self.watcher.interval).await;
+            }
+            self.poll().await?;
+        }
+    }
+
+    async fn load(&mut self) -> Result<(), WatchError> {
+        let watcher = &self.watcher;
+        self.last_id = watcher.store.load(&watcher.checkpoint_key)?;
+        if self.last_id.is_none() && !watcher.replay_history {
+            let latest = watcher.fetch(None, 1, Order::Desc).await?;
+            if let Some(activity) = latest.first() {
+                self.last_id = Some(activity.id);
+                self.save(activity.id)?;
+            }
+        }
+        self.loaded = true;
+        Ok(())
+    }
+
+    async fn poll(&mut self) -> Result<(), WatchError> {
+        let after = self.last_id.map(|id| id.value()).unwrap_or(0);
+        let min_id = self.last_id.map(|id| ActivityId::new(id.value() + 1));
+        let mut page = self.watcher.fetch(min_id, PAGE_SIZE, Order::Asc).await?;
+        self.poll_now = page.len() as u32 >= PAGE_SIZE;
+
+        page.retain(|activity| activity.id.value() > after);
+        page.sort_by_key(|activity| activity.id.value());
+        let Some(newest) = page.last().map(|activity| activity.id) else {
+            return Ok(());
+        };
+        self.last_id = Some(newest);
+
+        self.buffer
+            .extend(page.into_iter().filter(|a| self.watcher.matches_project(a)));
+        // Activities dropped by the project filter still move the checkpoint forward.
+        self.batch_end = Some(newest);
+        Ok(())
+    }
+
+    fn save(&self, id: ActivityId) -> Result<(), WatchError> {
+        self.watcher
+            .store
+            .save(&self.watcher.checkpoint_key, id)
+            .map_err(WatchError::from)
+    }
+}
@@ -0,0 +1,269 @@
+#![cfg(feature = "watcher")]
+
+#[cfg(test)]
+mod watcher_tests {
+    use backlog_activity::watcher::{
+        ActivityWatcher, CheckpointStore, FileCheckpointStore, MemoryCheckpointStore,
+    };
+    use backlog_core::activity::ActivityType;
+    use backlog_core::identifier::{ActivityId, Identifier, ProjectId};
+    use client::Client;
+    use futures::StreamExt;
+    use serde_json::{Value, json};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn activity(id: u32, project_id: u32) -> Value {
+        json!({
+            "id": id,
+            "project": {
+                "id": project_id,
+                "projectKey": format!("P{project_id}"),
+                "name": format!("Project {project_id}"),
+                "chartEnabled": false,
+                "subtaskingEnabled": false,
+                "projectLeaderCanEditProjectLeader": false,
+                "useWikiTreeView": false,
+                "textFormattingRule": "markdown",
+                "archived": false,
+                "displayOrder": 0,
+                "useDevAttributes": true,
+                "useWiki": true,
+                "useFileSharing": true,
+                "useOriginalImageSizeAtWiki": false
+            },
+            "type": 1,
+            "content": {"id": id, "key_id": id, "summary": "Issue", "description": ""},
+            "notifications": [],
+            "createdUser": {
+                "id": 1,
+                "userId": "admin",
+                "name": "Admin",
+                "roleType": 1,
+                "lang": "ja",
+                "mailAddress": "admin@example.com"
+            },
+            "created": "2024-01-01T10:00:00Z"
+        })
+    }
+
+    async fn mock_activities(server: &MockServer, api_path: &str, min_id: &str, body: Value) {
+        Mock::given(method("GET"))
+            .and(path(api_path))
+            .and(query_param("minId", min_id))
+            .and(query_param("order", "asc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(server)
+            .await;
+    }
+
+    /// Store wrapper that lets the test keep a handle on the checkpoints.
+    struct SharedStore(Arc<MemoryCheckpointStore>);
+
+    impl CheckpointStore for SharedStore {
+        fn load(&self, key: &str) -> std::io::Result<Option<ActivityId>> {
+            self.0.load(key)
+        }
+
+        fn save(&self, key: &str, activity_id: ActivityId) -> std::io::Result<()> {
+            self.0.save(key, activity_id)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_first_run_starts_from_latest_activity() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/projects/PROJ/activities"))
+            .and(query_param("order", "desc"))
+            .and(query_param("count", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([activity(10, 1)])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mock_activities(
+            &server,
+            "/api/v2/projects/PROJ/activities",
+            "11",
+            json!([activity(11, 1), activity(12, 1)]),
+        )
+        .await;
+
+        let store = Arc::new(MemoryCheckpointStore::new());
+        let client = Client::new(&server.uri()).unwrap();
+        let activities: Vec<_> =
+            ActivityWatcher::project(client, "PROJ".parse().unwrap(), SharedStore(store.clone()))
+                .interval(Duration::from_millis(10))
+                .stream()
+                .take(2)
+                .collect()
+                .await;
+
+        let ids: Vec<u32> = activities
+            .into_iter()
+            .map(|a| a.unwrap().id.value())
+            .collect();
+        assert_eq!(ids, vec![11, 12]);
+        // The last activity handed out has not been acknowledged yet.
+        assert_eq!(
+            store.load("project:PROJ").unwrap().map(|id| id.value()),
+            Some(11)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restart_resumes_from_checkpoint() {
+        let server = MockServer::start().await;
+        mock_activities(
+            &server,
+            "/api/v2/space/activities",
+            "21",
+            json!([activity(21, 1), activity(22, 1)]),
+        )
+        .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let checkpoint_path = dir.path().join("checkpoints.json");
+        FileCheckpointStore::new(&checkpoint_path)
+            .save("space", ActivityId::new(20))
+            .unwrap();
+
+        let client = Client::new(&server.uri()).unwrap();
+        let first = ActivityWatcher::space(client, FileCheckpointStore::new(&checkpoint_path))
+            .stream()
+            .take(1)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(first[0].as_ref().unwrap().id.value(), 21);
+
+        // Activity 21 was never acknowledged, so it is delivered again after a restart.
+        let client = Client::new(&server.uri()).unwrap();
+        let second = ActivityWatcher::space(client, FileCheckpointStore::new(&checkpoint_path))
+            .stream()
+            .take(2)
+            .collect::<Vec<_>>()
+            .await;
+        let ids: Vec<u32> = second
+            .iter()
+            .map(|a| a.as_ref().unwrap().id.value())
+            .collect();
+        assert_eq!(ids, vec![21, 22]);
+        assert_eq!(
+            FileCheckpointStore::new(&checkpoint_path)
+                .load("space")
+                .unwrap()
+                .map(|id| id.value()),
+            Some(21)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_filters_by_project_and_activity_type() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/space/activities"))
+            .and(query_param("minId", "6"))
+            .and(query_param("activityTypeId[]", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                activity(6, 1),
+                activity(7, 2),
+                activity(8, 1),
+                activity(9, 2)
+            ])))
+            .mount(&server)
+            .await;
+        mock_activities(&server, "/api/v2/space/activities", "10", json!([])).await;
+
+        let store = Arc::new(MemoryCheckpointStore::new());
+        store.save("space", ActivityId::new(5)).unwrap();
+        let client = Client::new(&server.uri()).unwrap();
+        let mut stream = Box::pin(
+            ActivityWatcher::space(client, SharedStore(store.clone()))
+                .interval(Duration::from_millis(10))
+                .activity_types([ActivityType::IssueCreated])
+                .projects([ProjectId::new(1)])
+                .stream(),
+        );
+
+        assert_eq!(stream.next().await.unwrap().unwrap().id.value(), 6);
+        assert_eq!(stream.next().await.unwrap().unwrap().id.value(), 8);
+
+        // Pull once more so the watcher acknowledges the batch, including the
+        // filtered-out activity 9, before polling again.
+        let next = tokio::time::timeout(Duration::from_millis(100), stream.next()).await;
+        assert!(next.is_err());
+        assert_eq!(store.load("space").unwrap().map(|id| id.value()), Some(9));
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_are_yielded_without_ending_the_stream() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/space/activities"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+                "errors": [{"message": "Internal error", "code": 1, "moreInfo": ""}]
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        mock_activities(
+            &server,
+            "/api/v2/space/activities",
+            "2",
+            json!([activity(2, 1)]),
+        )
+        .await;
+
+        let store = MemoryCheckpointStore::new();
+        store.save("space", ActivityId::new(1)).unwrap();
+        let client = Client::new(&server.uri()).unwrap();
+        let items = ActivityWatcher::space(client, store)
+            .interval(Duration::from_millis(10))
+            .stream()
+            .take(2)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(items[0].is_err());
+        assert_eq!(items[1].as_ref().unwrap().id.value(), 2);
+    }
+
+    #[test]
+    fn test_file_checkpoint_store_keeps_keys_separate() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileCheckpointStore::new(dir.path().join("nested/checkpoints.json"));
+
+        assert_eq!(store.load("space").unwrap(), None);
+        store.save("space", ActivityId::new(100)).unwrap();
+        store.save("project:PROJ", ActivityId::new(7)).unwrap();
+        store.save("space", ActivityId::new(101)).unwrap();
+
+        assert_eq!(store.load("space").unwrap().map(|id| id.value()), Some(101));
+        assert_eq!(
+            store.load("project:PROJ").unwrap().map(|id| id.value()),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn test_file_checkpoint_store_batches_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("checkpoints.json");
+        let store = FileCheckpointStore::new(&path).flush_interval(Duration::from_secs(3600));
+        let on_disk = || FileCheckpointStore::new(&path).load("space").unwrap();
+
+        store.save("space", ActivityId::new(1)).unwrap();
+        store.save("space", ActivityId::new(2)).unwrap();
+        assert_eq!(on_disk().map(|id| id.value()), Some(1));
+        assert_eq!(store.load("space").unwrap().map(|id| id.value()), Some(2));
+
+        store.flush().unwrap();
+        assert_eq!(on_disk().map(|id| id.value()), Some(2));
+
+        store.save("space", ActivityId::new(3)).unwrap();
+        drop(store);
+        assert_eq!(on_disk().map(|id| id.value()), Some(3));
+    }
+}
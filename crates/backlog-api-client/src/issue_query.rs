@@ -0,0 +1,123 @@
+//! Compiling [`IssueQuery`] search strings against the master data of a space.
+
+use crate::client::BacklogApiClient;
+use backlog_api_core::Error as ApiError;
+use backlog_core::User;
+use backlog_issue::GetIssueListParams;
+use backlog_issue::query::{Field, IssueQuery, ProjectData, QueryContext, QueryError};
+use backlog_project::{
+    GetCategoryListParams, GetIssueTypeListParams, GetMilestoneListParams, GetProjectListParams,
+    GetProjectUserListParams, GetStatusListParams,
+};
+use backlog_user::{GetOwnUserParams, GetUserListParams};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum IssueQueryError {
+    #[error(transparent)]
+    Query(#[from] QueryError),
+    #[error(transparent)]
+    Api(#[from] ApiError),
+}
+
+impl BacklogApiClient {
+    /// Parses `query` and resolves the names in it to IDs.
+    pub async fn compile_issue_query(
+        &self,
+        query: &str,
+    ) -> Result<GetIssueListParams, IssueQueryError> {
+        let query = IssueQuery::parse(query)?;
+        let context = self.load_query_context(&query).await?;
+        Ok(query.compile(&context)?)
+    }
+
+    /// Fetches the master data `query` refers to, and nothing else.
+    pub async fn load_query_context(
+        &self,
+        query: &IssueQuery,
+    ) -> Result<QueryContext, IssueQueryError> {
+        let mut context = QueryContext {
+            today: chrono::Local::now().date_naive(),
+            ..Default::default()
+        };
+
+        if query.uses(Field::Project) {
+            let projects = self
+                .project()
+                .get_project_list(GetProjectListParams::default())
+                .await?;
+            for project in query.resolve_projects(&projects)? {
+                context
+                    .projects
+                    .push(self.load_project_data(query, project.clone()).await?);
+            }
+        }
+        if query.uses(Field::Priority) {
+            context.priorities = self.project().get_priority_list().await?;
+        }
+        if query.uses(Field::Resolution) {
+            context.resolutions = self.project().get_resolution_list().await?;
+        }
+        if query.mentions_me() {
+            context.me = Some(self.user().get_own_user(GetOwnUserParams::new()).await?);
+        }
+        if query.needs_users() {
+            context.users = self.query_users(&context.projects).await?;
+        }
+        Ok(context)
+    }
+
+    async fn load_project_data(
+        &self,
+        query: &IssueQuery,
+        project: backlog_domain_models::Project,
+    ) -> Result<ProjectData, ApiError> {
+        let id = project.id;
+        let mut data = ProjectData::new(project);
+        if query.uses(Field::Status) {
+            data.statuses = self
+                .project()
+                .get_status_list(GetStatusListParams::new(id))
+                .await?;
+        }
+        if query.uses(Field::IssueType) {
+            data.issue_types = self
+                .project()
+                .get_issue_type_list(GetIssueTypeListParams::new(id))
+                .await?;
+        }
+        if query.uses(Field::Category) {
+            data.categories = self
+                .project()
+                .get_category_list(GetCategoryListParams::new(id))
+                .await?;
+        }
+        if query.uses(Field::Milestone) || query.uses(Field::Version) {
+            data.milestones = self
+                .project()
+                .get_version_milestone_list(GetMilestoneListParams::new(id))
+                .await?;
+        }
+        Ok(data)
+    }
+
+    /// Members of the queried projects, or every user of the space without a project.
+    async fn query_users(&self, projects: &[ProjectData]) -> Result<Vec<User>, ApiError> {
+        if projects.is_empty() {
+            return self.user().get_user_list(GetUserListParams::new()).await;
+        }
+        let mut users: Vec<User> = Vec::new();
+        for data in projects {
+            let members = self
+                .project()
+                .get_project_user_list(GetProjectUserListParams::new(data.project.id))
+                .await?;
+            for member in members {
+                if !users.iter().any(|user| user.id == member.id) {
+                    users.push(member);
+                }
+            }
+        }
+        Ok(users)
+    }
+}
@@ -1,4 +1,6 @@
 pub mod client;
+#[cfg(all(feature = "issue", feature = "project", feature = "user"))]
+pub mod issue_query;
 pub use ::client::DownloadedFile; // Re-export DownloadedFile from the client crate
 pub use backlog_api_core::Error as ApiError;
 pub use backlog_api_core::bytes; // Re-export bytes
@@ -8,6 +10,7 @@ pub use backlog_core::{
     Error as CoreError,
     IssueIdOrKey,
     IssueKey,
+    Order,
     ProjectIdOrKey,
     RepositoryIdOrName,
     // User,
@@ -18,6 +21,7 @@ pub use backlog_core::{
         AttachmentId, DocumentId, ProjectId, PullRequestAttachmentId, PullRequestCommentId,
         PullRequestNumber, StatusId, UserId, WatchingId, WikiId, WikiTagId,
     },
+    mention,
 };
 
 // Domain models (from backlog_domain_models)
@@ -26,9 +30,11 @@ pub use backlog_domain_models::Star as UserStar;
 
 // Document module (from backlog_document)
 #[cfg(feature = "document")]
+pub use backlog_document::prosemirror;
+#[cfg(feature = "document")]
 pub use backlog_document::{
-    DocumentApi, DocumentDetail, DocumentTreeRootNode, DownloadAttachmentParams, GetDocumentParams,
-    GetDocumentTreeParams, GetDocumentTreeResponse,
+    DocumentApi, DocumentDetail, DocumentTreeNode, DocumentTreeRootNode, DownloadAttachmentParams,
+    GetDocumentParams, GetDocumentTreeParams, GetDocumentTreeResponse,
 };
 
 // File module (from backlog_file)
@@ -39,10 +45,11 @@ pub use backlog_file::{FileApi, GetSharedFilesListParams, GetSharedFilesListResp
 
 // Issue module (from backlog_issue)
 #[cfg(feature = "issue")]
+#[allow(deprecated)]
 pub use backlog_issue::{
     Attachment, ChangeLogEntry, Comment, CommentOrder, GetCommentListParams,
     GetCommentListParamsBuilder, GetCommentNotificationsParams, GetIssueListParams,
-    GetIssueListParamsBuilder, Issue, IssueApi, SharedFile as IssueSharedFile,
+    GetIssueListParamsBuilder, Issue, IssueApi, IssueSort, SharedFile as IssueSharedFile,
 };
 
 #[cfg(all(feature = "issue", feature = "issue_writable"))]
@@ -54,12 +61,18 @@ pub use backlog_issue::{
 #[cfg(feature = "issue")]
 pub use backlog_issue;
 
+#[cfg(feature = "issue")]
+pub use backlog_issue::query::{IssueQuery, QueryContext, QueryError};
+#[cfg(all(feature = "issue", feature = "project", feature = "user"))]
+pub use issue_query::IssueQueryError;
+
 // Issue writable operations (from backlog_issue)
 #[cfg(all(feature = "issue", feature = "issue_writable"))]
 pub use backlog_issue::{LinkSharedFilesToIssueParams, LinkSharedFilesToIssueParamsBuilder};
 
 // Git module (from backlog_git)
 #[cfg(feature = "git")]
+#[allow(deprecated)]
 pub use backlog_git::{
     ChangeLog, DownloadPullRequestAttachmentParams, DownloadPullRequestAttachmentResponse,
     GetPullRequestAttachmentListParams, GetPullRequestAttachmentListResponse,
@@ -100,6 +113,7 @@ pub use backlog_space::{UpdateSpaceNotificationParams, UploadAttachmentParams};
 
 // User module (from backlog_user)
 #[cfg(feature = "user")]
+#[allow(deprecated)]
 pub use backlog_user::{
     GetUserStarCountParams, GetUserStarCountResponse, GetUserStarsParams, GetUserStarsResponse,
     GetWatchingCountParams, GetWatchingListParams, GetWatchingListParamsBuilder,
@@ -109,6 +123,11 @@ pub use backlog_user::{
 // Activity module (from backlog_activity)
 #[cfg(feature = "activity")]
 pub use backlog_activity::ActivityApi;
+#[cfg(feature = "activity-watcher")]
+pub use backlog_activity::watcher::{
+    ActivitySource, ActivityWatcher, CheckpointStore, FileCheckpointStore, MemoryCheckpointStore,
+    WatchError,
+};
 #[cfg(feature = "activity")]
 pub use backlog_core::activity::Activity;
 
@@ -126,6 +145,7 @@ pub use backlog_wiki::{UpdateWikiParams, UpdateWikiResponse};
 
 // Team module (from backlog_team)
 #[cfg(feature = "team")]
+#[allow(deprecated)]
 pub use backlog_team::{
     TeamApi,
     api::{GetTeamParams, GetTeamResponse, ListTeamsOrder, ListTeamsParams, ListTeamsResponse},
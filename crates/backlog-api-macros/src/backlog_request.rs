@@ -0,0 +1,201 @@
+use crate::to_form_params::is_skipped;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitStr, Result, spanned::Spanned};
+
+/// Struct-level `#[request(...)]` configuration
+struct RequestConfig {
+    method: Ident,
+    path: LitStr,
+}
+
+/// Main entry point for the BacklogRequest derive macro
+pub fn expand_derive_backlog_request(input: DeriveInput) -> Result<TokenStream> {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let config = parse_request_attribute(&input)?;
+
+    let fields = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) => fields.named.iter().collect(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(_) => {
+                return Err(Error::new(
+                    data_struct.fields.span(),
+                    "BacklogRequest does not support tuple structs",
+                ));
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "BacklogRequest can only be derived for structs",
+            ));
+        }
+    };
+    let field_names: Vec<String> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(Ident::to_string))
+        .collect();
+
+    let (format_string, placeholders) = parse_path_template(&config.path)?;
+    for placeholder in &placeholders {
+        if !field_names.contains(placeholder) {
+            return Err(Error::new(
+                config.path.span(),
+                format!(
+                    "path placeholder `{{{placeholder}}}` does not name a field of {struct_name}"
+                ),
+            ));
+        }
+    }
+    let path_args = placeholders.iter().map(|name| {
+        let ident = Ident::new(name, Span::call_site());
+        quote! { self.#ident }
+    });
+    let path_body = if placeholders.is_empty() {
+        quote! { #format_string.to_string() }
+    } else {
+        quote! { format!(#format_string, #(#path_args),*) }
+    };
+
+    // Fields that are neither interpolated into the path nor skipped are sent
+    // as the query string of GET requests and as the form body otherwise.
+    let mut has_params = false;
+    for field in &fields {
+        let in_path = field
+            .ident
+            .as_ref()
+            .is_some_and(|ident| placeholders.contains(&ident.to_string()));
+        if !in_path && !is_skipped(field)? {
+            has_params = true;
+        }
+    }
+    let method = &config.method;
+    let params_fn = if !has_params {
+        quote! {}
+    } else if method == "Get" {
+        quote! {
+            fn to_query(&self) -> impl ::serde::Serialize {
+                <Vec<(String, String)>>::from(self)
+            }
+        }
+    } else {
+        quote! {
+            fn to_form(&self) -> impl ::serde::Serialize {
+                <Vec<(String, String)>>::from(self)
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::backlog_api_core::IntoRequest for #struct_name #ty_generics #where_clause {
+            fn method(&self) -> ::backlog_api_core::HttpMethod {
+                ::backlog_api_core::HttpMethod::#method
+            }
+
+            fn path(&self) -> String {
+                #path_body
+            }
+
+            #params_fn
+        }
+    })
+}
+
+/// Parse `#[request(method = "PATCH", path = "/api/v2/issues/{issue_id_or_key}")]`
+fn parse_request_attribute(input: &DeriveInput) -> Result<RequestConfig> {
+    let mut method = format_ident!("Get");
+    let mut path = None;
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("request") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("method") {
+                let value: LitStr = meta.value()?.parse()?;
+                method = match value.value().to_ascii_uppercase().as_str() {
+                    "GET" => format_ident!("Get"),
+                    "POST" => format_ident!("Post"),
+                    "PUT" => format_ident!("Put"),
+                    "PATCH" => format_ident!("Patch"),
+                    "DELETE" => format_ident!("Delete"),
+                    _ => {
+                        return Err(Error::new(
+                            value.span(),
+                            "method must be one of GET, POST, PUT, PATCH or DELETE",
+                        ));
+                    }
+                };
+                Ok(())
+            } else if meta.path.is_ident("path") {
+                path = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported request attribute"))
+            }
+        })?;
+    }
+
+    let path = path.ok_or_else(|| {
+        Error::new(
+            input.ident.span(),
+            "BacklogRequest requires #[request(path = \"...\")]",
+        )
+    })?;
+    Ok(RequestConfig { method, path })
+}
+
+/// Splits a path template into a `format!` string and the field names it interpolates
+fn parse_path_template(path: &LitStr) -> Result<(String, Vec<String>)> {
+    let template = path.value();
+    let mut format_string = String::new();
+    let mut placeholders = Vec::new();
+    let mut rest = template.as_str();
+
+    while let Some(start) = rest.find('{') {
+        let (literal, after) = rest.split_at(start);
+        format_string.push_str(literal);
+        let end = after
+            .find('}')
+            .ok_or_else(|| Error::new(path.span(), "unclosed `{` in path"))?;
+        let name = &after[1..end];
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(Error::new(
+                path.span(),
+                format!("invalid path placeholder `{{{name}}}`"),
+            ));
+        }
+        format_string.push_str("{}");
+        placeholders.push(name.to_string());
+        rest = &after[end + 1..];
+    }
+    if rest.contains('}') {
+        return Err(Error::new(path.span(), "unmatched `}` in path"));
+    }
+    format_string.push_str(rest);
+
+    Ok((format_string, placeholders))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_path_template() {
+        let path = LitStr::new(
+            "/api/v2/projects/{project_id_or_key}/git/repositories/{repo_id_or_name}",
+            Span::call_site(),
+        );
+        let (format_string, placeholders) = parse_path_template(&path).unwrap();
+        assert_eq!(format_string, "/api/v2/projects/{}/git/repositories/{}");
+        assert_eq!(placeholders, ["project_id_or_key", "repo_id_or_name"]);
+
+        let path = LitStr::new("/api/v2/issues/{issue-id}", Span::call_site());
+        assert!(parse_path_template(&path).is_err());
+        let path = LitStr::new("/api/v2/issues/{issue_id", Span::call_site());
+        assert!(parse_path_template(&path).is_err());
+    }
+}
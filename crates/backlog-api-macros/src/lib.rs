@@ -1,6 +1,7 @@
 use proc_macro::TokenStream;
 use syn::{DeriveInput, parse_macro_input};
 
+mod backlog_request;
 mod to_form_params;
 
 /// Derives the `From<&Self> for Vec<(String, String)>` implementation for API parameter structs.
@@ -36,3 +37,68 @@ pub fn derive_to_form_params(input: TokenStream) -> TokenStream {
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+/// Derives the `From<&Self> for Vec<(String, String)>` implementation for query parameters.
+///
+/// The query string counterpart of [`ToFormParams`], for GET requests. It takes the same
+/// field attributes under `#[query(...)]` and adds an inherent `to_query()` method.
+/// A struct derives either `ToFormParams` or `ToQueryParams`, not both.
+///
+/// # Examples
+///
+/// ```rust
+/// use backlog_api_macros::ToQueryParams;
+///
+/// #[derive(ToQueryParams)]
+/// struct GetWikiListParams {
+///     #[query(name = "projectIdOrKey")]
+///     project: String,
+///     keyword: Option<String>,
+///     #[query(array, name = "tagId")]
+///     tag_ids: Option<Vec<u32>>,
+/// }
+/// ```
+#[proc_macro_derive(ToQueryParams, attributes(query))]
+pub fn derive_to_query_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_form_params::expand_derive_to_query_params(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derives `backlog_api_core::IntoRequest` from a method and a path template.
+///
+/// `{field}` placeholders in the path are replaced with the `Display` output of the
+/// named fields. When any other field is not marked `#[form(skip)]` or `#[query(skip)]`,
+/// the parameters are taken from `Vec<(String, String)>::from(&self)`, as generated by
+/// [`ToQueryParams`] or [`ToFormParams`]. GET requests send them as the query string and
+/// the other methods as the form body. Fields used in the path should be skipped there,
+/// so they are not sent twice.
+///
+/// # Attributes
+///
+/// - `#[request(path = "/api/v2/...")]` - Request path, required
+/// - `#[request(method = "PATCH")]` - One of `GET` (default), `POST`, `PUT`, `PATCH` and `DELETE`
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use backlog_api_macros::{BacklogRequest, ToFormParams};
+///
+/// #[derive(BacklogRequest, ToFormParams)]
+/// #[request(method = "POST", path = "/api/v2/issues/{issue_id_or_key}/comments")]
+/// struct AddCommentParams {
+///     #[form(skip)]
+///     issue_id_or_key: IssueIdOrKey,
+///     content: String,
+///     #[form(array, name = "notifiedUserId")]
+///     notified_user_id: Option<Vec<UserId>>,
+/// }
+/// ```
+#[proc_macro_derive(BacklogRequest, attributes(request))]
+pub fn derive_backlog_request(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    backlog_request::expand_derive_backlog_request(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -20,33 +20,72 @@ struct FieldConfig {
     date_format: Option<String>,
 }
 
+/// Where the serialized parameters are sent, which also names the field attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParamsKind {
+    /// Request body of POST/PUT/PATCH/DELETE requests, configured with `#[form(...)]`
+    Form,
+    /// Query string of GET requests, configured with `#[query(...)]`
+    Query,
+}
+
+impl ParamsKind {
+    pub(crate) fn attribute(self) -> &'static str {
+        match self {
+            ParamsKind::Form => "form",
+            ParamsKind::Query => "query",
+        }
+    }
+
+    fn derive_name(self) -> &'static str {
+        match self {
+            ParamsKind::Form => "ToFormParams",
+            ParamsKind::Query => "ToQueryParams",
+        }
+    }
+}
+
 /// Main entry point for the ToFormParams derive macro
 pub fn expand_derive_to_form_params(input: DeriveInput) -> Result<TokenStream> {
+    expand_derive_params(input, ParamsKind::Form)
+}
+
+/// Main entry point for the ToQueryParams derive macro
+pub fn expand_derive_to_query_params(input: DeriveInput) -> Result<TokenStream> {
+    expand_derive_params(input, ParamsKind::Query)
+}
+
+fn expand_derive_params(input: DeriveInput, kind: ParamsKind) -> Result<TokenStream> {
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     let data_struct = match &input.data {
         Data::Struct(data_struct) => data_struct,
         _ => {
             return Err(Error::new(
                 input.span(),
-                "ToFormParams can only be derived for structs",
+                format!("{} can only be derived for structs", kind.derive_name()),
             ));
         }
     };
 
-    let field_serializations = generate_field_serializations(data_struct)?;
+    let field_serializations = generate_field_serializations(data_struct, kind)?;
+    let method_name = match kind {
+        ParamsKind::Form => quote! { to_form },
+        ParamsKind::Query => quote! { to_query },
+    };
 
     Ok(quote! {
-        impl From<&#struct_name> for Vec<(String, String)> {
-            fn from(params: &#struct_name) -> Self {
+        impl #impl_generics From<&#struct_name #ty_generics> for Vec<(String, String)> #where_clause {
+            fn from(params: &#struct_name #ty_generics) -> Self {
                 let mut __form_params = Vec::new();
                 #(#field_serializations)*
                 __form_params
             }
         }
 
-        impl #struct_name {
-            pub fn to_form(&self) -> Vec<(String, String)> {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            pub fn #method_name(&self) -> Vec<(String, String)> {
                 self.into()
             }
         }
@@ -54,29 +93,32 @@ pub fn expand_derive_to_form_params(input: DeriveInput) -> Result<TokenStream> {
 }
 
 /// Generate serialization code for all fields in the struct
-fn generate_field_serializations(data_struct: &DataStruct) -> Result<Vec<TokenStream>> {
+fn generate_field_serializations(
+    data_struct: &DataStruct,
+    kind: ParamsKind,
+) -> Result<Vec<TokenStream>> {
     match &data_struct.fields {
         Fields::Named(fields) => fields
             .named
             .iter()
-            .map(generate_field_serialization)
+            .map(|field| generate_field_serialization(field, kind))
             .collect(),
         Fields::Unnamed(_) => Err(Error::new(
             data_struct.fields.span(),
-            "ToFormParams does not support tuple structs",
+            format!("{} does not support tuple structs", kind.derive_name()),
         )),
         Fields::Unit => Ok(vec![]),
     }
 }
 
 /// Generate serialization code for a single field
-fn generate_field_serialization(field: &Field) -> Result<TokenStream> {
+fn generate_field_serialization(field: &Field, kind: ParamsKind) -> Result<TokenStream> {
     let field_name = field
         .ident
         .as_ref()
         .ok_or_else(|| Error::new(field.span(), "Field must have a name"))?;
 
-    let config = parse_field_attributes(&field.attrs)?;
+    let config = parse_field_attributes(&field.attrs, kind)?;
 
     // Skip this field if configured
     if config.skip {
@@ -169,12 +211,18 @@ fn generate_array_field_serialization(
     }
 }
 
-/// Parse form attributes from a field
-fn parse_field_attributes(attrs: &[Attribute]) -> Result<FieldConfig> {
+/// Returns true if the field has `#[form(skip)]` or `#[query(skip)]`
+pub(crate) fn is_skipped(field: &Field) -> Result<bool> {
+    Ok(parse_field_attributes(&field.attrs, ParamsKind::Form)?.skip
+        || parse_field_attributes(&field.attrs, ParamsKind::Query)?.skip)
+}
+
+/// Parse form or query attributes from a field
+fn parse_field_attributes(attrs: &[Attribute], kind: ParamsKind) -> Result<FieldConfig> {
     let mut config = FieldConfig::default();
 
     for attr in attrs {
-        if !attr.path().is_ident("form") {
+        if !attr.path().is_ident(kind.attribute()) {
             continue;
         }
 
@@ -213,7 +261,7 @@ fn parse_field_attributes(attrs: &[Attribute]) -> Result<FieldConfig> {
                             _ => Err(meta.error("date_format attribute must be a string literal")),
                         }
                     } else {
-                        Err(meta.error("unsupported form attribute"))
+                        Err(meta.error(format!("unsupported {} attribute", kind.attribute())))
                     }
                 })?;
             }
@@ -221,7 +269,10 @@ fn parse_field_attributes(attrs: &[Attribute]) -> Result<FieldConfig> {
                 // #[form = "value"] - not supported
                 return Err(Error::new(
                     meta_name_value.span(),
-                    "form attribute does not support this syntax",
+                    format!(
+                        "{} attribute does not support this syntax",
+                        kind.attribute()
+                    ),
                 ));
             }
         }
@@ -0,0 +1,103 @@
+use backlog_api_core::{HttpMethod, IntoRequest};
+use backlog_api_macros::{BacklogRequest, ToFormParams, ToQueryParams};
+use serde_json::{Value, json};
+
+#[derive(BacklogRequest)]
+#[request(path = "/api/v2/issues/{issue_id_or_key}")]
+struct GetIssueParams {
+    issue_id_or_key: String,
+}
+
+#[derive(BacklogRequest, ToQueryParams)]
+#[request(method = "GET", path = "/api/v2/projects/{project_id}/issues")]
+struct ListParams {
+    #[query(skip)]
+    project_id: u32,
+    keyword: Option<String>,
+    #[query(array, name = "statusId")]
+    status_ids: Vec<u32>,
+}
+
+#[derive(BacklogRequest, ToFormParams)]
+#[request(
+    method = "patch",
+    path = "/api/v2/issues/{issue_id_or_key}/comments/{comment_id}"
+)]
+struct UpdateCommentParams {
+    #[form(skip)]
+    issue_id_or_key: String,
+    #[form(skip)]
+    comment_id: u32,
+    content: String,
+    #[form(array, name = "notifiedUserId")]
+    notified_user_ids: Option<Vec<u32>>,
+}
+
+#[derive(BacklogRequest)]
+#[request(method = "DELETE", path = "/api/v2/webhooks/{webhook_id}")]
+struct DeleteWebhookParams {
+    webhook_id: u32,
+}
+
+#[derive(BacklogRequest)]
+#[request(path = "/api/v2/space")]
+struct GetSpaceParams;
+
+fn serialized(value: impl serde::Serialize) -> Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn test_path_interpolation_and_default_method() {
+    let params = GetIssueParams {
+        issue_id_or_key: "PROJ-1".to_string(),
+    };
+    assert_eq!(params.method(), HttpMethod::Get);
+    assert_eq!(params.path(), "/api/v2/issues/PROJ-1");
+    assert_eq!(serialized(params.to_query()), Value::Null);
+
+    assert_eq!(GetSpaceParams.path(), "/api/v2/space");
+}
+
+#[test]
+fn test_get_request_sends_query_params() {
+    let params = ListParams {
+        project_id: 12,
+        keyword: Some("login".to_string()),
+        status_ids: vec![1, 2],
+    };
+    assert_eq!(params.path(), "/api/v2/projects/12/issues");
+    assert_eq!(
+        serialized(IntoRequest::to_query(&params)),
+        json!([
+            ["keyword", "login"],
+            ["statusId[]", "1"],
+            ["statusId[]", "2"]
+        ])
+    );
+    assert_eq!(serialized(IntoRequest::to_form(&params)), Value::Null);
+}
+
+#[test]
+fn test_write_request_sends_form_params() {
+    let params = UpdateCommentParams {
+        issue_id_or_key: "PROJ-1".to_string(),
+        comment_id: 42,
+        content: "Updated".to_string(),
+        notified_user_ids: Some(vec![7]),
+    };
+    assert_eq!(params.method(), HttpMethod::Patch);
+    assert_eq!(params.path(), "/api/v2/issues/PROJ-1/comments/42");
+    assert_eq!(
+        serialized(IntoRequest::to_form(&params)),
+        json!([["content", "Updated"], ["notifiedUserId[]", "7"]])
+    );
+}
+
+#[test]
+fn test_request_without_params() {
+    let params = DeleteWebhookParams { webhook_id: 3 };
+    assert_eq!(params.method(), HttpMethod::Delete);
+    assert_eq!(params.path(), "/api/v2/webhooks/3");
+    assert_eq!(serialized(IntoRequest::to_form(&params)), Value::Null);
+}
@@ -3,7 +3,7 @@ use crate::identifier::ActivityId;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::{Content, EmptyNotification};
+use super::{ActivityType, Content, EmptyNotification};
 
 #[cfg(feature = "typed-activity")]
 use super::project::ActivityProject;
@@ -26,8 +26,10 @@ pub struct Activity {
 }
 
 /// Unified activity structure with typed project
+///
+/// The `content` is deserialized into the variant matching `type_id`.
 #[cfg(feature = "typed-activity")]
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Activity {
     pub id: ActivityId,
@@ -40,6 +42,49 @@ pub struct Activity {
     pub created: DateTime<Utc>,
 }
 
+#[cfg(feature = "typed-activity")]
+impl<'de> Deserialize<'de> for Activity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RawActivity {
+            id: ActivityId,
+            project: ActivityProject,
+            #[serde(rename = "type")]
+            type_id: i32,
+            content: serde_json::Value,
+            #[serde(default)]
+            notifications: Vec<EmptyNotification>,
+            created_user: User,
+            created: DateTime<Utc>,
+        }
+
+        let raw = RawActivity::deserialize(deserializer)?;
+        let content =
+            Content::from_type_id(raw.type_id, raw.content).map_err(serde::de::Error::custom)?;
+
+        Ok(Activity {
+            id: raw.id,
+            project: raw.project,
+            type_id: raw.type_id,
+            content,
+            notifications: raw.notifications,
+            created_user: raw.created_user,
+            created: raw.created,
+        })
+    }
+}
+
+impl Activity {
+    /// Returns the kind of this activity, or `None` for types unknown to this client.
+    pub fn activity_type(&self) -> Option<ActivityType> {
+        ActivityType::from_type_id(self.type_id)
+    }
+}
+
 #[cfg(not(feature = "typed-activity"))]
 impl Activity {
     /// Helper method for migration: extract project info from JSON
@@ -192,7 +237,22 @@ mod tests {
         let activity: Activity = serde_json::from_str(json).unwrap();
         assert_eq!(activity.id.value(), 67890);
         assert_eq!(activity.type_id, 2);
+        assert_eq!(activity.activity_type(), Some(ActivityType::IssueUpdated));
+
+        #[cfg(feature = "typed-activity")]
+        match &activity.content {
+            Content::Issue(issue) => {
+                assert_eq!(issue.id, 300);
+                assert_eq!(issue.key_id, 400);
+                assert_eq!(issue.summary, "Issue Updated");
+                assert_eq!(issue.comment.as_ref().map(|c| c.id), Some(500));
+                assert_eq!(issue.changes.len(), 1);
+                assert_eq!(issue.changes[0].new_value, "Closed");
+            }
+            _ => panic!("Expected Issue content"),
+        }
 
+        #[cfg(not(feature = "typed-activity"))]
         match &activity.content {
             Content::Standard {
                 id,
@@ -213,6 +273,26 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "typed-activity")]
+    #[test]
+    fn test_activity_with_unexpected_content_shape() {
+        let json = r#"[{
+            "id": 1,
+            "project": {"id": 3, "projectKey": "WEB", "name": "Web"},
+            "type": 12,
+            "content": {"repository": "not an object"},
+            "notifications": [],
+            "createdUser": {"id": 1, "name": "Admin", "roleType": 1, "mailAddress": "admin@example.com"},
+            "created": "2024-01-03T12:00:00Z"
+        }]"#;
+
+        let activities: Vec<Activity> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            activities[0].content,
+            Content::Unknown(serde_json::json!({"repository": "not an object"}))
+        );
+    }
+
     #[test]
     fn test_activity_with_user_management_content() {
         #[cfg(not(feature = "typed-activity"))]
@@ -239,7 +319,7 @@ mod tests {
         let json = r#"{
             "id": 11111,
             "project": {"id": 3, "projectKey": "USER", "name": "User Project"},
-            "type": 6,
+            "type": 15,
             "content": {
                 "users": [{"id": 10, "userId": "newuser", "name": "New User", "roleType": 2, "mailAddress": "newuser@example.com"}],
                 "groupProjectActivities": [{"id": 20, "type": 5}],
@@ -257,8 +337,27 @@ mod tests {
 
         let activity: Activity = serde_json::from_str(json).unwrap();
         assert_eq!(activity.id.value(), 11111);
+
+        #[cfg(feature = "typed-activity")]
+        {
+            assert_eq!(
+                activity.activity_type(),
+                Some(ActivityType::ProjectUserAdded)
+            );
+            match &activity.content {
+                Content::EditMember(member) => {
+                    assert_eq!(member.users.len(), 1);
+                    assert_eq!(member.group_project_activities.len(), 1);
+                    assert_eq!(member.comment, "User added to project");
+                }
+                _ => panic!("Expected EditMember content"),
+            }
+        }
+
+        #[cfg(not(feature = "typed-activity"))]
         assert_eq!(activity.type_id, 6);
 
+        #[cfg(not(feature = "typed-activity"))]
         match &activity.content {
             Content::UserManagement {
                 users,
@@ -1,7 +1,8 @@
 use crate::User;
+use crate::identifier::{PullRequestNumber, RepositoryId, TeamId};
 use serde::{Deserialize, Deserializer, Serialize};
 
-use super::{Change, Comment, GroupProjectActivity};
+use super::{ActivityIssue, ActivityType, Change, Comment, GroupProjectActivity};
 
 /// Unified content type that merges both content variants
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -25,7 +26,7 @@ pub enum Content {
         group_project_activities: Option<Vec<GroupProjectActivity>>,
         comment: Option<String>,
     },
-    /// Detailed content variants (from content.rs)
+    /// Detailed content variants, selected by activity `type` (see [`Content::from_type_id`])
     IssueCreated(Box<IssueCreatedContent>),
     Issue(Box<IssueContent>),
     IssueDeleted(Box<IssueDeletedContent>),
@@ -40,31 +41,102 @@ pub enum Content {
     Version(Box<VersionContent>),
     VersionUpdated(Box<VersionUpdatedContent>),
     ProjectTeam(Box<ProjectTeamContent>),
+    /// Content whose shape matches none of the variants above, kept as it was received
+    Unknown(serde_json::Value),
+    #[deprecated(note = "Backlog has no status deleted activity; this variant is never produced")]
     StatusDeleted(Box<StatusDeletedContent>),
 }
 
-// Detailed content types (simplified for Phase 1)
+/// Content of an issue created activity (type 1)
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct IssueCreatedContent {
     pub id: i32,
-    #[serde(rename = "keyId")]
+    #[serde(rename = "keyId", alias = "key_id")]
     pub key_id: i32,
     pub summary: String,
+    #[serde(default)]
     pub description: String,
+    #[serde(default)]
+    pub attachments: Vec<ActivityAttachment>,
+    #[serde(default, rename = "sharedFiles", alias = "shared_files")]
+    pub shared_files: Vec<ActivitySharedFile>,
 }
 
+/// Content of issue updated (2), issue commented (3) and comment notification (17) activities
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct IssueContent {}
+pub struct IssueContent {
+    pub id: i32,
+    #[serde(rename = "keyId", alias = "key_id")]
+    pub key_id: i32,
+    pub summary: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default, deserialize_with = "deserialize_comment")]
+    pub comment: Option<Comment>,
+    #[serde(default)]
+    pub changes: Vec<Change>,
+    #[serde(default)]
+    pub attachments: Vec<ActivityAttachment>,
+    #[serde(default, rename = "sharedFiles", alias = "shared_files")]
+    pub shared_files: Vec<ActivitySharedFile>,
+}
 
+/// Content of an issue deleted activity (type 4)
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct IssueDeletedContent {}
+pub struct IssueDeletedContent {
+    pub id: i32,
+    #[serde(rename = "keyId", alias = "key_id")]
+    pub key_id: i32,
+    #[serde(default)]
+    pub summary: Option<String>,
+}
 
+/// Content of an issue multi update activity (type 14)
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct IssueMultiUpdateContent {}
+pub struct IssueMultiUpdateContent {
+    #[serde(rename = "txId", alias = "tx_id")]
+    pub tx_id: i64,
+    #[serde(default)]
+    pub comment: Option<MultiUpdateComment>,
+    #[serde(default)]
+    pub link: Vec<IssueLink>,
+    #[serde(default)]
+    pub changes: Vec<Change>,
+}
 
+/// Comment attached to every issue of a multi update
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct WikiContent {}
+pub struct MultiUpdateComment {
+    pub content: String,
+}
+
+/// Issue touched by a multi update
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct IssueLink {
+    pub id: i32,
+    #[serde(rename = "keyId", alias = "key_id")]
+    pub key_id: i32,
+    pub title: String,
+}
+
+/// Content of wiki created (5), updated (6) and deleted (7) activities
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WikiContent {
+    pub id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub diff: String,
+    #[serde(default)]
+    pub version: Option<u32>,
+    #[serde(default)]
+    pub attachments: Vec<ActivityAttachment>,
+    #[serde(default, rename = "sharedFiles", alias = "shared_files")]
+    pub shared_files: Vec<ActivitySharedFile>,
+}
 
+/// Content of file added (8), updated (9) and deleted (10) activities
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FileContent {
     pub id: i32,
@@ -73,36 +145,207 @@ pub struct FileContent {
     pub size: u64,
 }
 
+/// Content of an SVN committed activity (type 11)
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct SvnContent {
     pub rev: crate::identifier::SvnRevision,
     pub comment: String,
 }
 
+/// Content of a git pushed activity (type 12)
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct GitContent {}
+pub struct GitContent {
+    pub repository: ActivityRepository,
+    #[serde(rename = "changeType", alias = "change_type")]
+    pub change_type: String,
+    #[serde(rename = "revisionType", alias = "revision_type")]
+    pub revision_type: String,
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    #[serde(rename = "revisionCount", alias = "revision_count")]
+    pub revision_count: u32,
+    #[serde(default)]
+    pub revisions: Vec<GitRevision>,
+}
 
+/// A single pushed revision
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct GitRepositoryCreatedContent {}
+pub struct GitRevision {
+    pub rev: String,
+    #[serde(default)]
+    pub comment: String,
+}
 
+/// Content of a git repository created activity (type 13)
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct EditMemberContent {}
+pub struct GitRepositoryCreatedContent {
+    pub repository: ActivityRepository,
+}
 
+/// Content of project user added (15) and removed (16) activities
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct PullRequestContent {}
+pub struct EditMemberContent {
+    #[serde(default)]
+    pub users: Vec<User>,
+    #[serde(
+        default,
+        rename = "groupProjectActivities",
+        alias = "group_project_activities"
+    )]
+    pub group_project_activities: Vec<GroupProjectActivity>,
+    #[serde(default)]
+    pub comment: String,
+}
 
+/// Content of pull request added (18), updated (19), commented (20) and deleted (21) activities
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct VersionContent {}
+pub struct PullRequestContent {
+    pub id: i32,
+    pub number: PullRequestNumber,
+    pub summary: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default, deserialize_with = "deserialize_comment")]
+    pub comment: Option<Comment>,
+    #[serde(default)]
+    pub changes: Vec<Change>,
+    pub repository: ActivityRepository,
+    #[serde(default)]
+    pub issue: Option<ActivityIssue>,
+}
 
+/// Content of milestone created (22) and deleted (24) activities
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct VersionUpdatedContent {}
+pub struct VersionContent {
+    pub id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default, rename = "startDate", alias = "start_date")]
+    pub start_date: Option<String>,
+    #[serde(default, rename = "referenceDate", alias = "reference_date")]
+    pub reference_date: Option<String>,
+}
+
+/// Content of a milestone updated activity (type 23)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VersionUpdatedContent {
+    pub id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default, rename = "startDate", alias = "start_date")]
+    pub start_date: Option<String>,
+    #[serde(default, rename = "referenceDate", alias = "reference_date")]
+    pub reference_date: Option<String>,
+    #[serde(default)]
+    pub changes: Vec<Change>,
+}
 
+/// Content of project group added (25) and deleted (26) activities
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct ProjectTeamContent {}
+pub struct ProjectTeamContent {
+    #[serde(default)]
+    pub teams: Vec<ActivityTeam>,
+}
 
+/// Content of the deprecated [`Content::StatusDeleted`] variant
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct StatusDeletedContent {}
 
+/// Attachment referenced from issue and wiki activities
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ActivityAttachment {
+    pub id: i32,
+    pub name: String,
+}
+
+/// Shared file referenced from issue and wiki activities
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ActivitySharedFile {
+    pub id: i32,
+    #[serde(default)]
+    pub dir: String,
+    pub name: String,
+    #[serde(default)]
+    pub size: u64,
+}
+
+/// Repository referenced from git and pull request activities
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ActivityRepository {
+    pub id: RepositoryId,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Team referenced from project group activities
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ActivityTeam {
+    pub id: TeamId,
+    pub name: String,
+}
+
+impl Content {
+    /// Deserializes activity content into the variant matching the activity `type`.
+    ///
+    /// Unknown types fall back to the untagged `Standard`/`UserManagement` shapes, and a
+    /// known type whose payload does not have the expected shape to [`Content::Unknown`], so
+    /// one odd activity does not fail a whole listing.
+    pub fn from_type_id(type_id: i32, value: serde_json::Value) -> serde_json::Result<Self> {
+        let Some(activity_type) = ActivityType::from_type_id(type_id) else {
+            return serde_json::from_value(value);
+        };
+        Ok(Self::typed(activity_type, value.clone()).unwrap_or(Content::Unknown(value)))
+    }
+
+    fn typed(activity_type: ActivityType, value: serde_json::Value) -> serde_json::Result<Self> {
+        use serde_json::from_value;
+
+        let content = match activity_type {
+            ActivityType::IssueCreated => Content::IssueCreated(Box::new(from_value(value)?)),
+            ActivityType::IssueUpdated
+            | ActivityType::IssueCommented
+            | ActivityType::CommentNotificationAdded => {
+                Content::Issue(Box::new(from_value(value)?))
+            }
+            ActivityType::IssueDeleted => Content::IssueDeleted(Box::new(from_value(value)?)),
+            ActivityType::IssueMultiUpdated => {
+                Content::IssueMultiUpdate(Box::new(from_value(value)?))
+            }
+            ActivityType::WikiCreated | ActivityType::WikiUpdated | ActivityType::WikiDeleted => {
+                Content::Wiki(Box::new(from_value(value)?))
+            }
+            ActivityType::FileAdded | ActivityType::FileUpdated | ActivityType::FileDeleted => {
+                Content::File(Box::new(from_value(value)?))
+            }
+            ActivityType::SvnCommitted => Content::Svn(Box::new(from_value(value)?)),
+            ActivityType::GitPushed => Content::Git(Box::new(from_value(value)?)),
+            ActivityType::GitRepositoryCreated => {
+                Content::GitRepositoryCreated(Box::new(from_value(value)?))
+            }
+            ActivityType::ProjectUserAdded | ActivityType::ProjectUserRemoved => {
+                Content::EditMember(Box::new(from_value(value)?))
+            }
+            ActivityType::PullRequestAdded
+            | ActivityType::PullRequestUpdated
+            | ActivityType::PullRequestCommented
+            | ActivityType::PullRequestDeleted => {
+                Content::PullRequest(Box::new(from_value(value)?))
+            }
+            ActivityType::MilestoneCreated | ActivityType::MilestoneDeleted => {
+                Content::Version(Box::new(from_value(value)?))
+            }
+            ActivityType::MilestoneUpdated => Content::VersionUpdated(Box::new(from_value(value)?)),
+            ActivityType::ProjectGroupAdded | ActivityType::ProjectGroupDeleted => {
+                Content::ProjectTeam(Box::new(from_value(value)?))
+            }
+        };
+        Ok(content)
+    }
+}
+
 fn deserialize_comment<'de, D>(deserializer: D) -> Result<Option<Comment>, D::Error>
 where
     D: Deserializer<'de>,
@@ -201,6 +444,8 @@ mod tests {
             key_id: 888,
             summary: "New Issue".to_string(),
             description: "Issue description".to_string(),
+            attachments: vec![],
+            shared_files: vec![],
         }));
 
         let json = serde_json::to_string(&content).unwrap();
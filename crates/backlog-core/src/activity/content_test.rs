@@ -0,0 +1,382 @@
+#[cfg(test)]
+mod tests {
+    use crate::activity::{ActivityType, Content};
+    use crate::identifier::Identifier;
+    use serde_json::{Value, json};
+
+    fn parse(activity_type: ActivityType, content: Value) -> Content {
+        Content::from_type_id(activity_type.id().value() as i32, content).unwrap()
+    }
+
+    fn issue_fixture() -> Value {
+        json!({
+            "id": 4809,
+            "key_id": 121,
+            "summary": "Login fails on Safari",
+            "description": "Steps to reproduce...",
+            "comment": {"id": 7237, "content": "Fixed in the next release"},
+            "changes": [
+                {"field": "status", "new_value": "2", "old_value": "1", "type": "standard"}
+            ],
+            "attachments": [{"id": 33, "name": "screenshot.png"}],
+            "shared_files": [{"id": 54, "dir": "/docs", "name": "spec.pdf", "size": 1024}]
+        })
+    }
+
+    fn repository_fixture() -> Value {
+        json!({"id": 5, "name": "app", "description": "Main application"})
+    }
+
+    #[test]
+    fn test_issue_created_content() {
+        let content = parse(
+            ActivityType::IssueCreated,
+            json!({
+                "id": 4809,
+                "key_id": 121,
+                "summary": "Login fails on Safari",
+                "description": "",
+                "attachments": [],
+                "shared_files": []
+            }),
+        );
+        match content {
+            Content::IssueCreated(issue) => {
+                assert_eq!(issue.id, 4809);
+                assert_eq!(issue.key_id, 121);
+                assert_eq!(issue.summary, "Login fails on Safari");
+            }
+            other => panic!("Expected IssueCreated content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_issue_updated_and_commented_content() {
+        for activity_type in [
+            ActivityType::IssueUpdated,
+            ActivityType::IssueCommented,
+            ActivityType::CommentNotificationAdded,
+        ] {
+            match parse(activity_type, issue_fixture()) {
+                Content::Issue(issue) => {
+                    assert_eq!(issue.key_id, 121);
+                    assert_eq!(issue.comment.as_ref().unwrap().id, 7237);
+                    assert_eq!(issue.changes[0].field, "status");
+                    assert_eq!(issue.changes[0].new_value, "2");
+                    assert_eq!(issue.attachments[0].name, "screenshot.png");
+                    assert_eq!(issue.shared_files[0].dir, "/docs");
+                }
+                other => panic!("Expected Issue content for {activity_type}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_issue_commented_with_empty_comment() {
+        let content = parse(
+            ActivityType::IssueUpdated,
+            json!({"id": 1, "key_id": 2, "summary": "s", "description": "", "comment": ""}),
+        );
+        match content {
+            Content::Issue(issue) => assert!(issue.comment.is_none()),
+            other => panic!("Expected Issue content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_issue_deleted_content() {
+        match parse(
+            ActivityType::IssueDeleted,
+            json!({"id": 4809, "key_id": 121}),
+        ) {
+            Content::IssueDeleted(issue) => {
+                assert_eq!(issue.key_id, 121);
+                assert!(issue.summary.is_none());
+            }
+            other => panic!("Expected IssueDeleted content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_issue_multi_update_content() {
+        let content = parse(
+            ActivityType::IssueMultiUpdated,
+            json!({
+                "tx_id": 1001,
+                "comment": {"content": "Bulk close"},
+                "link": [
+                    {"id": 10, "key_id": 1, "title": "[PROJ-1] First"},
+                    {"id": 11, "key_id": 2, "title": "[PROJ-2] Second"}
+                ],
+                "changes": [
+                    {"field": "status", "new_value": "4", "old_value": "", "type": "standard"}
+                ]
+            }),
+        );
+        match content {
+            Content::IssueMultiUpdate(update) => {
+                assert_eq!(update.tx_id, 1001);
+                assert_eq!(update.comment.unwrap().content, "Bulk close");
+                assert_eq!(update.link.len(), 2);
+                assert_eq!(update.link[1].title, "[PROJ-2] Second");
+                assert_eq!(update.changes.len(), 1);
+            }
+            other => panic!("Expected IssueMultiUpdate content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_wiki_content() {
+        for activity_type in [
+            ActivityType::WikiCreated,
+            ActivityType::WikiUpdated,
+            ActivityType::WikiDeleted,
+        ] {
+            let content = parse(
+                activity_type,
+                json!({
+                    "id": 2,
+                    "name": "Home",
+                    "content": "Welcome",
+                    "diff": "+Welcome",
+                    "version": 3,
+                    "attachments": [],
+                    "shared_files": []
+                }),
+            );
+            match content {
+                Content::Wiki(wiki) => {
+                    assert_eq!(wiki.name, "Home");
+                    assert_eq!(wiki.diff, "+Welcome");
+                    assert_eq!(wiki.version, Some(3));
+                }
+                other => panic!("Expected Wiki content for {activity_type}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_file_content() {
+        for activity_type in [
+            ActivityType::FileAdded,
+            ActivityType::FileUpdated,
+            ActivityType::FileDeleted,
+        ] {
+            let content = parse(
+                activity_type,
+                json!({"id": 825952, "dir": "/docs/", "name": "a.pdf", "size": 2048}),
+            );
+            match content {
+                Content::File(file) => {
+                    assert_eq!(file.dir, "/docs/");
+                    assert_eq!(file.size, 2048);
+                }
+                other => panic!("Expected File content for {activity_type}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_svn_content() {
+        match parse(
+            ActivityType::SvnCommitted,
+            json!({"rev": 42, "comment": "Initial import"}),
+        ) {
+            Content::Svn(svn) => {
+                assert_eq!(svn.rev.value(), 42);
+                assert_eq!(svn.comment, "Initial import");
+            }
+            other => panic!("Expected Svn content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_git_pushed_content() {
+        let content = parse(
+            ActivityType::GitPushed,
+            json!({
+                "repository": repository_fixture(),
+                "change_type": "update",
+                "revision_type": "commit",
+                "ref": "refs/heads/main",
+                "revision_count": 2,
+                "revisions": [
+                    {"rev": "a1b2c3", "comment": "fix PROJ-1"},
+                    {"rev": "d4e5f6", "comment": "refactor"}
+                ]
+            }),
+        );
+        match content {
+            Content::Git(git) => {
+                assert_eq!(git.repository.name, "app");
+                assert_eq!(git.git_ref, "refs/heads/main");
+                assert_eq!(git.revision_count, 2);
+                assert_eq!(git.revisions[0].rev, "a1b2c3");
+            }
+            other => panic!("Expected Git content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_git_repository_created_content() {
+        match parse(
+            ActivityType::GitRepositoryCreated,
+            json!({"repository": repository_fixture()}),
+        ) {
+            Content::GitRepositoryCreated(created) => {
+                assert_eq!(created.repository.id.value(), 5);
+                assert_eq!(
+                    created.repository.description.as_deref(),
+                    Some("Main application")
+                );
+            }
+            other => panic!("Expected GitRepositoryCreated content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_edit_member_content() {
+        for activity_type in [
+            ActivityType::ProjectUserAdded,
+            ActivityType::ProjectUserRemoved,
+        ] {
+            let content = parse(
+                activity_type,
+                json!({
+                    "users": [{
+                        "id": 10,
+                        "userId": "newuser",
+                        "name": "New User",
+                        "roleType": 2,
+                        "mailAddress": "newuser@example.com"
+                    }],
+                    "group_project_activities": [{"id": 20, "type": 15}],
+                    "comment": ""
+                }),
+            );
+            match content {
+                Content::EditMember(member) => {
+                    assert_eq!(member.users[0].name, "New User");
+                    assert_eq!(member.group_project_activities[0].type_id, 15);
+                }
+                other => panic!("Expected EditMember content for {activity_type}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_pull_request_content() {
+        for activity_type in [
+            ActivityType::PullRequestAdded,
+            ActivityType::PullRequestUpdated,
+            ActivityType::PullRequestCommented,
+            ActivityType::PullRequestDeleted,
+        ] {
+            let content = parse(
+                activity_type,
+                json!({
+                    "id": 2,
+                    "number": 7,
+                    "summary": "Add login form",
+                    "description": "Implements PROJ-1",
+                    "comment": {"id": 36, "content": "LGTM"},
+                    "changes": [
+                        {"field": "status", "new_value": "3", "old_value": "1", "type": "standard"}
+                    ],
+                    "repository": repository_fixture(),
+                    "issue": {"id": 4809, "key_id": 1, "summary": "Login", "description": ""}
+                }),
+            );
+            match content {
+                Content::PullRequest(pr) => {
+                    assert_eq!(pr.number.value(), 7);
+                    assert_eq!(pr.repository.name, "app");
+                    assert_eq!(pr.comment.unwrap().content, "LGTM");
+                    assert_eq!(pr.issue.unwrap().key_id, 1);
+                }
+                other => panic!("Expected PullRequest content for {activity_type}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_milestone_content() {
+        for activity_type in [
+            ActivityType::MilestoneCreated,
+            ActivityType::MilestoneDeleted,
+        ] {
+            let content = parse(
+                activity_type,
+                json!({
+                    "id": 3,
+                    "name": "v1.0",
+                    "description": "First release",
+                    "start_date": "2024-01-01",
+                    "reference_date": "2024-01-31"
+                }),
+            );
+            match content {
+                Content::Version(version) => {
+                    assert_eq!(version.name, "v1.0");
+                    assert_eq!(version.reference_date.as_deref(), Some("2024-01-31"));
+                }
+                other => panic!("Expected Version content for {activity_type}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_milestone_updated_content() {
+        let content = parse(
+            ActivityType::MilestoneUpdated,
+            json!({
+                "id": 3,
+                "name": "v1.0",
+                "description": null,
+                "start_date": null,
+                "reference_date": "2024-02-29",
+                "changes": [
+                    {"field": "referenceDate", "new_value": "2024-02-29", "old_value": "2024-01-31", "type": "standard"}
+                ]
+            }),
+        );
+        match content {
+            Content::VersionUpdated(version) => {
+                assert_eq!(version.changes[0].old_value, "2024-01-31");
+                assert!(version.description.is_none());
+            }
+            other => panic!("Expected VersionUpdated content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_project_team_content() {
+        for activity_type in [
+            ActivityType::ProjectGroupAdded,
+            ActivityType::ProjectGroupDeleted,
+        ] {
+            match parse(
+                activity_type,
+                json!({"teams": [{"id": 9, "name": "Backend"}]}),
+            ) {
+                Content::ProjectTeam(team) => assert_eq!(team.teams[0].name, "Backend"),
+                other => panic!("Expected ProjectTeam content for {activity_type}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_type_falls_back_to_untagged_content() {
+        let content = Content::from_type_id(999, json!({"id": 1, "summary": "?"})).unwrap();
+        assert!(matches!(content, Content::Standard { .. }));
+    }
+
+    #[test]
+    fn test_mismatched_content_falls_back_to_unknown() {
+        let value = json!({"id": 1, "summary": "not a push"});
+        let content =
+            Content::from_type_id(ActivityType::GitPushed.id().value() as i32, value.clone())
+                .unwrap();
+        assert_eq!(content, Content::Unknown(value));
+    }
+}
@@ -0,0 +1,164 @@
+use crate::Error;
+use crate::identifier::{ActivityTypeId, Identifier};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
+
+/// The kind of an activity, as reported in the `type` field of activities and webhook payloads.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr)]
+pub enum ActivityType {
+    IssueCreated = 1,
+    IssueUpdated = 2,
+    IssueCommented = 3,
+    IssueDeleted = 4,
+    WikiCreated = 5,
+    WikiUpdated = 6,
+    WikiDeleted = 7,
+    FileAdded = 8,
+    FileUpdated = 9,
+    FileDeleted = 10,
+    SvnCommitted = 11,
+    GitPushed = 12,
+    GitRepositoryCreated = 13,
+    IssueMultiUpdated = 14,
+    ProjectUserAdded = 15,
+    ProjectUserRemoved = 16,
+    CommentNotificationAdded = 17,
+    PullRequestAdded = 18,
+    PullRequestUpdated = 19,
+    PullRequestCommented = 20,
+    PullRequestDeleted = 21,
+    MilestoneCreated = 22,
+    MilestoneUpdated = 23,
+    MilestoneDeleted = 24,
+    ProjectGroupAdded = 25,
+    ProjectGroupDeleted = 26,
+}
+
+impl ActivityType {
+    pub fn all() -> Vec<ActivityType> {
+        vec![
+            ActivityType::IssueCreated,
+            ActivityType::IssueUpdated,
+            ActivityType::IssueCommented,
+            ActivityType::IssueDeleted,
+            ActivityType::WikiCreated,
+            ActivityType::WikiUpdated,
+            ActivityType::WikiDeleted,
+            ActivityType::FileAdded,
+            ActivityType::FileUpdated,
+            ActivityType::FileDeleted,
+            ActivityType::SvnCommitted,
+            ActivityType::GitPushed,
+            ActivityType::GitRepositoryCreated,
+            ActivityType::IssueMultiUpdated,
+            ActivityType::ProjectUserAdded,
+            ActivityType::ProjectUserRemoved,
+            ActivityType::CommentNotificationAdded,
+            ActivityType::PullRequestAdded,
+            ActivityType::PullRequestUpdated,
+            ActivityType::PullRequestCommented,
+            ActivityType::PullRequestDeleted,
+            ActivityType::MilestoneCreated,
+            ActivityType::MilestoneUpdated,
+            ActivityType::MilestoneDeleted,
+            ActivityType::ProjectGroupAdded,
+            ActivityType::ProjectGroupDeleted,
+        ]
+    }
+
+    /// Returns the numeric activity type ID used by the API.
+    pub fn id(&self) -> ActivityTypeId {
+        ActivityTypeId::new(*self as u32)
+    }
+
+    /// Returns the activity type for a raw `type` value, or `None` if it is unknown.
+    pub fn from_type_id(type_id: i32) -> Option<ActivityType> {
+        u32::try_from(type_id)
+            .ok()
+            .and_then(|id| ActivityType::try_from(ActivityTypeId::new(id)).ok())
+    }
+}
+
+impl From<ActivityType> for ActivityTypeId {
+    fn from(activity_type: ActivityType) -> Self {
+        activity_type.id()
+    }
+}
+
+impl TryFrom<ActivityTypeId> for ActivityType {
+    type Error = Error;
+
+    fn try_from(id: ActivityTypeId) -> Result<Self, Self::Error> {
+        ActivityType::all()
+            .into_iter()
+            .find(|activity_type| *activity_type as u32 == id.value())
+            .ok_or_else(|| Error::InvalidParameter(format!("Unknown activity type ID: {id}")))
+    }
+}
+
+impl fmt::Display for ActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ActivityType::IssueCreated => "issue_created",
+            ActivityType::IssueUpdated => "issue_updated",
+            ActivityType::IssueCommented => "issue_commented",
+            ActivityType::IssueDeleted => "issue_deleted",
+            ActivityType::WikiCreated => "wiki_created",
+            ActivityType::WikiUpdated => "wiki_updated",
+            ActivityType::WikiDeleted => "wiki_deleted",
+            ActivityType::FileAdded => "file_added",
+            ActivityType::FileUpdated => "file_updated",
+            ActivityType::FileDeleted => "file_deleted",
+            ActivityType::SvnCommitted => "svn_committed",
+            ActivityType::GitPushed => "git_pushed",
+            ActivityType::GitRepositoryCreated => "git_repository_created",
+            ActivityType::IssueMultiUpdated => "issue_multi_updated",
+            ActivityType::ProjectUserAdded => "project_user_added",
+            ActivityType::ProjectUserRemoved => "project_user_removed",
+            ActivityType::CommentNotificationAdded => "comment_notification_added",
+            ActivityType::PullRequestAdded => "pull_request_added",
+            ActivityType::PullRequestUpdated => "pull_request_updated",
+            ActivityType::PullRequestCommented => "pull_request_commented",
+            ActivityType::PullRequestDeleted => "pull_request_deleted",
+            ActivityType::MilestoneCreated => "milestone_created",
+            ActivityType::MilestoneUpdated => "milestone_updated",
+            ActivityType::MilestoneDeleted => "milestone_deleted",
+            ActivityType::ProjectGroupAdded => "project_group_added",
+            ActivityType::ProjectGroupDeleted => "project_group_deleted",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_type_id_round_trip() {
+        for activity_type in ActivityType::all() {
+            let id: ActivityTypeId = activity_type.into();
+            assert_eq!(ActivityType::try_from(id).unwrap(), activity_type);
+        }
+    }
+
+    #[test]
+    fn test_activity_type_from_unknown_id() {
+        assert!(ActivityType::try_from(ActivityTypeId::new(0)).is_err());
+        assert_eq!(ActivityType::from_type_id(99), None);
+        assert_eq!(ActivityType::from_type_id(-1), None);
+        assert_eq!(
+            ActivityType::from_type_id(12),
+            Some(ActivityType::GitPushed)
+        );
+    }
+
+    #[test]
+    fn test_activity_type_serde() {
+        let json = serde_json::to_string(&ActivityType::PullRequestAdded).unwrap();
+        assert_eq!(json, "18");
+        let activity_type: ActivityType = serde_json::from_str("22").unwrap();
+        assert_eq!(activity_type, ActivityType::MilestoneCreated);
+    }
+}
@@ -1,13 +1,23 @@
 mod activity_type;
 pub mod change;
 pub mod content;
+#[cfg(test)]
+mod content_test;
+mod kind;
 pub mod notification;
 pub mod notification_reason;
 pub mod project;
 
 pub use activity_type::Activity;
 pub use change::{Change, Comment, GroupProjectActivity};
-pub use content::{Content, FileContent, IssueCreatedContent, SvnContent};
+pub use content::{
+    ActivityAttachment, ActivityRepository, ActivitySharedFile, ActivityTeam, Content,
+    EditMemberContent, FileContent, GitContent, GitRepositoryCreatedContent, GitRevision,
+    IssueContent, IssueCreatedContent, IssueDeletedContent, IssueLink, IssueMultiUpdateContent,
+    MultiUpdateComment, ProjectTeamContent, PullRequestContent, SvnContent, VersionContent,
+    VersionUpdatedContent, WikiContent,
+};
+pub use kind::ActivityType;
 pub use notification::{EmptyNotification, Notification};
 pub use notification_reason::NotificationReason;
 pub use project::{ActivityIssue, ActivityProject};
@@ -17,7 +17,7 @@ pub struct ActivityProject {
 #[serde(rename_all = "camelCase")]
 pub struct ActivityIssue {
     pub id: IssueId,
-    #[serde(rename = "keyId")]
+    #[serde(rename = "keyId", alias = "key_id")]
     pub key_id: i64,
     pub summary: String,
     #[serde(skip_serializing_if = "Option::is_none")]
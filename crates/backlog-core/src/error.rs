@@ -29,6 +29,9 @@ pub enum Error {
     #[error("Invalid repository id or name: {0}")]
     InvalidRepositoryIdOrName(String),
 
+    #[error("Invalid Backlog URL: {0}")]
+    InvalidBacklogUrl(String),
+
     #[error("Invalid parameter: {0}")]
     InvalidParameter(String),
 }
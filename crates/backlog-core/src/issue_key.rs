@@ -31,6 +31,14 @@ impl IssueKey {
             key_id,
         }
     }
+
+    pub fn project_key(&self) -> &ProjectKey {
+        &self.project_key
+    }
+
+    pub fn key_id(&self) -> u32 {
+        self.key_id
+    }
 }
 
 impl From<IssueKey> for String {
@@ -55,18 +63,15 @@ impl FromStr for IssueKey {
     /// Will return [`Err`] if it's not possible to parse this string slice into
     /// the `IssueKey`.
     fn from_str(key: &str) -> Result<Self, Self::Err> {
-        let cap = ISSUE_KEY_REGEXP.captures(key);
-        if let Some(m) = cap {
-            // safety use from_str_unchecked: the constraint of the regex ISSUE_KEY_REGEXP ensures the project_key is valid
-            let project_key = ProjectKey::from_str_unchecked(&m[1]);
+        let invalid = || Error::InvalidIssueKey(key.to_string());
+        let m = ISSUE_KEY_REGEXP.captures(key).ok_or_else(invalid)?;
+        // safety use from_str_unchecked: the constraint of the regex ISSUE_KEY_REGEXP ensures the project_key is valid
+        let project_key = ProjectKey::from_str_unchecked(&m[1]);
 
-            // safety unwrap: the constraint of the regex ISSUE_KEY_REGEXP ensures key_id can be converted into u32 and is greater than zero
-            let key_id = u32::from_str(&m[2]).unwrap();
+        // The regex ensures key_id is greater than zero, but it may not fit in u32.
+        let key_id = u32::from_str(&m[2]).map_err(|_| invalid())?;
 
-            Ok(IssueKey::new(project_key, key_id))
-        } else {
-            Err(Error::InvalidIssueKey(key.to_string()))
-        }
+        Ok(IssueKey::new(project_key, key_id))
     }
 }
 
@@ -107,6 +112,10 @@ fn test_issue_key_from_str() {
         IssueKey::from_str("BLG-a9"),
         Err(Error::InvalidIssueKey(String::from("BLG-a9")))
     );
+    assert_eq!(
+        IssueKey::from_str("BUILD-20241019123456"),
+        Err(Error::InvalidIssueKey(String::from("BUILD-20241019123456")))
+    );
     assert_eq!(
         IssueKey::from_str("TOO_LONG_PROJECT_KEY_LN25-9999"),
         Ok(IssueKey::new(
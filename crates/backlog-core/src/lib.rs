@@ -8,7 +8,10 @@ pub mod identifier;
 mod issue_id_or_key;
 mod issue_key;
 mod language;
+pub mod mention;
+pub mod notation;
 mod nulab_account;
+mod order;
 mod project_id_or_key;
 mod project_key;
 mod repository_id_or_name;
@@ -18,6 +21,7 @@ mod space_key;
 mod star;
 mod text_formatting_rule;
 mod user;
+pub mod web_url;
 
 pub use active_type_id::ActiveTypeId;
 pub use api_date::ApiDate;
@@ -28,6 +32,7 @@ pub use issue_id_or_key::IssueIdOrKey;
 pub use issue_key::IssueKey;
 pub use language::Language;
 pub use nulab_account::NulabAccount;
+pub use order::Order;
 pub use project_id_or_key::ProjectIdOrKey;
 pub use project_key::ProjectKey;
 pub use repository_id_or_name::RepositoryIdOrName;
@@ -37,6 +42,7 @@ pub use space_key::SpaceKey;
 pub use star::Star;
 pub use text_formatting_rule::TextFormattingRule;
 pub use user::User;
+pub use web_url::{BacklogResource, BacklogUrl, WikiPage};
 
 // Re-export identifiers under `id` namespace
 pub mod id {
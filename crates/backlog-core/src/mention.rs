@@ -0,0 +1,262 @@
+//! Finding issue key references such as `PROJ-123` in free text.
+//!
+//! [`IssueKey::from_str`](std::str::FromStr) parses a whole string, while
+//! [`IssueKeyExtractor`] scans commit messages, pull request descriptions,
+//! comments or wiki pages and reports every mention together with its byte span.
+//!
+//! Mentions may carry an [`IssueAction`], written either before the key as in
+//! GitHub (`fixes PROJ-1`, `Closes: PROJ-2, PROJ-3`) or after it as in Backlog's
+//! Git integration (`PROJ-1 #fix`, `PROJ-2 #close`).
+
+use crate::{IssueKey, ProjectKey};
+use regex::Regex;
+use std::collections::HashSet;
+use std::ops::Range;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+static CANDIDATE_REGEXP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[A-Z0-9_]+-[1-9][0-9]*").unwrap());
+
+static PREFIX_KEYWORD_REGEXP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(close[sd]?|fix(?:e[sd])?|resolve[sd]?)\s*:?\s*$").unwrap());
+
+static POSTFIX_KEYWORD_REGEXP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^[ \t]*#(fix(?:ed)?|close[d]?)\b").unwrap());
+
+static LIST_SEPARATOR_REGEXP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^\s*(?:,|&|and|,\s*and)?\s*$").unwrap());
+
+/// How many bytes before a key are searched for a closing keyword.
+const KEYWORD_WINDOW: usize = 16;
+
+/// What a mention asks to happen to the issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IssueAction {
+    /// `fix`, `fixes`, `fixed`, `resolve`, `resolves`, `resolved`, `#fix` or `#fixed`.
+    Resolve,
+    /// `close`, `closes`, `closed`, `#close` or `#closed`.
+    Close,
+}
+
+/// An issue key found in text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueKeyMention {
+    pub issue_key: IssueKey,
+    /// Byte range of the key within the scanned text.
+    pub span: Range<usize>,
+    pub action: Option<IssueAction>,
+}
+
+impl IssueKeyMention {
+    /// Returns true if the mention resolves or closes the issue.
+    pub fn is_closing(&self) -> bool {
+        self.action.is_some()
+    }
+}
+
+/// Scans text for issue keys.
+///
+/// A key is only recognized on word boundaries: `aPROJ-1` and `PROJ-1a` are not
+/// mentions, while `feature/PROJ-1-login` and `課題PROJ-1は` are. Project keys
+/// must contain at least one letter, so dates such as `2024-10` are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct IssueKeyExtractor {
+    projects: Option<HashSet<ProjectKey>>,
+}
+
+impl IssueKeyExtractor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only reports keys of the given projects.
+    pub fn projects(mut self, project_keys: impl IntoIterator<Item = ProjectKey>) -> Self {
+        self.projects = Some(project_keys.into_iter().collect());
+        self
+    }
+
+    /// Returns every mention in `text`, in order of appearance.
+    pub fn extract(&self, text: &str) -> Vec<IssueKeyMention> {
+        let mut mentions: Vec<IssueKeyMention> = Vec::new();
+        // The end of the previous mention and its prefix action, so that
+        // `fixes PROJ-1, PROJ-2` applies the keyword to both keys.
+        let mut list: Option<(usize, IssueAction)> = None;
+
+        for candidate in CANDIDATE_REGEXP.find_iter(text) {
+            let span = candidate.range();
+            let before = text[..span.start].chars().next_back();
+            let after = text[span.end..].chars().next();
+            if before.is_some_and(is_word_char) || after.is_some_and(is_word_char) {
+                continue;
+            }
+            let Ok(issue_key) = IssueKey::from_str(candidate.as_str()) else {
+                continue;
+            };
+            if !issue_key
+                .project_key()
+                .as_str()
+                .contains(|c: char| c.is_ascii_uppercase())
+            {
+                continue;
+            }
+            if let Some(projects) = &self.projects
+                && !projects.contains(issue_key.project_key())
+            {
+                continue;
+            }
+
+            let prefix = prefix_action(text, span.start).or_else(|| {
+                list.filter(|(end, _)| LIST_SEPARATOR_REGEXP.is_match(&text[*end..span.start]))
+                    .map(|(_, action)| action)
+            });
+            list = prefix.map(|action| (span.end, action));
+
+            let action = postfix_action(&text[span.end..]).or(prefix);
+            mentions.push(IssueKeyMention {
+                issue_key,
+                span,
+                action,
+            });
+        }
+        mentions
+    }
+
+    /// Returns the distinct issue keys mentioned in `text`, in order of first appearance.
+    pub fn issue_keys(&self, text: &str) -> Vec<IssueKey> {
+        let mut seen = HashSet::new();
+        self.extract(text)
+            .into_iter()
+            .map(|mention| mention.issue_key)
+            .filter(|key| seen.insert(key.clone()))
+            .collect()
+    }
+}
+
+/// Returns every issue key mention in `text`, for any project.
+pub fn extract_issue_keys(text: &str) -> Vec<IssueKeyMention> {
+    IssueKeyExtractor::new().extract(text)
+}
+
+/// Only ASCII counts, since Japanese text puts keys right next to other characters.
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn prefix_action(text: &str, key_start: usize) -> Option<IssueAction> {
+    let mut window_start = key_start.saturating_sub(KEYWORD_WINDOW);
+    while !text.is_char_boundary(window_start) {
+        window_start -= 1;
+    }
+    let captures = PREFIX_KEYWORD_REGEXP.captures(&text[window_start..key_start])?;
+    let keyword = captures.get(1)?;
+    let keyword_start = window_start + keyword.start();
+    // `#close` after one key must not close the next one.
+    if text[..keyword_start]
+        .chars()
+        .next_back()
+        .is_some_and(|c| is_word_char(c) || c == '#')
+    {
+        return None;
+    }
+    Some(action_for(keyword.as_str()))
+}
+
+fn postfix_action(rest: &str) -> Option<IssueAction> {
+    let captures = POSTFIX_KEYWORD_REGEXP.captures(rest)?;
+    Some(action_for(captures.get(1)?.as_str()))
+}
+
+fn action_for(keyword: &str) -> IssueAction {
+    if keyword.to_ascii_lowercase().starts_with("close") {
+        IssueAction::Close
+    } else {
+        IssueAction::Resolve
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(mentions: &[IssueKeyMention]) -> Vec<String> {
+        mentions.iter().map(|m| m.issue_key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_extract_with_spans() {
+        let text = "See PROJ-12 and (OTHER_2-3).";
+        let mentions = extract_issue_keys(text);
+        assert_eq!(keys(&mentions), ["PROJ-12", "OTHER_2-3"]);
+        assert_eq!(&text[mentions[0].span.clone()], "PROJ-12");
+        assert_eq!(mentions[1].span, 17..26);
+        assert!(mentions.iter().all(|m| m.action.is_none()));
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        let text = "aPROJ-1 PROJ-2a PROJ-03 proj-4 2024-10-18 feature/PROJ-5-login PROJ-6.";
+        assert_eq!(keys(&extract_issue_keys(text)), ["PROJ-5", "PROJ-6"]);
+    }
+
+    #[test]
+    fn test_oversized_key_ids_are_skipped() {
+        let text = "deployed BUILD-20241019123456 today, fixes PROJ-4294967295 and PROJ-4294967296";
+        assert_eq!(keys(&extract_issue_keys(text)), ["PROJ-4294967295"]);
+    }
+
+    #[test]
+    fn test_urls_and_multibyte_text() {
+        let text = "課題PROJ-1は https://example.backlog.com/view/PROJ-2 を参照「PROJ-3」";
+        let mentions = extract_issue_keys(text);
+        assert_eq!(keys(&mentions), ["PROJ-1", "PROJ-2", "PROJ-3"]);
+        assert_eq!(&text[mentions[0].span.clone()], "PROJ-1");
+    }
+
+    #[test]
+    fn test_prefix_closing_keywords() {
+        let mentions = extract_issue_keys(
+            "Fixes PROJ-1, PROJ-2 and PROJ-3. closes: PROJ-4; prefix PROJ-5 refs PROJ-6",
+        );
+        let actions: Vec<_> = mentions.iter().map(|m| m.action).collect();
+        assert_eq!(
+            actions,
+            [
+                Some(IssueAction::Resolve),
+                Some(IssueAction::Resolve),
+                Some(IssueAction::Resolve),
+                Some(IssueAction::Close),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_postfix_backlog_keywords() {
+        let mentions = extract_issue_keys("PROJ-1 #fix PROJ-2 #closed PROJ-3 #fixture");
+        let actions: Vec<_> = mentions.iter().map(|m| m.action).collect();
+        assert_eq!(
+            actions,
+            [Some(IssueAction::Resolve), Some(IssueAction::Close), None]
+        );
+        assert!(mentions[0].is_closing());
+    }
+
+    #[test]
+    fn test_project_allowlist_and_distinct_keys() {
+        let extractor = IssueKeyExtractor::new().projects([ProjectKey::from_str("PROJ").unwrap()]);
+        let text = "PROJ-1 OTHER-2 PROJ-1 PROJ-3";
+        assert_eq!(
+            keys(&extractor.extract(text)),
+            ["PROJ-1", "PROJ-1", "PROJ-3"]
+        );
+        assert_eq!(
+            extractor.issue_keys(text),
+            [
+                IssueKey::from_str("PROJ-1").unwrap(),
+                IssueKey::from_str("PROJ-3").unwrap()
+            ]
+        );
+    }
+}
@@ -0,0 +1,532 @@
+//! Conversion between Backlog notation and CommonMark.
+//!
+//! Covers the constructs both syntaxes share: headings, bullet and numbered
+//! lists, code blocks, quotes, tables, links, and bold/italic/strikethrough.
+//! Anything else is passed through unchanged, and code blocks and inline code
+//! spans are never rewritten.
+
+use crate::TextFormattingRule;
+use regex::{Captures, Regex};
+use std::sync::LazyLock;
+
+static MD_HEADING: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(#{1,6})\s+(.*?)(?:\s+#+)?\s*$").unwrap());
+static MD_LIST_ITEM: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\s*)([-*+]|\d{1,9}[.)])\s+(.*)$").unwrap());
+static MD_FENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(`{3,}|~{3,})\s*([\w+#.-]*)").unwrap());
+static MD_TABLE_SEPARATOR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*\|?(\s*:?-+:?\s*\|)+\s*(:?-+:?)?\s*$").unwrap());
+static MD_LINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"!?\[([^\]]+)\]\(([^)\s]+)\)").unwrap());
+static MD_BOLD: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\*\*([^*\n]+?)\*\*|__([^_\n]+?)__").unwrap());
+static MD_ITALIC_STAR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\*([^*\s][^*\n]*?)\*").unwrap());
+static MD_ITALIC_UNDERSCORE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(^|[^\w])_([^_\s][^_\n]*?)_($|[^\w])").unwrap());
+static MD_STRIKE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"~~([^~\n]+?)~~").unwrap());
+
+static BACKLOG_HEADING: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\*{1,6})\s+(.*)$").unwrap());
+static BACKLOG_LIST_ITEM: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(-+|\++)\s+(.*)$").unwrap());
+static BACKLOG_CODE_OPEN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*\{code(?::([\w+#.-]+))?\}(.*)$").unwrap());
+static BACKLOG_LINK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[\[([^\]>]+)>([^\]]+)\]\]|\[\[([^\]:>]+):((?:https?|ftp)://[^\]]+)\]\]").unwrap()
+});
+static BACKLOG_ITALIC: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"'''([^'\n]+?)'''").unwrap());
+static BACKLOG_BOLD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"''([^'\n]+?)''").unwrap());
+static BACKLOG_STRIKE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"%%([^%\n]+?)%%").unwrap());
+
+impl TextFormattingRule {
+    /// Converts Markdown text into this formatting rule.
+    pub fn markdown_to_native(&self, markdown: &str) -> String {
+        match self {
+            TextFormattingRule::Backlog => markdown_to_backlog(markdown),
+            TextFormattingRule::Markdown => markdown.to_string(),
+        }
+    }
+
+    /// Converts text written in this formatting rule into Markdown.
+    pub fn native_to_markdown(&self, text: &str) -> String {
+        match self {
+            TextFormattingRule::Backlog => backlog_to_markdown(text),
+            TextFormattingRule::Markdown => text.to_string(),
+        }
+    }
+}
+
+/// Converts CommonMark text into Backlog notation.
+pub fn markdown_to_backlog(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut out = Vec::with_capacity(lines.len());
+    let mut list_indents: Vec<usize> = Vec::new();
+    let mut fence: Option<String> = None;
+    let mut in_table = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+
+        if let Some(marker) = &fence {
+            if line.trim() == marker.as_str() || is_closing_fence(line, marker) {
+                out.push("{/code}".to_string());
+                fence = None;
+            } else {
+                out.push(line.to_string());
+            }
+            continue;
+        }
+
+        if let Some(caps) = MD_FENCE.captures(line) {
+            let lang = &caps[2];
+            out.push(if lang.is_empty() {
+                "{code}".to_string()
+            } else {
+                format!("{{code:{lang}}}")
+            });
+            fence = Some(caps[1].to_string());
+            list_indents.clear();
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('|') {
+            if !in_table && lines.get(i).is_some_and(|l| MD_TABLE_SEPARATOR.is_match(l)) {
+                let cells = split_table_row(trimmed);
+                if cells.iter().any(|c| !c.is_empty()) {
+                    out.push(format!(
+                        "|{}|h",
+                        join_cells(&cells, markdown_inline_to_backlog)
+                    ));
+                }
+                in_table = true;
+                i += 1;
+                continue;
+            }
+            if in_table {
+                let cells = split_table_row(trimmed);
+                out.push(format!(
+                    "|{}|",
+                    join_cells(&cells, markdown_inline_to_backlog)
+                ));
+                continue;
+            }
+        }
+        in_table = false;
+
+        if let Some(caps) = MD_HEADING.captures(line) {
+            list_indents.clear();
+            out.push(format!(
+                "{} {}",
+                "*".repeat(caps[1].len()),
+                markdown_inline_to_backlog(&caps[2])
+            ));
+            continue;
+        }
+
+        if let Some(caps) = MD_LIST_ITEM.captures(line) {
+            let indent = indent_width(&caps[1]);
+            while list_indents.last().is_some_and(|&last| last > indent) {
+                list_indents.pop();
+            }
+            if list_indents.last().is_none_or(|&last| last < indent) {
+                list_indents.push(indent);
+            }
+            let marker = if caps[2].starts_with(|c: char| c.is_ascii_digit()) {
+                "+"
+            } else {
+                "-"
+            };
+            out.push(format!(
+                "{} {}",
+                marker.repeat(list_indents.len()),
+                markdown_inline_to_backlog(&caps[3])
+            ));
+            continue;
+        }
+
+        if !line.starts_with(char::is_whitespace) {
+            list_indents.clear();
+        }
+        out.push(markdown_inline_to_backlog(line));
+    }
+
+    if fence.is_some() {
+        out.push("{/code}".to_string());
+    }
+    join_lines(out, markdown)
+}
+
+/// Converts Backlog notation into CommonMark text.
+pub fn backlog_to_markdown(text: &str) -> String {
+    let mut out = Vec::new();
+    let mut in_code = false;
+    let mut in_quote = false;
+    let mut in_table = false;
+
+    for line in text.lines() {
+        if in_code {
+            match line.find("{/code}") {
+                Some(end) => {
+                    if !line[..end].is_empty() {
+                        out.push(line[..end].to_string());
+                    }
+                    out.push("```".to_string());
+                    in_code = false;
+                }
+                None => out.push(line.to_string()),
+            }
+            continue;
+        }
+
+        if let Some(caps) = BACKLOG_CODE_OPEN.captures(line) {
+            out.push(format!("```{}", caps.get(1).map_or("", |m| m.as_str())));
+            let rest = caps.get(2).map_or("", |m| m.as_str());
+            match rest.find("{/code}") {
+                Some(end) => {
+                    if !rest[..end].is_empty() {
+                        out.push(rest[..end].to_string());
+                    }
+                    out.push("```".to_string());
+                }
+                None => {
+                    if !rest.is_empty() {
+                        out.push(rest.to_string());
+                    }
+                    in_code = true;
+                }
+            }
+            in_table = false;
+            continue;
+        }
+
+        let mut line = line;
+        let mut marker_only = false;
+        if let Some(rest) = line.trim_start().strip_prefix("{quote}") {
+            in_quote = true;
+            marker_only = rest.trim().is_empty();
+            line = rest;
+        }
+        let closes_quote = line.trim_end().ends_with("{/quote}");
+        if closes_quote {
+            line = line.trim_end().trim_end_matches("{/quote}");
+            marker_only = line.trim().is_empty();
+        }
+        if in_quote && line.trim().is_empty() {
+            if !marker_only {
+                out.push(">".to_string());
+            }
+            in_quote = !closes_quote;
+            continue;
+        }
+
+        let converted = if line.trim_start().starts_with('|') {
+            let (cells, header) = split_backlog_table_row(line.trim());
+            let row = format!("| {} |", join_cells_spaced(&cells));
+            let columns = cells.len().max(1);
+            let rows = match (in_table, header) {
+                (true, _) => vec![row],
+                (false, true) => vec![row, format!("|{}", " --- |".repeat(columns))],
+                (false, false) => vec![
+                    format!("|{}", " |".repeat(columns)),
+                    format!("|{}", " --- |".repeat(columns)),
+                    row,
+                ],
+            };
+            in_table = true;
+            rows
+        } else {
+            in_table = false;
+            vec![backlog_block_line_to_markdown(line)]
+        };
+
+        for row in converted {
+            out.push(if in_quote { format!("> {row}") } else { row });
+        }
+        if closes_quote {
+            in_quote = false;
+        }
+    }
+
+    if in_code {
+        out.push("```".to_string());
+    }
+    join_lines(out, text)
+}
+
+fn backlog_block_line_to_markdown(line: &str) -> String {
+    if let Some(caps) = BACKLOG_HEADING.captures(line) {
+        return format!(
+            "{} {}",
+            "#".repeat(caps[1].len()),
+            backlog_inline_to_markdown(&caps[2])
+        );
+    }
+    if let Some(caps) = BACKLOG_LIST_ITEM.captures(line) {
+        let depth = caps[1].len();
+        let marker = if caps[1].starts_with('+') { "1." } else { "-" };
+        return format!(
+            "{}{marker} {}",
+            "    ".repeat(depth - 1),
+            backlog_inline_to_markdown(&caps[2])
+        );
+    }
+    backlog_inline_to_markdown(line)
+}
+
+/// Converts inline Markdown markup (outside code spans) into Backlog notation.
+fn markdown_inline_to_backlog(text: &str) -> String {
+    map_outside_code_spans(text, |segment| {
+        let s = MD_LINK.replace_all(segment, |caps: &Captures| {
+            if caps[0].starts_with('!') {
+                caps[0].to_string()
+            } else {
+                format!("[[{}>{}]]", &caps[1], &caps[2])
+            }
+        });
+        let s = MD_BOLD.replace_all(&s, |caps: &Captures| {
+            let inner = caps
+                .get(1)
+                .or_else(|| caps.get(2))
+                .map_or("", |m| m.as_str());
+            format!("''{inner}''")
+        });
+        let s = MD_ITALIC_STAR.replace_all(&s, "'''$1'''");
+        let s = MD_ITALIC_UNDERSCORE.replace_all(&s, "$1'''$2'''$3");
+        MD_STRIKE.replace_all(&s, "%%$1%%").into_owned()
+    })
+}
+
+/// Converts inline Backlog notation (outside code spans) into Markdown.
+fn backlog_inline_to_markdown(text: &str) -> String {
+    map_outside_code_spans(text, |segment| {
+        let s = BACKLOG_LINK.replace_all(segment, |caps: &Captures| {
+            let label = caps
+                .get(1)
+                .or_else(|| caps.get(3))
+                .map_or("", |m| m.as_str());
+            let url = caps
+                .get(2)
+                .or_else(|| caps.get(4))
+                .map_or("", |m| m.as_str());
+            format!("[{label}]({url})")
+        });
+        let s = BACKLOG_ITALIC.replace_all(&s, "*$1*");
+        let s = BACKLOG_BOLD.replace_all(&s, "**$1**");
+        BACKLOG_STRIKE.replace_all(&s, "~~$1~~").into_owned()
+    })
+}
+
+/// Applies `f` to the parts of `text` that are not inside backtick code spans.
+fn map_outside_code_spans(text: &str, f: impl Fn(&str) -> String) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('`') {
+        let ticks = rest[start..].len() - rest[start..].trim_start_matches('`').len();
+        let delimiter = &rest[start..start + ticks];
+        match rest[start + ticks..].find(delimiter) {
+            Some(len) => {
+                let end = start + ticks + len + ticks;
+                out.push_str(&f(&rest[..start]));
+                out.push_str(&rest[start..end]);
+                rest = &rest[end..];
+            }
+            None => break,
+        }
+    }
+    out.push_str(&f(rest));
+    out
+}
+
+fn is_closing_fence(line: &str, marker: &str) -> bool {
+    let trimmed = line.trim();
+    let fence_char = marker.chars().next().unwrap_or('`');
+    trimmed.len() >= marker.len() && trimmed.chars().all(|c| c == fence_char)
+}
+
+fn indent_width(indent: &str) -> usize {
+    indent.chars().map(|c| if c == '\t' { 4 } else { 1 }).sum()
+}
+
+fn split_table_row(row: &str) -> Vec<String> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = row.strip_suffix('|').unwrap_or(row);
+    row.split('|').map(|cell| cell.trim().to_string()).collect()
+}
+
+fn split_backlog_table_row(row: &str) -> (Vec<String>, bool) {
+    let (row, header) = match row.strip_suffix("|h") {
+        Some(row) => (row, true),
+        None => (row.strip_suffix('|').unwrap_or(row), false),
+    };
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let cells = row
+        .split('|')
+        .map(|cell| backlog_inline_to_markdown(cell.trim()))
+        .collect();
+    (cells, header)
+}
+
+fn join_cells(cells: &[String], convert: fn(&str) -> String) -> String {
+    cells
+        .iter()
+        .map(|cell| convert(cell))
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+fn join_cells_spaced(cells: &[String]) -> String {
+    cells.join(" | ")
+}
+
+/// Joins converted lines, keeping the trailing newline of the source if it had one.
+fn join_lines(lines: Vec<String>, source: &str) -> String {
+    let mut joined = lines.join("\n");
+    if source.ends_with('\n') {
+        joined.push('\n');
+    }
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headings() {
+        assert_eq!(
+            markdown_to_backlog("# Title\n### Sub ###"),
+            "* Title\n*** Sub"
+        );
+        assert_eq!(backlog_to_markdown("* Title\n*** Sub"), "# Title\n### Sub");
+    }
+
+    #[test]
+    fn test_nested_lists() {
+        let markdown = "- one\n  - nested\n    1. deep\n- two\n1. first";
+        let backlog = "- one\n-- nested\n+++ deep\n- two\n+ first";
+        assert_eq!(markdown_to_backlog(markdown), backlog);
+        assert_eq!(
+            backlog_to_markdown(backlog),
+            "- one\n    - nested\n        1. deep\n- two\n1. first"
+        );
+    }
+
+    #[test]
+    fn test_star_list_is_not_a_heading() {
+        assert_eq!(markdown_to_backlog("* item"), "- item");
+    }
+
+    #[test]
+    fn test_code_blocks_are_left_untouched() {
+        let markdown = "```rust\nlet x = **y**;\n# not a heading\n```";
+        let backlog = "{code:rust}\nlet x = **y**;\n# not a heading\n{/code}";
+        assert_eq!(markdown_to_backlog(markdown), backlog);
+        assert_eq!(backlog_to_markdown(backlog), markdown);
+        assert_eq!(backlog_to_markdown("{code}a{/code}"), "```\na\n```");
+        assert_eq!(markdown_to_backlog("~~~\n* x\n~~~"), "{code}\n* x\n{/code}");
+    }
+
+    #[test]
+    fn test_unterminated_code_block_is_closed() {
+        assert_eq!(markdown_to_backlog("```\ncode"), "{code}\ncode\n{/code}");
+        assert_eq!(backlog_to_markdown("{code}\ncode"), "```\ncode\n```");
+    }
+
+    #[test]
+    fn test_inline_markup() {
+        let markdown = "**bold** and *italic* and ~~gone~~ with [docs](https://example.com)";
+        let backlog = "''bold'' and '''italic''' and %%gone%% with [[docs>https://example.com]]";
+        assert_eq!(markdown_to_backlog(markdown), backlog);
+        assert_eq!(backlog_to_markdown(backlog), markdown);
+    }
+
+    #[test]
+    fn test_underscore_emphasis_respects_word_boundaries() {
+        assert_eq!(
+            markdown_to_backlog("__strong__ _em_"),
+            "''strong'' '''em'''"
+        );
+        assert_eq!(markdown_to_backlog("snake_case_name"), "snake_case_name");
+    }
+
+    #[test]
+    fn test_inline_code_spans_are_left_untouched() {
+        assert_eq!(
+            markdown_to_backlog("run `a **b**` then **c**"),
+            "run `a **b**` then ''c''"
+        );
+        assert_eq!(backlog_to_markdown("`''x''` ''y''"), "`''x''` **y**");
+    }
+
+    #[test]
+    fn test_images_are_not_turned_into_links() {
+        assert_eq!(
+            markdown_to_backlog("![logo](logo.png)"),
+            "![logo](logo.png)"
+        );
+    }
+
+    #[test]
+    fn test_backlog_colon_links() {
+        assert_eq!(
+            backlog_to_markdown("[[Backlog:https://backlog.com]] [[WikiPage]]"),
+            "[Backlog](https://backlog.com) [[WikiPage]]"
+        );
+    }
+
+    #[test]
+    fn test_tables() {
+        let markdown = "| Name | Value |\n| --- | :---: |\n| a | **b** |";
+        let backlog = "|Name|Value|h\n|a|''b''|";
+        assert_eq!(markdown_to_backlog(markdown), backlog);
+        assert_eq!(
+            backlog_to_markdown(backlog),
+            markdown.replace(":---:", "---")
+        );
+    }
+
+    #[test]
+    fn test_table_without_header_round_trips() {
+        let backlog = "|a|b|\n|c|d|";
+        let markdown = backlog_to_markdown(backlog);
+        assert_eq!(markdown, "| | |\n| --- | --- |\n| a | b |\n| c | d |");
+        assert_eq!(markdown_to_backlog(&markdown), backlog);
+    }
+
+    #[test]
+    fn test_quote_block() {
+        assert_eq!(
+            backlog_to_markdown("{quote}\nquoted ''text''\n{/quote}\nafter"),
+            "> quoted **text**\nafter"
+        );
+        assert_eq!(backlog_to_markdown("{quote}inline{/quote}"), "> inline");
+        assert_eq!(markdown_to_backlog("> quoted"), "> quoted");
+    }
+
+    #[test]
+    fn test_plain_text_and_trailing_newline_are_preserved() {
+        let text = "Plain text with 2 * 3 = 6.\n\nSecond paragraph.\n";
+        assert_eq!(backlog_to_markdown(text), text);
+        assert_eq!(markdown_to_backlog(text), text);
+    }
+
+    #[test]
+    fn test_text_formatting_rule_conversion() {
+        assert_eq!(TextFormattingRule::Backlog.markdown_to_native("# T"), "* T");
+        assert_eq!(
+            TextFormattingRule::Markdown.markdown_to_native("# T"),
+            "# T"
+        );
+        assert_eq!(TextFormattingRule::Backlog.native_to_markdown("* T"), "# T");
+        assert_eq!(
+            TextFormattingRule::Markdown.native_to_markdown("* T"),
+            "* T"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use super::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// Sort direction shared by the `order` parameter of list endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Order {
+    /// Ascending order (oldest or smallest first)
+    Asc,
+    /// Descending order (newest or largest first)
+    Desc,
+}
+
+impl Order {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Order::Asc => "asc",
+            Order::Desc => "desc",
+        }
+    }
+}
+
+impl fmt::Display for Order {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Order {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "asc" => Ok(Order::Asc),
+            "desc" => Ok(Order::Desc),
+            _ => Err(Error::InvalidParameter(format!(
+                "invalid order '{s}': expected 'asc' or 'desc'"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_round_trip() {
+        assert_eq!(Order::Asc.to_string(), "asc");
+        assert_eq!("DESC".parse::<Order>().unwrap(), Order::Desc);
+        assert!("ascending".parse::<Order>().is_err());
+        assert_eq!(serde_json::to_string(&Order::Desc).unwrap(), "\"desc\"");
+        assert_eq!(
+            serde_json::from_str::<Order>("\"asc\"").unwrap(),
+            Order::Asc
+        );
+    }
+}
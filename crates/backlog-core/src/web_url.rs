@@ -0,0 +1,571 @@
+//! Parsing and building of Backlog web (browser) URLs.
+//!
+//! Users tend to paste links such as `https://acme.backlog.com/view/PROJ-12#comment-345`
+//! rather than bare keys. The identifier types in this crate parse keys strictly;
+//! front ends that want to accept links parse them with [`BacklogUrl`] and pick
+//! the identifier out of its [`BacklogResource`].
+//!
+//! Supported paths:
+//!
+//! | Path | Resource |
+//! |------|----------|
+//! | `/`, `/dashboard` | [`BacklogResource::Space`] |
+//! | `/projects/{projectKey}` | [`BacklogResource::Project`] |
+//! | `/view/{issueKey}[#comment-{commentId}]` | [`BacklogResource::Issue`] |
+//! | `/wiki/{projectKey}/{pageName}`, `/alias/wiki/{wikiId}` | [`BacklogResource::Wiki`] |
+//! | `/git/{projectKey}/{repositoryName}[/...]` | [`BacklogResource::Repository`] |
+//! | `/git/{projectKey}/{repositoryName}/pullRequests/{number}[#comment-{commentId}]` | [`BacklogResource::PullRequest`] |
+//! | `/file/{projectKey}/{path}` | [`BacklogResource::SharedFile`] |
+//! | `/document/{projectKey}/{documentId}` | [`BacklogResource::Document`] |
+
+use crate::error::{Error, Result};
+use crate::identifier::{CommentId, DocumentId, PullRequestCommentId, PullRequestNumber, WikiId};
+use crate::{IssueKey, ProjectKey, RepositoryName, SpaceKey};
+use std::fmt;
+use std::str::FromStr;
+
+/// A link to a page of a Backlog space.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BacklogUrl {
+    space_key: SpaceKey,
+    domain: String,
+    resource: BacklogResource,
+}
+
+/// The page a [`BacklogUrl`] points to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BacklogResource {
+    Space,
+    Project(ProjectKey),
+    Issue {
+        issue_key: IssueKey,
+        comment_id: Option<CommentId>,
+    },
+    Wiki(WikiPage),
+    Repository {
+        project_key: ProjectKey,
+        repository_name: RepositoryName,
+    },
+    PullRequest {
+        project_key: ProjectKey,
+        repository_name: RepositoryName,
+        number: PullRequestNumber,
+        comment_id: Option<PullRequestCommentId>,
+    },
+    /// A shared file or directory. `path` is absolute, e.g. `/docs/a.pdf`.
+    SharedFile {
+        project_key: ProjectKey,
+        path: String,
+    },
+    Document {
+        project_key: ProjectKey,
+        document_id: DocumentId,
+    },
+}
+
+/// A wiki page, addressed either by ID or by its name within a project.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WikiPage {
+    Id(WikiId),
+    Name {
+        project_key: ProjectKey,
+        name: String,
+    },
+}
+
+impl BacklogUrl {
+    /// Creates a URL for `resource` in the space `https://{space_key}.{domain}`.
+    pub fn new(space_key: SpaceKey, domain: impl Into<String>, resource: BacklogResource) -> Self {
+        Self {
+            space_key,
+            domain: domain.into(),
+            resource,
+        }
+    }
+
+    pub fn space_key(&self) -> &SpaceKey {
+        &self.space_key
+    }
+
+    /// The domain the space is hosted on, e.g. `backlog.com` or `backlog.jp`.
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    pub fn resource(&self) -> &BacklogResource {
+        &self.resource
+    }
+
+    pub fn into_resource(self) -> BacklogResource {
+        self.resource
+    }
+
+    /// The space URL, e.g. `https://acme.backlog.com`.
+    pub fn base_url(&self) -> String {
+        format!("https://{}.{}", self.space_key, self.domain)
+    }
+}
+
+impl BacklogResource {
+    /// The project this resource belongs to, if the URL names one.
+    pub fn project_key(&self) -> Option<&ProjectKey> {
+        match self {
+            BacklogResource::Space | BacklogResource::Wiki(WikiPage::Id(_)) => None,
+            BacklogResource::Project(project_key)
+            | BacklogResource::Wiki(WikiPage::Name { project_key, .. })
+            | BacklogResource::Repository { project_key, .. }
+            | BacklogResource::PullRequest { project_key, .. }
+            | BacklogResource::SharedFile { project_key, .. }
+            | BacklogResource::Document { project_key, .. } => Some(project_key),
+            BacklogResource::Issue { issue_key, .. } => Some(issue_key.project_key()),
+        }
+    }
+
+    pub fn issue_key(&self) -> Option<&IssueKey> {
+        match self {
+            BacklogResource::Issue { issue_key, .. } => Some(issue_key),
+            _ => None,
+        }
+    }
+
+    /// The issue comment a `#comment-{id}` fragment points to.
+    pub fn comment_id(&self) -> Option<CommentId> {
+        match self {
+            BacklogResource::Issue { comment_id, .. } => *comment_id,
+            _ => None,
+        }
+    }
+
+    pub fn wiki_page(&self) -> Option<&WikiPage> {
+        match self {
+            BacklogResource::Wiki(page) => Some(page),
+            _ => None,
+        }
+    }
+
+    pub fn repository_name(&self) -> Option<&RepositoryName> {
+        match self {
+            BacklogResource::Repository {
+                repository_name, ..
+            }
+            | BacklogResource::PullRequest {
+                repository_name, ..
+            } => Some(repository_name),
+            _ => None,
+        }
+    }
+
+    pub fn pull_request_number(&self) -> Option<PullRequestNumber> {
+        match self {
+            BacklogResource::PullRequest { number, .. } => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// The pull request comment a `#comment-{id}` fragment points to.
+    pub fn pull_request_comment_id(&self) -> Option<PullRequestCommentId> {
+        match self {
+            BacklogResource::PullRequest { comment_id, .. } => *comment_id,
+            _ => None,
+        }
+    }
+
+    pub fn shared_file_path(&self) -> Option<&str> {
+        match self {
+            BacklogResource::SharedFile { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    pub fn document_id(&self) -> Option<&DocumentId> {
+        match self {
+            BacklogResource::Document { document_id, .. } => Some(document_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the path and fragment of this resource's page, e.g. `/view/PROJ-12#comment-345`.
+    pub fn path(&self) -> String {
+        match self {
+            BacklogResource::Space => "/dashboard".to_string(),
+            BacklogResource::Project(project_key) => format!("/projects/{project_key}"),
+            BacklogResource::Issue {
+                issue_key,
+                comment_id,
+            } => match comment_id {
+                Some(comment_id) => format!("/view/{issue_key}#comment-{comment_id}"),
+                None => format!("/view/{issue_key}"),
+            },
+            BacklogResource::Wiki(WikiPage::Id(wiki_id)) => format!("/alias/wiki/{wiki_id}"),
+            BacklogResource::Wiki(WikiPage::Name { project_key, name }) => {
+                format!("/wiki/{project_key}/{}", encode(name))
+            }
+            BacklogResource::Repository {
+                project_key,
+                repository_name,
+            } => format!("/git/{project_key}/{repository_name}"),
+            BacklogResource::PullRequest {
+                project_key,
+                repository_name,
+                number,
+                comment_id,
+            } => {
+                let path = format!("/git/{project_key}/{repository_name}/pullRequests/{number}");
+                match comment_id {
+                    Some(comment_id) => format!("{path}#comment-{comment_id}"),
+                    None => path,
+                }
+            }
+            BacklogResource::SharedFile { project_key, path } => {
+                let segments: Vec<String> = path
+                    .split('/')
+                    .filter(|segment| !segment.is_empty())
+                    .map(encode)
+                    .collect();
+                let trailing = if path.ends_with('/') { "/" } else { "" };
+                format!("/file/{project_key}/{}{trailing}", segments.join("/"))
+            }
+            BacklogResource::Document {
+                project_key,
+                document_id,
+            } => format!("/document/{project_key}/{document_id}"),
+        }
+    }
+
+    fn parse(path: &str, fragment: Option<&str>, url: &str) -> Result<Self> {
+        let invalid = || Error::InvalidBacklogUrl(url.to_string());
+        let raw: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let segment = |index: usize| -> Result<String> {
+            raw.get(index).map(|s| decode(s, url)).ok_or_else(invalid)?
+        };
+        let project_key =
+            || -> Result<ProjectKey> { ProjectKey::from_str(&segment(1)?).map_err(|_| invalid()) };
+        let comment = fragment.and_then(|f| f.strip_prefix("comment-"));
+
+        let resource = match raw.first().copied() {
+            None | Some("dashboard") => BacklogResource::Space,
+            Some("projects") => BacklogResource::Project(project_key()?),
+            Some("view") => BacklogResource::Issue {
+                issue_key: IssueKey::from_str(&segment(1)?).map_err(|_| invalid())?,
+                comment_id: comment.map(CommentId::from_str).transpose()?,
+            },
+            Some("alias") if raw.get(1) == Some(&"wiki") => {
+                BacklogResource::Wiki(WikiPage::Id(WikiId::from_str(&segment(2)?)?))
+            }
+            Some("wiki") if raw.len() > 2 => {
+                let name = raw[2..]
+                    .iter()
+                    .map(|s| decode(s, url))
+                    .collect::<Result<Vec<_>>>()?
+                    .join("/");
+                BacklogResource::Wiki(WikiPage::Name {
+                    project_key: project_key()?,
+                    name,
+                })
+            }
+            Some("git") if raw.len() > 2 => {
+                let project_key = project_key()?;
+                let repository_name =
+                    RepositoryName::from_str(&segment(2)?).map_err(|_| invalid())?;
+                match raw.get(3).copied() {
+                    Some("pullRequests") if raw.len() > 4 => BacklogResource::PullRequest {
+                        project_key,
+                        repository_name,
+                        number: PullRequestNumber::from_str(&segment(4)?)?,
+                        comment_id: comment.map(PullRequestCommentId::from_str).transpose()?,
+                    },
+                    _ => BacklogResource::Repository {
+                        project_key,
+                        repository_name,
+                    },
+                }
+            }
+            Some("file") if raw.len() > 1 => {
+                let segments = raw[2..]
+                    .iter()
+                    .map(|s| decode(s, url))
+                    .collect::<Result<Vec<_>>>()?;
+                let trailing = if path.ends_with('/') && !segments.is_empty() {
+                    "/"
+                } else {
+                    ""
+                };
+                BacklogResource::SharedFile {
+                    project_key: project_key()?,
+                    path: format!("/{}{trailing}", segments.join("/")),
+                }
+            }
+            Some("document") if raw.len() > 2 => BacklogResource::Document {
+                project_key: project_key()?,
+                document_id: DocumentId::from_str(&segment(2)?)?,
+            },
+            _ => return Err(invalid()),
+        };
+        Ok(resource)
+    }
+}
+
+impl FromStr for BacklogUrl {
+    type Err = Error;
+
+    /// Parses a Backlog web URL such as `https://acme.backlog.com/view/PROJ-12`.
+    ///
+    /// The space key is taken from the first label of the host and the rest of
+    /// the host is kept as the domain, so spaces on `backlog.jp` and
+    /// `backlogtool.com` are accepted as well.
+    fn from_str(url: &str) -> Result<Self> {
+        let invalid = || Error::InvalidBacklogUrl(url.to_string());
+        let trimmed = url.trim();
+        let rest = strip_scheme(trimmed).ok_or_else(invalid)?;
+        let (rest, fragment) = match rest.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (rest, None),
+        };
+        let rest = rest.split_once('?').map_or(rest, |(rest, _)| rest);
+        let (authority, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, ""),
+        };
+        let host = authority.rsplit('@').next().unwrap_or_default();
+        let host = host.split(':').next().unwrap_or_default().to_lowercase();
+        let (space, domain) = host.split_once('.').ok_or_else(invalid)?;
+        if domain.is_empty() {
+            return Err(invalid());
+        }
+        let space_key = SpaceKey::from_str(space).map_err(|_| invalid())?;
+
+        Ok(BacklogUrl {
+            space_key,
+            domain: domain.to_string(),
+            resource: BacklogResource::parse(path, fragment, url)?,
+        })
+    }
+}
+
+impl fmt::Display for BacklogUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.base_url(), self.resource.path())
+    }
+}
+
+/// Returns true if `s` starts with an `http://` or `https://` scheme.
+pub fn is_url(s: &str) -> bool {
+    strip_scheme(s.trim()).is_some()
+}
+
+fn strip_scheme(s: &str) -> Option<&str> {
+    ["https://", "http://"].iter().find_map(|scheme| {
+        s.get(..scheme.len())
+            .filter(|prefix| prefix.eq_ignore_ascii_case(scheme))
+            .map(|_| &s[scheme.len()..])
+    })
+}
+
+fn decode(segment: &str, url: &str) -> Result<String> {
+    let bytes = segment.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = segment
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| Error::InvalidBacklogUrl(url.to_string()))?;
+            out.push(hex);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(out).map_err(|_| Error::InvalidBacklogUrl(url.to_string()))
+}
+
+fn encode(segment: &str) -> String {
+    let mut out = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(url: &str) -> BacklogResource {
+        BacklogUrl::from_str(url).unwrap().into_resource()
+    }
+
+    fn project(key: &str) -> ProjectKey {
+        ProjectKey::from_str(key).unwrap()
+    }
+
+    #[test]
+    fn test_parse_issue_with_comment() {
+        let url =
+            BacklogUrl::from_str("https://acme.backlog.com/view/PROJ-12#comment-345").unwrap();
+        assert_eq!(url.space_key().as_str(), "acme");
+        assert_eq!(url.domain(), "backlog.com");
+        assert_eq!(
+            url.resource(),
+            &BacklogResource::Issue {
+                issue_key: IssueKey::from_str("PROJ-12").unwrap(),
+                comment_id: Some(CommentId::new(345)),
+            }
+        );
+        assert_eq!(url.resource().project_key(), Some(&project("PROJ")));
+    }
+
+    #[test]
+    fn test_parse_wiki_pages() {
+        assert_eq!(
+            parse("https://acme.backlog.jp/wiki/PROJ/Page%2FSub"),
+            BacklogResource::Wiki(WikiPage::Name {
+                project_key: project("PROJ"),
+                name: "Page/Sub".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("https://acme.backlog.com/wiki/PROJ/Home/Setup?x=1"),
+            BacklogResource::Wiki(WikiPage::Name {
+                project_key: project("PROJ"),
+                name: "Home/Setup".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("https://acme.backlog.com/alias/wiki/1234"),
+            BacklogResource::Wiki(WikiPage::Id(WikiId::new(1234)))
+        );
+    }
+
+    #[test]
+    fn test_parse_git_urls() {
+        assert_eq!(
+            parse("https://acme.backlog.com/git/PROJ/repo/pullRequests/7"),
+            BacklogResource::PullRequest {
+                project_key: project("PROJ"),
+                repository_name: RepositoryName::from_str("repo").unwrap(),
+                number: PullRequestNumber::new(7),
+                comment_id: None,
+            }
+        );
+        assert_eq!(
+            parse("https://acme.backlog.com/git/PROJ/repo/tree/main"),
+            BacklogResource::Repository {
+                project_key: project("PROJ"),
+                repository_name: RepositoryName::from_str("repo").unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_shared_file_and_document() {
+        assert_eq!(
+            parse("https://acme.backlog.com/file/PROJ/docs/a%20b.pdf"),
+            BacklogResource::SharedFile {
+                project_key: project("PROJ"),
+                path: "/docs/a b.pdf".to_string(),
+            }
+        );
+        assert_eq!(
+            parse("https://acme.backlog.com/file/PROJ/"),
+            BacklogResource::SharedFile {
+                project_key: project("PROJ"),
+                path: "/".to_string(),
+            }
+        );
+        assert_eq!(
+            parse("https://acme.backlog.com/document/PROJ/0123456789abcdef0123456789abcdef")
+                .document_id()
+                .map(ToString::to_string),
+            Some("0123456789abcdef0123456789abcdef".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_space_and_project() {
+        assert_eq!(parse("https://acme.backlog.com"), BacklogResource::Space);
+        assert_eq!(
+            parse("HTTPS://ACME.backlog.com/projects/PROJ"),
+            BacklogResource::Project(project("PROJ"))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_urls() {
+        for url in [
+            "acme.backlog.com/view/PROJ-12",
+            "https://localhost/view/PROJ-12",
+            "https://acme.backlog.com/view/proj-12",
+            "https://acme.backlog.com/unknown/PROJ",
+            "https://acme.backlog.com/wiki/PROJ/%ZZ",
+            "https://acme.backlog.com/view/PROJ-12#comment-x",
+            "https://acme.backlog.com/view/PROJ-99999999999",
+        ] {
+            assert!(
+                BacklogUrl::from_str(url).is_err(),
+                "{url} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_urls() {
+        let space_key = SpaceKey::from_str("acme").unwrap();
+        let url =
+            |resource| BacklogUrl::new(space_key.clone(), "backlog.com", resource).to_string();
+
+        assert_eq!(
+            url(BacklogResource::Issue {
+                issue_key: IssueKey::from_str("PROJ-12").unwrap(),
+                comment_id: Some(CommentId::new(345)),
+            }),
+            "https://acme.backlog.com/view/PROJ-12#comment-345"
+        );
+        assert_eq!(
+            url(BacklogResource::Wiki(WikiPage::Name {
+                project_key: project("PROJ"),
+                name: "Page/Sub ä".to_string(),
+            })),
+            "https://acme.backlog.com/wiki/PROJ/Page%2FSub%20%C3%A4"
+        );
+        assert_eq!(
+            url(BacklogResource::SharedFile {
+                project_key: project("PROJ"),
+                path: "/docs/a b.pdf".to_string(),
+            }),
+            "https://acme.backlog.com/file/PROJ/docs/a%20b.pdf"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for url in [
+            "https://acme.backlog.com/view/PROJ-12",
+            "https://acme.backlog.jp/wiki/PROJ/Page%2FSub",
+            "https://acme.backlog.com/alias/wiki/99",
+            "https://acme.backlog.com/git/PROJ/repo.js/pullRequests/7#comment-8",
+            "https://acme.backlog.com/git/PROJ/repo",
+            "https://acme.backlogtool.com/file/PROJ/docs/",
+            "https://acme.backlog.com/projects/PROJ",
+        ] {
+            assert_eq!(BacklogUrl::from_str(url).unwrap().to_string(), url);
+        }
+    }
+
+    #[test]
+    fn test_identifiers_reject_urls() {
+        use crate::{IssueIdOrKey, ProjectIdOrKey};
+
+        let url = "https://acme.backlog.com/view/PROJ-12";
+        assert!(IssueIdOrKey::from_str(url).is_err());
+        assert!(ProjectIdOrKey::from_str(url).is_err());
+        assert!(RepositoryName::from_str("https://acme.backlog.com/git/PROJ/repo").is_err());
+        assert!(DocumentId::from_str("https://acme.backlog.com/document/PROJ/0123").is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::models::Document;
 use backlog_api_core::IntoRequest;
 use backlog_api_macros::ToFormParams;
-use backlog_core::identifier::ProjectId;
+use backlog_core::{Order, identifier::ProjectId};
 use derive_builder::Builder;
 use serde::Serialize;
 use std::fmt;
@@ -9,6 +9,10 @@ use std::fmt;
 /// Response type for listing documents
 pub type ListDocumentsResponse = Vec<Document>;
 
+/// Sort order for document lists.
+#[deprecated(note = "use `backlog_core::Order`")]
+pub type DocumentOrder = backlog_core::Order;
+
 /// Parameters for listing documents
 ///
 /// Corresponds to `GET /api/v2/documents`.
@@ -27,7 +31,7 @@ pub struct ListDocumentsParams {
     #[builder(default, setter(into))]
     pub sort: Option<DocumentSortKey>, // Enum to be defined
     #[builder(default, setter(into))]
-    pub order: Option<DocumentOrder>, // Sort order
+    pub order: Option<Order>, // Sort order
     #[builder(default)]
     pub offset: Option<u32>,
     #[builder(default)]
@@ -42,14 +46,6 @@ pub enum DocumentSortKey {
     Updated,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq)]
-pub enum DocumentOrder {
-    #[serde(rename = "asc")]
-    Asc,
-    #[serde(rename = "desc")]
-    Desc,
-}
-
 impl fmt::Display for DocumentSortKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -59,15 +55,6 @@ impl fmt::Display for DocumentSortKey {
     }
 }
 
-impl fmt::Display for DocumentOrder {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            DocumentOrder::Asc => write!(f, "asc"),
-            DocumentOrder::Desc => write!(f, "desc"),
-        }
-    }
-}
-
 // This From implementation is crucial for client.get_with_params
 impl From<ListDocumentsParams> for Vec<(String, String)> {
     fn from(params: ListDocumentsParams) -> Self {
@@ -6,3 +6,4 @@ pub use models::*;
 
 pub mod api;
 pub mod models;
+pub mod prosemirror;
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 use crate::models::{attachment::DocumentAttachment, tag::DocumentTag};
+use crate::prosemirror::{self, Node, RenderOptions};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -29,3 +30,37 @@ pub struct DocumentDetail {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<DocumentTag>,
 }
+
+impl DocumentDetail {
+    /// Parses the ProseMirror document in [`json`](Self::json).
+    pub fn content(&self) -> serde_json::Result<Node> {
+        Node::from_value(&self.json)
+    }
+
+    /// Renders the document body as Markdown, linking images to this document's attachments.
+    ///
+    /// Falls back to [`plain`](Self::plain) if the ProseMirror JSON cannot be parsed.
+    pub fn to_markdown(&self) -> String {
+        self.content()
+            .map(|node| prosemirror::to_markdown(&node, &self.render_options()))
+            .unwrap_or_else(|_| self.plain.clone())
+    }
+
+    /// Renders the document body as an HTML fragment, linking images to this document's attachments.
+    ///
+    /// Falls back to the escaped [`plain`](Self::plain) text if the ProseMirror JSON cannot be parsed.
+    pub fn to_html(&self) -> String {
+        let node = self.content().unwrap_or_else(|_| {
+            Node::Paragraph(vec![Node::Text {
+                text: self.plain.clone(),
+                marks: Vec::new(),
+            }])
+        });
+        prosemirror::to_html(&node, &self.render_options())
+    }
+
+    /// Returns render options for this document's attachments.
+    pub fn render_options(&self) -> RenderOptions<'_> {
+        RenderOptions::new(&self.id, &self.attachments)
+    }
+}
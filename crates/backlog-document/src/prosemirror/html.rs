@@ -0,0 +1,138 @@
+use super::{Mark, Node, RenderOptions, is_image_name};
+
+/// Renders a ProseMirror node tree as an HTML fragment.
+pub fn to_html(node: &Node, options: &RenderOptions) -> String {
+    let mut out = String::new();
+    render(node, options, &mut out);
+    out
+}
+
+fn render_all(nodes: &[Node], options: &RenderOptions, out: &mut String) {
+    for node in nodes {
+        render(node, options, out);
+    }
+}
+
+fn wrap(tag: &str, content: &[Node], options: &RenderOptions, out: &mut String) {
+    out.push_str(&format!("<{tag}>"));
+    render_all(content, options, out);
+    out.push_str(&format!("</{tag}>"));
+}
+
+fn render(node: &Node, options: &RenderOptions, out: &mut String) {
+    match node {
+        Node::Doc(content) | Node::Unknown { content, .. } => render_all(content, options, out),
+        Node::Paragraph(content) => wrap("p", content, options, out),
+        Node::Heading { level, content } => wrap(&format!("h{level}"), content, options, out),
+        Node::Text { text, marks } => render_text(text, marks, out),
+        Node::HardBreak => out.push_str("<br>"),
+        Node::HorizontalRule => out.push_str("<hr>"),
+        Node::Blockquote(content) => wrap("blockquote", content, options, out),
+        Node::CodeBlock { language, code } => {
+            match language {
+                Some(language) => out.push_str(&format!(
+                    "<pre><code class=\"language-{}\">",
+                    escape(language)
+                )),
+                None => out.push_str("<pre><code>"),
+            }
+            out.push_str(&escape(code));
+            out.push_str("</code></pre>");
+        }
+        Node::BulletList(items) => wrap("ul", items, options, out),
+        Node::OrderedList { start, items } => {
+            if *start == 1 {
+                out.push_str("<ol>");
+            } else {
+                out.push_str(&format!("<ol start=\"{start}\">"));
+            }
+            render_all(items, options, out);
+            out.push_str("</ol>");
+        }
+        Node::ListItem(content) => wrap("li", content, options, out),
+        Node::TaskList(items) => {
+            out.push_str("<ul class=\"task-list\">");
+            render_all(items, options, out);
+            out.push_str("</ul>");
+        }
+        Node::TaskItem { checked, content } => {
+            out.push_str("<li class=\"task-list-item\"><input type=\"checkbox\" disabled");
+            if *checked {
+                out.push_str(" checked");
+            }
+            out.push('>');
+            render_all(content, options, out);
+            out.push_str("</li>");
+        }
+        Node::Table(rows) => wrap("table", rows, options, out),
+        Node::TableRow(cells) => wrap("tr", cells, options, out),
+        Node::TableCell { header, content } => {
+            wrap(if *header { "th" } else { "td" }, content, options, out)
+        }
+        Node::Image {
+            src,
+            alt,
+            title,
+            attachment_id,
+        } => match options.resolve_attachment(src, alt.as_deref(), attachment_id.as_ref()) {
+            Some(attachment) => {
+                let url = escape(&options.attachment_url(attachment));
+                let name = escape(&attachment.name);
+                if is_image_name(&attachment.name) {
+                    out.push_str(&format!(
+                        "<a href=\"{url}\"><img src=\"{url}\" alt=\"{name}\"></a>"
+                    ));
+                } else {
+                    out.push_str(&format!("<a href=\"{url}\">{name}</a>"));
+                }
+            }
+            None => {
+                out.push_str(&format!(
+                    "<img src=\"{}\" alt=\"{}\"",
+                    escape(src),
+                    escape(alt.as_deref().unwrap_or_default())
+                ));
+                if let Some(title) = title {
+                    out.push_str(&format!(" title=\"{}\"", escape(title)));
+                }
+                out.push('>');
+            }
+        },
+    }
+}
+
+fn render_text(text: &str, marks: &[Mark], out: &mut String) {
+    let mut closing = Vec::new();
+    for mark in marks {
+        let (open, close) = match mark {
+            Mark::Bold => ("<strong>".to_string(), "</strong>"),
+            Mark::Italic => ("<em>".to_string(), "</em>"),
+            Mark::Strike => ("<s>".to_string(), "</s>"),
+            Mark::Underline => ("<u>".to_string(), "</u>"),
+            Mark::Code => ("<code>".to_string(), "</code>"),
+            Mark::Link { href } => (format!("<a href=\"{}\">", escape(href)), "</a>"),
+            Mark::Unknown(_) => continue,
+        };
+        out.push_str(&open);
+        closing.push(close);
+    }
+    out.push_str(&escape(text));
+    for close in closing.iter().rev() {
+        out.push_str(close);
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,255 @@
+use super::{Mark, Node, RenderOptions, is_image_name};
+
+/// Renders a ProseMirror node tree as CommonMark with GitHub-style tables,
+/// strikethrough and task lists.
+pub fn to_markdown(node: &Node, options: &RenderOptions) -> String {
+    let mut markdown = block(node, options);
+    let trimmed_len = markdown.trim_end().len();
+    markdown.truncate(trimmed_len);
+    markdown
+}
+
+fn blocks(nodes: &[Node], options: &RenderOptions, separator: &str) -> String {
+    nodes
+        .iter()
+        .map(|node| block(node, options))
+        .filter(|rendered| !rendered.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn block(node: &Node, options: &RenderOptions) -> String {
+    match node {
+        Node::Doc(content) | Node::ListItem(content) => blocks(content, options, "\n\n"),
+        Node::Paragraph(content) => inline(content, options),
+        Node::Heading { level, content } => {
+            format!(
+                "{} {}",
+                "#".repeat(*level as usize),
+                inline(content, options)
+            )
+        }
+        Node::Blockquote(content) => prefix_lines(&blocks(content, options, "\n\n"), "> ", ">"),
+        Node::CodeBlock { language, code } => {
+            let fence = "`".repeat(longest_run(code, '`').max(2) + 1);
+            format!(
+                "{fence}{}\n{}\n{fence}",
+                language.as_deref().unwrap_or_default(),
+                code.trim_end_matches('\n')
+            )
+        }
+        Node::BulletList(items) => items
+            .iter()
+            .map(|item| list_item("- ", item, options))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Node::OrderedList { start, items } => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| list_item(&format!("{}. ", *start as usize + i), item, options))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Node::TaskList(items) => items
+            .iter()
+            .map(|item| {
+                let marker = match item {
+                    Node::TaskItem { checked: true, .. } => "- [x] ",
+                    _ => "- [ ] ",
+                };
+                list_item(marker, item, options)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Node::TaskItem { content, .. } => blocks(content, options, "\n"),
+        Node::HorizontalRule => "---".to_string(),
+        Node::Table(rows) => table(rows, options),
+        Node::Unknown { content, .. } if content.iter().all(is_inline) => inline(content, options),
+        Node::Unknown { content, .. } => blocks(content, options, "\n\n"),
+        Node::TableRow(_)
+        | Node::TableCell { .. }
+        | Node::Text { .. }
+        | Node::HardBreak
+        | Node::Image { .. } => inline(std::slice::from_ref(node), options),
+    }
+}
+
+fn list_item(marker: &str, item: &Node, options: &RenderOptions) -> String {
+    let body = blocks(item.children(), options, "\n");
+    let indent = " ".repeat(marker.len());
+    let mut lines = body.lines();
+    let first = lines.next().unwrap_or_default();
+    let mut out = format!("{marker}{first}");
+    for line in lines {
+        out.push('\n');
+        if !line.is_empty() {
+            out.push_str(&indent);
+            out.push_str(line);
+        }
+    }
+    out.trim_end().to_string()
+}
+
+fn inline(nodes: &[Node], options: &RenderOptions) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text { text, marks } => out.push_str(&marked_text(text, marks)),
+            Node::HardBreak => out.push_str("\\\n"),
+            Node::Image {
+                src,
+                alt,
+                title,
+                attachment_id,
+            } => match options.resolve_attachment(src, alt.as_deref(), attachment_id.as_ref()) {
+                Some(attachment) => {
+                    let bang = if is_image_name(&attachment.name) {
+                        "!"
+                    } else {
+                        ""
+                    };
+                    out.push_str(&format!(
+                        "{bang}[{}]({})",
+                        attachment.name,
+                        options.attachment_url(attachment)
+                    ));
+                }
+                None => {
+                    out.push_str(&format!("![{}]({src}", alt.as_deref().unwrap_or_default()));
+                    if let Some(title) = title {
+                        out.push_str(&format!(" \"{}\"", title.replace('"', "\\\"")));
+                    }
+                    out.push(')');
+                }
+            },
+            other if other.children().iter().all(is_inline) => {
+                out.push_str(&inline(other.children(), options))
+            }
+            other => out.push_str(&block(other, options)),
+        }
+    }
+    out
+}
+
+fn marked_text(text: &str, marks: &[Mark]) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    let is_code = marks.contains(&Mark::Code);
+    let mut body = if is_code {
+        let ticks = "`".repeat(longest_run(text, '`') + 1);
+        let pad = if text.starts_with('`') || text.ends_with('`') {
+            " "
+        } else {
+            ""
+        };
+        format!("{ticks}{pad}{text}{pad}{ticks}")
+    } else {
+        escape(text)
+    };
+
+    // Emphasis delimiters must hug the text, so keep surrounding spaces outside.
+    let leading = body[..body.len() - body.trim_start().len()].to_string();
+    let trailing = body[body.trim_end().len()..].to_string();
+    body = body.trim().to_string();
+    if body.is_empty() {
+        return format!("{leading}{trailing}");
+    }
+
+    for mark in marks {
+        body = match mark {
+            Mark::Bold => format!("**{body}**"),
+            Mark::Italic => format!("*{body}*"),
+            Mark::Strike => format!("~~{body}~~"),
+            Mark::Underline => format!("<u>{body}</u>"),
+            Mark::Code | Mark::Unknown(_) | Mark::Link { .. } => body,
+        };
+    }
+    if let Some(Mark::Link { href }) = marks.iter().find(|m| matches!(m, Mark::Link { .. })) {
+        body = format!("[{body}]({href})");
+    }
+    format!("{leading}{body}{trailing}")
+}
+
+fn table(rows: &[Node], options: &RenderOptions) -> String {
+    let cells: Vec<Vec<(bool, String)>> = rows
+        .iter()
+        .map(|row| {
+            row.children()
+                .iter()
+                .map(|cell| {
+                    let header = matches!(cell, Node::TableCell { header: true, .. });
+                    let text = blocks(cell.children(), options, "<br>")
+                        .replace("\\\n", "<br>")
+                        .replace('|', "\\|")
+                        .replace('\n', "<br>");
+                    (header, text)
+                })
+                .collect()
+        })
+        .collect();
+    let columns = cells.iter().map(Vec::len).max().unwrap_or(0).max(1);
+    let format_row = |row: &[(bool, String)]| {
+        let mut texts: Vec<&str> = row.iter().map(|(_, text)| text.as_str()).collect();
+        texts.resize(columns, "");
+        format!("| {} |", texts.join(" | "))
+    };
+
+    let mut lines = Vec::new();
+    let mut body = cells.as_slice();
+    match cells.first() {
+        Some(first) if !first.is_empty() && first.iter().all(|(header, _)| *header) => {
+            lines.push(format_row(first));
+            body = &cells[1..];
+        }
+        _ => lines.push(format!("|{}", "  |".repeat(columns))),
+    }
+    lines.push(format!("|{}", " --- |".repeat(columns)));
+    lines.extend(body.iter().map(|row| format_row(row)));
+    lines.join("\n")
+}
+
+fn is_inline(node: &Node) -> bool {
+    match node {
+        Node::Text { .. } | Node::HardBreak | Node::Image { .. } => true,
+        Node::Unknown { content, .. } => content.iter().all(is_inline),
+        _ => false,
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '`' | '[' | ']') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn prefix_lines(text: &str, prefix: &str, empty_prefix: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                empty_prefix.to_string()
+            } else {
+                format!("{prefix}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn longest_run(text: &str, c: char) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    for ch in text.chars() {
+        if ch == c {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+    longest
+}
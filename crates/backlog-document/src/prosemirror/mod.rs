@@ -0,0 +1,315 @@
+//! Typed model of the ProseMirror document stored in [`DocumentDetail::json`](crate::DocumentDetail),
+//! with renderers to Markdown and HTML.
+//!
+//! Node types this model does not know are kept as [`Node::Unknown`] together with
+//! their children, so rendering degrades to the contained text instead of failing.
+
+mod html;
+mod markdown;
+
+use crate::models::DocumentAttachment;
+use backlog_core::identifier::{DocumentAttachmentId, DocumentId, Identifier};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+pub use html::to_html;
+pub use markdown::to_markdown;
+
+/// A ProseMirror node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Doc(Vec<Node>),
+    Paragraph(Vec<Node>),
+    Heading {
+        level: u8,
+        content: Vec<Node>,
+    },
+    Text {
+        text: String,
+        marks: Vec<Mark>,
+    },
+    HardBreak,
+    HorizontalRule,
+    Blockquote(Vec<Node>),
+    CodeBlock {
+        language: Option<String>,
+        code: String,
+    },
+    BulletList(Vec<Node>),
+    OrderedList {
+        start: u32,
+        items: Vec<Node>,
+    },
+    ListItem(Vec<Node>),
+    /// A checklist.
+    TaskList(Vec<Node>),
+    TaskItem {
+        checked: bool,
+        content: Vec<Node>,
+    },
+    Table(Vec<Node>),
+    TableRow(Vec<Node>),
+    TableCell {
+        header: bool,
+        content: Vec<Node>,
+    },
+    Image {
+        src: String,
+        alt: Option<String>,
+        title: Option<String>,
+        attachment_id: Option<DocumentAttachmentId>,
+    },
+    /// A node type this model does not know.
+    Unknown {
+        node_type: String,
+        content: Vec<Node>,
+    },
+}
+
+/// An inline mark applied to a text node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mark {
+    Bold,
+    Italic,
+    Strike,
+    Underline,
+    Code,
+    Link { href: String },
+    Unknown(String),
+}
+
+impl Node {
+    /// Parses a ProseMirror JSON value.
+    pub fn from_value(value: &Value) -> serde_json::Result<Node> {
+        Node::deserialize(value)
+    }
+
+    /// Returns the child nodes of container nodes, or an empty slice for leaves.
+    pub fn children(&self) -> &[Node] {
+        match self {
+            Node::Doc(content)
+            | Node::Paragraph(content)
+            | Node::Heading { content, .. }
+            | Node::Blockquote(content)
+            | Node::BulletList(content)
+            | Node::OrderedList { items: content, .. }
+            | Node::ListItem(content)
+            | Node::TaskList(content)
+            | Node::TaskItem { content, .. }
+            | Node::Table(content)
+            | Node::TableRow(content)
+            | Node::TableCell { content, .. }
+            | Node::Unknown { content, .. } => content,
+            Node::Text { .. }
+            | Node::HardBreak
+            | Node::HorizontalRule
+            | Node::CodeBlock { .. }
+            | Node::Image { .. } => &[],
+        }
+    }
+
+    /// Returns the concatenated text of this node and its descendants.
+    pub fn text_content(&self) -> String {
+        match self {
+            Node::Text { text, .. } => text.clone(),
+            Node::CodeBlock { code, .. } => code.clone(),
+            Node::HardBreak => "\n".to_string(),
+            _ => self.children().iter().map(Node::text_content).collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawNode {
+    #[serde(rename = "type")]
+    node_type: String,
+    #[serde(default)]
+    attrs: Value,
+    #[serde(default)]
+    content: Vec<Node>,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    marks: Vec<RawMark>,
+}
+
+#[derive(Deserialize)]
+struct RawMark {
+    #[serde(rename = "type")]
+    mark_type: String,
+    #[serde(default)]
+    attrs: Value,
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawNode::deserialize(deserializer)?;
+        let attr_str = |key: &str| {
+            raw.attrs
+                .get(key)
+                .and_then(Value::as_str)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+        };
+        let attr_u64 = |key: &str| raw.attrs.get(key).and_then(Value::as_u64);
+
+        let node = match raw.node_type.as_str() {
+            "doc" => Node::Doc(raw.content),
+            "paragraph" => Node::Paragraph(raw.content),
+            "heading" => Node::Heading {
+                level: attr_u64("level").unwrap_or(1).clamp(1, 6) as u8,
+                content: raw.content,
+            },
+            "text" => Node::Text {
+                text: raw.text,
+                marks: raw.marks.into_iter().map(Mark::from).collect(),
+            },
+            "hardBreak" | "hard_break" => Node::HardBreak,
+            "horizontalRule" | "horizontal_rule" => Node::HorizontalRule,
+            "blockquote" => Node::Blockquote(raw.content),
+            "codeBlock" | "code_block" => Node::CodeBlock {
+                language: attr_str("language"),
+                code: raw.content.iter().map(Node::text_content).collect(),
+            },
+            "bulletList" | "bullet_list" => Node::BulletList(raw.content),
+            "orderedList" | "ordered_list" => Node::OrderedList {
+                start: attr_u64("start").or_else(|| attr_u64("order")).unwrap_or(1) as u32,
+                items: raw.content,
+            },
+            "listItem" | "list_item" => Node::ListItem(raw.content),
+            "taskList" | "checkList" => Node::TaskList(raw.content),
+            "taskItem" | "checkListItem" => Node::TaskItem {
+                checked: raw
+                    .attrs
+                    .get("checked")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+                content: raw.content,
+            },
+            "table" => Node::Table(raw.content),
+            "tableRow" | "table_row" => Node::TableRow(raw.content),
+            "tableHeader" | "table_header" => Node::TableCell {
+                header: true,
+                content: raw.content,
+            },
+            "tableCell" | "table_cell" => Node::TableCell {
+                header: false,
+                content: raw.content,
+            },
+            "image" | "attachment" => Node::Image {
+                src: attr_str("src").unwrap_or_default(),
+                alt: attr_str("alt").or_else(|| attr_str("name")),
+                title: attr_str("title"),
+                attachment_id: attr_u64("attachmentId")
+                    .or_else(|| attr_u64("id"))
+                    .map(|id| DocumentAttachmentId::new(id as u32)),
+            },
+            _ => Node::Unknown {
+                node_type: raw.node_type,
+                content: raw.content,
+            },
+        };
+        Ok(node)
+    }
+}
+
+impl From<RawMark> for Mark {
+    fn from(raw: RawMark) -> Self {
+        match raw.mark_type.as_str() {
+            "bold" | "strong" => Mark::Bold,
+            "italic" | "em" => Mark::Italic,
+            "strike" | "strikethrough" => Mark::Strike,
+            "underline" => Mark::Underline,
+            "code" => Mark::Code,
+            "link" => Mark::Link {
+                href: raw
+                    .attrs
+                    .get("href")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            },
+            _ => Mark::Unknown(raw.mark_type),
+        }
+    }
+}
+
+/// Settings shared by the Markdown and HTML renderers.
+///
+/// Images that refer to one of the document's attachments are rendered as links to
+/// that attachment's download URL,
+/// `{base_url}/api/v2/documents/{documentId}/attachments/{attachmentId}`.
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions<'a> {
+    document_id: Option<&'a DocumentId>,
+    attachments: &'a [DocumentAttachment],
+    base_url: String,
+}
+
+impl<'a> RenderOptions<'a> {
+    pub fn new(document_id: &'a DocumentId, attachments: &'a [DocumentAttachment]) -> Self {
+        Self {
+            document_id: Some(document_id),
+            attachments,
+            base_url: String::new(),
+        }
+    }
+
+    /// Prefixes attachment links with the space URL, e.g. `https://example.backlog.com`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Finds the attachment an image node refers to, by ID or by file name.
+    pub(crate) fn resolve_attachment(
+        &self,
+        src: &str,
+        alt: Option<&str>,
+        attachment_id: Option<&DocumentAttachmentId>,
+    ) -> Option<&'a DocumentAttachment> {
+        if let Some(id) = attachment_id
+            && let Some(attachment) = self.attachments.iter().find(|a| a.id == *id)
+        {
+            return Some(attachment);
+        }
+        let last_segment = src
+            .split(['?', '#'])
+            .next()
+            .and_then(|path| path.rsplit('/').next())
+            .unwrap_or_default();
+        let src_id = last_segment.parse::<u32>().ok();
+        let src_has_id = |a: &DocumentAttachment| {
+            src_id == Some(a.id.value())
+                && (src.contains("/attachments/") || src.contains("/attachment/"))
+        };
+        self.attachments.iter().find(|a| src_has_id(a)).or_else(|| {
+            self.attachments
+                .iter()
+                .find(|a| a.name == last_segment || Some(a.name.as_str()) == alt)
+        })
+    }
+
+    pub(crate) fn attachment_url(&self, attachment: &DocumentAttachment) -> String {
+        match self.document_id {
+            Some(document_id) => format!(
+                "{}/api/v2/documents/{}/attachments/{}",
+                self.base_url,
+                document_id,
+                attachment.id.value()
+            ),
+            None => format!("{}/attachments/{}", self.base_url, attachment.id.value()),
+        }
+    }
+}
+
+/// Returns true if a file name looks like an image that can be shown inline.
+pub(crate) fn is_image_name(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    [".png", ".jpg", ".jpeg", ".gif", ".webp", ".svg", ".bmp"]
+        .iter()
+        .any(|ext| lower.ends_with(ext))
+}
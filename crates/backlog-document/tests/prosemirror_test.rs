@@ -0,0 +1,248 @@
+use backlog_document::DocumentDetail;
+use backlog_document::prosemirror::{Mark, Node, RenderOptions, to_html, to_markdown};
+use serde_json::{Value, json};
+
+fn text(value: &str) -> Value {
+    json!({"type": "text", "text": value})
+}
+
+fn paragraph(content: Vec<Value>) -> Value {
+    json!({"type": "paragraph", "content": content})
+}
+
+fn user() -> Value {
+    json!({
+        "id": 1,
+        "userId": "admin",
+        "name": "Admin",
+        "roleType": 1,
+        "lang": "ja",
+        "mailAddress": "admin@example.com"
+    })
+}
+
+fn document(body: Value) -> DocumentDetail {
+    serde_json::from_value(json!({
+        "id": "0123456789abcdef0123456789abcdef",
+        "projectId": 1,
+        "title": "Spec",
+        "json": body,
+        "plain": "fallback text",
+        "statusId": 1,
+        "attachments": [
+            {"id": 22, "name": "diagram.png", "size": 2048, "createdUser": user(), "created": "2024-01-01T00:00:00Z"},
+            {"id": 23, "name": "report.pdf", "size": 4096, "createdUser": user(), "created": "2024-01-01T00:00:00Z"}
+        ],
+        "createdUser": user(),
+        "created": "2024-01-01T00:00:00Z",
+        "updatedUser": user(),
+        "updated": "2024-01-02T00:00:00Z"
+    }))
+    .unwrap()
+}
+
+fn render_markdown(value: Value) -> String {
+    to_markdown(
+        &Node::from_value(&value).unwrap(),
+        &RenderOptions::default(),
+    )
+}
+
+#[test]
+fn test_parse_typed_nodes() {
+    let node = Node::from_value(&json!({
+        "type": "doc",
+        "content": [
+            {"type": "heading", "attrs": {"level": 2}, "content": [text("Title")]},
+            {"type": "codeBlock", "attrs": {"language": "rust"}, "content": [text("fn main() {}")]},
+            paragraph(vec![json!({
+                "type": "text",
+                "text": "site",
+                "marks": [{"type": "bold"}, {"type": "link", "attrs": {"href": "https://example.com"}}]
+            })])
+        ]
+    }))
+    .unwrap();
+
+    let Node::Doc(children) = node else {
+        panic!("Expected doc node");
+    };
+    assert_eq!(
+        children[0],
+        Node::Heading {
+            level: 2,
+            content: vec![Node::Text {
+                text: "Title".to_string(),
+                marks: vec![]
+            }]
+        }
+    );
+    assert_eq!(
+        children[1],
+        Node::CodeBlock {
+            language: Some("rust".to_string()),
+            code: "fn main() {}".to_string()
+        }
+    );
+    assert_eq!(
+        children[2].children()[0],
+        Node::Text {
+            text: "site".to_string(),
+            marks: vec![
+                Mark::Bold,
+                Mark::Link {
+                    href: "https://example.com".to_string()
+                }
+            ]
+        }
+    );
+}
+
+#[test]
+fn test_markdown_blocks() {
+    let markdown = render_markdown(json!({
+        "type": "doc",
+        "content": [
+            {"type": "heading", "attrs": {"level": 1}, "content": [text("Overview")]},
+            paragraph(vec![
+                text("Plain, "),
+                json!({"type": "text", "text": "bold ", "marks": [{"type": "bold"}]}),
+                json!({"type": "text", "text": "code", "marks": [{"type": "code"}]}),
+                json!({"type": "hardBreak"}),
+                text("next line")
+            ]),
+            {"type": "codeBlock", "attrs": {"language": "sql"}, "content": [text("SELECT 1;")]},
+            {"type": "blockquote", "content": [paragraph(vec![text("quoted")])]},
+            {"type": "horizontalRule"}
+        ]
+    }));
+
+    assert_eq!(
+        markdown,
+        "# Overview\n\n\
+         Plain, **bold** `code`\\\nnext line\n\n\
+         ```sql\nSELECT 1;\n```\n\n\
+         > quoted\n\n\
+         ---"
+    );
+}
+
+#[test]
+fn test_markdown_nested_and_task_lists() {
+    let markdown = render_markdown(json!({
+        "type": "doc",
+        "content": [
+            {"type": "bulletList", "content": [
+                {"type": "listItem", "content": [
+                    paragraph(vec![text("parent")]),
+                    {"type": "orderedList", "attrs": {"start": 3}, "content": [
+                        {"type": "listItem", "content": [paragraph(vec![text("third")])]},
+                        {"type": "listItem", "content": [paragraph(vec![text("fourth")])]}
+                    ]}
+                ]}
+            ]},
+            {"type": "taskList", "content": [
+                {"type": "taskItem", "attrs": {"checked": true}, "content": [paragraph(vec![text("done")])]},
+                {"type": "taskItem", "attrs": {"checked": false}, "content": [paragraph(vec![text("todo")])]}
+            ]}
+        ]
+    }));
+
+    assert_eq!(
+        markdown,
+        "- parent\n  3. third\n  4. fourth\n\n- [x] done\n- [ ] todo"
+    );
+}
+
+#[test]
+fn test_markdown_table() {
+    let cell =
+        |kind: &str, value: &str| json!({"type": kind, "content": [paragraph(vec![text(value)])]});
+    let markdown = render_markdown(json!({
+        "type": "table",
+        "content": [
+            {"type": "tableRow", "content": [cell("tableHeader", "Key"), cell("tableHeader", "Value")]},
+            {"type": "tableRow", "content": [cell("tableCell", "a|b"), cell("tableCell", "1")]}
+        ]
+    }));
+
+    assert_eq!(markdown, "| Key | Value |\n| --- | --- |\n| a\\|b | 1 |");
+}
+
+#[test]
+fn test_unknown_nodes_keep_their_text() {
+    let markdown = render_markdown(json!({
+        "type": "doc",
+        "content": [{"type": "callout", "content": [paragraph(vec![text("Heads up")])]}]
+    }));
+    assert_eq!(markdown, "Heads up");
+}
+
+#[test]
+fn test_images_link_to_document_attachments() {
+    let detail = document(json!({
+        "type": "doc",
+        "content": [paragraph(vec![
+            json!({"type": "image", "attrs": {"src": "/document/attachments/22", "alt": "diagram"}}),
+            text(" and "),
+            json!({"type": "attachment", "attrs": {"attachmentId": 23}}),
+            text(" and "),
+            json!({"type": "image", "attrs": {"src": "https://example.com/logo.png", "alt": "logo"}})
+        ])]
+    }));
+
+    let base = "/api/v2/documents/0123456789abcdef0123456789abcdef/attachments";
+    assert_eq!(
+        detail.to_markdown(),
+        format!(
+            "![diagram.png]({base}/22) and [report.pdf]({base}/23) and ![logo](https://example.com/logo.png)"
+        )
+    );
+
+    let html = detail.to_html();
+    assert!(html.contains(&format!(
+        "<a href=\"{base}/22\"><img src=\"{base}/22\" alt=\"diagram.png\"></a>"
+    )));
+    assert!(html.contains(&format!("<a href=\"{base}/23\">report.pdf</a>")));
+
+    let options = detail
+        .render_options()
+        .base_url("https://example.backlog.com/");
+    let markdown = to_markdown(&detail.content().unwrap(), &options);
+    assert!(markdown.contains(&format!("(https://example.backlog.com{base}/23)")));
+}
+
+#[test]
+fn test_html_rendering_escapes_text() {
+    let node = Node::from_value(&json!({
+        "type": "doc",
+        "content": [
+            {"type": "heading", "attrs": {"level": 3}, "content": [text("a < b")]},
+            {"type": "codeBlock", "attrs": {"language": "html"}, "content": [text("<p>&</p>")]},
+            {"type": "taskList", "content": [
+                {"type": "taskItem", "attrs": {"checked": true}, "content": [paragraph(vec![text("done")])]}
+            ]},
+            {"type": "orderedList", "attrs": {"start": 2}, "content": [
+                {"type": "listItem", "content": [paragraph(vec![
+                    json!({"type": "text", "text": "x", "marks": [{"type": "italic"}, {"type": "strike"}]})
+                ])]}
+            ]}
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(
+        to_html(&node, &RenderOptions::default()),
+        "<h3>a &lt; b</h3>\
+         <pre><code class=\"language-html\">&lt;p&gt;&amp;&lt;/p&gt;</code></pre>\
+         <ul class=\"task-list\"><li class=\"task-list-item\"><input type=\"checkbox\" disabled checked><p>done</p></li></ul>\
+         <ol start=\"2\"><li><p><em><s>x</s></em></p></li></ol>"
+    );
+}
+
+#[test]
+fn test_invalid_json_falls_back_to_plain_text() {
+    let detail = document(json!({"content": "not a node"}));
+    assert_eq!(detail.to_markdown(), "fallback text");
+    assert_eq!(detail.to_html(), "<p>fallback text</p>");
+}
@@ -1,6 +1,6 @@
 use crate::models::SharedFile;
 use backlog_api_core::IntoRequest;
-use backlog_core::ProjectIdOrKey;
+use backlog_core::{Order, ProjectIdOrKey};
 use derive_builder::Builder;
 use serde::Serialize;
 
@@ -27,7 +27,7 @@ pub struct GetSharedFilesListParams {
     /// Sort order for the files ("asc" or "desc")
     #[builder(default, setter(into))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<Order>,
 
     /// Offset for pagination
     #[builder(default)]
@@ -2,6 +2,7 @@ mod common;
 use common::*;
 
 use backlog_api_core::Error as ApiError;
+use backlog_core::Order;
 use backlog_file::{GetFileParams, GetSharedFilesListParams, SharedFile};
 use std::str::FromStr;
 
@@ -52,7 +53,7 @@ async fn test_get_shared_files_list_success() {
     let params = GetSharedFilesListParams {
         project_id_or_key: project_id.into(),
         path: dir_path.to_string(),
-        order: Some("desc".to_string()),
+        order: Some(Order::Desc),
         offset: Some(0),
         count: Some(20),
     };
@@ -163,7 +164,7 @@ async fn test_get_shared_files_list_with_custom_params() {
     let params = GetSharedFilesListParams {
         project_id_or_key: project_id.into(),
         path: dir_path.to_string(),
-        order: Some("asc".to_string()),
+        order: Some(Order::Asc),
         offset: Some(10),
         count: Some(50),
     };
@@ -1,6 +1,6 @@
-use crate::models::{PrCommentOrder, PullRequestComment};
+use crate::models::PullRequestComment;
 use backlog_api_core::IntoRequest;
-use backlog_core::{ProjectIdOrKey, RepositoryIdOrName, identifier::PullRequestNumber};
+use backlog_core::{Order, ProjectIdOrKey, RepositoryIdOrName, identifier::PullRequestNumber};
 use serde::Serialize;
 
 pub type GetPullRequestCommentListResponse = Vec<PullRequestComment>;
@@ -21,7 +21,7 @@ pub struct GetPullRequestCommentListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<PrCommentOrder>,
+    pub order: Option<Order>,
 }
 
 impl GetPullRequestCommentListParams {
@@ -56,7 +56,7 @@ impl GetPullRequestCommentListParams {
         self
     }
 
-    pub fn order(mut self, order: PrCommentOrder) -> Self {
+    pub fn order(mut self, order: Order) -> Self {
         self.order = Some(order);
         self
     }
@@ -1,6 +1,5 @@
 //! Data models for Backlog Git and Pull Request entities.
 
-use backlog_core::Error as CoreError;
 use backlog_core::{
     User,
     identifier::{
@@ -10,7 +9,6 @@ use backlog_core::{
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize}; // Assuming User is defined in backlog-core and public, and implements Serialize, JsonSchema
-use std::{fmt, str::FromStr};
 
 #[cfg(feature = "schemars")]
 use schemars::JsonSchema;
@@ -175,35 +173,9 @@ pub struct PullRequestComment {
     pub notifications: Vec<Notification>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum PrCommentOrder {
-    Asc,
-    Desc,
-}
-
-impl fmt::Display for PrCommentOrder {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            PrCommentOrder::Asc => write!(f, "asc"),
-            PrCommentOrder::Desc => write!(f, "desc"),
-        }
-    }
-}
-
-impl FromStr for PrCommentOrder {
-    type Err = CoreError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "asc" => Ok(PrCommentOrder::Asc),
-            "desc" => Ok(PrCommentOrder::Desc),
-            _ => Err(CoreError::InvalidParameter(format!(
-                "Invalid pull request comment order: '{s}'. Must be 'asc' or 'desc'."
-            ))),
-        }
-    }
-}
+/// Sort order for pull request comments.
+#[deprecated(note = "use `backlog_core::Order`")]
+pub type PrCommentOrder = backlog_core::Order;
 
 /// Represents the count of comments on a pull request.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -0,0 +1,352 @@
+//! Flow metrics computed from the status transitions in an issue's history.
+//!
+//! For each issue, [`IssueFlow`] measures the time spent in every status, the lead
+//! time from creation to closing and the cycle time from first starting work to
+//! closing. [`FlowReport`] aggregates these over a set of issues and adds weekly
+//! throughput. Use [`IssueApi::get_flow_report`](crate::IssueApi::get_flow_report)
+//! to build a report for an issue search in one call.
+//!
+//! Change logs record statuses by name, so the statuses that mark work as started
+//! and closed are configured by name in [`FlowOptions`]. Durations are in days.
+
+use crate::history::IssueHistory;
+use crate::models::Issue;
+use backlog_core::IssueKey;
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// Issues a flow report analyzes by default, as each one costs a history request.
+pub const DEFAULT_MAX_ISSUES: usize = 500;
+
+/// Status names that mark the start and end of work, compared case-insensitively,
+/// and how many issues a report covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowOptions {
+    /// Entering one of these statuses for the first time starts the cycle time
+    pub started_statuses: Vec<String>,
+    /// An issue whose current status is one of these is closed
+    pub closed_statuses: Vec<String>,
+    /// Only the first this many matching issues are analyzed; `None` analyzes all
+    pub max_issues: Option<usize>,
+}
+
+impl Default for FlowOptions {
+    /// The built-in Backlog statuses, in English and Japanese, and
+    /// [`DEFAULT_MAX_ISSUES`].
+    fn default() -> Self {
+        FlowOptions {
+            started_statuses: vec!["In Progress".to_string(), "処理中".to_string()],
+            closed_statuses: vec!["Closed".to_string(), "完了".to_string()],
+            max_issues: Some(DEFAULT_MAX_ISSUES),
+        }
+    }
+}
+
+impl FlowOptions {
+    fn is_started(&self, status: &str) -> bool {
+        contains_name(&self.started_statuses, status)
+    }
+
+    fn is_closed(&self, status: &str) -> bool {
+        contains_name(&self.closed_statuses, status)
+    }
+}
+
+fn contains_name(names: &[String], name: &str) -> bool {
+    names.iter().any(|n| n.eq_ignore_ascii_case(name))
+}
+
+/// Time spent in one status.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusTime {
+    pub status: String,
+    pub days: f64,
+}
+
+/// Flow metrics of a single issue.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueFlow {
+    pub issue_key: IssueKey,
+    pub summary: String,
+    /// The current status
+    pub status: String,
+    pub created: DateTime<Utc>,
+    /// When the issue first entered a started status
+    pub started: Option<DateTime<Utc>>,
+    /// When the issue last entered a closed status, if it is closed now
+    pub closed: Option<DateTime<Utc>>,
+    /// Days from creation to closing
+    pub lead_time_days: Option<f64>,
+    /// Days from first starting to closing
+    pub cycle_time_days: Option<f64>,
+    /// Days spent in each status, in order of first visit. Time in the final closed
+    /// status is not counted; an open issue's current status counts up to `now`.
+    pub time_in_status: Vec<StatusTime>,
+}
+
+impl IssueFlow {
+    /// Computes the metrics of `issue` from its history, measuring open issues up
+    /// to `now`. Returns `None` if the creation time of the issue cannot be parsed.
+    pub fn new(
+        issue: &Issue,
+        history: &IssueHistory,
+        options: &FlowOptions,
+        now: DateTime<Utc>,
+    ) -> Option<Self> {
+        let created = issue.created.parse::<DateTime<Utc>>().ok()?;
+        let transitions: Vec<_> = history.field("status").collect();
+
+        let mut time_in_status: Vec<StatusTime> = Vec::new();
+        let mut add = |status: &str, from: DateTime<Utc>, to: DateTime<Utc>| {
+            let days = days_between(from, to);
+            match time_in_status.iter_mut().find(|t| t.status == status) {
+                Some(entry) => entry.days += days,
+                None => time_in_status.push(StatusTime {
+                    status: status.to_string(),
+                    days,
+                }),
+            }
+        };
+
+        let mut status = transitions
+            .first()
+            .and_then(|t| t.from.as_deref())
+            .unwrap_or(&issue.status.name);
+        let mut since = created;
+        let mut started = None;
+        let mut closed_at = created;
+        for transition in &transitions {
+            add(status, since, transition.at);
+            status = transition.to.as_deref().unwrap_or_default();
+            since = transition.at;
+            if options.is_started(status) {
+                started.get_or_insert(transition.at);
+            }
+            if options.is_closed(status) {
+                closed_at = transition.at;
+            }
+        }
+
+        let closed = options.is_closed(&issue.status.name).then_some(closed_at);
+        if closed.is_none() {
+            add(status, since, now);
+        }
+
+        Some(IssueFlow {
+            issue_key: issue.issue_key.clone(),
+            summary: issue.summary.clone(),
+            status: issue.status.name.clone(),
+            created,
+            started,
+            closed,
+            lead_time_days: closed.map(|closed| days_between(created, closed)),
+            cycle_time_days: started
+                .zip(closed)
+                .map(|(started, closed)| days_between(started, closed)),
+            time_in_status,
+        })
+    }
+}
+
+fn days_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    (to - from).num_seconds().max(0) as f64 / 86_400.0
+}
+
+/// Distribution of a duration over several issues.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DurationStats {
+    pub count: usize,
+    pub mean_days: f64,
+    pub median_days: f64,
+    /// 85% of the issues took this long or less
+    pub p85_days: f64,
+    pub max_days: f64,
+}
+
+impl DurationStats {
+    /// Summarizes `days`, or returns `None` if there are none.
+    pub fn new(days: impl IntoIterator<Item = f64>) -> Option<Self> {
+        let mut days: Vec<f64> = days.into_iter().collect();
+        if days.is_empty() {
+            return None;
+        }
+        days.sort_by(f64::total_cmp);
+        let percentile = |p: f64| days[((days.len() - 1) as f64 * p).round() as usize];
+        Some(DurationStats {
+            count: days.len(),
+            mean_days: days.iter().sum::<f64>() / days.len() as f64,
+            median_days: percentile(0.5),
+            p85_days: percentile(0.85),
+            max_days: days[days.len() - 1],
+        })
+    }
+}
+
+/// Number of issues closed in the week starting on Monday `week`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklyThroughput {
+    pub week: NaiveDate,
+    pub closed: usize,
+}
+
+/// Flow metrics of a set of issues.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowReport {
+    pub total_issues: usize,
+    /// True if more issues matched than [`FlowOptions::max_issues`], so only the first
+    /// ones are covered
+    pub truncated: bool,
+    pub closed_issues: usize,
+    pub lead_time: Option<DurationStats>,
+    pub cycle_time: Option<DurationStats>,
+    /// Distribution of the days issues spent in each status, over the issues that
+    /// visited it
+    pub time_in_status: Vec<StatusStats>,
+    /// Closed issues per week, including weeks without any, from the first to the
+    /// last week an issue was closed
+    pub throughput: Vec<WeeklyThroughput>,
+    pub issues: Vec<IssueFlow>,
+}
+
+/// Time spent in one status over several issues.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusStats {
+    pub status: String,
+    #[serde(flatten)]
+    pub stats: DurationStats,
+}
+
+impl FlowReport {
+    pub fn new(issue_flows: Vec<IssueFlow>) -> Self {
+        let closed: Vec<&IssueFlow> = issue_flows.iter().filter(|f| f.closed.is_some()).collect();
+
+        let statuses = status_names(&issue_flows);
+        let time_in_status = statuses
+            .into_iter()
+            .filter_map(|status| {
+                let days = issue_flows.iter().flat_map(|flow| {
+                    flow.time_in_status
+                        .iter()
+                        .filter(|t| t.status == status)
+                        .map(|t| t.days)
+                });
+                DurationStats::new(days).map(|stats| StatusStats {
+                    status: status.to_string(),
+                    stats,
+                })
+            })
+            .collect();
+
+        FlowReport {
+            total_issues: issue_flows.len(),
+            truncated: false,
+            closed_issues: closed.len(),
+            lead_time: DurationStats::new(closed.iter().filter_map(|f| f.lead_time_days)),
+            cycle_time: DurationStats::new(closed.iter().filter_map(|f| f.cycle_time_days)),
+            time_in_status,
+            throughput: weekly_throughput(closed.iter().filter_map(|f| f.closed)),
+            issues: issue_flows,
+        }
+    }
+
+    /// Formats one row per issue, with a `days in <status>` column per status.
+    pub fn issues_csv(&self) -> String {
+        let statuses = status_names(&self.issues);
+        let mut csv = String::from(
+            "issue_key,summary,status,created,started,closed,lead_time_days,cycle_time_days",
+        );
+        for status in &statuses {
+            csv.push(',');
+            csv.push_str(&escape_csv(&format!("days in {status}")));
+        }
+        csv.push('\n');
+
+        for flow in &self.issues {
+            let _ = write!(
+                csv,
+                "{},{},{},{},{},{},{},{}",
+                flow.issue_key,
+                escape_csv(&flow.summary),
+                escape_csv(&flow.status),
+                flow.created.to_rfc3339(),
+                flow.started.map(|t| t.to_rfc3339()).unwrap_or_default(),
+                flow.closed.map(|t| t.to_rfc3339()).unwrap_or_default(),
+                format_days(flow.lead_time_days),
+                format_days(flow.cycle_time_days),
+            );
+            for status in &statuses {
+                let days = flow
+                    .time_in_status
+                    .iter()
+                    .find(|t| t.status == *status)
+                    .map(|t| t.days);
+                csv.push(',');
+                csv.push_str(&format_days(days));
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Formats the weekly throughput as `week,closed` rows.
+    pub fn throughput_csv(&self) -> String {
+        let mut csv = String::from("week,closed\n");
+        for week in &self.throughput {
+            let _ = writeln!(csv, "{},{}", week.week, week.closed);
+        }
+        csv
+    }
+}
+
+/// All statuses visited by the issues, in order of first appearance.
+fn status_names(flows: &[IssueFlow]) -> Vec<&str> {
+    let mut names: Vec<&str> = Vec::new();
+    for time in flows.iter().flat_map(|flow| &flow.time_in_status) {
+        if !names.contains(&time.status.as_str()) {
+            names.push(&time.status);
+        }
+    }
+    names
+}
+
+fn weekly_throughput(closed: impl Iterator<Item = DateTime<Utc>>) -> Vec<WeeklyThroughput> {
+    let mut weeks: Vec<NaiveDate> = closed.map(|at| week_start(at.date_naive())).collect();
+    weeks.sort_unstable();
+    let (Some(&first), Some(&last)) = (weeks.first(), weeks.last()) else {
+        return Vec::new();
+    };
+
+    let mut throughput = Vec::new();
+    let mut week = first;
+    while week <= last {
+        throughput.push(WeeklyThroughput {
+            week,
+            closed: weeks.iter().filter(|w| **w == week).count(),
+        });
+        week = week + Days::new(7);
+    }
+    throughput
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Days::new(u64::from(date.weekday().num_days_from_monday()))
+}
+
+fn format_days(days: Option<f64>) -> String {
+    days.map(|days| format!("{days:.2}")).unwrap_or_default()
+}
+
+fn escape_csv(s: &str) -> String {
+    if s.contains(',') || s.contains('"') || s.contains('\n') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
@@ -1,9 +1,9 @@
 #[cfg(feature = "writable")]
 use crate::models::Comment;
 #[cfg(feature = "writable")]
-use backlog_api_core::{Error as ApiError, HttpMethod, IntoRequest};
+use backlog_api_core::Error as ApiError;
 #[cfg(feature = "writable")]
-use backlog_api_macros::ToFormParams;
+use backlog_api_macros::{BacklogRequest, ToFormParams};
 #[cfg(feature = "writable")]
 use backlog_core::{
     IssueIdOrKey,
@@ -11,16 +11,15 @@ use backlog_core::{
 };
 #[cfg(feature = "writable")]
 use derive_builder::Builder;
-#[cfg(feature = "writable")]
-use serde::Serialize;
 
 /// Response type for adding a comment
 #[cfg(feature = "writable")]
 pub type AddCommentResponse = Comment;
 
 #[cfg(feature = "writable")]
-#[derive(Debug, Clone, Builder, ToFormParams)]
+#[derive(Debug, Clone, Builder, ToFormParams, BacklogRequest)]
 #[builder(build_fn(error = "ApiError"))]
+#[request(method = "POST", path = "/api/v2/issues/{issue_id_or_key}/comments")]
 pub struct AddCommentParams {
     #[builder(setter(into))]
     #[form(skip)]
@@ -34,19 +33,3 @@ pub struct AddCommentParams {
     #[form(array, name = "attachmentId")]
     pub attachment_id: Option<Vec<AttachmentId>>,
 }
-
-#[cfg(feature = "writable")]
-impl IntoRequest for AddCommentParams {
-    fn method(&self) -> HttpMethod {
-        HttpMethod::Post
-    }
-
-    fn path(&self) -> String {
-        format!("/api/v2/issues/{}/comments", self.issue_id_or_key)
-    }
-
-    fn to_form(&self) -> impl Serialize {
-        let params: Vec<(String, String)> = self.into();
-        params
-    }
-}
@@ -1,16 +1,12 @@
 #[cfg(feature = "writable")]
 use crate::models::Comment;
 #[cfg(feature = "writable")]
-use backlog_api_core::{HttpMethod, IntoRequest};
-#[cfg(feature = "writable")]
-use backlog_api_macros::ToFormParams;
+use backlog_api_macros::{BacklogRequest, ToFormParams};
 #[cfg(feature = "writable")]
 use backlog_core::{
     IssueIdOrKey,
     identifier::{CommentId, UserId},
 };
-#[cfg(feature = "writable")]
-use serde::Serialize;
 
 /// Response type for adding comment notification
 #[cfg(feature = "writable")]
@@ -18,7 +14,11 @@ pub type AddCommentNotificationResponse = Comment;
 
 /// Parameters for adding notifications to a comment
 #[cfg(feature = "writable")]
-#[derive(Debug, Clone, ToFormParams)]
+#[derive(Debug, Clone, ToFormParams, BacklogRequest)]
+#[request(
+    method = "POST",
+    path = "/api/v2/issues/{issue_id_or_key}/comments/{comment_id}/notifications"
+)]
 pub struct AddCommentNotificationParams {
     #[form(skip)]
     pub issue_id_or_key: IssueIdOrKey,
@@ -43,29 +43,11 @@ impl AddCommentNotificationParams {
     }
 }
 
-#[cfg(feature = "writable")]
-impl IntoRequest for AddCommentNotificationParams {
-    fn method(&self) -> HttpMethod {
-        HttpMethod::Post
-    }
-
-    fn path(&self) -> String {
-        format!(
-            "/api/v2/issues/{}/comments/{}/notifications",
-            self.issue_id_or_key, self.comment_id
-        )
-    }
-
-    fn to_form(&self) -> impl Serialize {
-        let params: Vec<(String, String)> = self.into();
-        params
-    }
-}
-
 #[cfg(all(test, feature = "writable"))]
 mod tests {
     use super::*;
     use crate::api::IssueApi;
+    use backlog_api_core::IntoRequest;
     use backlog_core::{IssueKey, identifier::Identifier};
     use client::test_utils::setup_client;
     use wiremock::matchers::{body_string_contains, method, path};
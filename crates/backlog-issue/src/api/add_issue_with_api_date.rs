@@ -1,7 +1,7 @@
 #[cfg(feature = "writable")]
 use crate::models::Issue;
 #[cfg(feature = "writable")]
-use backlog_api_core::{Error as ApiError, HttpMethod, IntoRequest};
+use backlog_api_core::Error as ApiError;
 #[cfg(feature = "writable")]
 use backlog_core::ApiDate;
 #[cfg(feature = "writable")]
@@ -10,11 +10,9 @@ use backlog_core::identifier::{
 };
 #[cfg(feature = "writable")]
 use derive_builder::Builder;
-#[cfg(feature = "writable")]
-use serde::Serialize;
 
 #[cfg(feature = "writable")]
-use backlog_api_macros::ToFormParams;
+use backlog_api_macros::{BacklogRequest, ToFormParams};
 
 #[cfg(feature = "writable")]
 pub type AddIssueWithApiDateResponse = Issue;
@@ -24,8 +22,9 @@ pub type AddIssueWithApiDateResponse = Issue;
 /// This demonstrates how ApiDate can eliminate the need for special handling
 /// of DateTime<Utc> fields in API parameters.
 #[cfg(feature = "writable")]
-#[derive(Debug, Builder, ToFormParams)]
+#[derive(Debug, Builder, ToFormParams, BacklogRequest)]
 #[builder(build_fn(error = "ApiError"))]
+#[request(method = "POST", path = "/api/v2/issues")]
 pub struct AddIssueWithApiDateParams {
     #[builder(setter(into))]
     #[form(name = "projectId")]
@@ -78,22 +77,6 @@ pub struct AddIssueWithApiDateParams {
     pub attachment_ids: Option<Vec<AttachmentId>>,
 }
 
-#[cfg(feature = "writable")]
-impl IntoRequest for AddIssueWithApiDateParams {
-    fn method(&self) -> HttpMethod {
-        HttpMethod::Post
-    }
-
-    fn path(&self) -> String {
-        "/api/v2/issues".to_string()
-    }
-
-    fn to_form(&self) -> impl Serialize {
-        let params: Vec<(String, String)> = self.into();
-        params
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
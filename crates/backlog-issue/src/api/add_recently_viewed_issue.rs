@@ -1,7 +1,6 @@
 use crate::models::Issue;
-use backlog_api_core::{HttpMethod, IntoRequest};
+use backlog_api_macros::{BacklogRequest, ToFormParams};
 use backlog_core::IssueIdOrKey;
-use serde::Serialize;
 
 pub type AddRecentlyViewedIssueResponse = Issue;
 
@@ -9,41 +8,18 @@ pub type AddRecentlyViewedIssueResponse = Issue;
 ///
 /// Corresponds to `POST /api/v2/users/myself/recentlyViewedIssues`.
 #[cfg(feature = "writable")]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, ToFormParams, BacklogRequest)]
+#[request(method = "POST", path = "/api/v2/users/myself/recentlyViewedIssues")]
 pub struct AddRecentlyViewedIssueParams {
     /// Issue ID or issue key
+    #[form(name = "issueIdOrKey")]
     pub issue_id_or_key: IssueIdOrKey,
 }
 
-#[cfg(feature = "writable")]
-impl From<&AddRecentlyViewedIssueParams> for Vec<(String, String)> {
-    fn from(params: &AddRecentlyViewedIssueParams) -> Self {
-        vec![(
-            "issueIdOrKey".to_string(),
-            params.issue_id_or_key.to_string(),
-        )]
-    }
-}
-
-#[cfg(feature = "writable")]
-impl IntoRequest for AddRecentlyViewedIssueParams {
-    fn method(&self) -> HttpMethod {
-        HttpMethod::Post
-    }
-
-    fn path(&self) -> String {
-        "/api/v2/users/myself/recentlyViewedIssues".to_string()
-    }
-
-    fn to_form(&self) -> impl Serialize {
-        let params: Vec<(String, String)> = self.into();
-        params
-    }
-}
-
 #[cfg(all(test, feature = "writable"))]
 mod tests {
     use super::*;
+    use backlog_api_core::{HttpMethod, IntoRequest};
     use backlog_core::{IssueKey, ProjectKey, id::IssueId};
     use std::str::FromStr;
 
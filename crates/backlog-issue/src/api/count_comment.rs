@@ -1,4 +1,4 @@
-use backlog_api_core::IntoRequest;
+use backlog_api_macros::BacklogRequest;
 use backlog_core::IssueIdOrKey;
 use serde::Deserialize;
 
@@ -10,7 +10,8 @@ pub struct CountCommentResponse {
 
 /// Parameters for counting comments for a specific issue.
 /// Corresponds to `GET /api/v2/issues/:issueIdOrKey/comments/count`.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, BacklogRequest)]
+#[request(path = "/api/v2/issues/{issue_id_or_key}/comments/count")]
 pub struct CountCommentParams {
     pub issue_id_or_key: IssueIdOrKey,
 }
@@ -22,9 +23,3 @@ impl CountCommentParams {
         }
     }
 }
-
-impl IntoRequest for CountCommentParams {
-    fn path(&self) -> String {
-        format!("/api/v2/issues/{}/comments/count", self.issue_id_or_key)
-    }
-}
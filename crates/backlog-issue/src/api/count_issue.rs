@@ -1,11 +1,36 @@
-// Re-export from get_issue_list since CountIssue uses the same parameters
-pub use super::get_issue_list::{
-    GetIssueListParams as CountIssueParams, GetIssueListParamsBuilder as CountIssueParamsBuilder,
-};
-use serde::Deserialize;
+use super::get_issue_list::GetIssueListParams;
+// Counting takes the same filters as listing, so the list builder is reused.
+pub use super::get_issue_list::GetIssueListParamsBuilder as CountIssueParamsBuilder;
+use backlog_api_core::IntoRequest;
+use serde::{Deserialize, Serialize};
 
 /// Response type for counting issues
 #[derive(Debug, Deserialize)]
 pub struct CountIssueResponse {
     pub count: u32,
 }
+
+/// Parameters for counting issues, which takes the same filters as listing them.
+/// `sort`, `order`, `offset` and `count` are ignored by the API.
+///
+/// Build it with [`CountIssueParamsBuilder`] or convert existing [`GetIssueListParams`].
+///
+/// Corresponds to `GET /api/v2/issues/count`.
+#[derive(Debug, Clone)]
+pub struct CountIssueParams(pub GetIssueListParams);
+
+impl From<GetIssueListParams> for CountIssueParams {
+    fn from(params: GetIssueListParams) -> Self {
+        Self(params)
+    }
+}
+
+impl IntoRequest for CountIssueParams {
+    fn path(&self) -> String {
+        "/api/v2/issues/count".to_string()
+    }
+
+    fn to_query(&self) -> impl Serialize {
+        <Vec<(String, String)>>::from(&self.0)
+    }
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "writable")]
-use backlog_api_core::{HttpMethod, IntoRequest};
+use backlog_api_macros::BacklogRequest;
 #[cfg(feature = "writable")]
 use backlog_core::IssueIdOrKey;
 #[cfg(feature = "writable")]
@@ -14,7 +14,11 @@ pub type DeleteAttachmentResponse = Attachment;
 
 /// Parameters for deleting an attachment from an issue.
 #[cfg(feature = "writable")]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, BacklogRequest)]
+#[request(
+    method = "DELETE",
+    path = "/api/v2/issues/{issue_id_or_key}/attachments/{attachment_id}"
+)]
 pub struct DeleteAttachmentParams {
     pub issue_id_or_key: IssueIdOrKey,
     pub attachment_id: AttachmentId,
@@ -33,17 +37,3 @@ impl DeleteAttachmentParams {
         }
     }
 }
-
-#[cfg(feature = "writable")]
-impl IntoRequest for DeleteAttachmentParams {
-    fn method(&self) -> HttpMethod {
-        HttpMethod::Delete
-    }
-
-    fn path(&self) -> String {
-        format!(
-            "/api/v2/issues/{}/attachments/{}",
-            self.issue_id_or_key, self.attachment_id
-        )
-    }
-}
@@ -1,5 +1,5 @@
 #[cfg(feature = "writable")]
-use backlog_api_core::{HttpMethod, IntoRequest};
+use backlog_api_macros::BacklogRequest;
 #[cfg(feature = "writable")]
 use backlog_core::IssueIdOrKey;
 #[cfg(feature = "writable")]
@@ -14,7 +14,11 @@ pub type DeleteCommentResponse = Comment;
 
 /// Parameters for deleting a comment from an issue.
 #[cfg(feature = "writable")]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, BacklogRequest)]
+#[request(
+    method = "DELETE",
+    path = "/api/v2/issues/{issue_id_or_key}/comments/{comment_id}"
+)]
 pub struct DeleteCommentParams {
     pub issue_id_or_key: IssueIdOrKey,
     pub comment_id: CommentId,
@@ -30,17 +34,3 @@ impl DeleteCommentParams {
         }
     }
 }
-
-#[cfg(feature = "writable")]
-impl IntoRequest for DeleteCommentParams {
-    fn method(&self) -> HttpMethod {
-        HttpMethod::Delete
-    }
-
-    fn path(&self) -> String {
-        format!(
-            "/api/v2/issues/{}/comments/{}",
-            self.issue_id_or_key, self.comment_id
-        )
-    }
-}
@@ -1,7 +1,7 @@
 #[cfg(feature = "writable")]
 use crate::models::Issue;
 #[cfg(feature = "writable")]
-use backlog_api_core::{HttpMethod, IntoRequest};
+use backlog_api_macros::BacklogRequest;
 #[cfg(feature = "writable")]
 use backlog_core::IssueKey;
 
@@ -12,7 +12,8 @@ pub type DeleteIssueResponse = Issue;
 /// Parameters for deleting a specific issue.
 /// Corresponds to `DELETE /api/v2/issues/:issueKey`.
 #[cfg(feature = "writable")]
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, BacklogRequest)]
+#[request(method = "DELETE", path = "/api/v2/issues/{issue_key}")]
 pub struct DeleteIssueParams {
     pub issue_key: IssueKey,
 }
@@ -26,20 +27,10 @@ impl DeleteIssueParams {
     }
 }
 
-#[cfg(feature = "writable")]
-impl IntoRequest for DeleteIssueParams {
-    fn method(&self) -> HttpMethod {
-        HttpMethod::Delete
-    }
-
-    fn path(&self) -> String {
-        format!("/api/v2/issues/{}", self.issue_key)
-    }
-}
-
 #[cfg(all(test, feature = "writable"))]
 mod tests {
     use super::*;
+    use backlog_api_core::{HttpMethod, IntoRequest};
     use std::str::FromStr;
 
     #[test]
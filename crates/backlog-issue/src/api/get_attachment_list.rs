@@ -1,5 +1,5 @@
 use crate::models::Attachment;
-use backlog_api_core::IntoRequest;
+use backlog_api_macros::BacklogRequest;
 use backlog_core::IssueIdOrKey;
 
 /// Response type for getting a list of attachments
@@ -7,7 +7,8 @@ pub type GetAttachmentListResponse = Vec<Attachment>;
 
 /// Parameters for getting attachment list for a specific issue.
 /// Corresponds to `GET /api/v2/issues/:issueIdOrKey/attachments`.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, BacklogRequest)]
+#[request(path = "/api/v2/issues/{issue_id_or_key}/attachments")]
 pub struct GetAttachmentListParams {
     pub issue_id_or_key: IssueIdOrKey,
 }
@@ -19,9 +20,3 @@ impl GetAttachmentListParams {
         }
     }
 }
-
-impl IntoRequest for GetAttachmentListParams {
-    fn path(&self) -> String {
-        format!("/api/v2/issues/{}/attachments", self.issue_id_or_key)
-    }
-}
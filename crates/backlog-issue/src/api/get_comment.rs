@@ -1,5 +1,5 @@
 use crate::models::Comment;
-use backlog_api_core::IntoRequest;
+use backlog_api_macros::BacklogRequest;
 use backlog_core::{IssueIdOrKey, identifier::CommentId};
 
 /// Response type for getting a specific comment
@@ -7,7 +7,8 @@ pub type GetCommentResponse = Comment;
 
 /// Parameters for getting a specific comment.
 /// Corresponds to `GET /api/v2/issues/:issueIdOrKey/comments/:commentId`.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, BacklogRequest)]
+#[request(path = "/api/v2/issues/{issue_id_or_key}/comments/{comment_id}")]
 pub struct GetCommentParams {
     pub issue_id_or_key: IssueIdOrKey,
     pub comment_id: CommentId,
@@ -21,12 +22,3 @@ impl GetCommentParams {
         }
     }
 }
-
-impl IntoRequest for GetCommentParams {
-    fn path(&self) -> String {
-        format!(
-            "/api/v2/issues/{}/comments/{}",
-            self.issue_id_or_key, self.comment_id
-        )
-    }
-}
@@ -1,16 +1,13 @@
 use crate::models::NotificationForComment;
-use backlog_api_core::IntoRequest;
+use backlog_api_macros::BacklogRequest;
 use backlog_core::IssueIdOrKey;
 use backlog_core::identifier::CommentId;
-use serde::Serialize;
 
 /// Parameters for retrieving comment notifications
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, BacklogRequest)]
+#[request(path = "/api/v2/issues/{issue_id_or_key}/comments/{comment_id}/notifications")]
 pub struct GetCommentNotificationsParams {
-    #[serde(skip)]
     pub issue_id_or_key: IssueIdOrKey,
-    #[serde(skip)]
     pub comment_id: CommentId,
 }
 
@@ -26,23 +23,11 @@ impl GetCommentNotificationsParams {
     }
 }
 
-impl IntoRequest for GetCommentNotificationsParams {
-    fn path(&self) -> String {
-        format!(
-            "/api/v2/issues/{}/comments/{}/notifications",
-            self.issue_id_or_key, self.comment_id
-        )
-    }
-
-    fn to_query(&self) -> impl Serialize {
-        serde_json::json!({})
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::api::IssueApi;
+    use backlog_api_core::IntoRequest;
     use backlog_core::{IssueKey, identifier::Identifier};
     use client::test_utils::setup_client;
     use wiremock::matchers::{method, path};
@@ -1,5 +1,5 @@
 use crate::models::Issue;
-use backlog_api_core::IntoRequest;
+use backlog_api_macros::BacklogRequest;
 use backlog_core::IssueIdOrKey;
 
 /// Response type for getting a specific issue
@@ -7,7 +7,8 @@ pub type GetIssueResponse = Issue;
 
 /// Parameters for getting a specific issue.
 /// Corresponds to `GET /api/v2/issues/:issueIdOrKey`.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, BacklogRequest)]
+#[request(path = "/api/v2/issues/{issue_id_or_key}")]
 pub struct GetIssueParams {
     pub issue_id_or_key: IssueIdOrKey,
 }
@@ -20,15 +21,10 @@ impl GetIssueParams {
     }
 }
 
-impl IntoRequest for GetIssueParams {
-    fn path(&self) -> String {
-        format!("/api/v2/issues/{}", self.issue_id_or_key)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use backlog_api_core::IntoRequest;
     use backlog_core::IssueKey;
     use std::str::FromStr;
 
@@ -1,111 +1,100 @@
-use crate::models::{Issue, ParentChildCondition};
-use backlog_api_core::{Error as ApiError, IntoRequest};
-use backlog_api_macros::ToFormParams;
-use backlog_core::ApiDate;
+use crate::models::{Issue, IssueSort, ParentChildCondition};
+use backlog_api_core::Error as ApiError;
+use backlog_api_macros::{BacklogRequest, ToQueryParams};
 use backlog_core::identifier::{
     CategoryId, IssueId, IssueTypeId, MilestoneId, PriorityId, ProjectId, ResolutionId, StatusId,
     UserId,
 };
+use backlog_core::{ApiDate, Order};
 use derive_builder::Builder;
-use serde::Serialize;
 
 /// Response type for getting a list of issues
 pub type GetIssueListResponse = Vec<Issue>;
 
-#[derive(Debug, Clone, Builder, ToFormParams)]
+#[derive(Debug, Clone, Builder, ToQueryParams, BacklogRequest)]
 #[builder(build_fn(error = "ApiError"))]
+#[request(path = "/api/v2/issues")]
 pub struct GetIssueListParams {
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "projectId")]
+    #[query(array, name = "projectId")]
     pub project_id: Option<Vec<ProjectId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "issueTypeId")]
+    #[query(array, name = "issueTypeId")]
     pub issue_type_id: Option<Vec<IssueTypeId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "categoryId")]
+    #[query(array, name = "categoryId")]
     pub category_id: Option<Vec<CategoryId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "versionId")]
+    #[query(array, name = "versionId")]
     pub version_id: Option<Vec<MilestoneId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "milestoneId")]
+    #[query(array, name = "milestoneId")]
     pub milestone_id: Option<Vec<MilestoneId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "statusId")]
+    #[query(array, name = "statusId")]
     pub status_id: Option<Vec<StatusId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "priorityId")]
+    #[query(array, name = "priorityId")]
     pub priority_id: Option<Vec<PriorityId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "assigneeId")]
+    #[query(array, name = "assigneeId")]
     pub assignee_id: Option<Vec<UserId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "createdUserId")]
+    #[query(array, name = "createdUserId")]
     pub created_user_id: Option<Vec<UserId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "resolutionId")]
+    #[query(array, name = "resolutionId")]
     pub resolution_id: Option<Vec<ResolutionId>>,
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "parentChild")]
+    #[query(name = "parentChild")]
     pub parent_child_condition: Option<ParentChildCondition>,
     #[builder(default, setter(into, strip_option))]
     pub attachment: Option<bool>,
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "sharedFile")]
+    #[query(name = "sharedFile")]
     pub shared_file: Option<bool>,
     #[builder(default, setter(into, strip_option))]
-    pub sort: Option<String>, // (e.g., "issueType", "created")
+    pub sort: Option<IssueSort>,
     #[builder(default, setter(into, strip_option))]
-    pub order: Option<String>, // ("asc" or "desc")
+    pub order: Option<Order>,
     #[builder(default, setter(into, strip_option))]
     pub offset: Option<u32>,
     #[builder(default, setter(into, strip_option))]
     pub count: Option<u32>, // (1-100)
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "createdSince")]
+    #[query(name = "createdSince")]
     pub created_since: Option<ApiDate>,
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "createdUntil")]
+    #[query(name = "createdUntil")]
     pub created_until: Option<ApiDate>,
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "updatedSince")]
+    #[query(name = "updatedSince")]
     pub updated_since: Option<ApiDate>,
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "updatedUntil")]
+    #[query(name = "updatedUntil")]
     pub updated_until: Option<ApiDate>,
     #[builder(default, setter(into, strip_option))]
-    #[form(array, name = "parentIssueId")]
+    #[query(array, name = "parentIssueId")]
     pub parent_issue_id: Option<Vec<IssueId>>, // (Note: different from single parentIssueId in Add/Update)
     #[builder(default, setter(into, strip_option))]
     pub keyword: Option<String>, // (e.g., "bug", "feature")
     #[builder(default, setter(into, strip_option))]
-    #[form(array)]
+    #[query(array)]
     pub id: Option<Vec<IssueId>>, // for id[] parameter
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "startDateSince")]
+    #[query(name = "startDateSince")]
     pub start_date_since: Option<ApiDate>,
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "startDateUntil")]
+    #[query(name = "startDateUntil")]
     pub start_date_until: Option<ApiDate>,
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "dueDateSince")]
+    #[query(name = "dueDateSince")]
     pub due_date_since: Option<ApiDate>,
     #[builder(default, setter(into, strip_option))]
-    #[form(name = "dueDateUntil")]
+    #[query(name = "dueDateUntil")]
     pub due_date_until: Option<ApiDate>,
 }
 
-impl IntoRequest for GetIssueListParams {
-    fn path(&self) -> String {
-        "/api/v2/issues".to_string()
-    }
-
-    fn to_query(&self) -> impl Serialize {
-        let params: Vec<(String, String)> = self.into();
-        params
-    }
-}
-
 // Convert GetIssueListParams to vector of pairs because
 // RequestBuilder.query doesn't support serialization of vector type.
 // Support both owned and borrowed values
@@ -1,4 +1,4 @@
-use backlog_api_core::IntoRequest;
+use backlog_api_macros::BacklogRequest;
 use backlog_core::{IssueIdOrKey, User};
 
 /// Response type for getting a list of participants in an issue
@@ -6,7 +6,8 @@ pub type GetParticipantListResponse = Vec<User>;
 
 /// Parameters for getting participant list for a specific issue.
 /// Corresponds to `GET /api/v2/issues/:issueIdOrKey/participants`.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, BacklogRequest)]
+#[request(path = "/api/v2/issues/{issue_id_or_key}/participants")]
 pub struct GetParticipantListParams {
     pub issue_id_or_key: IssueIdOrKey,
 }
@@ -19,15 +20,10 @@ impl GetParticipantListParams {
     }
 }
 
-impl IntoRequest for GetParticipantListParams {
-    fn path(&self) -> String {
-        format!("/api/v2/issues/{}/participants", self.issue_id_or_key)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use backlog_api_core::IntoRequest;
     use backlog_core::identifier::{Identifier, IssueId};
     use backlog_core::{IssueKey, Role};
 
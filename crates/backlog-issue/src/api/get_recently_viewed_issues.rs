@@ -1,20 +1,21 @@
 use crate::models::Issue;
-use backlog_api_core::{Error as ApiError, IntoRequest};
-use backlog_api_macros::ToFormParams;
+use backlog_api_core::Error as ApiError;
+use backlog_api_macros::{BacklogRequest, ToQueryParams};
+use backlog_core::Order;
 use derive_builder::Builder;
-use serde::Serialize;
 
 pub type GetRecentlyViewedIssuesResponse = Vec<Issue>;
 
 /// Parameters for getting recently viewed issues
 ///
 /// Corresponds to `GET /api/v2/users/myself/recentlyViewedIssues`.
-#[derive(Debug, Clone, Builder, ToFormParams)]
+#[derive(Debug, Clone, Builder, ToQueryParams, BacklogRequest)]
 #[builder(build_fn(error = "ApiError"))]
+#[request(path = "/api/v2/users/myself/recentlyViewedIssues")]
 pub struct GetRecentlyViewedIssuesParams {
     /// Order of results ("asc" or "desc", default: "desc")
     #[builder(default, setter(into, strip_option))]
-    pub order: Option<String>,
+    pub order: Option<Order>,
 
     /// Offset for pagination
     #[builder(default, setter(into, strip_option))]
@@ -25,31 +26,21 @@ pub struct GetRecentlyViewedIssuesParams {
     pub count: Option<u32>,
 }
 
-impl IntoRequest for GetRecentlyViewedIssuesParams {
-    fn path(&self) -> String {
-        "/api/v2/users/myself/recentlyViewedIssues".to_string()
-    }
-
-    fn to_query(&self) -> impl Serialize {
-        let params: Vec<(String, String)> = self.into();
-        params
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use backlog_api_core::IntoRequest;
 
     #[test]
     fn test_params_builder() {
         let params = GetRecentlyViewedIssuesParamsBuilder::default()
-            .order("asc")
+            .order(Order::Asc)
             .offset(10u32)
             .count(50u32)
             .build()
             .unwrap();
 
-        assert_eq!(params.order, Some("asc".to_string()));
+        assert_eq!(params.order, Some(Order::Asc));
         assert_eq!(params.offset, Some(10));
         assert_eq!(params.count, Some(50));
     }
@@ -57,7 +48,7 @@ mod tests {
     #[test]
     fn test_params_to_query() {
         let params = GetRecentlyViewedIssuesParamsBuilder::default()
-            .order("desc")
+            .order(Order::Desc)
             .count(25u32)
             .build()
             .unwrap();
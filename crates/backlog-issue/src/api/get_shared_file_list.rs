@@ -1,5 +1,5 @@
 use crate::models::SharedFile;
-use backlog_api_core::IntoRequest;
+use backlog_api_macros::BacklogRequest;
 use backlog_core::IssueIdOrKey;
 
 /// Response type for getting a list of shared files linked to an issue
@@ -7,7 +7,8 @@ pub type GetSharedFileListResponse = Vec<SharedFile>;
 
 /// Parameters for getting shared file list for a specific issue.
 /// Corresponds to `GET /api/v2/issues/:issueIdOrKey/sharedFiles`.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, BacklogRequest)]
+#[request(path = "/api/v2/issues/{issue_id_or_key}/sharedFiles")]
 pub struct GetSharedFileListParams {
     pub issue_id_or_key: IssueIdOrKey,
 }
@@ -19,9 +20,3 @@ impl GetSharedFileListParams {
         }
     }
 }
-
-impl IntoRequest for GetSharedFileListParams {
-    fn path(&self) -> String {
-        format!("/api/v2/issues/{}/sharedFiles", self.issue_id_or_key)
-    }
-}
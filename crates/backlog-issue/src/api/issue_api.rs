@@ -1,11 +1,15 @@
+use crate::analytics::{FlowOptions, FlowReport, IssueFlow};
+use crate::history::IssueHistory;
+use crate::models::Issue;
 use backlog_api_core::Result;
+use backlog_core::{IssueIdOrKey, Order, identifier::Identifier};
 use client::{Client, DownloadedFile};
 
 #[cfg(feature = "writable")]
 use super::{
     AddCommentNotificationParams, AddCommentParams, AddIssueParams, AddRecentlyViewedIssueParams,
     DeleteAttachmentParams, DeleteCommentParams, DeleteIssueParams, LinkSharedFilesToIssueParams,
-    UnlinkSharedFileParams, UpdateCommentParams, UpdateIssueParams,
+    MinimalUpdateOutcome, UnlinkSharedFileParams, UpdateCommentParams, UpdateIssueParams,
 };
 #[cfg(feature = "writable")]
 use super::{
@@ -16,9 +20,9 @@ use super::{
 };
 use super::{
     CountCommentParams, CountIssueParams, GetAttachmentFileParams, GetAttachmentListParams,
-    GetCommentListParams, GetCommentNotificationsParams, GetCommentParams, GetIssueListParams,
-    GetIssueParams, GetParticipantListParams, GetRecentlyViewedIssuesParams,
-    GetSharedFileListParams,
+    GetCommentListParams, GetCommentListParamsBuilder, GetCommentNotificationsParams,
+    GetCommentParams, GetIssueListParams, GetIssueParams, GetParticipantListParams,
+    GetRecentlyViewedIssuesParams, GetSharedFileListParams,
 };
 use super::{
     CountCommentResponse, CountIssueResponse, GetAttachmentListResponse, GetCommentListResponse,
@@ -44,8 +48,11 @@ impl IssueApi {
     }
 
     /// Count issues based on the provided parameters.
-    pub async fn count_issue(&self, params: CountIssueParams) -> Result<CountIssueResponse> {
-        self.0.execute(params).await
+    pub async fn count_issue(
+        &self,
+        params: impl Into<CountIssueParams>,
+    ) -> Result<CountIssueResponse> {
+        self.0.execute(params.into()).await
     }
 
     /// Add a new issue with the provided parameters.
@@ -66,6 +73,34 @@ impl IssueApi {
         self.0.execute(params).await
     }
 
+    /// Update an issue with only the fields of `desired` that differ from the server,
+    /// refusing to write if the issue was updated after `original` was read.
+    ///
+    /// The current issue is fetched first. If its `updated` timestamp differs from
+    /// `original`, [`MinimalUpdateOutcome::Conflict`] is returned unless `force` is set.
+    /// Backlog has no conditional requests, so an edit landing between the check and
+    /// the write can still be overwritten, but only for the fields that changed.
+    #[cfg(feature = "writable")]
+    pub async fn update_issue_minimal(
+        &self,
+        original: &Issue,
+        desired: UpdateIssueParams,
+        force: bool,
+    ) -> Result<MinimalUpdateOutcome> {
+        let current = self
+            .get_issue(GetIssueParams::new(desired.issue_id_or_key.clone()))
+            .await?;
+        if current.updated != original.updated && !force {
+            return Ok(MinimalUpdateOutcome::Conflict(Box::new(current)));
+        }
+        let params = desired.minimize(&current);
+        if params.is_empty() {
+            return Ok(MinimalUpdateOutcome::Unchanged);
+        }
+        let issue = self.update_issue(params).await?;
+        Ok(MinimalUpdateOutcome::Updated(Box::new(issue)))
+    }
+
     /// Add a new comment to an existing issue.
     #[cfg(feature = "writable")]
     pub async fn add_comment(&self, params: AddCommentParams) -> Result<AddCommentResponse> {
@@ -113,6 +148,85 @@ impl IssueApi {
         self.0.execute(params).await
     }
 
+    /// Get the field change history of an issue, walking all of its comments oldest
+    /// first with `minId` paging.
+    pub async fn get_issue_history(
+        &self,
+        issue_id_or_key: impl Into<IssueIdOrKey>,
+    ) -> Result<IssueHistory> {
+        const PAGE_SIZE: u8 = 100;
+
+        let issue_id_or_key = issue_id_or_key.into();
+        let mut comments = Vec::new();
+        let mut min_id = None;
+        loop {
+            let mut builder = GetCommentListParamsBuilder::default();
+            builder
+                .issue_id_or_key(issue_id_or_key.clone())
+                .count(PAGE_SIZE)
+                .order(Order::Asc);
+            if let Some(min_id) = min_id {
+                builder.min_id(min_id);
+            }
+            let page = self.get_comment_list(builder.build()?).await?;
+            let last_page = page.len() < usize::from(PAGE_SIZE);
+            min_id = page.last().map(|comment| u64::from(comment.id.value()) + 1);
+            comments.extend(page);
+            if last_page || min_id.is_none() {
+                break;
+            }
+        }
+        Ok(IssueHistory::from_comments(&comments))
+    }
+
+    /// Compute flow metrics for the issues matching `params`, fetching the issues
+    /// 100 at a time (overriding `offset` and `count`) and the history of each.
+    ///
+    /// Only the first [`FlowOptions::max_issues`] issues are covered, as each one costs
+    /// at least one more request; [`FlowReport::truncated`] tells whether more matched.
+    pub async fn get_flow_report(
+        &self,
+        mut params: GetIssueListParams,
+        options: &FlowOptions,
+    ) -> Result<FlowReport> {
+        const PAGE_SIZE: usize = 100;
+
+        let mut issues: Vec<Issue> = Vec::new();
+        let mut truncated = false;
+        loop {
+            // One issue past the cap is asked for, to tell whether more matched
+            let count = match options.max_issues {
+                Some(max) => PAGE_SIZE.min(max + 1 - issues.len()),
+                None => PAGE_SIZE,
+            };
+            params.offset = Some(issues.len() as u32);
+            params.count = Some(count as u32);
+            let page = self.get_issue_list(params.clone()).await?;
+            let last_page = page.len() < count;
+            issues.extend(page);
+            if let Some(max) = options.max_issues
+                && issues.len() > max
+            {
+                issues.truncate(max);
+                truncated = true;
+                break;
+            }
+            if last_page {
+                break;
+            }
+        }
+
+        let now = chrono::Utc::now();
+        let mut flows = Vec::with_capacity(issues.len());
+        for issue in &issues {
+            let history = self.get_issue_history(issue.id).await?;
+            flows.extend(IssueFlow::new(issue, &history, options, now));
+        }
+        let mut report = FlowReport::new(flows);
+        report.truncated = truncated;
+        Ok(report)
+    }
+
     /// Count comments for an issue by its ID or key.
     pub async fn count_comment(&self, params: CountCommentParams) -> Result<CountCommentResponse> {
         self.0.execute(params).await
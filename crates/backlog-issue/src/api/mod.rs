@@ -41,6 +41,8 @@ mod unlink_shared_file;
 mod update_comment;
 #[cfg(feature = "writable")]
 mod update_issue;
+#[cfg(feature = "writable")]
+mod update_issue_minimal;
 
 // Re-export all parameter types and response types
 
@@ -50,6 +52,7 @@ pub use count_issue::{CountIssueParams, CountIssueParamsBuilder, CountIssueRespo
 pub use get_attachment_file::{GetAttachmentFileParams, GetAttachmentFileParamsBuilder};
 pub use get_attachment_list::{GetAttachmentListParams, GetAttachmentListResponse};
 pub use get_comment::{GetCommentParams, GetCommentResponse};
+#[allow(deprecated)]
 pub use get_comment_list::{
     CommentOrder, GetCommentListParams, GetCommentListParamsBuilder, GetCommentListResponse,
 };
@@ -95,3 +98,5 @@ pub use unlink_shared_file::{UnlinkSharedFileParams, UnlinkSharedFileResponse};
 pub use update_comment::{UpdateCommentParams, UpdateCommentResponse};
 #[cfg(feature = "writable")]
 pub use update_issue::{UpdateIssueParams, UpdateIssueParamsBuilder, UpdateIssueResponse};
+#[cfg(feature = "writable")]
+pub use update_issue_minimal::MinimalUpdateOutcome;
@@ -1,5 +1,5 @@
 #[cfg(feature = "writable")]
-use backlog_api_core::{HttpMethod, IntoRequest};
+use backlog_api_macros::BacklogRequest;
 #[cfg(feature = "writable")]
 use backlog_core::IssueIdOrKey;
 #[cfg(feature = "writable")]
@@ -14,7 +14,11 @@ pub type UnlinkSharedFileResponse = SharedFile;
 
 /// Parameters for unlinking a shared file from an issue.
 #[cfg(feature = "writable")]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, BacklogRequest)]
+#[request(
+    method = "DELETE",
+    path = "/api/v2/issues/{issue_id_or_key}/sharedFiles/{shared_file_id}"
+)]
 pub struct UnlinkSharedFileParams {
     pub issue_id_or_key: IssueIdOrKey,
     pub shared_file_id: SharedFileId,
@@ -33,17 +37,3 @@ impl UnlinkSharedFileParams {
         }
     }
 }
-
-#[cfg(feature = "writable")]
-impl IntoRequest for UnlinkSharedFileParams {
-    fn method(&self) -> HttpMethod {
-        HttpMethod::Delete
-    }
-
-    fn path(&self) -> String {
-        format!(
-            "/api/v2/issues/{}/sharedFiles/{}",
-            self.issue_id_or_key, self.shared_file_id
-        )
-    }
-}
@@ -1,18 +1,20 @@
 #[cfg(feature = "writable")]
 use crate::models::Comment;
-use backlog_api_core::{HttpMethod, IntoRequest};
 use backlog_core::IssueIdOrKey;
 use backlog_core::identifier::CommentId;
-use serde::Serialize;
 
 #[cfg(feature = "writable")]
-use backlog_api_macros::ToFormParams;
+use backlog_api_macros::{BacklogRequest, ToFormParams};
 
 #[cfg(feature = "writable")]
 pub type UpdateCommentResponse = Comment;
 
 #[cfg(feature = "writable")]
-#[derive(Debug, Clone, ToFormParams)]
+#[derive(Debug, Clone, ToFormParams, BacklogRequest)]
+#[request(
+    method = "PATCH",
+    path = "/api/v2/issues/{issue_id_or_key}/comments/{comment_id}"
+)]
 pub struct UpdateCommentParams {
     #[form(skip)]
     pub issue_id_or_key: IssueIdOrKey,
@@ -35,22 +37,3 @@ impl UpdateCommentParams {
         }
     }
 }
-
-#[cfg(feature = "writable")]
-impl IntoRequest for UpdateCommentParams {
-    fn method(&self) -> HttpMethod {
-        HttpMethod::Patch
-    }
-
-    fn path(&self) -> String {
-        format!(
-            "/api/v2/issues/{}/comments/{}",
-            self.issue_id_or_key, self.comment_id
-        )
-    }
-
-    fn to_form(&self) -> impl Serialize {
-        let params: Vec<(String, String)> = self.into();
-        params
-    }
-}
@@ -0,0 +1,284 @@
+use super::UpdateIssueParams;
+use crate::models::{CustomField, CustomFieldInput, CustomFieldWithValue, Issue};
+use backlog_core::{ApiDate, identifier::Identifier};
+
+/// Result of [`IssueApi::update_issue_minimal`](super::IssueApi::update_issue_minimal).
+#[derive(Debug, Clone, PartialEq)]
+pub enum MinimalUpdateOutcome {
+    /// The changed fields were written; holds the issue returned by the server.
+    Updated(Box<Issue>),
+    /// The desired state already matches the server, so nothing was sent.
+    Unchanged,
+    /// The issue was updated on the server after the original was read. Nothing
+    /// was sent; holds the current issue so the caller can merge and retry.
+    Conflict(Box<Issue>),
+}
+
+impl UpdateIssueParams {
+    /// Drops every field that already has its desired value in `current`, so that
+    /// only changed fields are sent and concurrent edits to other fields survive.
+    ///
+    /// `comment`, `notified_user_id` and `attachment_id` are actions rather than
+    /// state and are always kept.
+    pub fn minimize(mut self, current: &Issue) -> Self {
+        fn keep_if<T>(value: &mut Option<T>, unchanged: impl FnOnce(&T) -> bool) {
+            if value.as_ref().is_some_and(unchanged) {
+                *value = None;
+            }
+        }
+
+        keep_if(&mut self.summary, |v| *v == current.summary);
+        keep_if(&mut self.description, |v| *v == current.description);
+        keep_if(&mut self.parent_issue_id, |v| {
+            current.parent_issue_id.map(i64::from) == Some(i64::from(v.value()))
+        });
+        keep_if(&mut self.start_date, |v| {
+            same_date(v, current.start_date.as_deref())
+        });
+        keep_if(&mut self.due_date, |v| {
+            same_date(v, current.due_date.as_deref())
+        });
+        keep_if(&mut self.estimated_hours, |v| {
+            current.estimated_hours.map(|h| h as f32) == Some(*v)
+        });
+        keep_if(&mut self.actual_hours, |v| {
+            current.actual_hours.map(|h| h as f32) == Some(*v)
+        });
+        keep_if(&mut self.issue_type_id, |v| *v == current.issue_type.id);
+        keep_if(&mut self.category_id, |v| {
+            same_ids(v, current.category.iter().map(|c| c.id))
+        });
+        keep_if(&mut self.version_id, |v| {
+            same_ids(v, current.versions.iter().map(|m| m.id))
+        });
+        keep_if(&mut self.milestone_id, |v| {
+            same_ids(v, current.milestone.iter().map(|m| m.id))
+        });
+        keep_if(&mut self.priority_id, |v| {
+            current.priority.as_ref().map(|p| p.id) == Some(*v)
+        });
+        keep_if(&mut self.assignee_id, |v| {
+            current.assignee.as_ref().map(|u| u.id) == Some(*v)
+        });
+        keep_if(&mut self.status_id, |v| *v == current.status.id.to_string());
+        keep_if(&mut self.resolution_id, |v| {
+            current.resolution.as_ref().map(|r| r.id) == Some(*v)
+        });
+
+        if let Some(fields) = self.custom_fields.as_mut() {
+            fields.retain(|id, input| {
+                !current
+                    .custom_fields
+                    .iter()
+                    .find(|field| field.id == *id)
+                    .is_some_and(|field| same_custom_field(input, field))
+            });
+        }
+        keep_if(&mut self.custom_fields, |fields| fields.is_empty());
+
+        self
+    }
+
+    /// Returns `true` if sending these parameters would change nothing.
+    pub fn is_empty(&self) -> bool {
+        self.summary.is_none()
+            && self.parent_issue_id.is_none()
+            && self.description.is_none()
+            && self.start_date.is_none()
+            && self.due_date.is_none()
+            && self.estimated_hours.is_none()
+            && self.actual_hours.is_none()
+            && self.issue_type_id.is_none()
+            && self.category_id.is_none()
+            && self.version_id.is_none()
+            && self.milestone_id.is_none()
+            && self.priority_id.is_none()
+            && self.assignee_id.is_none()
+            && self.notified_user_id.is_none()
+            && self.attachment_id.is_none()
+            && self.status_id.is_none()
+            && self.resolution_id.is_none()
+            && self.comment.is_none()
+            && self.custom_fields.is_none()
+    }
+}
+
+/// Compares a date parameter with an issue date such as `2024-06-24T00:00:00Z`.
+fn same_date(desired: &ApiDate, current: Option<&str>) -> bool {
+    current.and_then(|date| date.get(..10)) == Some(desired.to_string().as_str())
+}
+
+/// Compares ID lists ignoring order, as Backlog does not preserve it.
+fn same_ids<T: Identifier>(desired: &[T], current: impl Iterator<Item = T>) -> bool
+where
+    T::Id: Ord,
+{
+    let mut desired: Vec<_> = desired.iter().map(|id| id.value()).collect();
+    let mut current: Vec<_> = current.map(|id| id.value()).collect();
+    desired.sort_unstable();
+    current.sort_unstable();
+    desired == current
+}
+
+/// Compares a custom field input with the value stored on the issue. Values that
+/// cannot be read (such as unset fields) never match, so the input is sent.
+fn same_custom_field(desired: &CustomFieldInput, current: &CustomField) -> bool {
+    let Some(current) = serde_json::to_value(current)
+        .ok()
+        .and_then(|v| serde_json::from_value::<CustomFieldWithValue>(v).ok())
+    else {
+        return false;
+    };
+    let (desired_value, desired_other) = normalize(desired);
+    let (current_value, current_other) = normalize(&CustomFieldInput::from(&current.value));
+    desired_value == current_value && desired_other == current_other
+}
+
+fn normalize(input: &CustomFieldInput) -> (String, Option<String>) {
+    match input {
+        CustomFieldInput::MultipleList { ids, other_value } => {
+            let mut ids: Vec<_> = ids.iter().map(|id| id.value()).collect();
+            ids.sort_unstable();
+            (format!("{ids:?}"), other_value.clone())
+        }
+        CustomFieldInput::CheckBox(ids) => {
+            let mut ids: Vec<_> = ids.iter().map(|id| id.value()).collect();
+            ids.sort_unstable();
+            (format!("{ids:?}"), None)
+        }
+        _ => input.to_form_value(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UpdateIssueParamsBuilder;
+    use backlog_core::identifier::{
+        CategoryId, CustomFieldId, CustomFieldItemId, IssueId, PriorityId, UserId,
+    };
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn issue() -> Issue {
+        let user = json!({
+            "id": 1, "userId": "admin", "name": "admin", "roleType": 1,
+            "lang": null, "mailAddress": "admin@example.com", "lastLoginTime": null
+        });
+        serde_json::from_value(json!({
+            "id": 10, "projectId": 1, "issueKey": "TEST-1", "keyId": 1,
+            "issueType": {"id": 2, "projectId": 1, "name": "Task", "color": "#7ea800", "displayOrder": 0},
+            "summary": "Summary", "description": "Description",
+            "resolution": null, "priority": {"id": 3, "name": "Normal"},
+            "status": {"id": 1, "projectId": 1, "name": "Open", "color": "#ed8077", "displayOrder": 1000},
+            "assignee": user,
+            "category": [{"id": 5, "projectId": 1, "name": "A", "displayOrder": 0},
+                         {"id": 6, "projectId": 1, "name": "B", "displayOrder": 1}],
+            "versions": [], "milestone": [],
+            "startDate": null, "dueDate": "2024-06-24T00:00:00Z",
+            "estimatedHours": 1.5, "actualHours": null, "parentIssueId": null,
+            "createdUser": user, "created": "2024-01-01T00:00:00Z",
+            "updatedUser": user, "updated": "2024-01-02T00:00:00Z",
+            "customFields": [
+                {"id": 100, "fieldTypeId": 1, "name": "Text", "value": "same"},
+                {"id": 101, "fieldTypeId": 6, "name": "Multi",
+                 "value": [{"id": 2, "name": "Two"}, {"id": 1, "name": "One"}], "otherValue": null},
+                {"id": 102, "fieldTypeId": 3, "name": "Points", "value": null}
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_minimize_drops_unchanged_fields() {
+        let mut builder = UpdateIssueParamsBuilder::default();
+        builder
+            .issue_id_or_key(IssueId::new(10))
+            .summary("Summary")
+            .description("New description")
+            .due_date(ApiDate::from(
+                Utc.with_ymd_and_hms(2024, 6, 24, 0, 0, 0).unwrap(),
+            ))
+            .estimated_hours(1.5)
+            .category_id(vec![CategoryId::new(6), CategoryId::new(5)])
+            .priority_id(PriorityId::new(2))
+            .assignee_id(UserId::new(1))
+            .status_id("1")
+            .custom_fields(HashMap::from([
+                (
+                    CustomFieldId::new(100),
+                    CustomFieldInput::Text("same".into()),
+                ),
+                (
+                    CustomFieldId::new(101),
+                    CustomFieldInput::MultipleList {
+                        ids: vec![CustomFieldItemId::new(1), CustomFieldItemId::new(2)],
+                        other_value: None,
+                    },
+                ),
+                (CustomFieldId::new(102), CustomFieldInput::Numeric(3.0)),
+            ]));
+        let params = builder.build().unwrap().minimize(&issue());
+
+        assert_eq!(params.summary, None);
+        assert_eq!(params.description.as_deref(), Some("New description"));
+        assert!(params.due_date.is_none());
+        assert_eq!(params.estimated_hours, None);
+        assert_eq!(params.category_id, None);
+        assert_eq!(params.priority_id, Some(PriorityId::new(2)));
+        assert_eq!(params.assignee_id, None);
+        assert_eq!(params.status_id, None);
+        assert_eq!(
+            params.custom_fields,
+            Some(HashMap::from([(
+                CustomFieldId::new(102),
+                CustomFieldInput::Numeric(3.0)
+            )]))
+        );
+        assert!(!params.is_empty());
+    }
+
+    #[test]
+    fn test_minimize_to_empty() {
+        let mut builder = UpdateIssueParamsBuilder::default();
+        builder
+            .issue_id_or_key(IssueId::new(10))
+            .summary("Summary")
+            .custom_field(
+                CustomFieldId::new(100),
+                CustomFieldInput::Text("same".into()),
+            );
+        let params = builder.build().unwrap().minimize(&issue());
+        assert_eq!(params.custom_fields, None);
+        assert!(params.is_empty());
+
+        // A comment is an action, so it is still sent
+        let mut builder = UpdateIssueParamsBuilder::default();
+        builder
+            .issue_id_or_key(IssueId::new(10))
+            .summary("Summary")
+            .comment("note");
+        assert!(!builder.build().unwrap().minimize(&issue()).is_empty());
+    }
+
+    #[test]
+    fn test_custom_field_date_comparison() {
+        let mut current = issue();
+        current.custom_fields = vec![
+            serde_json::from_value(
+                json!({"id": 103, "fieldTypeId": 4, "name": "Date", "value": "2024-06-24"}),
+            )
+            .unwrap(),
+        ];
+        let date = NaiveDate::from_ymd_opt(2024, 6, 24).unwrap();
+        assert!(same_custom_field(
+            &CustomFieldInput::Date(date),
+            &current.custom_fields[0]
+        ));
+        assert!(!same_custom_field(
+            &CustomFieldInput::Date(date.succ_opt().unwrap()),
+            &current.custom_fields[0]
+        ));
+    }
+}
@@ -0,0 +1,343 @@
+//! Custom field values written by field name and list-item name.
+//!
+//! Numeric IDs are hard to remember and differ between projects. This module
+//! resolves JSON values such as `{"Severity": "High", "Points": 3}` against the
+//! definitions returned by `ProjectApi::get_custom_field_list`, and builds a
+//! JSON Schema of that format so editors can autocomplete it.
+//!
+//! Accepted values per field type:
+//!
+//! | Type | Value |
+//! |------|-------|
+//! | text, textarea | `"text"` |
+//! | numeric | `1.5` |
+//! | date | `"2024-06-24"` |
+//! | single list, radio | `"Item"` or `{"name": "Item", "other": "..."}` |
+//! | multiple list | `["A", "B"]` or `{"items": ["A", "B"], "other": "..."}` |
+//! | checkbox | `["A", "B"]` |
+
+use crate::models::CustomFieldInput;
+use backlog_core::identifier::{CustomFieldId, CustomFieldItemId};
+use backlog_domain_models::{CustomFieldSettings, CustomFieldType, ListSettings};
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A name-based custom field value that cannot be mapped onto the project's definitions.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum CustomFieldNameError {
+    #[error("Custom field '{0}' not found in project")]
+    UnknownField(String),
+
+    #[error("Custom field '{field}': option '{item}' not found. Available options: {available}")]
+    UnknownItem {
+        field: String,
+        item: String,
+        available: String,
+    },
+
+    #[error("Custom field '{field}' {message}")]
+    InvalidValue {
+        field: String,
+        message: &'static str,
+    },
+}
+
+type Result<T> = std::result::Result<T, CustomFieldNameError>;
+
+/// Resolves values keyed by field name into values keyed by field ID.
+pub fn resolve_custom_field_names(
+    definitions: &[CustomFieldType],
+    values: impl IntoIterator<Item = (String, Value)>,
+) -> Result<HashMap<CustomFieldId, CustomFieldInput>> {
+    let mut result = HashMap::new();
+    for (name, value) in values {
+        let field = definitions
+            .iter()
+            .find(|field| field.name == name)
+            .ok_or(CustomFieldNameError::UnknownField(name))?;
+        result.insert(field.id, custom_field_input_from_json(field, &value)?);
+    }
+    Ok(result)
+}
+
+/// Converts one name-based JSON value into the input for `field`.
+pub fn custom_field_input_from_json(
+    field: &CustomFieldType,
+    value: &Value,
+) -> Result<CustomFieldInput> {
+    let name = field.name.as_str();
+    match &field.settings {
+        CustomFieldSettings::Text => Ok(CustomFieldInput::Text(string_value(value, name)?)),
+        CustomFieldSettings::TextArea => Ok(CustomFieldInput::TextArea(string_value(value, name)?)),
+        CustomFieldSettings::Numeric(_) => value
+            .as_f64()
+            .map(CustomFieldInput::Numeric)
+            .ok_or_else(|| invalid(name, "expects a numeric value")),
+        CustomFieldSettings::Date(_) => match value {
+            Value::String(s) => backlog_core::Date::from_str(s)
+                .map(|date| CustomFieldInput::Date(date.into()))
+                .map_err(|_| invalid(name, "expects date in yyyy-MM-dd format")),
+            _ => Err(invalid(name, "expects a date string in yyyy-MM-dd format")),
+        },
+        CustomFieldSettings::SingleList(settings) => {
+            let (item, other_value) = parse_single_list_value(value, name)?;
+            Ok(CustomFieldInput::SingleList {
+                id: find_item(settings, name, &item)?,
+                other_value,
+            })
+        }
+        CustomFieldSettings::Radio(settings) => {
+            let (item, other_value) = parse_single_list_value(value, name)?;
+            Ok(CustomFieldInput::Radio {
+                id: find_item(settings, name, &item)?,
+                other_value,
+            })
+        }
+        CustomFieldSettings::MultipleList(settings) => {
+            let (items, other_value) = parse_multiple_list_value(value, name)?;
+            Ok(CustomFieldInput::MultipleList {
+                ids: find_items(settings, name, &items)?,
+                other_value,
+            })
+        }
+        CustomFieldSettings::Checkbox(settings) => {
+            let items = value
+                .as_array()
+                .ok_or_else(|| invalid(name, "expects an array of strings"))
+                .and_then(|items| string_array(items, name, "expects an array of strings"))?;
+            Ok(CustomFieldInput::CheckBox(find_items(
+                settings, name, &items,
+            )?))
+        }
+    }
+}
+
+/// Builds a JSON Schema describing the name-based values accepted for `definitions`.
+///
+/// Every field is optional, since updates only send the fields that change. A
+/// `$schema` key is allowed so the file can point editors at the schema.
+pub fn custom_fields_json_schema(definitions: &[CustomFieldType]) -> Value {
+    let mut properties = Map::new();
+    properties.insert("$schema".into(), json!({ "type": "string" }));
+    for field in definitions {
+        let mut schema = value_schema(&field.settings);
+        schema.insert("title".into(), field.name.clone().into());
+        if !field.description.is_empty() {
+            schema.insert("description".into(), field.description.clone().into());
+        }
+        properties.insert(field.name.clone(), Value::Object(schema));
+    }
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Backlog custom fields",
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false
+    })
+}
+
+fn value_schema(settings: &CustomFieldSettings) -> Map<String, Value> {
+    let schema = match settings {
+        CustomFieldSettings::Text | CustomFieldSettings::TextArea => json!({ "type": "string" }),
+        CustomFieldSettings::Numeric(numeric) => {
+            let mut schema = json!({ "type": "number" });
+            if let Some(min) = numeric.min {
+                schema["minimum"] = min.into();
+            }
+            if let Some(max) = numeric.max {
+                schema["maximum"] = max.into();
+            }
+            schema
+        }
+        CustomFieldSettings::Date(_) => json!({
+            "type": "string",
+            "format": "date",
+            "pattern": "^[0-9]{4}-[0-9]{2}-[0-9]{2}$"
+        }),
+        CustomFieldSettings::SingleList(list) | CustomFieldSettings::Radio(list) => {
+            let names = item_names_schema(list);
+            let mut object = json!({
+                "type": "object",
+                "properties": { "name": names },
+                "required": ["name"],
+                "additionalProperties": false
+            });
+            if list.allow_input == Some(true) {
+                object["properties"]["other"] = json!({ "type": "string" });
+            }
+            json!({ "anyOf": [names, object] })
+        }
+        CustomFieldSettings::MultipleList(list) => {
+            let array = item_array_schema(list);
+            let mut object = json!({
+                "type": "object",
+                "properties": { "items": array },
+                "required": ["items"],
+                "additionalProperties": false
+            });
+            if list.allow_input == Some(true) {
+                object["properties"]["other"] = json!({ "type": "string" });
+            }
+            json!({ "anyOf": [array, object] })
+        }
+        CustomFieldSettings::Checkbox(list) => item_array_schema(list),
+    };
+    match schema {
+        Value::Object(map) => map,
+        _ => unreachable!("value schemas are objects"),
+    }
+}
+
+fn item_names_schema(list: &ListSettings) -> Value {
+    let names: Vec<&str> = list.items.iter().map(|item| item.name.as_str()).collect();
+    json!({ "type": "string", "enum": names })
+}
+
+fn item_array_schema(list: &ListSettings) -> Value {
+    json!({
+        "type": "array",
+        "items": item_names_schema(list),
+        "uniqueItems": true
+    })
+}
+
+fn invalid(field: &str, message: &'static str) -> CustomFieldNameError {
+    CustomFieldNameError::InvalidValue {
+        field: field.to_string(),
+        message,
+    }
+}
+
+fn string_value(value: &Value, field: &str) -> Result<String> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| invalid(field, "expects a string value"))
+}
+
+fn string_array(values: &[Value], field: &str, message: &'static str) -> Result<Vec<String>> {
+    values
+        .iter()
+        .map(|v| {
+            v.as_str()
+                .map(str::to_string)
+                .ok_or_else(|| invalid(field, message))
+        })
+        .collect()
+}
+
+fn other_value(obj: &Map<String, Value>) -> Option<String> {
+    obj.get("other")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
+
+fn find_item(settings: &ListSettings, field: &str, name: &str) -> Result<CustomFieldItemId> {
+    settings
+        .items
+        .iter()
+        .find(|item| item.name == name)
+        .map(|item| item.id)
+        .ok_or_else(|| CustomFieldNameError::UnknownItem {
+            field: field.to_string(),
+            item: name.to_string(),
+            available: settings
+                .items
+                .iter()
+                .map(|item| format!("'{}'", item.name))
+                .collect::<Vec<_>>()
+                .join(", "),
+        })
+}
+
+fn find_items(
+    settings: &ListSettings,
+    field: &str,
+    names: &[String],
+) -> Result<Vec<CustomFieldItemId>> {
+    names
+        .iter()
+        .map(|name| find_item(settings, field, name))
+        .collect()
+}
+
+fn parse_single_list_value(value: &Value, field: &str) -> Result<(String, Option<String>)> {
+    match value {
+        Value::String(s) => Ok((s.clone(), None)),
+        Value::Object(obj) => {
+            let name = obj
+                .get("name")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| invalid(field, "object must have a 'name' field"))?
+                .to_string();
+            Ok((name, other_value(obj)))
+        }
+        _ => Err(invalid(
+            field,
+            "expects a string or object with 'name' field",
+        )),
+    }
+}
+
+fn parse_multiple_list_value(value: &Value, field: &str) -> Result<(Vec<String>, Option<String>)> {
+    match value {
+        Value::Array(arr) => Ok((
+            string_array(arr, field, "array must contain strings")?,
+            None,
+        )),
+        Value::Object(obj) => {
+            // Object format: { items: ["name1", "name2"], other: "other value" }
+            let items = obj
+                .get("items")
+                .and_then(|v| v.as_array())
+                .ok_or_else(|| invalid(field, "object must have an 'items' array"))?;
+            Ok((
+                string_array(items, field, "items array must contain strings")?,
+                other_value(obj),
+            ))
+        }
+        _ => Err(invalid(
+            field,
+            "expects an array or object with 'items' array",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_list_value_string() {
+        let value = json!("High");
+        let (name, other) = parse_single_list_value(&value, "Priority").unwrap();
+        assert_eq!(name, "High");
+        assert_eq!(other, None);
+    }
+
+    #[test]
+    fn test_parse_single_list_value_object() {
+        let value = json!({"name": "Other", "other": "Custom value"});
+        let (name, other) = parse_single_list_value(&value, "Priority").unwrap();
+        assert_eq!(name, "Other");
+        assert_eq!(other, Some("Custom value".to_string()));
+    }
+
+    #[test]
+    fn test_parse_multiple_list_value_array() {
+        let value = json!(["Backend", "Frontend"]);
+        let (names, other) = parse_multiple_list_value(&value, "Tags").unwrap();
+        assert_eq!(names, vec!["Backend", "Frontend"]);
+        assert_eq!(other, None);
+    }
+
+    #[test]
+    fn test_parse_multiple_list_value_object() {
+        let value = json!({"items": ["Backend", "Frontend"], "other": "Custom tag"});
+        let (names, other) = parse_multiple_list_value(&value, "Tags").unwrap();
+        assert_eq!(names, vec!["Backend", "Frontend"]);
+        assert_eq!(other, Some("Custom tag".to_string()));
+    }
+}
@@ -0,0 +1,324 @@
+//! Pre-flight validation of custom field values against the definitions of a project.
+//!
+//! Backlog rejects invalid custom field values one at a time. [`CustomFieldValidator`]
+//! checks every value up front and reports all violations at once, so callers can fix
+//! their input before anything is written.
+//!
+//! ```
+//! # use backlog_issue::custom_field_validation::CustomFieldValidator;
+//! # use backlog_issue::CustomFieldInput;
+//! # use backlog_core::identifier::{CustomFieldId, IssueTypeId};
+//! # use std::collections::HashMap;
+//! # let definitions = vec![];
+//! let mut values = HashMap::new();
+//! values.insert(CustomFieldId::new(1), CustomFieldInput::Numeric(42.0));
+//!
+//! let result = CustomFieldValidator::new(&definitions)
+//!     .issue_type(IssueTypeId::new(10))
+//!     .check_required(true)
+//!     .validate(&values);
+//! assert!(result.is_err()); // field 1 is not defined in the project
+//! ```
+
+use crate::models::CustomFieldInput;
+use backlog_core::identifier::{CustomFieldId, CustomFieldItemId, IssueTypeId};
+use backlog_domain_models::{CustomFieldSettings, CustomFieldType, ListSettings};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::fmt;
+use thiserror::Error;
+
+/// A custom field value that Backlog would reject.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum CustomFieldViolation {
+    #[error("custom field {field_id} is not defined in the project")]
+    UnknownField { field_id: CustomFieldId },
+
+    #[error("custom field '{field}' is required")]
+    Required { field: String },
+
+    #[error("custom field '{field}' does not apply to this issue type")]
+    NotApplicable { field: String },
+
+    #[error("custom field '{field}' expects {expected}")]
+    TypeMismatch {
+        field: String,
+        expected: &'static str,
+    },
+
+    #[error("custom field '{field}' accepts a single item, got {count}")]
+    SingleItemOnly { field: String, count: usize },
+
+    #[error("custom field '{field}': {value} is less than the minimum {min}")]
+    BelowMinimum { field: String, value: f64, min: f64 },
+
+    #[error("custom field '{field}': {value} is greater than the maximum {max}")]
+    AboveMaximum { field: String, value: f64, max: f64 },
+
+    #[error("custom field '{field}': {value} is before the earliest date {min}")]
+    DateBeforeMinimum {
+        field: String,
+        value: NaiveDate,
+        min: NaiveDate,
+    },
+
+    #[error("custom field '{field}': {value} is after the latest date {max}")]
+    DateAfterMaximum {
+        field: String,
+        value: NaiveDate,
+        max: NaiveDate,
+    },
+
+    #[error("custom field '{field}': item {item_id} is not an option (available: {available})")]
+    UnknownItem {
+        field: String,
+        item_id: CustomFieldItemId,
+        available: String,
+    },
+
+    #[error("custom field '{field}' does not accept an \"other\" value")]
+    OtherNotAllowed { field: String },
+}
+
+/// All violations found by [`CustomFieldValidator::validate`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub struct CustomFieldValidationError {
+    pub violations: Vec<CustomFieldViolation>,
+}
+
+impl fmt::Display for CustomFieldValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid custom field values:")?;
+        for violation in &self.violations {
+            write!(f, "\n  - {violation}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks custom field values against the project's [`CustomFieldType`] definitions.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomFieldValidator<'a> {
+    definitions: &'a [CustomFieldType],
+    issue_type_id: Option<IssueTypeId>,
+    check_required: bool,
+}
+
+impl<'a> CustomFieldValidator<'a> {
+    pub fn new(definitions: &'a [CustomFieldType]) -> Self {
+        Self {
+            definitions,
+            issue_type_id: None,
+            check_required: false,
+        }
+    }
+
+    /// Only fields applicable to this issue type may be set, and only they are required.
+    pub fn issue_type(mut self, issue_type_id: IssueTypeId) -> Self {
+        self.issue_type_id = Some(issue_type_id);
+        self
+    }
+
+    /// Reports required fields without a value. Use it when creating issues; updates
+    /// leave omitted fields unchanged.
+    pub fn check_required(mut self, check_required: bool) -> Self {
+        self.check_required = check_required;
+        self
+    }
+
+    /// Validates `values`, returning every violation in field display order.
+    pub fn validate(
+        &self,
+        values: &HashMap<CustomFieldId, CustomFieldInput>,
+    ) -> Result<(), CustomFieldValidationError> {
+        let mut definitions: Vec<&CustomFieldType> = self.definitions.iter().collect();
+        definitions.sort_by_key(|definition| (definition.display_order, definition.id.0));
+
+        let mut violations = Vec::new();
+        for definition in definitions {
+            let applies = self.applies(definition);
+            match values.get(&definition.id) {
+                Some(_) if !applies => violations.push(CustomFieldViolation::NotApplicable {
+                    field: definition.name.clone(),
+                }),
+                Some(input) => check_value(definition, input, &mut violations),
+                None if applies && self.check_required && definition.required => {
+                    violations.push(CustomFieldViolation::Required {
+                        field: definition.name.clone(),
+                    })
+                }
+                None => {}
+            }
+        }
+
+        let mut unknown: Vec<CustomFieldId> = values
+            .keys()
+            .filter(|id| !self.definitions.iter().any(|d| d.id == **id))
+            .copied()
+            .collect();
+        unknown.sort_by_key(|id| id.0);
+        violations.extend(
+            unknown
+                .into_iter()
+                .map(|field_id| CustomFieldViolation::UnknownField { field_id }),
+        );
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(CustomFieldValidationError { violations })
+        }
+    }
+
+    fn applies(&self, definition: &CustomFieldType) -> bool {
+        match (self.issue_type_id, &definition.applicable_issue_types) {
+            (Some(issue_type_id), Some(types)) if !types.is_empty() => {
+                types.contains(&issue_type_id)
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Selected list items of an input, whichever list variant it uses.
+struct Selection<'a> {
+    ids: &'a [CustomFieldItemId],
+    other_value: Option<&'a str>,
+}
+
+fn selection(input: &CustomFieldInput) -> Option<Selection<'_>> {
+    match input {
+        CustomFieldInput::SingleList { id, other_value }
+        | CustomFieldInput::Radio { id, other_value } => Some(Selection {
+            ids: std::slice::from_ref(id),
+            other_value: other_value.as_deref(),
+        }),
+        CustomFieldInput::MultipleList { ids, other_value } => Some(Selection {
+            ids,
+            other_value: other_value.as_deref(),
+        }),
+        CustomFieldInput::CheckBox(ids) => Some(Selection {
+            ids,
+            other_value: None,
+        }),
+        _ => None,
+    }
+}
+
+fn check_value(
+    definition: &CustomFieldType,
+    input: &CustomFieldInput,
+    violations: &mut Vec<CustomFieldViolation>,
+) {
+    let field = || definition.name.clone();
+    let mismatch = |expected| CustomFieldViolation::TypeMismatch {
+        field: field(),
+        expected,
+    };
+
+    match (&definition.settings, input) {
+        (
+            CustomFieldSettings::Text | CustomFieldSettings::TextArea,
+            CustomFieldInput::Text(text) | CustomFieldInput::TextArea(text),
+        ) => {
+            if definition.required && text.trim().is_empty() {
+                violations.push(CustomFieldViolation::Required { field: field() });
+            }
+        }
+        (CustomFieldSettings::Text | CustomFieldSettings::TextArea, _) => {
+            violations.push(mismatch("text"))
+        }
+        (CustomFieldSettings::Numeric(settings), CustomFieldInput::Numeric(value)) => {
+            let value = *value;
+            if let Some(min) = settings.min.filter(|min| value < *min) {
+                violations.push(CustomFieldViolation::BelowMinimum {
+                    field: field(),
+                    value,
+                    min,
+                });
+            }
+            if let Some(max) = settings.max.filter(|max| value > *max) {
+                violations.push(CustomFieldViolation::AboveMaximum {
+                    field: field(),
+                    value,
+                    max,
+                });
+            }
+        }
+        (CustomFieldSettings::Numeric(_), _) => violations.push(mismatch("a number")),
+        (CustomFieldSettings::Date(settings), CustomFieldInput::Date(value)) => {
+            let value = *value;
+            let min = settings.min.clone().map(NaiveDate::from);
+            if let Some(min) = min.filter(|min| value < *min) {
+                violations.push(CustomFieldViolation::DateBeforeMinimum {
+                    field: field(),
+                    value,
+                    min,
+                });
+            }
+            let max = settings.max.clone().map(NaiveDate::from);
+            if let Some(max) = max.filter(|max| value > *max) {
+                violations.push(CustomFieldViolation::DateAfterMaximum {
+                    field: field(),
+                    value,
+                    max,
+                });
+            }
+        }
+        (CustomFieldSettings::Date(_), _) => violations.push(mismatch("a date")),
+        (
+            CustomFieldSettings::SingleList(settings) | CustomFieldSettings::Radio(settings),
+            input,
+        ) => match selection(input) {
+            Some(selection) => check_selection(definition, settings, &selection, true, violations),
+            None => violations.push(mismatch("a list item")),
+        },
+        (
+            CustomFieldSettings::MultipleList(settings) | CustomFieldSettings::Checkbox(settings),
+            input,
+        ) => match selection(input) {
+            Some(selection) => check_selection(definition, settings, &selection, false, violations),
+            None => violations.push(mismatch("list items")),
+        },
+    }
+}
+
+fn check_selection(
+    definition: &CustomFieldType,
+    settings: &ListSettings,
+    selection: &Selection<'_>,
+    single: bool,
+    violations: &mut Vec<CustomFieldViolation>,
+) {
+    let field = || definition.name.clone();
+    let has_other = selection
+        .other_value
+        .is_some_and(|other| !other.trim().is_empty());
+
+    if definition.required && selection.ids.is_empty() && !has_other {
+        violations.push(CustomFieldViolation::Required { field: field() });
+    }
+    if single && selection.ids.len() > 1 {
+        violations.push(CustomFieldViolation::SingleItemOnly {
+            field: field(),
+            count: selection.ids.len(),
+        });
+    }
+    for item_id in selection.ids {
+        if !settings.items.iter().any(|item| item.id == *item_id) {
+            violations.push(CustomFieldViolation::UnknownItem {
+                field: field(),
+                item_id: *item_id,
+                available: settings
+                    .items
+                    .iter()
+                    .map(|item| format!("{} ({})", item.name, item.id))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            });
+        }
+    }
+    if selection.other_value.is_some() && settings.allow_input != Some(true) {
+        violations.push(CustomFieldViolation::OtherNotAllowed { field: field() });
+    }
+}
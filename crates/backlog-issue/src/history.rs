@@ -0,0 +1,174 @@
+//! Chronological field history of an issue, rebuilt from the change logs of its comments.
+//!
+//! Backlog records every field change as a `changeLog` entry on the comment that made
+//! it. [`IssueHistory`] flattens those entries into typed transitions, so questions
+//! like "what was the status of PROJ-1 over time" can be answered without walking the
+//! comments by hand. Use [`IssueApi::get_issue_history`](crate::IssueApi::get_issue_history)
+//! to fetch all comments and build the history in one call.
+
+use crate::models::{ChangeLogEntry, Comment};
+use backlog_core::User;
+use backlog_core::identifier::{AttachmentId, CommentId, CustomFieldId, Identifier};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fmt;
+
+/// An issue attribute whose changes are recorded in comment change logs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IssueField {
+    Summary,
+    Description,
+    Status,
+    Assignee,
+    Priority,
+    Resolution,
+    IssueType,
+    Category,
+    Version,
+    Milestone,
+    StartDate,
+    DueDate,
+    EstimatedHours,
+    ActualHours,
+    ParentIssue,
+    /// An attachment was added (`to` is set) or removed (`from` is set)
+    Attachment {
+        id: AttachmentId,
+    },
+    CustomField {
+        id: CustomFieldId,
+        name: String,
+    },
+    /// A change log field this crate does not know about, by its API name
+    Other(String),
+}
+
+impl IssueField {
+    /// Classifies a change log entry by its API field name.
+    pub fn from_change_log(entry: &ChangeLogEntry) -> Self {
+        if let Some(attribute) = &entry.attribute_info {
+            return IssueField::CustomField {
+                id: attribute.id,
+                name: entry.field.clone(),
+            };
+        }
+        match entry.field.as_str() {
+            "summary" => IssueField::Summary,
+            "description" => IssueField::Description,
+            "status" => IssueField::Status,
+            "assigner" => IssueField::Assignee,
+            "priority" => IssueField::Priority,
+            "resolution" => IssueField::Resolution,
+            "issueType" => IssueField::IssueType,
+            "component" => IssueField::Category,
+            "version" => IssueField::Version,
+            "milestone" => IssueField::Milestone,
+            "startDate" => IssueField::StartDate,
+            "limitDate" => IssueField::DueDate,
+            "estimatedHours" => IssueField::EstimatedHours,
+            "actualHours" => IssueField::ActualHours,
+            "parentIssue" => IssueField::ParentIssue,
+            "attachment" => match &entry.attachment_info {
+                Some(info) => IssueField::Attachment { id: info.id },
+                None => IssueField::Other(entry.field.clone()),
+            },
+            other => IssueField::Other(other.to_string()),
+        }
+    }
+
+    /// Gets the name used to select this field, e.g. `status`, `dueDate`, or the
+    /// custom field's name.
+    pub fn name(&self) -> &str {
+        match self {
+            IssueField::Summary => "summary",
+            IssueField::Description => "description",
+            IssueField::Status => "status",
+            IssueField::Assignee => "assignee",
+            IssueField::Priority => "priority",
+            IssueField::Resolution => "resolution",
+            IssueField::IssueType => "issueType",
+            IssueField::Category => "category",
+            IssueField::Version => "version",
+            IssueField::Milestone => "milestone",
+            IssueField::StartDate => "startDate",
+            IssueField::DueDate => "dueDate",
+            IssueField::EstimatedHours => "estimatedHours",
+            IssueField::ActualHours => "actualHours",
+            IssueField::ParentIssue => "parentIssue",
+            IssueField::Attachment { .. } => "attachment",
+            IssueField::CustomField { name, .. } => name,
+            IssueField::Other(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for IssueField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// One field changing value, as recorded by a comment.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldTransition {
+    /// When the comment that made the change was posted
+    pub at: DateTime<Utc>,
+    pub comment_id: CommentId,
+    pub changed_by: User,
+    pub field: IssueField,
+    /// The value before the change, `None` if it was empty
+    pub from: Option<String>,
+    /// The value after the change, `None` if it was cleared
+    pub to: Option<String>,
+}
+
+/// Field transitions of an issue in chronological order.
+///
+/// The history only covers changes made after creation: the initial value of a
+/// field is the `from` of its first transition, or the current value if it never
+/// changed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct IssueHistory {
+    pub transitions: Vec<FieldTransition>,
+}
+
+impl IssueHistory {
+    /// Builds the history from an issue's comments, in any order.
+    pub fn from_comments<'a>(comments: impl IntoIterator<Item = &'a Comment>) -> Self {
+        let mut comments: Vec<&Comment> = comments.into_iter().collect();
+        comments.sort_by_key(|comment| (comment.created, comment.id.value()));
+
+        let transitions = comments
+            .into_iter()
+            .flat_map(|comment| {
+                comment.change_log.iter().map(move |entry| FieldTransition {
+                    at: comment.created,
+                    comment_id: comment.id,
+                    changed_by: comment.created_user.clone(),
+                    field: IssueField::from_change_log(entry),
+                    from: non_empty(&entry.original_value),
+                    to: non_empty(&entry.new_value),
+                })
+            })
+            .collect();
+        IssueHistory { transitions }
+    }
+
+    /// Transitions of the field selected by `name` (see [`IssueField::name`]),
+    /// compared case-insensitively.
+    pub fn field<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a FieldTransition> {
+        self.transitions
+            .iter()
+            .filter(move |transition| transition.field.name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<String> {
+    value.clone().filter(|value| !value.is_empty())
+}
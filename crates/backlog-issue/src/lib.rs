@@ -1,5 +1,10 @@
+pub mod analytics;
 pub mod api;
+pub mod custom_field_names;
+pub mod custom_field_validation;
+pub mod history;
 pub mod models;
+pub mod query;
 
 // Re-export API components
 pub use api::*;
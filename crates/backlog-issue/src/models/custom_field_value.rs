@@ -118,6 +118,35 @@ pub enum CustomFieldInput {
     },
 }
 
+/// Converts a stored value back into the input that would set it.
+impl From<&CustomFieldValue> for CustomFieldInput {
+    fn from(value: &CustomFieldValue) -> Self {
+        match value {
+            CustomFieldValue::Text(s) => CustomFieldInput::Text(s.clone()),
+            CustomFieldValue::TextArea(s) => CustomFieldInput::TextArea(s.clone()),
+            CustomFieldValue::Numeric(n) => CustomFieldInput::Numeric(*n),
+            CustomFieldValue::Date(d) => CustomFieldInput::Date(*d),
+            CustomFieldValue::SingleList { item, other_value } => CustomFieldInput::SingleList {
+                id: item.id,
+                other_value: other_value.clone(),
+            },
+            CustomFieldValue::MultipleList { items, other_value } => {
+                CustomFieldInput::MultipleList {
+                    ids: items.iter().map(|item| item.id).collect(),
+                    other_value: other_value.clone(),
+                }
+            }
+            CustomFieldValue::CheckBox(items) => {
+                CustomFieldInput::CheckBox(items.iter().map(|item| item.id).collect())
+            }
+            CustomFieldValue::Radio { item, other_value } => CustomFieldInput::Radio {
+                id: item.id,
+                other_value: other_value.clone(),
+            },
+        }
+    }
+}
+
 impl CustomFieldInput {
     /// Convert to form parameter value for API requests.
     /// Returns (value, optional_other_value) tuple.
@@ -0,0 +1,210 @@
+use backlog_core::Error as CoreError;
+use backlog_core::identifier::{CustomFieldId, Identifier};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Sort key for the issue list, sent as the `sort` parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSort {
+    IssueType,
+    Category,
+    Version,
+    Milestone,
+    Summary,
+    Status,
+    Priority,
+    Attachment,
+    SharedFile,
+    Created,
+    CreatedUser,
+    Updated,
+    UpdatedUser,
+    Assignee,
+    StartDate,
+    DueDate,
+    EstimatedHours,
+    ActualHours,
+    ChildIssue,
+    /// Sort by the value of a custom field (`customField_<ID>`)
+    CustomField(CustomFieldId),
+}
+
+const CUSTOM_FIELD_PREFIX: &str = "customField_";
+
+impl IssueSort {
+    /// Get all sort keys except [`IssueSort::CustomField`]
+    pub fn all() -> &'static [IssueSort] {
+        &[
+            IssueSort::IssueType,
+            IssueSort::Category,
+            IssueSort::Version,
+            IssueSort::Milestone,
+            IssueSort::Summary,
+            IssueSort::Status,
+            IssueSort::Priority,
+            IssueSort::Attachment,
+            IssueSort::SharedFile,
+            IssueSort::Created,
+            IssueSort::CreatedUser,
+            IssueSort::Updated,
+            IssueSort::UpdatedUser,
+            IssueSort::Assignee,
+            IssueSort::StartDate,
+            IssueSort::DueDate,
+            IssueSort::EstimatedHours,
+            IssueSort::ActualHours,
+            IssueSort::ChildIssue,
+        ]
+    }
+
+    /// Get the API name of a sort key, or `None` for [`IssueSort::CustomField`]
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self {
+            IssueSort::IssueType => "issueType",
+            IssueSort::Category => "category",
+            IssueSort::Version => "version",
+            IssueSort::Milestone => "milestone",
+            IssueSort::Summary => "summary",
+            IssueSort::Status => "status",
+            IssueSort::Priority => "priority",
+            IssueSort::Attachment => "attachment",
+            IssueSort::SharedFile => "sharedFile",
+            IssueSort::Created => "created",
+            IssueSort::CreatedUser => "createdUser",
+            IssueSort::Updated => "updated",
+            IssueSort::UpdatedUser => "updatedUser",
+            IssueSort::Assignee => "assignee",
+            IssueSort::StartDate => "startDate",
+            IssueSort::DueDate => "dueDate",
+            IssueSort::EstimatedHours => "estimatedHours",
+            IssueSort::ActualHours => "actualHours",
+            IssueSort::ChildIssue => "childIssue",
+            IssueSort::CustomField(_) => return None,
+        })
+    }
+}
+
+impl fmt::Display for IssueSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssueSort::CustomField(id) => write!(f, "{CUSTOM_FIELD_PREFIX}{}", id.value()),
+            sort => f.write_str(sort.name().unwrap_or_default()),
+        }
+    }
+}
+
+/// Parses the API name of a sort key, ignoring case and underscores
+/// (`dueDate`, `due_date`), or `customField_<ID>`.
+impl FromStr for IssueSort {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(id) = s.strip_prefix(CUSTOM_FIELD_PREFIX) {
+            return id
+                .parse::<u32>()
+                .map(|id| IssueSort::CustomField(CustomFieldId::new(id)))
+                .map_err(|_| {
+                    CoreError::InvalidParameter(format!(
+                        "invalid sort key '{s}': expected customField_<ID>"
+                    ))
+                });
+        }
+        let normalized = s.replace('_', "").to_ascii_lowercase();
+        IssueSort::all()
+            .iter()
+            .find(|sort| sort.name().unwrap_or_default().to_ascii_lowercase() == normalized)
+            .copied()
+            .ok_or_else(|| CoreError::InvalidParameter(format!("invalid sort key '{s}'")))
+    }
+}
+
+impl Serialize for IssueSort {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssueSort {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for IssueSort {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "IssueSort".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let names: Vec<&str> = IssueSort::all()
+            .iter()
+            .filter_map(IssueSort::name)
+            .collect();
+        schemars::json_schema!({
+            "type": "string",
+            "description": "Sort key of the issue list, or customField_<ID> to sort by a custom field.",
+            "anyOf": [
+                { "enum": names },
+                { "pattern": "^customField_[0-9]+$" }
+            ]
+        })
+    }
+}
+
+/// Lists the fixed sort keys as possible values. clap's enum parser only
+/// matches listed values, so arguments that should also take
+/// `customField_<ID>` need `value_parser = <IssueSort as FromStr>::from_str`.
+#[cfg(feature = "clap")]
+impl clap::ValueEnum for IssueSort {
+    fn value_variants<'a>() -> &'a [Self] {
+        IssueSort::all()
+    }
+
+    fn from_str(input: &str, _ignore_case: bool) -> Result<Self, String> {
+        input.parse().map_err(|e: CoreError| e.to_string())
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        self.name().map(clap::builder::PossibleValue::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_and_parse() {
+        for sort in IssueSort::all() {
+            assert_eq!(sort.to_string().parse::<IssueSort>().unwrap(), *sort);
+        }
+        assert_eq!(IssueSort::DueDate.to_string(), "dueDate");
+        assert_eq!("due_date".parse::<IssueSort>().unwrap(), IssueSort::DueDate);
+        assert_eq!(
+            "customField_12".parse::<IssueSort>().unwrap(),
+            IssueSort::CustomField(CustomFieldId::new(12))
+        );
+        assert_eq!(
+            IssueSort::CustomField(CustomFieldId::new(12)).to_string(),
+            "customField_12"
+        );
+        assert!("customField_x".parse::<IssueSort>().is_err());
+        assert!("color".parse::<IssueSort>().is_err());
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_eq!(
+            serde_json::to_string(&IssueSort::CreatedUser).unwrap(),
+            "\"createdUser\""
+        );
+        assert_eq!(
+            serde_json::from_str::<IssueSort>("\"customField_3\"").unwrap(),
+            IssueSort::CustomField(CustomFieldId::new(3))
+        );
+        assert!(serde_json::from_str::<IssueSort>("\"colour\"").is_err());
+    }
+}
@@ -14,6 +14,7 @@ mod custom_field_with_value_test;
 mod external_file_link;
 mod initial_date;
 mod issue;
+mod issue_sort;
 mod list_item;
 mod notification;
 mod parent_child;
@@ -29,6 +30,7 @@ pub use custom_field_with_value::CustomFieldWithValue;
 pub use external_file_link::ExternalFileLink;
 pub use initial_date::InitialDate;
 pub use issue::Issue;
+pub use issue_sort::IssueSort;
 pub use list_item::ListItem;
 pub use notification::NotificationForComment;
 pub use parent_child::ParentChildCondition;
@@ -0,0 +1,78 @@
+use thiserror::Error;
+
+/// Errors from parsing or compiling an [`IssueQuery`](super::IssueQuery).
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum QueryError {
+    #[error("syntax error at column {column}: {message}")]
+    Syntax { column: usize, message: String },
+
+    #[error("unknown field `{field}` at column {column}{}", did_you_mean(.suggestion))]
+    UnknownField {
+        field: String,
+        column: usize,
+        suggestion: Option<String>,
+    },
+
+    #[error("unknown {kind} `{name}`{}{}", did_you_mean(.suggestion), available(.candidates))]
+    UnknownName {
+        kind: &'static str,
+        name: String,
+        suggestion: Option<String>,
+        candidates: Vec<String>,
+    },
+
+    #[error("invalid value `{value}` for `{field}`: {reason}")]
+    InvalidValue {
+        field: String,
+        value: String,
+        reason: String,
+    },
+
+    #[error("`{field}` depends on the project; add `project:KEY` to the query")]
+    MissingProject { field: String },
+}
+
+/// How many candidates an unknown name error lists.
+const MAX_LISTED_CANDIDATES: usize = 20;
+
+fn did_you_mean(suggestion: &Option<String>) -> String {
+    suggestion
+        .as_ref()
+        .map(|s| format!("; did you mean `{s}`?"))
+        .unwrap_or_default()
+}
+
+fn available(candidates: &[String]) -> String {
+    if candidates.is_empty() {
+        return String::new();
+    }
+    let mut listed = candidates
+        .iter()
+        .take(MAX_LISTED_CANDIDATES)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ");
+    if candidates.len() > MAX_LISTED_CANDIDATES {
+        listed.push_str(", ...");
+    }
+    format!(" (available: {listed})")
+}
+
+/// Returns the candidate closest to `name`, if any is close enough to be a typo.
+pub(crate) fn closest<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let name = name.to_lowercase();
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            let distance = strsim::levenshtein(&name, &candidate.to_lowercase());
+            (distance, candidate)
+        })
+        .filter(|(distance, candidate)| {
+            *distance <= (candidate.chars().count().max(name.chars().count()) / 3).max(1)
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_string())
+}
@@ -0,0 +1,701 @@
+//! A search query language for issues, compiled to [`GetIssueListParams`].
+//!
+//! ```text
+//! project:WEB status:"In Progress",Open assignee:@me type:Bug due<2026-11-01 updated>-7d login
+//! ```
+//!
+//! A query is a list of conditions separated by whitespace, all of which must hold.
+//! Each condition is `field:value`, or `field<value`, `field<=value`, `field>value`
+//! and `field>=value` for dates. Values containing spaces are quoted, several values
+//! separated by commas match any of them, and a leading `-` excludes the values.
+//! Words without a field are searched in the summary and description.
+//!
+//! | Field | Values |
+//! | --- | --- |
+//! | `project` | project key, name or ID |
+//! | `status`, `type`, `category`, `milestone`, `version` | names in the given projects |
+//! | `assignee`, `created_by` (`author`) | `@me`, user ID, name or numeric ID |
+//! | `priority`, `resolution` | names |
+//! | `parent`, `id` | numeric issue IDs |
+//! | `is` | `parent`, `child` (or `-is:child`), `standalone` |
+//! | `has` | `attachment`, `file` |
+//! | `created`, `updated`, `start`, `due` | `YYYY-MM-DD`, `today`, `yesterday`, `tomorrow`, `-7d`, `+2w`, `-1m`, or a range `A..B` |
+//! | `keyword` | text |
+//! | `sort`, `order`, `count` (`limit`), `offset` | list options |
+//!
+//! Names are matched case-insensitively against master data in a [`QueryContext`],
+//! and unknown names are reported with the closest match and the available names.
+
+mod error;
+mod parser;
+
+pub use error::QueryError;
+pub use parser::{Field, Operator, Term};
+
+use crate::api::{GetIssueListParams, GetIssueListParamsBuilder};
+use crate::models::{IssueSort, ParentChildCondition};
+use backlog_core::identifier::{IssueId, UserId};
+use backlog_core::{ApiDate, Order, User};
+use backlog_domain_models::{
+    Category, IssueType, Milestone, Priority, Project, Resolution, Status,
+};
+use chrono::{Days, Months, NaiveDate};
+use error::closest;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A parsed issue search query.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IssueQuery {
+    terms: Vec<Term>,
+}
+
+/// Master data of one project, used to resolve project-scoped names.
+#[derive(Debug, Clone)]
+pub struct ProjectData {
+    pub project: Project,
+    pub statuses: Vec<Status>,
+    pub issue_types: Vec<IssueType>,
+    pub categories: Vec<Category>,
+    /// Versions and milestones, which share one list in Backlog.
+    pub milestones: Vec<Milestone>,
+}
+
+impl ProjectData {
+    pub fn new(project: Project) -> Self {
+        Self {
+            project,
+            statuses: Vec::new(),
+            issue_types: Vec::new(),
+            categories: Vec::new(),
+            milestones: Vec::new(),
+        }
+    }
+}
+
+/// Everything names in a query are resolved against.
+///
+/// Only the data a query refers to has to be filled in; see
+/// [`IssueQuery::uses`], [`IssueQuery::mentions_me`] and [`IssueQuery::needs_users`].
+#[derive(Debug, Clone, Default)]
+pub struct QueryContext {
+    /// The date relative dates such as `-7d` count from.
+    pub today: NaiveDate,
+    /// The user `@me` refers to.
+    pub me: Option<User>,
+    /// The projects named in the query, with their master data.
+    pub projects: Vec<ProjectData>,
+    pub priorities: Vec<Priority>,
+    pub resolutions: Vec<Resolution>,
+    /// Users that `assignee:` and `created_by:` can name.
+    pub users: Vec<User>,
+}
+
+/// Builds a query from conditions assembled in code rather than parsed from text.
+impl From<Vec<Term>> for IssueQuery {
+    fn from(terms: Vec<Term>) -> Self {
+        Self { terms }
+    }
+}
+
+impl FromStr for IssueQuery {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl IssueQuery {
+    pub fn parse(input: &str) -> Result<Self, QueryError> {
+        Ok(Self {
+            terms: parser::parse(input)?,
+        })
+    }
+
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns true if the query has a condition on `field`.
+    pub fn uses(&self, field: Field) -> bool {
+        self.terms.iter().any(|term| term.field == field)
+    }
+
+    /// Returns true if the query refers to the current user with `@me`.
+    pub fn mentions_me(&self) -> bool {
+        self.user_terms()
+            .any(|term| term.values.iter().any(|value| is_me(value)))
+    }
+
+    /// Returns true if resolving the query needs [`QueryContext::users`].
+    pub fn needs_users(&self) -> bool {
+        self.user_terms()
+            .any(|term| term.negated || term.values.iter().any(|value| !is_me(value)))
+    }
+
+    fn user_terms(&self) -> impl Iterator<Item = &Term> {
+        self.terms
+            .iter()
+            .filter(|term| matches!(term.field, Field::Assignee | Field::CreatedBy))
+    }
+
+    fn terms_of(&self, field: Field) -> impl Iterator<Item = &Term> {
+        self.terms.iter().filter(move |term| term.field == field)
+    }
+
+    /// Picks the projects named by `project:` conditions out of `projects`.
+    pub fn resolve_projects<'a>(
+        &self,
+        projects: &'a [Project],
+    ) -> Result<Vec<&'a Project>, QueryError> {
+        let indices = self.project_indices(projects.iter())?;
+        Ok(indices.into_iter().map(|i| &projects[i]).collect())
+    }
+
+    fn project_indices<'a>(
+        &self,
+        projects: impl Iterator<Item = &'a Project>,
+    ) -> Result<Vec<usize>, QueryError> {
+        let candidates: Vec<Candidate<usize>> = projects
+            .enumerate()
+            .map(|(i, project)| {
+                Candidate::new(i, project.project_key.to_string())
+                    .alias(&project.name)
+                    .alias(project.id)
+            })
+            .collect();
+        Ok(self
+            .select(Field::Project, "project", &candidates, |_| None)?
+            .unwrap_or_default())
+    }
+
+    /// Resolves the query against `context`.
+    pub fn compile(&self, context: &QueryContext) -> Result<GetIssueListParams, QueryError> {
+        for term in &self.terms {
+            if term.negated && !is_negatable(term.field) {
+                return Err(invalid(
+                    term.field,
+                    &term.values.join(","),
+                    "this condition cannot be excluded",
+                ));
+            }
+        }
+
+        let mut builder = GetIssueListParamsBuilder::default();
+        let projects: Vec<&ProjectData> = self
+            .project_indices(context.projects.iter().map(|data| &data.project))?
+            .into_iter()
+            .map(|i| &context.projects[i])
+            .collect();
+        if !projects.is_empty() {
+            builder.project_id(
+                projects
+                    .iter()
+                    .map(|data| data.project.id)
+                    .collect::<Vec<_>>(),
+            );
+        }
+        for field in [
+            Field::Status,
+            Field::IssueType,
+            Field::Category,
+            Field::Milestone,
+            Field::Version,
+        ] {
+            if projects.is_empty() && self.uses(field) {
+                return Err(QueryError::MissingProject {
+                    field: field.name().to_string(),
+                });
+            }
+        }
+
+        let statuses: Vec<_> = projects
+            .iter()
+            .flat_map(|data| &data.statuses)
+            .map(|status| Candidate::new(status.id, &status.name).alias(status.id))
+            .collect();
+        if let Some(ids) = self.select(Field::Status, "status", &statuses, |_| None)? {
+            builder.status_id(ids);
+        }
+        let issue_types: Vec<_> = projects
+            .iter()
+            .flat_map(|data| &data.issue_types)
+            .map(|issue_type| Candidate::new(issue_type.id, &issue_type.name).alias(issue_type.id))
+            .collect();
+        if let Some(ids) = self.select(Field::IssueType, "issue type", &issue_types, |_| None)? {
+            builder.issue_type_id(ids);
+        }
+        let categories: Vec<_> = projects
+            .iter()
+            .flat_map(|data| &data.categories)
+            .map(|category| Candidate::new(category.id, &category.name).alias(category.id))
+            .collect();
+        if let Some(ids) = self.select(Field::Category, "category", &categories, |_| None)? {
+            builder.category_id(ids);
+        }
+        let milestones: Vec<_> = projects
+            .iter()
+            .flat_map(|data| &data.milestones)
+            .map(|milestone| Candidate::new(milestone.id, &milestone.name).alias(milestone.id))
+            .collect();
+        if let Some(ids) = self.select(Field::Milestone, "milestone", &milestones, |_| None)? {
+            builder.milestone_id(ids);
+        }
+        if let Some(ids) = self.select(Field::Version, "version", &milestones, |_| None)? {
+            builder.version_id(ids);
+        }
+
+        let priorities: Vec<_> = context
+            .priorities
+            .iter()
+            .map(|priority| Candidate::new(priority.id, &priority.name).alias(priority.id))
+            .collect();
+        if let Some(ids) = self.select(Field::Priority, "priority", &priorities, |_| None)? {
+            builder.priority_id(ids);
+        }
+        let resolutions: Vec<_> = context
+            .resolutions
+            .iter()
+            .map(|resolution| Candidate::new(resolution.id, &resolution.name).alias(resolution.id))
+            .collect();
+        if let Some(ids) = self.select(Field::Resolution, "resolution", &resolutions, |_| None)? {
+            builder.resolution_id(ids);
+        }
+
+        let users: Vec<_> = context
+            .users
+            .iter()
+            .map(|user| {
+                Candidate::new(user.id, user.user_id.as_deref().unwrap_or(&user.name))
+                    .alias(&user.name)
+                    .alias(user.id)
+            })
+            .collect();
+        let me =
+            |value: &str| -> Option<Result<UserId, QueryError>> {
+                is_me(value).then(|| {
+                    context.me.as_ref().map(|user| user.id).ok_or_else(|| {
+                        QueryError::InvalidValue {
+                            field: "assignee".to_string(),
+                            value: value.to_string(),
+                            reason: "the current user is not known".to_string(),
+                        }
+                    })
+                })
+            };
+        if let Some(ids) = self.select(Field::Assignee, "user", &users, me)? {
+            builder.assignee_id(ids);
+        }
+        if let Some(ids) = self.select(Field::CreatedBy, "user", &users, me)? {
+            builder.created_user_id(ids);
+        }
+
+        if let Some(ids) = self.issue_ids(Field::Parent)? {
+            builder.parent_issue_id(ids);
+        }
+        if let Some(ids) = self.issue_ids(Field::Id)? {
+            builder.id(ids);
+        }
+        if let Some(condition) = self.parent_child_condition()? {
+            builder.parent_child_condition(condition);
+        }
+        for term in self.terms_of(Field::Has) {
+            for value in &term.values {
+                match value.to_ascii_lowercase().as_str() {
+                    "attachment" | "attachments" => builder.attachment(true),
+                    "file" | "files" | "shared_file" => builder.shared_file(true),
+                    _ => {
+                        return Err(invalid(
+                            Field::Has,
+                            value,
+                            "expected `attachment` or `file`",
+                        ));
+                    }
+                };
+            }
+        }
+
+        for field in [
+            Field::Created,
+            Field::Updated,
+            Field::StartDate,
+            Field::DueDate,
+        ] {
+            let (since, until) = self.date_bounds(field, context.today)?;
+            if let Some(since) = since.map(api_date) {
+                match field {
+                    Field::Created => builder.created_since(since),
+                    Field::Updated => builder.updated_since(since),
+                    Field::StartDate => builder.start_date_since(since),
+                    _ => builder.due_date_since(since),
+                };
+            }
+            if let Some(until) = until.map(api_date) {
+                match field {
+                    Field::Created => builder.created_until(until),
+                    Field::Updated => builder.updated_until(until),
+                    Field::StartDate => builder.start_date_until(until),
+                    _ => builder.due_date_until(until),
+                };
+            }
+        }
+
+        let keywords: Vec<&str> = self
+            .terms_of(Field::Keyword)
+            .flat_map(|term| term.values.iter().map(String::as_str))
+            .collect();
+        if !keywords.is_empty() {
+            builder.keyword(keywords.join(" "));
+        }
+        if let Some(sort) = self.single(Field::Sort)? {
+            builder.sort(sort_key(sort)?);
+        }
+        if let Some(order) = self.single(Field::Order)? {
+            match order.parse::<Order>() {
+                Ok(order) => builder.order(order),
+                Err(_) => return Err(invalid(Field::Order, order, "expected `asc` or `desc`")),
+            };
+        }
+        if let Some(count) = self.single(Field::Count)? {
+            match count.parse::<u32>() {
+                Ok(count @ 1..=100) => builder.count(count),
+                _ => {
+                    return Err(invalid(
+                        Field::Count,
+                        count,
+                        "expected a number from 1 to 100",
+                    ));
+                }
+            };
+        }
+        if let Some(offset) = self.single(Field::Offset)? {
+            let offset = offset
+                .parse::<u32>()
+                .map_err(|_| invalid(Field::Offset, offset, "expected a number"))?;
+            builder.offset(offset);
+        }
+
+        Ok(builder
+            .build()
+            .expect("every issue list parameter is optional"))
+    }
+
+    /// Resolves the values of every `field` condition to IDs.
+    ///
+    /// Returns `None` if the query has no such condition. Values of negated
+    /// conditions are removed from the values of the others, or from all
+    /// candidates when every condition on the field is negated.
+    fn select<Id: Copy + PartialEq>(
+        &self,
+        field: Field,
+        kind: &'static str,
+        candidates: &[Candidate<Id>],
+        special: impl Fn(&str) -> Option<Result<Id, QueryError>>,
+    ) -> Result<Option<Vec<Id>>, QueryError> {
+        let mut included = Vec::new();
+        let mut excluded = Vec::new();
+        let mut used = false;
+        for term in self.terms_of(field) {
+            used = true;
+            let ids = if term.negated {
+                &mut excluded
+            } else {
+                &mut included
+            };
+            for value in &term.values {
+                match special(value) {
+                    Some(id) => ids.push(id?),
+                    None => ids.extend(resolve(kind, value, candidates)?),
+                }
+            }
+        }
+        if !used {
+            return Ok(None);
+        }
+
+        if included.is_empty() {
+            included = candidates.iter().map(|candidate| candidate.id).collect();
+        }
+        let mut ids: Vec<Id> = Vec::new();
+        for id in included {
+            if !excluded.contains(&id) && !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        if ids.is_empty() {
+            let values: Vec<&str> = self
+                .terms_of(field)
+                .flat_map(|term| term.values.iter().map(String::as_str))
+                .collect();
+            return Err(invalid(
+                field,
+                &values.join(","),
+                &format!("the conditions exclude every {kind}"),
+            ));
+        }
+        Ok(Some(ids))
+    }
+
+    fn issue_ids(&self, field: Field) -> Result<Option<Vec<IssueId>>, QueryError> {
+        let mut ids = Vec::new();
+        for term in self.terms_of(field) {
+            for value in &term.values {
+                let id = value
+                    .parse::<u32>()
+                    .map_err(|_| invalid(field, value, "expected a numeric issue ID"))?;
+                ids.push(IssueId::new(id));
+            }
+        }
+        Ok((!ids.is_empty()).then_some(ids))
+    }
+
+    fn parent_child_condition(&self) -> Result<Option<ParentChildCondition>, QueryError> {
+        let mut conditions = self.terms_of(Field::Is).flat_map(|term| {
+            term.values
+                .iter()
+                .map(move |value| (term.negated, value.as_str()))
+        });
+        let Some((negated, value)) = conditions.next() else {
+            return Ok(None);
+        };
+        if conditions.next().is_some() {
+            return Err(invalid(
+                Field::Is,
+                value,
+                "only one `is:` condition is supported",
+            ));
+        }
+        let condition = match (negated, value.to_ascii_lowercase().as_str()) {
+            (false, "parent") => ParentChildCondition::ParentIssue,
+            (false, "child") => ParentChildCondition::ChildIssue,
+            (true, "child") => ParentChildCondition::ExcludeChildIssue,
+            (false, "standalone") => ParentChildCondition::NeitherParentIssueNorChildIssue,
+            (true, _) => return Err(invalid(Field::Is, value, "only `child` can be excluded")),
+            (false, _) => {
+                return Err(invalid(
+                    Field::Is,
+                    value,
+                    "expected `parent`, `child` or `standalone`",
+                ));
+            }
+        };
+        Ok(Some(condition))
+    }
+
+    /// Returns the inclusive date bounds of all conditions on a date field.
+    fn date_bounds(
+        &self,
+        field: Field,
+        today: NaiveDate,
+    ) -> Result<(Option<NaiveDate>, Option<NaiveDate>), QueryError> {
+        let mut since: Option<NaiveDate> = None;
+        let mut until: Option<NaiveDate> = None;
+        for term in self.terms_of(field) {
+            let [value] = term.values.as_slice() else {
+                return Err(invalid(field, &term.values.join(","), "expected one date"));
+            };
+            let (from, to) = date_range(field, value, today)?;
+            let is_range = from != to;
+            if is_range && term.operator != Operator::Eq {
+                return Err(invalid(field, value, "ranges can only be used with `:`"));
+            }
+            let day = |date: Option<NaiveDate>, days: i64| {
+                date.and_then(|date| date.checked_add_signed(chrono::Duration::days(days)))
+            };
+            let (term_since, term_until) = match term.operator {
+                Operator::Eq => (from, to),
+                Operator::Lt => (None, day(from, -1)),
+                Operator::Le => (None, to),
+                Operator::Gt => (day(to, 1), None),
+                Operator::Ge => (from, None),
+            };
+            since = since.max(term_since);
+            until = match (until, term_until) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+        }
+        Ok((since, until))
+    }
+
+    /// Returns the value of a field that takes a single value.
+    fn single(&self, field: Field) -> Result<Option<&str>, QueryError> {
+        let values: Vec<&str> = self
+            .terms_of(field)
+            .flat_map(|term| term.values.iter().map(String::as_str))
+            .collect();
+        match values.as_slice() {
+            [] => Ok(None),
+            [value] => Ok(Some(value)),
+            _ => Err(invalid(field, &values.join(","), "expected a single value")),
+        }
+    }
+}
+
+/// A value a name in the query can resolve to.
+struct Candidate<Id> {
+    id: Id,
+    label: String,
+    aliases: Vec<String>,
+}
+
+impl<Id> Candidate<Id> {
+    fn new(id: Id, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            aliases: Vec::new(),
+        }
+    }
+
+    fn alias(mut self, alias: impl Display) -> Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        std::iter::once(&self.label)
+            .chain(&self.aliases)
+            .any(|candidate| candidate.to_lowercase() == name.to_lowercase())
+    }
+}
+
+/// Returns the IDs of every candidate named `name`; equal names in several
+/// projects resolve to all of them.
+fn resolve<Id: Copy>(
+    kind: &'static str,
+    name: &str,
+    candidates: &[Candidate<Id>],
+) -> Result<Vec<Id>, QueryError> {
+    let name = name.trim();
+    let ids: Vec<Id> = candidates
+        .iter()
+        .filter(|candidate| candidate.matches(name))
+        .map(|candidate| candidate.id)
+        .collect();
+    if !ids.is_empty() {
+        return Ok(ids);
+    }
+
+    let mut labels: Vec<String> = Vec::new();
+    for candidate in candidates {
+        if !labels.contains(&candidate.label) {
+            labels.push(candidate.label.clone());
+        }
+    }
+    Err(QueryError::UnknownName {
+        kind,
+        name: name.to_string(),
+        suggestion: closest(
+            name,
+            candidates
+                .iter()
+                .flat_map(|c| std::iter::once(&c.label).chain(&c.aliases))
+                .map(String::as_str),
+        ),
+        candidates: labels,
+    })
+}
+
+fn is_me(value: &str) -> bool {
+    value.eq_ignore_ascii_case("@me")
+}
+
+fn is_negatable(field: Field) -> bool {
+    field.is_project_scoped()
+        || matches!(
+            field,
+            Field::Assignee | Field::CreatedBy | Field::Priority | Field::Resolution | Field::Is
+        )
+}
+
+fn invalid(field: Field, value: &str, reason: &str) -> QueryError {
+    QueryError::InvalidValue {
+        field: field.name().to_string(),
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+fn sort_key(value: &str) -> Result<IssueSort, QueryError> {
+    if value.starts_with("customField_") {
+        return value
+            .parse()
+            .map_err(|_| invalid(Field::Sort, value, "expected `customField_<ID>`"));
+    }
+    let names = || IssueSort::all().iter().filter_map(IssueSort::name);
+    value.parse().map_err(|_| QueryError::UnknownName {
+        kind: "sort key",
+        name: value.to_string(),
+        suggestion: closest(value, names()),
+        candidates: names().map(str::to_string).collect(),
+    })
+}
+
+/// Parses a date value to the inclusive range of days it covers.
+fn date_range(
+    field: Field,
+    value: &str,
+    today: NaiveDate,
+) -> Result<(Option<NaiveDate>, Option<NaiveDate>), QueryError> {
+    if let Some((from, to)) = value.split_once("..") {
+        let bound = |part: &str| {
+            if part.is_empty() {
+                Ok(None)
+            } else {
+                date(field, part, today).map(Some)
+            }
+        };
+        return Ok((bound(from)?, bound(to)?));
+    }
+    let date = date(field, value, today)?;
+    Ok((Some(date), Some(date)))
+}
+
+fn date(field: Field, value: &str, today: NaiveDate) -> Result<NaiveDate, QueryError> {
+    let error = || {
+        invalid(
+            field,
+            value,
+            "expected YYYY-MM-DD, `today`, `yesterday`, `tomorrow` or an offset such as `-7d`",
+        )
+    };
+    match value.to_ascii_lowercase().as_str() {
+        "today" => return Ok(today),
+        "yesterday" => return today.checked_sub_days(Days::new(1)).ok_or_else(error),
+        "tomorrow" => return today.checked_add_days(Days::new(1)).ok_or_else(error),
+        _ => {}
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date);
+    }
+
+    let (sign, rest) = match value.split_at_checked(1) {
+        Some(("-", rest)) => (-1, rest),
+        Some(("+", rest)) => (1, rest),
+        _ => return Err(error()),
+    };
+    let Some((amount, unit)) = rest.split_at_checked(rest.len().saturating_sub(1)) else {
+        return Err(error());
+    };
+    let amount: u32 = amount.parse().map_err(|_| error())?;
+    let date = match (sign, unit) {
+        (-1, "d") => today.checked_sub_days(Days::new(amount.into())),
+        (_, "d") => today.checked_add_days(Days::new(amount.into())),
+        (-1, "w") => today.checked_sub_days(Days::new(u64::from(amount) * 7)),
+        (_, "w") => today.checked_add_days(Days::new(u64::from(amount) * 7)),
+        (-1, "m") => today.checked_sub_months(Months::new(amount)),
+        (_, "m") => today.checked_add_months(Months::new(amount)),
+        _ => None,
+    };
+    date.ok_or_else(error)
+}
+
+fn api_date(date: NaiveDate) -> ApiDate {
+    ApiDate::from(date.and_time(chrono::NaiveTime::MIN).and_utc())
+}
@@ -0,0 +1,382 @@
+use super::error::{QueryError, closest};
+
+/// A field that can appear on the left of a query condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    Project,
+    Status,
+    Assignee,
+    CreatedBy,
+    IssueType,
+    Category,
+    Milestone,
+    Version,
+    Priority,
+    Resolution,
+    Parent,
+    Id,
+    Is,
+    Has,
+    Created,
+    Updated,
+    StartDate,
+    DueDate,
+    Keyword,
+    Sort,
+    Order,
+    Count,
+    Offset,
+}
+
+/// Field names and their aliases, the first one for each field being canonical.
+const FIELD_NAMES: &[(&str, Field)] = &[
+    ("project", Field::Project),
+    ("status", Field::Status),
+    ("assignee", Field::Assignee),
+    ("created_by", Field::CreatedBy),
+    ("author", Field::CreatedBy),
+    ("type", Field::IssueType),
+    ("issue_type", Field::IssueType),
+    ("category", Field::Category),
+    ("milestone", Field::Milestone),
+    ("version", Field::Version),
+    ("priority", Field::Priority),
+    ("resolution", Field::Resolution),
+    ("parent", Field::Parent),
+    ("id", Field::Id),
+    ("is", Field::Is),
+    ("has", Field::Has),
+    ("created", Field::Created),
+    ("updated", Field::Updated),
+    ("start", Field::StartDate),
+    ("start_date", Field::StartDate),
+    ("due", Field::DueDate),
+    ("due_date", Field::DueDate),
+    ("keyword", Field::Keyword),
+    ("sort", Field::Sort),
+    ("order", Field::Order),
+    ("count", Field::Count),
+    ("limit", Field::Count),
+    ("offset", Field::Offset),
+];
+
+impl Field {
+    /// Looks up a field by name or alias, ignoring case.
+    pub fn from_name(name: &str) -> Option<Field> {
+        FIELD_NAMES
+            .iter()
+            .find(|(field_name, _)| field_name.eq_ignore_ascii_case(name))
+            .map(|(_, field)| *field)
+    }
+
+    /// Returns the canonical name of the field.
+    pub fn name(self) -> &'static str {
+        FIELD_NAMES
+            .iter()
+            .find(|(_, field)| *field == self)
+            .map(|(name, _)| *name)
+            .unwrap_or_default()
+    }
+
+    /// Returns true for fields compared with `<`, `<=`, `>` and `>=`.
+    pub fn is_date(self) -> bool {
+        matches!(
+            self,
+            Field::Created | Field::Updated | Field::StartDate | Field::DueDate
+        )
+    }
+
+    /// Returns true for fields whose values are defined per project.
+    pub fn is_project_scoped(self) -> bool {
+        matches!(
+            self,
+            Field::Status | Field::IssueType | Field::Category | Field::Milestone | Field::Version
+        )
+    }
+}
+
+/// The operator between a field and its values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `:`
+    Eq,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+impl Operator {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Operator::Eq => ":",
+            Operator::Lt => "<",
+            Operator::Le => "<=",
+            Operator::Gt => ">",
+            Operator::Ge => ">=",
+        }
+    }
+}
+
+/// One condition of a query, such as `-status:Closed,Resolved` or a bare keyword.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Term {
+    pub field: Field,
+    pub operator: Operator,
+    pub values: Vec<String>,
+    pub negated: bool,
+    /// 1-based character column where the term starts.
+    pub column: usize,
+}
+
+pub(crate) fn parse(input: &str) -> Result<Vec<Term>, QueryError> {
+    let mut scanner = Scanner { input, pos: 0 };
+    let mut terms = Vec::new();
+    loop {
+        scanner.skip_whitespace();
+        if scanner.peek().is_none() {
+            return Ok(terms);
+        }
+        terms.push(scanner.term()?);
+    }
+}
+
+struct Scanner<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn column(&self, pos: usize) -> usize {
+        self.input[..pos].chars().count() + 1
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn syntax_error(&self, pos: usize, message: impl Into<String>) -> QueryError {
+        QueryError::Syntax {
+            column: self.column(pos),
+            message: message.into(),
+        }
+    }
+
+    fn term(&mut self) -> Result<Term, QueryError> {
+        let start = self.pos;
+        let negated = self.eat('-');
+        let name_start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.bump();
+        }
+        let name = &self.input[name_start..self.pos];
+
+        if !name.is_empty()
+            && let Some(operator) = self.operator()
+        {
+            let field = Field::from_name(name).ok_or_else(|| QueryError::UnknownField {
+                field: name.to_string(),
+                column: self.column(name_start),
+                suggestion: closest(name, FIELD_NAMES.iter().map(|(name, _)| *name)),
+            })?;
+            if operator != Operator::Eq && !field.is_date() {
+                return Err(self.syntax_error(
+                    start,
+                    format!(
+                        "`{}` only works with dates; use `{}:`",
+                        operator.as_str(),
+                        field.name()
+                    ),
+                ));
+            }
+            let values = self.values(field)?;
+            return Ok(Term {
+                field,
+                operator,
+                values,
+                negated,
+                column: self.column(start),
+            });
+        }
+
+        if negated {
+            return Err(self.syntax_error(start, "keywords cannot be excluded"));
+        }
+        self.pos = start;
+        let word = if self.peek() == Some('"') {
+            self.quoted()?
+        } else {
+            self.bare(false)
+        };
+        Ok(Term {
+            field: Field::Keyword,
+            operator: Operator::Eq,
+            values: vec![word],
+            negated: false,
+            column: self.column(start),
+        })
+    }
+
+    fn operator(&mut self) -> Option<Operator> {
+        let operator = match self.peek()? {
+            ':' => Operator::Eq,
+            '<' => Operator::Lt,
+            '>' => Operator::Gt,
+            _ => return None,
+        };
+        self.bump();
+        Some(match operator {
+            Operator::Lt if self.eat('=') => Operator::Le,
+            Operator::Gt if self.eat('=') => Operator::Ge,
+            operator => operator,
+        })
+    }
+
+    fn values(&mut self, field: Field) -> Result<Vec<String>, QueryError> {
+        let mut values = Vec::new();
+        loop {
+            let start = self.pos;
+            let value = if self.peek() == Some('"') {
+                self.quoted()?
+            } else {
+                self.bare(true)
+            };
+            if value.is_empty() {
+                return Err(
+                    self.syntax_error(start, format!("missing value for `{}`", field.name()))
+                );
+            }
+            values.push(value);
+            if !self.eat(',') {
+                break;
+            }
+        }
+        match self.peek() {
+            Some(c) if !c.is_whitespace() => Err(self.syntax_error(
+                self.pos,
+                format!("unexpected `{c}` after the value of `{}`", field.name()),
+            )),
+            _ => Ok(values),
+        }
+    }
+
+    fn bare(&mut self, stop_at_comma: bool) -> String {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| !(c.is_whitespace() || (stop_at_comma && c == ',')))
+        {
+            self.bump();
+        }
+        self.input[start..self.pos].to_string()
+    }
+
+    fn quoted(&mut self) -> Result<String, QueryError> {
+        let start = self.pos;
+        self.bump();
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.bump() {
+                    Some(c) => value.push(c),
+                    None => break,
+                },
+                Some(c) => value.push(c),
+                None => break,
+            }
+        }
+        Err(self.syntax_error(start, "unterminated quoted string"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn term(field: Field, operator: Operator, values: &[&str], column: usize) -> Term {
+        Term {
+            field,
+            operator,
+            values: values.iter().map(|v| v.to_string()).collect(),
+            negated: false,
+            column,
+        }
+    }
+
+    #[test]
+    fn test_parse_terms() {
+        let terms = parse(r#"project:WEB status:"In Progress",Open due<2026-11-01 login"#).unwrap();
+        assert_eq!(
+            terms,
+            [
+                term(Field::Project, Operator::Eq, &["WEB"], 1),
+                term(Field::Status, Operator::Eq, &["In Progress", "Open"], 13),
+                term(Field::DueDate, Operator::Lt, &["2026-11-01"], 39),
+                term(Field::Keyword, Operator::Eq, &["login"], 54),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_negation_operators_and_aliases() {
+        let terms = parse(r#"-STATUS:Closed updated>=-7d author:@me "two words""#).unwrap();
+        assert!(terms[0].negated);
+        assert_eq!(terms[0].field, Field::Status);
+        assert_eq!(terms[1].operator, Operator::Ge);
+        assert_eq!(terms[1].values, ["-7d"]);
+        assert_eq!(terms[2].field, Field::CreatedBy);
+        assert_eq!(terms[3].values, ["two words"]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("statsu:Open").unwrap_err(),
+            QueryError::UnknownField {
+                field: "statsu".to_string(),
+                column: 1,
+                suggestion: Some("status".to_string()),
+            }
+        );
+        assert_eq!(
+            parse(r#"keyword:"open"#).unwrap_err().to_string(),
+            "syntax error at column 9: unterminated quoted string"
+        );
+        assert_eq!(
+            parse("status<Open").unwrap_err().to_string(),
+            "syntax error at column 1: `<` only works with dates; use `status:`"
+        );
+        assert!(parse("status:").is_err());
+        assert!(parse("-login").is_err());
+        assert!(parse(r#"status:"Open"x"#).is_err());
+    }
+}
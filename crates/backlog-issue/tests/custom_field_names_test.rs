@@ -0,0 +1,147 @@
+use backlog_core::identifier::{CustomFieldId, CustomFieldItemId};
+use backlog_domain_models::CustomFieldType;
+use backlog_issue::CustomFieldInput;
+use backlog_issue::custom_field_names::{
+    CustomFieldNameError, custom_fields_json_schema, resolve_custom_field_names,
+};
+use chrono::NaiveDate;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+fn definitions() -> Vec<CustomFieldType> {
+    let items = json!([
+        {"id": 11, "name": "Low", "displayOrder": 0},
+        {"id": 12, "name": "High", "displayOrder": 1}
+    ]);
+    let field = |id: u32, type_id: u32, name: &str, extra: Value| {
+        let mut field = json!({
+            "id": id,
+            "projectId": 1,
+            "typeId": type_id,
+            "name": name,
+            "description": "",
+            "required": false,
+            "displayOrder": id
+        });
+        field
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        field
+    };
+    serde_json::from_value(json!([
+        field(1, 1, "Note", json!({})),
+        field(2, 3, "Points", json!({"min": 0.0, "max": 13.0})),
+        field(3, 4, "Release", json!({})),
+        field(4, 5, "Impact", json!({"items": items, "allowInput": false})),
+        field(5, 6, "Tags", json!({"items": items, "allowInput": true})),
+        field(6, 7, "Checks", json!({"items": items})),
+        field(7, 8, "Choice", json!({"items": items, "allowInput": true})),
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_resolve_by_name() {
+    let values = HashMap::from([
+        ("Note".to_string(), json!("text")),
+        ("Points".to_string(), json!(5)),
+        ("Release".to_string(), json!("2026-10-18")),
+        ("Impact".to_string(), json!("High")),
+        (
+            "Tags".to_string(),
+            json!({"items": ["Low", "High"], "other": "More"}),
+        ),
+        ("Checks".to_string(), json!(["Low"])),
+        ("Choice".to_string(), json!({"name": "Low", "other": "Why"})),
+    ]);
+    let resolved = resolve_custom_field_names(&definitions(), values).unwrap();
+
+    assert_eq!(
+        resolved,
+        HashMap::from([
+            (CustomFieldId::new(1), CustomFieldInput::Text("text".into())),
+            (CustomFieldId::new(2), CustomFieldInput::Numeric(5.0)),
+            (
+                CustomFieldId::new(3),
+                CustomFieldInput::Date(NaiveDate::from_ymd_opt(2026, 10, 18).unwrap())
+            ),
+            (
+                CustomFieldId::new(4),
+                CustomFieldInput::SingleList {
+                    id: CustomFieldItemId::new(12),
+                    other_value: None
+                }
+            ),
+            (
+                CustomFieldId::new(5),
+                CustomFieldInput::MultipleList {
+                    ids: vec![CustomFieldItemId::new(11), CustomFieldItemId::new(12)],
+                    other_value: Some("More".into())
+                }
+            ),
+            (
+                CustomFieldId::new(6),
+                CustomFieldInput::CheckBox(vec![CustomFieldItemId::new(11)])
+            ),
+            (
+                CustomFieldId::new(7),
+                CustomFieldInput::Radio {
+                    id: CustomFieldItemId::new(11),
+                    other_value: Some("Why".into())
+                }
+            ),
+        ])
+    );
+}
+
+#[test]
+fn test_resolve_errors() {
+    let definitions = definitions();
+    let resolve = |name: &str, value: Value| {
+        resolve_custom_field_names(&definitions, [(name.to_string(), value)]).unwrap_err()
+    };
+
+    assert_eq!(
+        resolve("Missing", json!("x")),
+        CustomFieldNameError::UnknownField("Missing".into())
+    );
+    assert_eq!(
+        resolve("Impact", json!("Medium")).to_string(),
+        "Custom field 'Impact': option 'Medium' not found. Available options: 'Low', 'High'"
+    );
+    assert_eq!(
+        resolve("Points", json!("five")).to_string(),
+        "Custom field 'Points' expects a numeric value"
+    );
+    assert_eq!(
+        resolve("Checks", json!("Low")).to_string(),
+        "Custom field 'Checks' expects an array of strings"
+    );
+}
+
+#[test]
+fn test_json_schema() {
+    let schema = custom_fields_json_schema(&definitions());
+    let properties = &schema["properties"];
+
+    assert_eq!(schema["additionalProperties"], json!(false));
+    assert_eq!(properties["$schema"], json!({"type": "string"}));
+    assert_eq!(properties["Note"]["type"], "string");
+    assert_eq!(properties["Points"]["maximum"], 13.0);
+    assert_eq!(properties["Release"]["format"], "date");
+    assert_eq!(
+        properties["Impact"]["anyOf"][0],
+        json!({"type": "string", "enum": ["Low", "High"]})
+    );
+    // "other" is only offered where the field allows free input
+    assert!(properties["Impact"]["anyOf"][1]["properties"]["other"].is_null());
+    assert_eq!(
+        properties["Tags"]["anyOf"][1]["properties"]["other"],
+        json!({"type": "string"})
+    );
+    assert_eq!(
+        properties["Checks"]["items"]["enum"],
+        json!(["Low", "High"])
+    );
+}
@@ -0,0 +1,213 @@
+use backlog_core::identifier::{CustomFieldId, CustomFieldItemId, IssueTypeId};
+use backlog_domain_models::CustomFieldType;
+use backlog_issue::CustomFieldInput;
+use backlog_issue::custom_field_validation::{CustomFieldValidator, CustomFieldViolation};
+use chrono::NaiveDate;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+fn field(id: u32, type_id: u32, name: &str, required: bool, extra: Value) -> Value {
+    let mut field = json!({
+        "id": id,
+        "projectId": 1,
+        "typeId": type_id,
+        "name": name,
+        "description": "",
+        "required": required,
+        "applicableIssueTypes": [],
+        "displayOrder": id
+    });
+    field
+        .as_object_mut()
+        .unwrap()
+        .extend(extra.as_object().unwrap().clone());
+    field
+}
+
+fn definitions() -> Vec<CustomFieldType> {
+    let items = json!([
+        {"id": 11, "name": "Low", "displayOrder": 0},
+        {"id": 12, "name": "High", "displayOrder": 1}
+    ]);
+    serde_json::from_value(json!([
+        field(1, 1, "Note", true, json!({})),
+        field(2, 3, "Points", false, json!({"min": 0.0, "max": 13.0})),
+        field(
+            3,
+            4,
+            "Release",
+            false,
+            json!({"min": "2026-01-01", "max": "2026-12-31"})
+        ),
+        field(
+            4,
+            5,
+            "Impact",
+            true,
+            json!({"items": items, "allowInput": false})
+        ),
+        field(
+            5,
+            6,
+            "Tags",
+            false,
+            json!({"items": items, "allowInput": true})
+        ),
+        field(
+            6,
+            1,
+            "Bug only",
+            true,
+            json!({"applicableIssueTypes": [100]})
+        ),
+    ]))
+    .unwrap()
+}
+
+fn date(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+}
+
+#[test]
+fn test_valid_values() {
+    let definitions = definitions();
+    let values = HashMap::from([
+        (CustomFieldId::new(1), CustomFieldInput::Text("ok".into())),
+        (CustomFieldId::new(2), CustomFieldInput::Numeric(13.0)),
+        (
+            CustomFieldId::new(3),
+            CustomFieldInput::Date(date("2026-10-18")),
+        ),
+        (
+            CustomFieldId::new(4),
+            CustomFieldInput::SingleList {
+                id: CustomFieldItemId::new(12),
+                other_value: None,
+            },
+        ),
+        (
+            CustomFieldId::new(5),
+            CustomFieldInput::MultipleList {
+                ids: vec![CustomFieldItemId::new(11), CustomFieldItemId::new(12)],
+                other_value: Some("Other".into()),
+            },
+        ),
+    ]);
+    let result = CustomFieldValidator::new(&definitions)
+        .issue_type(IssueTypeId::new(200))
+        .check_required(true)
+        .validate(&values);
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn test_reports_all_violations_in_display_order() {
+    let definitions = definitions();
+    let values = HashMap::from([
+        (CustomFieldId::new(99), CustomFieldInput::Text("?".into())),
+        (CustomFieldId::new(2), CustomFieldInput::Numeric(21.0)),
+        (
+            CustomFieldId::new(3),
+            CustomFieldInput::Date(date("2025-12-31")),
+        ),
+        (
+            CustomFieldId::new(4),
+            CustomFieldInput::MultipleList {
+                ids: vec![CustomFieldItemId::new(11), CustomFieldItemId::new(13)],
+                other_value: Some("Other".into()),
+            },
+        ),
+        (CustomFieldId::new(5), CustomFieldInput::Numeric(1.0)),
+    ]);
+    let error = CustomFieldValidator::new(&definitions)
+        .check_required(true)
+        .validate(&values)
+        .unwrap_err();
+
+    assert_eq!(
+        error.violations,
+        [
+            CustomFieldViolation::Required {
+                field: "Note".into()
+            },
+            CustomFieldViolation::AboveMaximum {
+                field: "Points".into(),
+                value: 21.0,
+                max: 13.0
+            },
+            CustomFieldViolation::DateBeforeMinimum {
+                field: "Release".into(),
+                value: date("2025-12-31"),
+                min: date("2026-01-01")
+            },
+            CustomFieldViolation::SingleItemOnly {
+                field: "Impact".into(),
+                count: 2
+            },
+            CustomFieldViolation::UnknownItem {
+                field: "Impact".into(),
+                item_id: CustomFieldItemId::new(13),
+                available: "Low (11), High (12)".into()
+            },
+            CustomFieldViolation::OtherNotAllowed {
+                field: "Impact".into()
+            },
+            CustomFieldViolation::TypeMismatch {
+                field: "Tags".into(),
+                expected: "list items"
+            },
+            CustomFieldViolation::Required {
+                field: "Bug only".into()
+            },
+            CustomFieldViolation::UnknownField {
+                field_id: CustomFieldId::new(99)
+            },
+        ]
+    );
+    assert!(
+        error
+            .to_string()
+            .starts_with("invalid custom field values:\n  - custom field 'Note' is required\n")
+    );
+}
+
+#[test]
+fn test_issue_type_and_update_rules() {
+    let definitions = definitions();
+
+    // Updates leave omitted fields alone, but cannot clear a required one.
+    let values = HashMap::from([(CustomFieldId::new(1), CustomFieldInput::Text(" ".into()))]);
+    let error = CustomFieldValidator::new(&definitions)
+        .validate(&values)
+        .unwrap_err();
+    assert_eq!(
+        error.violations,
+        [CustomFieldViolation::Required {
+            field: "Note".into()
+        }]
+    );
+
+    // Fields limited to other issue types are neither required nor accepted.
+    let values = HashMap::from([
+        (CustomFieldId::new(1), CustomFieldInput::Text("ok".into())),
+        (
+            CustomFieldId::new(4),
+            CustomFieldInput::Radio {
+                id: CustomFieldItemId::new(11),
+                other_value: None,
+            },
+        ),
+        (CustomFieldId::new(6), CustomFieldInput::Text("x".into())),
+    ]);
+    let error = CustomFieldValidator::new(&definitions)
+        .issue_type(IssueTypeId::new(200))
+        .check_required(true)
+        .validate(&values)
+        .unwrap_err();
+    assert_eq!(
+        error.violations,
+        [CustomFieldViolation::NotApplicable {
+            field: "Bug only".into()
+        }]
+    );
+}
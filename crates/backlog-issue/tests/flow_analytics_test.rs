@@ -0,0 +1,272 @@
+mod common;
+
+use backlog_issue::analytics::{FlowOptions, FlowReport, IssueFlow};
+use backlog_issue::history::IssueHistory;
+use chrono::{DateTime, NaiveDate};
+use common::*;
+use serde_json::Value;
+
+const USER: &str = r#"{
+    "id": 1, "userId": "admin", "name": "Admin", "roleType": 1,
+    "lang": null, "mailAddress": "admin@example.com", "lastLoginTime": null
+}"#;
+
+fn issue_json(id: u32, status: &str, created: &str) -> Value {
+    let user: Value = serde_json::from_str(USER).unwrap();
+    json!({
+        "id": id, "projectId": 1, "issueKey": format!("TEST-{id}"), "keyId": id,
+        "issueType": {"id": 2, "projectId": 1, "name": "Task", "color": "#7ea800", "displayOrder": 0},
+        "summary": format!("Issue {id}"), "description": "",
+        "resolution": null, "priority": {"id": 3, "name": "Normal"},
+        "status": {"id": 1, "projectId": 1, "name": status, "color": "#ed8077", "displayOrder": 1000},
+        "assignee": null, "category": [], "versions": [], "milestone": [],
+        "startDate": null, "dueDate": null, "estimatedHours": null, "actualHours": null,
+        "parentIssueId": null, "createdUser": user, "created": created,
+        "updatedUser": user, "updated": created
+    })
+}
+
+fn status_comment(id: u32, created: &str, from: &str, to: &str) -> Value {
+    json!({
+        "id": id,
+        "content": null,
+        "changeLog": [{
+            "field": "status", "originalValue": from, "newValue": to,
+            "attachmentInfo": null, "attributeInfo": null, "notificationInfo": null
+        }],
+        "createdUser": serde_json::from_str::<Value>(USER).unwrap(),
+        "created": created,
+        "updated": created,
+        "stars": [],
+        "notifications": []
+    })
+}
+
+fn flow(issue: Value, comments: Vec<Value>, now: &str) -> IssueFlow {
+    let issue: Issue = serde_json::from_value(issue).unwrap();
+    let comments: Vec<Comment> = serde_json::from_value(Value::Array(comments)).unwrap();
+    IssueFlow::new(
+        &issue,
+        &IssueHistory::from_comments(&comments),
+        &FlowOptions::default(),
+        now.parse::<DateTime<Utc>>().unwrap(),
+    )
+    .unwrap()
+}
+
+fn time_in(flow: &IssueFlow) -> Vec<(&str, f64)> {
+    flow.time_in_status
+        .iter()
+        .map(|t| (t.status.as_str(), t.days))
+        .collect()
+}
+
+#[test]
+fn test_closed_issue_flow() {
+    // Open 1 day, In Progress 2 days, Resolved 1 day, back In Progress 1 day, then Closed
+    let flow = flow(
+        issue_json(1, "Closed", "2024-01-01T00:00:00Z"),
+        vec![
+            status_comment(1, "2024-01-02T00:00:00Z", "Open", "In Progress"),
+            status_comment(2, "2024-01-04T00:00:00Z", "In Progress", "Resolved"),
+            status_comment(3, "2024-01-05T00:00:00Z", "Resolved", "In Progress"),
+            status_comment(4, "2024-01-06T00:00:00Z", "In Progress", "Closed"),
+        ],
+        "2024-02-01T00:00:00Z",
+    );
+
+    assert_eq!(
+        time_in(&flow),
+        [("Open", 1.0), ("In Progress", 3.0), ("Resolved", 1.0)]
+    );
+    assert_eq!(
+        flow.started,
+        Some(Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap())
+    );
+    assert_eq!(
+        flow.closed,
+        Some(Utc.with_ymd_and_hms(2024, 1, 6, 0, 0, 0).unwrap())
+    );
+    assert_eq!(flow.lead_time_days, Some(5.0));
+    assert_eq!(flow.cycle_time_days, Some(4.0));
+}
+
+#[test]
+fn test_open_issue_flow() {
+    // Never changed: all time so far is in the current status
+    let untouched = flow(
+        issue_json(2, "Open", "2024-01-01T00:00:00Z"),
+        vec![],
+        "2024-01-03T12:00:00Z",
+    );
+    assert_eq!(time_in(&untouched), [("Open", 2.5)]);
+    assert_eq!(untouched.closed, None);
+    assert_eq!(untouched.lead_time_days, None);
+
+    // Reopened after closing: not closed, and the closed interval counts
+    let reopened = flow(
+        issue_json(3, "処理中", "2024-01-01T00:00:00Z"),
+        vec![
+            status_comment(1, "2024-01-02T00:00:00Z", "未対応", "完了"),
+            status_comment(2, "2024-01-04T00:00:00Z", "完了", "処理中"),
+        ],
+        "2024-01-05T00:00:00Z",
+    );
+    assert_eq!(
+        time_in(&reopened),
+        [("未対応", 1.0), ("完了", 2.0), ("処理中", 1.0)]
+    );
+    assert_eq!(reopened.closed, None);
+    assert_eq!(reopened.cycle_time_days, None);
+}
+
+#[test]
+fn test_flow_report() {
+    let now = "2024-02-01T00:00:00Z";
+    let report = FlowReport::new(vec![
+        flow(
+            issue_json(1, "Closed", "2024-01-01T00:00:00Z"),
+            vec![
+                status_comment(1, "2024-01-02T00:00:00Z", "Open", "In Progress"),
+                status_comment(2, "2024-01-03T00:00:00Z", "In Progress", "Closed"),
+            ],
+            now,
+        ),
+        // Closed straight from Open, so it has a lead time but no cycle time
+        flow(
+            issue_json(2, "Closed", "2024-01-01T00:00:00Z"),
+            vec![status_comment(1, "2024-01-20T00:00:00Z", "Open", "Closed")],
+            now,
+        ),
+        flow(issue_json(3, "Open", "2024-01-31T00:00:00Z"), vec![], now),
+    ]);
+
+    assert_eq!(report.total_issues, 3);
+    assert_eq!(report.closed_issues, 2);
+    let lead_time = report.lead_time.as_ref().unwrap();
+    assert_eq!(lead_time.count, 2);
+    assert_eq!(lead_time.mean_days, 10.5);
+    assert_eq!(lead_time.max_days, 19.0);
+    assert_eq!(report.cycle_time.as_ref().unwrap().count, 1);
+
+    let weeks: Vec<(NaiveDate, usize)> = report
+        .throughput
+        .iter()
+        .map(|w| (w.week, w.closed))
+        .collect();
+    let monday = |day| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+    assert_eq!(weeks, [(monday(1), 1), (monday(8), 0), (monday(15), 1)]);
+
+    assert_eq!(
+        report.throughput_csv(),
+        "week,closed\n2024-01-01,1\n2024-01-08,0\n2024-01-15,1\n"
+    );
+    let csv = report.issues_csv();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "issue_key,summary,status,created,started,closed,lead_time_days,cycle_time_days,\
+         days in Open,days in In Progress"
+    );
+    assert_eq!(
+        lines.next().unwrap(),
+        "TEST-1,Issue 1,Closed,2024-01-01T00:00:00+00:00,2024-01-02T00:00:00+00:00,\
+         2024-01-03T00:00:00+00:00,2.00,1.00,1.00,1.00"
+    );
+    assert_eq!(
+        lines.nth(1).unwrap(),
+        "TEST-3,Issue 3,Open,2024-01-31T00:00:00+00:00,,,,,1.00,"
+    );
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["timeInStatus"][0]["status"], "Open");
+    assert_eq!(json["timeInStatus"][0]["count"], 3);
+    assert_eq!(json["issues"][1]["cycleTimeDays"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_get_flow_report() {
+    let mock_server = wiremock::MockServer::start().await;
+    let issue_api = setup_issue_api(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/issues"))
+        .and(query_param("offset", "0"))
+        .and(query_param("count", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([issue_json(
+            1,
+            "Closed",
+            "2024-01-01T00:00:00Z"
+        )])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/issues/1/comments"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([status_comment(
+                1,
+                "2024-01-08T00:00:00Z",
+                "Open",
+                "Closed"
+            )])),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let params = backlog_issue::GetIssueListParamsBuilder::default()
+        .build()
+        .unwrap();
+    let report = issue_api
+        .get_flow_report(params, &FlowOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(report.closed_issues, 1);
+    assert_eq!(report.issues[0].lead_time_days, Some(7.0));
+    assert!(!report.truncated);
+}
+
+#[tokio::test]
+async fn test_get_flow_report_stops_at_max_issues() {
+    let mock_server = wiremock::MockServer::start().await;
+    let issue_api = setup_issue_api(&mock_server).await;
+
+    // One issue past the cap is asked for, to tell whether more matched
+    Mock::given(method("GET"))
+        .and(path("/api/v2/issues"))
+        .and(query_param("offset", "0"))
+        .and(query_param("count", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            issue_json(1, "Closed", "2024-01-01T00:00:00Z"),
+            issue_json(2, "Open", "2024-01-02T00:00:00Z"),
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/issues/1/comments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/issues/2/comments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let params = backlog_issue::GetIssueListParamsBuilder::default()
+        .build()
+        .unwrap();
+    let options = FlowOptions {
+        max_issues: Some(1),
+        ..FlowOptions::default()
+    };
+    let report = issue_api.get_flow_report(params, &options).await.unwrap();
+
+    assert_eq!(report.total_issues, 1);
+    assert!(report.truncated);
+}
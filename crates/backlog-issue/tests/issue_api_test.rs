@@ -1,10 +1,16 @@
+#![allow(deprecated)]
+
 mod common;
-use backlog_core::{IssueKey, identifier::Identifier};
+use backlog_core::{
+    IssueKey, Order,
+    identifier::{CustomFieldId, Identifier},
+};
 use common::*;
 
 use backlog_issue::{
-    CommentOrder, CountCommentParams, GetAttachmentListParams, GetCommentListParamsBuilder,
-    GetCommentParams, GetIssueListParamsBuilder, GetParticipantListParams, GetSharedFileListParams,
+    CommentOrder, CountCommentParams, CountIssueParamsBuilder, GetAttachmentListParams,
+    GetCommentListParamsBuilder, GetCommentParams, GetIssueListParamsBuilder,
+    GetParticipantListParams, GetSharedFileListParams, IssueSort,
 };
 
 fn create_mock_user(id: u32, name: &str) -> User {
@@ -171,6 +177,28 @@ async fn test_get_issue_list_with_project_id_param() {
     assert_eq!(issues[0].project_id, project_id);
 }
 
+#[tokio::test]
+async fn test_get_issue_list_with_sort_and_order() {
+    let mock_server = wiremock::MockServer::start().await;
+    let issue_api = setup_issue_api(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/issues"))
+        .and(query_param("sort", "customField_12"))
+        .and(query_param("order", "asc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&mock_server)
+        .await;
+
+    let params = GetIssueListParamsBuilder::default()
+        .sort(IssueSort::CustomField(CustomFieldId::new(12)))
+        .order(Order::Asc)
+        .build()
+        .unwrap();
+    let issues = issue_api.get_issue_list(params).await.unwrap();
+    assert!(issues.is_empty());
+}
+
 #[tokio::test]
 async fn test_get_issue_list_server_error() {
     let mock_server = wiremock::MockServer::start().await;
@@ -417,6 +445,30 @@ async fn test_count_comment_success() {
     assert_eq!(response.count, 5);
 }
 
+#[tokio::test]
+async fn test_count_issue_success() {
+    let mock_server = wiremock::MockServer::start().await;
+    let issue_api = setup_issue_api(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/issues/count"))
+        .and(query_param("projectId[]", "123"))
+        .and(query_param("keyword", "login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "count": 42
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let params = CountIssueParamsBuilder::default()
+        .project_id(vec![ProjectId::new(123)])
+        .keyword("login")
+        .build()
+        .unwrap();
+    let response = issue_api.count_issue(params).await.unwrap();
+    assert_eq!(response.count, 42);
+}
+
 #[tokio::test]
 async fn test_get_comment_success() {
     let mock_server = wiremock::MockServer::start().await;
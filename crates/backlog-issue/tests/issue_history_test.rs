@@ -0,0 +1,150 @@
+mod common;
+
+use backlog_core::identifier::{AttachmentId, CommentId, CustomFieldId};
+use backlog_issue::history::{IssueField, IssueHistory};
+use backlog_issue::models::Comment;
+use common::*;
+use serde_json::Value;
+
+fn comment(id: u32, created: &str, change_log: Value) -> Value {
+    json!({
+        "id": id,
+        "content": null,
+        "changeLog": change_log,
+        "createdUser": {
+            "id": 1, "userId": "admin", "name": "Admin", "roleType": 1,
+            "lang": null, "mailAddress": "admin@example.com", "lastLoginTime": null
+        },
+        "created": created,
+        "updated": created,
+        "stars": [],
+        "notifications": []
+    })
+}
+
+fn change(field: &str, from: Value, to: Value) -> Value {
+    json!({
+        "field": field, "originalValue": from, "newValue": to,
+        "attachmentInfo": null, "attributeInfo": null, "notificationInfo": null
+    })
+}
+
+#[test]
+fn test_history_from_comments() {
+    let comments: Vec<Comment> = serde_json::from_value(json!([
+        // Out of order on purpose: the history is sorted by creation time
+        comment(
+            3,
+            "2024-01-03T00:00:00Z",
+            json!([
+                change("status", json!("In Progress"), json!("Closed")),
+                {
+                    "field": "Severity", "originalValue": "Low", "newValue": "High",
+                    "attachmentInfo": null,
+                    "attributeInfo": {"id": 77, "typeId": 5},
+                    "notificationInfo": null
+                }
+            ])
+        ),
+        comment(
+            1,
+            "2024-01-01T00:00:00Z",
+            json!([
+                change("status", json!("Open"), json!("In Progress")),
+                change("assigner", json!(""), json!("Alice")),
+                change("limitDate", json!(null), json!("2024-02-01")),
+            ])
+        ),
+        comment(2, "2024-01-02T00:00:00Z", json!([])),
+        comment(
+            4,
+            "2024-01-04T00:00:00Z",
+            json!([{
+                "field": "attachment", "originalValue": null, "newValue": "spec.pdf",
+                "attachmentInfo": {"id": 9, "name": "spec.pdf"},
+                "attributeInfo": null, "notificationInfo": null
+            }])
+        ),
+    ]))
+    .unwrap();
+
+    let history = IssueHistory::from_comments(&comments);
+    let fields: Vec<&IssueField> = history.transitions.iter().map(|t| &t.field).collect();
+    assert_eq!(
+        fields,
+        [
+            &IssueField::Status,
+            &IssueField::Assignee,
+            &IssueField::DueDate,
+            &IssueField::Status,
+            &IssueField::CustomField {
+                id: CustomFieldId::new(77),
+                name: "Severity".into()
+            },
+            &IssueField::Attachment {
+                id: AttachmentId::new(9)
+            },
+        ]
+    );
+
+    let status: Vec<(Option<&str>, Option<&str>)> = history
+        .field("Status")
+        .map(|t| (t.from.as_deref(), t.to.as_deref()))
+        .collect();
+    assert_eq!(
+        status,
+        [
+            (Some("Open"), Some("In Progress")),
+            (Some("In Progress"), Some("Closed"))
+        ]
+    );
+
+    // Empty values are normalised to None
+    let assignee = history.field("assignee").next().unwrap();
+    assert_eq!(assignee.from, None);
+    assert_eq!(assignee.comment_id, CommentId::new(1));
+    assert_eq!(history.field("severity").count(), 1);
+    assert_eq!(
+        history.field("dueDate").next().unwrap().to.as_deref(),
+        Some("2024-02-01")
+    );
+}
+
+#[tokio::test]
+async fn test_get_issue_history_pages_through_comments() {
+    let mock_server = wiremock::MockServer::start().await;
+    let issue_api = setup_issue_api(&mock_server).await;
+
+    let first_page: Vec<Value> = (1..=100)
+        .map(|id| comment(id, "2024-01-01T00:00:00Z", json!([])))
+        .collect();
+    let second_page = vec![comment(
+        101,
+        "2024-01-02T00:00:00Z",
+        json!([change("status", json!("Open"), json!("Closed"))]),
+    )];
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/issues/TEST-1/comments"))
+        .and(query_param("minId", "101"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&second_page))
+        .with_priority(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/issues/TEST-1/comments"))
+        .and(query_param("order", "asc"))
+        .and(query_param("count", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&first_page))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let key: IssueIdOrKey = "TEST-1".parse().unwrap();
+    let history = issue_api.get_issue_history(key).await.unwrap();
+
+    assert_eq!(history.transitions.len(), 1);
+    assert_eq!(history.transitions[0].comment_id, CommentId::new(101));
+    assert_eq!(history.transitions[0].to.as_deref(), Some("Closed"));
+}
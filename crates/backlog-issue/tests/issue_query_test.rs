@@ -0,0 +1,276 @@
+use backlog_issue::query::{
+    Field, IssueQuery, Operator, ProjectData, QueryContext, QueryError, Term,
+};
+use backlog_issue::{GetIssueListParams, ParentChildCondition};
+use chrono::NaiveDate;
+use serde_json::{Value, json};
+
+fn from_json<T: serde::de::DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).unwrap()
+}
+
+fn user(id: u32, user_id: &str, name: &str) -> Value {
+    json!({
+        "id": id,
+        "userId": user_id,
+        "name": name,
+        "roleType": 1,
+        "lang": "ja",
+        "mailAddress": format!("{user_id}@example.com")
+    })
+}
+
+fn project(id: u32, key: &str, name: &str) -> ProjectData {
+    let status = |id: u32, name: &str| json!({"id": id, "projectId": 1, "name": name, "color": "#ed8077", "displayOrder": id * 1000});
+    let mut data = ProjectData::new(from_json(json!({
+        "id": id,
+        "projectKey": key,
+        "name": name,
+        "chartEnabled": false,
+        "subtaskingEnabled": true,
+        "projectLeaderCanEditProjectLeader": false,
+        "useWiki": true,
+        "useFileSharing": true,
+        "useWikiTreeView": false,
+        "useOriginalImageSizeAtWiki": false,
+        "textFormattingRule": "markdown",
+        "archived": false,
+        "displayOrder": 0,
+        "useDevAttributes": true
+    })));
+    data.statuses = from_json(json!([
+        status(1, "Open"),
+        status(2, "In Progress"),
+        status(3, "Resolved"),
+        status(4, "Closed")
+    ]));
+    data.issue_types = from_json(json!([
+        {"id": id * 10 + 1, "projectId": id, "name": "Bug", "color": "#990000", "displayOrder": 0},
+        {"id": id * 10 + 2, "projectId": id, "name": "Task", "color": "#7ea800", "displayOrder": 1}
+    ]));
+    data.milestones = from_json(json!([
+        {"id": id * 100, "projectId": id, "name": "v1.0", "archived": false, "displayOrder": 0}
+    ]));
+    data
+}
+
+fn context() -> QueryContext {
+    QueryContext {
+        today: NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+        me: Some(from_json(user(7, "me", "Myself"))),
+        projects: vec![
+            project(1, "WEB", "Web Site"),
+            project(2, "APP", "Mobile App"),
+        ],
+        priorities: from_json(json!([
+            {"id": 2, "name": "High"},
+            {"id": 3, "name": "Normal"},
+            {"id": 4, "name": "Low"}
+        ])),
+        resolutions: vec![],
+        users: from_json(json!([
+            user(7, "me", "Myself"),
+            user(8, "hanako", "Hanako Yamada")
+        ])),
+    }
+}
+
+fn compile(query: &str) -> Result<Vec<(String, String)>, QueryError> {
+    let params: GetIssueListParams = IssueQuery::parse(query)?.compile(&context())?;
+    Ok((&params).into())
+}
+
+fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+    expected
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_compile_full_query() {
+    let params = compile(
+        r#"project:WEB status:"In Progress" assignee:@me type:Bug due<2026-11-01 updated>-7d keyword:"login""#,
+    )
+    .unwrap();
+    assert_eq!(
+        params,
+        pairs(&[
+            ("projectId[]", "1"),
+            ("issueTypeId[]", "11"),
+            ("statusId[]", "2"),
+            ("assigneeId[]", "7"),
+            ("updatedSince", "2026-10-12"),
+            ("keyword", "login"),
+            ("dueDateUntil", "2026-10-31"),
+        ])
+    );
+}
+
+#[test]
+fn test_names_are_case_insensitive_and_span_projects() {
+    let params = compile("project:web,\"Mobile App\" type:bug milestone:V1.0").unwrap();
+    assert_eq!(
+        params,
+        pairs(&[
+            ("projectId[]", "1"),
+            ("projectId[]", "2"),
+            ("issueTypeId[]", "11"),
+            ("issueTypeId[]", "21"),
+            ("milestoneId[]", "100"),
+            ("milestoneId[]", "200"),
+        ])
+    );
+}
+
+#[test]
+fn test_master_data_can_be_given_by_id() {
+    let params = compile("project:WEB status:2 type:12 milestone:100 priority:4").unwrap();
+    assert_eq!(
+        params,
+        pairs(&[
+            ("projectId[]", "1"),
+            ("issueTypeId[]", "12"),
+            ("milestoneId[]", "100"),
+            ("statusId[]", "2"),
+            ("priorityId[]", "4"),
+        ])
+    );
+}
+
+#[test]
+fn test_negation_takes_the_complement() {
+    let params = compile("project:WEB -status:Closed,Resolved -priority:Low").unwrap();
+    assert_eq!(
+        params,
+        pairs(&[
+            ("projectId[]", "1"),
+            ("statusId[]", "1"),
+            ("statusId[]", "2"),
+            ("priorityId[]", "2"),
+            ("priorityId[]", "3"),
+        ])
+    );
+
+    let params = compile("author:hanako,me -author:@me").unwrap();
+    assert_eq!(params, pairs(&[("createdUserId[]", "8")]));
+}
+
+#[test]
+fn test_query_built_from_terms() {
+    let term = |field, operator, values: &[&str], negated| Term {
+        field,
+        operator,
+        values: values.iter().map(|v| v.to_string()).collect(),
+        negated,
+        column: 0,
+    };
+    let query = IssueQuery::from(vec![
+        term(Field::Project, Operator::Eq, &["web site"], false),
+        term(Field::Status, Operator::Eq, &["Closed", "Resolved"], true),
+        term(Field::Updated, Operator::Ge, &["-7d"], false),
+    ]);
+    let params: GetIssueListParams = query.compile(&context()).unwrap();
+    assert_eq!(
+        <Vec<(String, String)>>::from(&params),
+        compile("project:WEB -status:Closed,Resolved updated>=-7d").unwrap()
+    );
+}
+
+#[test]
+fn test_dates_and_list_options() {
+    let params = compile(
+        "created:2026-10-01..2026-10-15 start>=yesterday due:today sort:due_date order:ASC limit:50 offset:100 is:child has:attachment",
+    )
+    .unwrap();
+    assert_eq!(
+        params,
+        pairs(&[
+            ("parentChild", "2"),
+            ("attachment", "true"),
+            ("sort", "dueDate"),
+            ("order", "asc"),
+            ("offset", "100"),
+            ("count", "50"),
+            ("createdSince", "2026-10-01"),
+            ("createdUntil", "2026-10-15"),
+            ("startDateSince", "2026-10-17"),
+            ("dueDateSince", "2026-10-18"),
+            ("dueDateUntil", "2026-10-18"),
+        ])
+    );
+
+    let query = IssueQuery::parse("-is:child").unwrap();
+    assert_eq!(
+        query.compile(&context()).unwrap().parent_child_condition,
+        Some(ParentChildCondition::ExcludeChildIssue)
+    );
+}
+
+#[test]
+fn test_bare_words_become_the_keyword() {
+    let params = compile(r#"login "error page" id:12,13"#).unwrap();
+    assert_eq!(
+        params,
+        pairs(&[
+            ("keyword", "login error page"),
+            ("id[]", "12"),
+            ("id[]", "13")
+        ])
+    );
+}
+
+#[test]
+fn test_unknown_names_suggest_candidates() {
+    let error = compile(r#"project:WEB status:"In Progres""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unknown status `In Progres`; did you mean `In Progress`? \
+         (available: Open, In Progress, Resolved, Closed)"
+    );
+
+    let error = compile("project:WEBB").unwrap_err();
+    assert!(matches!(
+        error,
+        QueryError::UnknownName { kind: "project", ref suggestion, .. }
+            if suggestion.as_deref() == Some("WEB")
+    ));
+
+    let error = compile("assignee:hanak").unwrap_err();
+    assert!(error.to_string().contains("did you mean `hanako`?"));
+}
+
+#[test]
+fn test_invalid_queries() {
+    assert_eq!(
+        compile("status:Open").unwrap_err(),
+        QueryError::MissingProject {
+            field: "status".to_string()
+        }
+    );
+    assert!(matches!(
+        compile("due:next-week").unwrap_err(),
+        QueryError::InvalidValue { .. }
+    ));
+    assert!(compile("project:WEB -status:Open,\"In Progress\",Resolved,Closed").is_err());
+    assert!(compile("-project:WEB").is_err());
+    assert!(compile("count:500").is_err());
+    assert!(compile("sort:color").is_err());
+    assert!(compile("parent:WEB-1").is_err());
+}
+
+#[test]
+fn test_required_master_data() {
+    let query = IssueQuery::parse("assignee:@me priority:High").unwrap();
+    assert!(query.mentions_me());
+    assert!(!query.needs_users());
+
+    let query = IssueQuery::parse("-assignee:@me").unwrap();
+    assert!(query.needs_users());
+
+    let context = context();
+    let projects: Vec<_> = context.projects.into_iter().map(|p| p.project).collect();
+    let query = IssueQuery::parse("project:2").unwrap();
+    let resolved = query.resolve_projects(&projects).unwrap();
+    assert_eq!(resolved[0].name, "Mobile App");
+}
@@ -1,6 +1,7 @@
 mod common;
 use common::*;
 
+use backlog_core::Order;
 use backlog_issue::GetRecentlyViewedIssuesParamsBuilder;
 
 #[tokio::test]
@@ -97,7 +98,7 @@ async fn test_get_recently_viewed_issues_with_order_asc() {
         .await;
 
     let params = GetRecentlyViewedIssuesParamsBuilder::default()
-        .order("asc".to_string())
+        .order(Order::Asc)
         .build()
         .unwrap();
     let result = issue_api.get_recently_viewed_issues(params).await;
@@ -169,7 +170,7 @@ async fn test_get_recently_viewed_issues_all_params() {
         .await;
 
     let params = GetRecentlyViewedIssuesParamsBuilder::default()
-        .order("desc".to_string())
+        .order(Order::Desc)
         .offset(50u32)
         .count(100u32)
         .build()
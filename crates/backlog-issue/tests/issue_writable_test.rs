@@ -796,4 +796,103 @@ mod writable_tests {
         assert_eq!(attachment.name, "deleted_file.pdf");
         assert_eq!(attachment.size, 1024);
     }
+
+    fn mock_issue_json(summary: &str, updated: &str) -> serde_json::Value {
+        let user = serde_json::to_value(create_mock_user(1, "admin")).unwrap();
+        serde_json::json!({
+            "id": 10, "projectId": 1, "issueKey": "TEST-1", "keyId": 1,
+            "issueType": {"id": 2, "projectId": 1, "name": "Task", "color": "#7ea800", "displayOrder": 0},
+            "summary": summary, "description": "Description",
+            "resolution": null, "priority": {"id": 3, "name": "Normal"},
+            "status": {"id": 1, "projectId": 1, "name": "Open", "color": "#ed8077", "displayOrder": 1000},
+            "assignee": null, "category": [], "versions": [], "milestone": [],
+            "startDate": null, "dueDate": null, "estimatedHours": null, "actualHours": null,
+            "parentIssueId": null, "createdUser": user, "created": "2024-01-01T00:00:00Z",
+            "updatedUser": user, "updated": updated
+        })
+    }
+
+    #[tokio::test]
+    async fn test_update_issue_minimal_conflict_and_force() {
+        use backlog_issue::models::Issue;
+        use backlog_issue::{MinimalUpdateOutcome, UpdateIssueParamsBuilder};
+
+        let mock_server = MockServer::start().await;
+        let issue_api = IssueApi::new(setup_client(&mock_server).await);
+
+        let original: Issue =
+            serde_json::from_value(mock_issue_json("Old", "2024-01-02T00:00:00Z")).unwrap();
+        // Someone else changed the summary after `original` was read
+        let current = mock_issue_json("Changed elsewhere", "2024-01-03T00:00:00Z");
+        Mock::given(method("GET"))
+            .and(path("/api/v2/issues/TEST-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&current))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/api/v2/issues/TEST-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&current))
+            .mount(&mock_server)
+            .await;
+
+        let desired = || {
+            UpdateIssueParamsBuilder::default()
+                .issue_id_or_key("TEST-1".parse::<IssueIdOrKey>().unwrap())
+                .summary("Changed elsewhere")
+                .description("New description")
+                .build()
+                .unwrap()
+        };
+
+        let outcome = issue_api
+            .update_issue_minimal(&original, desired(), false)
+            .await
+            .unwrap();
+        assert!(
+            matches!(&outcome, MinimalUpdateOutcome::Conflict(issue) if issue.summary == "Changed elsewhere")
+        );
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests.iter().all(|r| r.method.as_str() == "GET"));
+
+        let outcome = issue_api
+            .update_issue_minimal(&original, desired(), true)
+            .await
+            .unwrap();
+        assert!(matches!(outcome, MinimalUpdateOutcome::Updated(_)));
+        let requests = mock_server.received_requests().await.unwrap();
+        let patch = requests
+            .iter()
+            .find(|r| r.method.as_str() == "PATCH")
+            .unwrap();
+        let body = String::from_utf8_lossy(&patch.body);
+        assert_eq!(body, "description=New+description");
+    }
+
+    #[tokio::test]
+    async fn test_update_issue_minimal_unchanged() {
+        use backlog_issue::models::Issue;
+        use backlog_issue::{MinimalUpdateOutcome, UpdateIssueParamsBuilder};
+
+        let mock_server = MockServer::start().await;
+        let issue_api = IssueApi::new(setup_client(&mock_server).await);
+
+        let json = mock_issue_json("Same", "2024-01-02T00:00:00Z");
+        let original: Issue = serde_json::from_value(json.clone()).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/api/v2/issues/TEST-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&json))
+            .mount(&mock_server)
+            .await;
+
+        let desired = UpdateIssueParamsBuilder::default()
+            .issue_id_or_key("TEST-1".parse::<IssueIdOrKey>().unwrap())
+            .summary("Same")
+            .build()
+            .unwrap();
+        let outcome = issue_api
+            .update_issue_minimal(&original, desired, false)
+            .await
+            .unwrap();
+        assert_eq!(outcome, MinimalUpdateOutcome::Unchanged);
+    }
 }
@@ -1,7 +1,7 @@
 use backlog_api_core::IntoRequest;
 use backlog_api_macros::ToFormParams;
 use backlog_core::{
-    ProjectIdOrKey,
+    Order, ProjectIdOrKey,
     identifier::{ActivityId, ActivityTypeId},
 };
 use serde::Serialize;
@@ -25,7 +25,7 @@ pub struct GetProjectRecentUpdatesParams {
     pub max_id: Option<ActivityId>,
 
     pub count: Option<u32>,
-    pub order: Option<String>,
+    pub order: Option<Order>,
 }
 
 impl GetProjectRecentUpdatesParams {
@@ -1,6 +1,7 @@
 use crate::Project;
 use backlog_api_core::{Error as ApiError, IntoRequest};
 use backlog_api_macros::ToFormParams;
+use backlog_core::Order;
 use derive_builder::Builder;
 use serde::Serialize;
 
@@ -14,7 +15,7 @@ pub type GetRecentlyViewedProjectsResponse = Vec<Project>;
 pub struct GetRecentlyViewedProjectsParams {
     /// Order of results ("asc" or "desc", default: "desc")
     #[builder(default, setter(into, strip_option))]
-    pub order: Option<String>,
+    pub order: Option<Order>,
 
     /// Offset for pagination
     #[builder(default, setter(into, strip_option))]
@@ -43,13 +44,13 @@ mod tests {
     #[test]
     fn test_params_builder() {
         let params = GetRecentlyViewedProjectsParamsBuilder::default()
-            .order("asc")
+            .order(Order::Asc)
             .offset(10u32)
             .count(50u32)
             .build()
             .unwrap();
 
-        assert_eq!(params.order, Some("asc".to_string()));
+        assert_eq!(params.order, Some(Order::Asc));
         assert_eq!(params.offset, Some(10));
         assert_eq!(params.count, Some(50));
     }
@@ -57,7 +58,7 @@ mod tests {
     #[test]
     fn test_params_to_query() {
         let params = GetRecentlyViewedProjectsParamsBuilder::default()
-            .order("desc")
+            .order(Order::Desc)
             .count(25u32)
             .build()
             .unwrap();
@@ -1,7 +1,7 @@
 mod common;
 
 use backlog_core::{
-    ProjectKey,
+    Order, ProjectKey,
     identifier::{ActivityId, ActivityTypeId, Identifier, ProjectId},
 };
 use backlog_project::api::GetProjectRecentUpdatesParams;
@@ -93,7 +93,7 @@ async fn test_get_project_recent_updates_with_filters() {
     let mut params = GetProjectRecentUpdatesParams::new(ProjectKey::from_str("EXAMPLE").unwrap());
     params.activity_type_ids = Some(vec![ActivityTypeId::new(1), ActivityTypeId::new(2)]);
     params.count = Some(50);
-    params.order = Some("desc".to_string());
+    params.order = Some(Order::Desc);
 
     let result = project_api.get_project_recent_updates(params).await;
     if let Err(ref e) = result {
@@ -1,3 +1,4 @@
+use backlog_core::Order;
 use backlog_project::GetRecentlyViewedProjectsParamsBuilder;
 use serde_json::json;
 use wiremock::matchers::{method, path, query_param};
@@ -101,7 +102,7 @@ async fn test_get_recently_viewed_projects_with_order_asc() {
         .await;
 
     let params = GetRecentlyViewedProjectsParamsBuilder::default()
-        .order("asc")
+        .order(Order::Asc)
         .build()
         .unwrap();
     let result = project_api.get_recently_viewed_projects(params).await;
@@ -174,7 +175,7 @@ async fn test_get_recently_viewed_projects_with_all_params() {
         .await;
 
     let params = GetRecentlyViewedProjectsParamsBuilder::default()
-        .order("desc")
+        .order(Order::Desc)
         .offset(100u32)
         .count(20u32)
         .build()
@@ -1,5 +1,6 @@
 use backlog_api_core::IntoRequest;
 use backlog_api_macros::ToFormParams;
+use backlog_core::Order;
 use backlog_core::activity::Activity;
 use backlog_core::identifier::{ActivityId, ActivityTypeId};
 use serde::Serialize;
@@ -18,7 +19,7 @@ pub struct GetSpaceRecentUpdatesParams {
     pub max_id: Option<ActivityId>,
 
     pub count: Option<u32>,
-    pub order: Option<String>,
+    pub order: Option<Order>,
 }
 
 impl IntoRequest for GetSpaceRecentUpdatesParams {
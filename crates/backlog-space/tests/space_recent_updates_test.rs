@@ -1,5 +1,6 @@
 mod common;
 
+use backlog_core::Order;
 use backlog_core::identifier::{ActivityId, ActivityTypeId, Identifier};
 use backlog_space::api::GetSpaceRecentUpdatesParams;
 use common::*;
@@ -88,7 +89,7 @@ async fn test_get_space_recent_updates_with_filters() {
     let params = GetSpaceRecentUpdatesParams {
         activity_type_ids: Some(vec![ActivityTypeId::new(1), ActivityTypeId::new(2)]),
         count: Some(50),
-        order: Some("desc".to_string()),
+        order: Some(Order::Desc),
         ..Default::default()
     };
 
@@ -1,10 +1,15 @@
 use crate::models::TeamResponse;
 use backlog_api_core::{HttpMethod, IntoRequest};
+use backlog_core::Order;
 use serde::{Deserialize, Serialize};
 
 /// Response type for listing teams.
 pub type ListTeamsResponse = Vec<ListTeamResponse>;
 
+/// Sort order for team lists.
+#[deprecated(note = "use `backlog_core::Order`")]
+pub type ListTeamsOrder = backlog_core::Order;
+
 /// Individual team in the list response, which includes displayOrder
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -23,7 +28,7 @@ pub struct ListTeamResponse {
 pub struct ListTeamsParams {
     /// Sort order of the results.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<ListTeamsOrder>,
+    pub order: Option<Order>,
     /// Offset for pagination.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
@@ -32,14 +37,6 @@ pub struct ListTeamsParams {
     pub count: Option<u32>,
 }
 
-/// Sort order for listing teams.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ListTeamsOrder {
-    Asc,
-    Desc,
-}
-
 impl IntoRequest for ListTeamsParams {
     fn method(&self) -> HttpMethod {
         HttpMethod::Get
@@ -74,11 +74,12 @@ impl TeamApi {
     /// # Example
     ///
     /// ```no_run
-    /// use backlog_team::api::{TeamApi, ListTeamsParams, ListTeamsOrder};
+    /// use backlog_core::Order;
+    /// use backlog_team::api::{TeamApi, ListTeamsParams};
     ///
     /// # async fn example(api: TeamApi) -> Result<(), Box<dyn std::error::Error>> {
     /// let params = ListTeamsParams {
-    ///     order: Some(ListTeamsOrder::Asc),
+    ///     order: Some(Order::Asc),
     ///     count: Some(50),
     ///     ..Default::default()
     /// };
@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use backlog_core::{id::TeamId, identifier::Identifier};
 use backlog_team::api::{GetTeamIconParams, GetTeamParams, ListTeamsOrder, ListTeamsParams};
 use pretty_assertions::assert_eq;
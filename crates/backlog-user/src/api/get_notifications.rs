@@ -1,21 +1,12 @@
 use backlog_api_core::IntoRequest;
-use backlog_core::identifier::UserId;
+use backlog_core::{Order, identifier::UserId};
 use serde::Serialize;
 
 use crate::models::Notification;
 
-#[derive(Debug, Clone, Copy, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum NotificationOrder {
-    Asc,
-    Desc,
-}
-
-impl Default for NotificationOrder {
-    fn default() -> Self {
-        Self::Desc
-    }
-}
+/// Sort order for notification lists.
+#[deprecated(note = "use `backlog_core::Order`")]
+pub type NotificationOrder = backlog_core::Order;
 
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -27,7 +18,7 @@ pub struct GetNotificationsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<NotificationOrder>,
+    pub order: Option<Order>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sender_id: Option<UserId>,
 }
@@ -52,7 +43,7 @@ impl GetNotificationsParams {
         self
     }
 
-    pub fn with_order(mut self, order: NotificationOrder) -> Self {
+    pub fn with_order(mut self, order: Order) -> Self {
         self.order = Some(order);
         self
     }
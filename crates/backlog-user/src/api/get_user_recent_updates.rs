@@ -1,5 +1,6 @@
 use backlog_api_core::IntoRequest;
 use backlog_api_macros::ToFormParams;
+use backlog_core::Order;
 use backlog_core::activity::Activity;
 use backlog_core::identifier::{ActivityTypeId, UserId};
 use serde::Serialize;
@@ -44,7 +45,7 @@ pub struct GetUserRecentUpdatesParams {
     pub count: Option<u32>,
 
     /// Sort order (asc or desc)
-    pub order: Option<String>,
+    pub order: Option<Order>,
 }
 
 impl IntoRequest for GetUserRecentUpdatesParams {
@@ -1,38 +1,22 @@
 use backlog_api_core::IntoRequest;
 use backlog_api_macros::ToFormParams;
-use backlog_core::identifier::UserId;
+use backlog_core::{Order, identifier::UserId};
 use backlog_domain_models::Star;
 use serde::Serialize;
-use std::fmt;
-
-/// Order for sorting stars
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum StarOrder {
-    /// Ascending order (oldest first)
-    Asc,
-    /// Descending order (newest first)
-    Desc,
-}
 
-impl fmt::Display for StarOrder {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            StarOrder::Asc => write!(f, "asc"),
-            StarOrder::Desc => write!(f, "desc"),
-        }
-    }
-}
+/// Sort order for star lists.
+#[deprecated(note = "use `backlog_core::Order`")]
+pub type StarOrder = backlog_core::Order;
 
 /// Parameters for getting user stars.
 ///
 /// # Example
 /// ```no_run
-/// # use backlog_user::api::{GetUserStarsParams, StarOrder};
-/// # use backlog_core::identifier::UserId;
+/// # use backlog_user::api::GetUserStarsParams;
+/// # use backlog_core::{Order, identifier::UserId};
 /// let params = GetUserStarsParams::new(12345u32)
 ///     .with_count(50)
-///     .with_order(StarOrder::Desc);
+///     .with_order(Order::Desc);
 /// ```
 #[derive(Debug, Clone, Serialize, ToFormParams)]
 pub struct GetUserStarsParams {
@@ -57,7 +41,7 @@ pub struct GetUserStarsParams {
 
     /// Sort order
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<StarOrder>,
+    pub order: Option<Order>,
 }
 
 impl GetUserStarsParams {
@@ -91,7 +75,7 @@ impl GetUserStarsParams {
     }
 
     /// Sets the sort order.
-    pub fn with_order(mut self, order: StarOrder) -> Self {
+    pub fn with_order(mut self, order: Order) -> Self {
         self.order = Some(order);
         self
     }
@@ -133,12 +117,12 @@ mod tests {
             .with_min_id(100)
             .with_max_id(200)
             .with_count(50)
-            .with_order(StarOrder::Asc);
+            .with_order(Order::Asc);
 
         assert_eq!(params.min_id, Some(100));
         assert_eq!(params.max_id, Some(200));
         assert_eq!(params.count, Some(50));
-        assert!(matches!(params.order, Some(StarOrder::Asc)));
+        assert!(matches!(params.order, Some(Order::Asc)));
     }
 
     #[test]
@@ -154,21 +138,12 @@ mod tests {
         assert_eq!(params3.count, Some(50));
     }
 
-    #[test]
-    fn test_star_order_serialization() {
-        let asc_json = serde_json::to_string(&StarOrder::Asc).unwrap();
-        assert_eq!(asc_json, "\"asc\"");
-
-        let desc_json = serde_json::to_string(&StarOrder::Desc).unwrap();
-        assert_eq!(desc_json, "\"desc\"");
-    }
-
     #[test]
     fn test_params_to_form() {
         let params = GetUserStarsParams::new(12345u32)
             .with_min_id(100)
             .with_count(25)
-            .with_order(StarOrder::Desc);
+            .with_order(Order::Desc);
 
         let _form = params.to_form();
         // ToFormParams macro generates the correct form structure
@@ -3,29 +3,18 @@ use serde::{Deserialize, Serialize};
 use backlog_api_core::IntoRequest;
 use backlog_core::identifier::{IssueId, UserId};
 
-/// Sort order for watching list
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Order {
-    Asc,
-    Desc,
-}
-
-impl std::fmt::Display for Order {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Order::Asc => write!(f, "asc"),
-            Order::Desc => write!(f, "desc"),
-        }
-    }
-}
+/// Sort order for watching lists.
+#[deprecated(note = "use `backlog_core::Order`")]
+pub type Order = backlog_core::Order;
 
 /// Sort attribute for watching list
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum WatchingSort {
     Created,
     Updated,
+    #[cfg_attr(feature = "clap", value(name = "issueUpdated"))]
     IssueUpdated,
 }
 
@@ -42,7 +31,7 @@ impl std::fmt::Display for WatchingSort {
 /// Parameters for getting watching list
 #[derive(Debug, Clone, Default)]
 pub struct GetWatchingListParams {
-    pub order: Option<Order>,
+    pub order: Option<backlog_core::Order>,
     pub sort: Option<WatchingSort>,
     pub count: Option<u8>,
     pub offset: Option<u64>,
@@ -96,7 +85,7 @@ impl From<&GetWatchingListParams> for Vec<(String, String)> {
 /// Builder for GetWatchingListParams
 #[derive(Debug, Default)]
 pub struct GetWatchingListParamsBuilder {
-    order: Option<Order>,
+    order: Option<backlog_core::Order>,
     sort: Option<WatchingSort>,
     count: Option<u8>,
     offset: Option<u64>,
@@ -105,7 +94,7 @@ pub struct GetWatchingListParamsBuilder {
 }
 
 impl GetWatchingListParamsBuilder {
-    pub fn order(mut self, order: Order) -> Self {
+    pub fn order(mut self, order: backlog_core::Order) -> Self {
         self.order = Some(order);
         self
     }
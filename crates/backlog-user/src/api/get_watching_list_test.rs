@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(deprecated)]
 mod get_watching_list_tests {
 
     use backlog_api_core::IntoRequest;
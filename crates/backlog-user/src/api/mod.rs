@@ -18,6 +18,7 @@ mod user_api;
 pub use user_api::UserApi;
 
 pub use get_notification_count::{GetNotificationCountParams, GetNotificationCountResponse};
+#[allow(deprecated)]
 pub use get_notifications::{GetNotificationsParams, GetNotificationsResponse, NotificationOrder};
 pub use get_own_user::{GetOwnUserParams, GetOwnUserResponse};
 pub use get_user::{GetUserParams, GetUserResponse};
@@ -25,8 +26,10 @@ pub use get_user_icon::{GetUserIconParams, GetUserIconResponse};
 pub use get_user_list::{GetUserListParams, GetUserListResponse};
 pub use get_user_recent_updates::{GetUserRecentUpdatesParams, GetUserRecentUpdatesResponse};
 pub use get_user_star_count::{GetUserStarCountParams, GetUserStarCountResponse, StarCount};
+#[allow(deprecated)]
 pub use get_user_stars::{GetUserStarsParams, GetUserStarsResponse, StarOrder};
 pub use get_watching_count::GetWatchingCountParams;
+#[allow(deprecated)]
 pub use get_watching_list::{
     GetWatchingListParams, GetWatchingListParamsBuilder, GetWatchingListRequest, Order,
     WatchingSort,
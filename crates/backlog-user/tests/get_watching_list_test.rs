@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 mod common;
 
 use backlog_core::identifier::{Identifier, IssueId, UserId};
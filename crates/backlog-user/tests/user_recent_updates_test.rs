@@ -4,6 +4,7 @@ mod common;
 mod user_recent_updates_tests {
     use super::common::setup_user_api;
     use backlog_api_core::IntoRequest;
+    use backlog_core::Order;
     use backlog_core::identifier::{ActivityTypeId, Identifier, UserId};
     use backlog_user::GetUserRecentUpdatesParams;
     use serde_json::json;
@@ -107,7 +108,7 @@ mod user_recent_updates_tests {
             min_id: Some(100),
             max_id: Some(200),
             count: Some(50),
-            order: Some("asc".to_string()),
+            order: Some(Order::Asc),
         };
 
         let result = api.get_user_recent_updates(params).await;
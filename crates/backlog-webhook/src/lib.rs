@@ -1,5 +1,6 @@
 pub mod api;
 pub mod models;
+pub mod receiver;
 
 pub use api::*;
 pub use models::*;
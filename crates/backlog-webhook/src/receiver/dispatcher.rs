@@ -0,0 +1,146 @@
+use super::payload::WebhookPayload;
+use backlog_core::activity::ActivityType;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+type HandlerFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+type Handler = Arc<dyn Fn(Arc<WebhookPayload>) -> HandlerFuture + Send + Sync>;
+type Predicate = Arc<dyn Fn(&WebhookPayload) -> bool + Send + Sync>;
+
+/// Routes incoming webhook payloads to registered handlers.
+///
+/// Handlers run in registration order; every handler whose filter matches is invoked.
+///
+/// ```no_run
+/// use backlog_core::activity::ActivityType;
+/// use backlog_webhook::receiver::WebhookDispatcher;
+///
+/// let dispatcher = WebhookDispatcher::new()
+///     .on(ActivityType::IssueCreated, |payload| async move {
+///         println!("New issue in {}", payload.project.project_key);
+///     })
+///     .on_pull_request_merged(|payload| async move {
+///         println!("PR merged by {}", payload.created_user.name);
+///     });
+/// ```
+#[derive(Clone, Default)]
+pub struct WebhookDispatcher {
+    routes: Vec<(Predicate, Handler)>,
+}
+
+impl WebhookDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a handler for payloads matching `predicate`.
+    pub fn on_matching<P, F, Fut>(mut self, predicate: P, handler: F) -> Self
+    where
+        P: Fn(&WebhookPayload) -> bool + Send + Sync + 'static,
+        F: Fn(Arc<WebhookPayload>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler: Handler = Arc::new(move |payload| Box::pin(handler(payload)));
+        self.routes.push((Arc::new(predicate), handler));
+        self
+    }
+
+    /// Registers a handler for a single activity type.
+    pub fn on<F, Fut>(self, activity_type: ActivityType, handler: F) -> Self
+    where
+        F: Fn(Arc<WebhookPayload>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on_matching(
+            move |payload| payload.activity_type() == Some(activity_type),
+            handler,
+        )
+    }
+
+    /// Registers a handler invoked for every payload.
+    pub fn on_any<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Arc<WebhookPayload>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on_matching(|_| true, handler)
+    }
+
+    /// Registers a handler for newly created issues.
+    pub fn on_issue_created<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Arc<WebhookPayload>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on(ActivityType::IssueCreated, handler)
+    }
+
+    /// Registers a handler for issue updates.
+    pub fn on_issue_updated<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Arc<WebhookPayload>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on(ActivityType::IssueUpdated, handler)
+    }
+
+    /// Registers a handler for comments added to issues.
+    pub fn on_issue_commented<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Arc<WebhookPayload>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on(ActivityType::IssueCommented, handler)
+    }
+
+    /// Registers a handler for newly created pull requests.
+    pub fn on_pull_request_created<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Arc<WebhookPayload>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on(ActivityType::PullRequestAdded, handler)
+    }
+
+    /// Registers a handler for pull requests whose status changed to merged.
+    pub fn on_pull_request_merged<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Arc<WebhookPayload>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on_matching(WebhookPayload::is_pull_request_merged, handler)
+    }
+
+    /// Runs every matching handler and returns how many were invoked.
+    pub async fn dispatch(&self, payload: WebhookPayload) -> usize {
+        let payload = Arc::new(payload);
+        let mut invoked = 0;
+        for (predicate, handler) in &self.routes {
+            if predicate(&payload) {
+                handler(payload.clone()).await;
+                invoked += 1;
+            }
+        }
+        invoked
+    }
+
+    /// Parses a raw webhook request body and dispatches it.
+    pub async fn dispatch_slice(&self, body: &[u8]) -> serde_json::Result<usize> {
+        let payload = WebhookPayload::from_slice(body)?;
+        Ok(self.dispatch(payload).await)
+    }
+
+    /// Returns true if no handler has been registered.
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+}
+
+impl std::fmt::Debug for WebhookDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookDispatcher")
+            .field("routes", &self.routes.len())
+            .finish()
+    }
+}
@@ -0,0 +1,12 @@
+//! Receiving side of Backlog webhooks: payload parsing, handler dispatch and
+//! an optional HTTP listener (`server` feature).
+
+mod dispatcher;
+mod payload;
+#[cfg(feature = "server")]
+mod server;
+
+pub use dispatcher::WebhookDispatcher;
+pub use payload::WebhookPayload;
+#[cfg(feature = "server")]
+pub use server::WebhookServer;
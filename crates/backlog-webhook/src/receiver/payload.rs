@@ -0,0 +1,58 @@
+use backlog_core::{
+    activity::{Activity, ActivityType, Content},
+    identifier::ActivityTypeId,
+};
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+
+/// Body of a webhook request sent by Backlog.
+///
+/// Backlog sends the activity that triggered the webhook, with no other fields,
+/// so the payload wraps an [`Activity`] and derefs to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WebhookPayload {
+    pub activity: Activity,
+}
+
+impl WebhookPayload {
+    /// Parses a raw webhook request body.
+    pub fn from_slice(body: &[u8]) -> serde_json::Result<Self> {
+        serde_json::from_slice(body)
+    }
+
+    /// Returns the activity type ID this payload was sent for.
+    pub fn activity_type_id(&self) -> ActivityTypeId {
+        ActivityTypeId::new(self.type_id.max(0) as u32)
+    }
+
+    /// Returns true if this payload reports a pull request being merged.
+    pub fn is_pull_request_merged(&self) -> bool {
+        let Content::PullRequest(pull_request) = &self.content else {
+            return false;
+        };
+        self.activity_type() == Some(ActivityType::PullRequestUpdated)
+            && pull_request.changes.iter().any(|change| {
+                change.field == "status"
+                    && (change.new_value == MERGED_STATUS_ID
+                        || change.new_value.eq_ignore_ascii_case("merged"))
+            })
+    }
+}
+
+impl Deref for WebhookPayload {
+    type Target = Activity;
+
+    fn deref(&self) -> &Activity {
+        &self.activity
+    }
+}
+
+impl From<Activity> for WebhookPayload {
+    fn from(activity: Activity) -> Self {
+        Self { activity }
+    }
+}
+
+/// Pull request status ID for "Merged".
+const MERGED_STATUS_ID: &str = "3";
@@ -0,0 +1,87 @@
+use super::dispatcher::WebhookDispatcher;
+use super::payload::WebhookPayload;
+use axum::{
+    Router,
+    body::Bytes,
+    extract::{DefaultBodyLimit, State},
+    http::StatusCode,
+    routing::post,
+};
+use std::future::Future;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+
+/// Maximum accepted request body size. Backlog payloads are well below this.
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Small HTTP listener that receives Backlog webhooks and hands them to a [`WebhookDispatcher`].
+///
+/// The listener answers `200 OK` as soon as the body has been parsed and runs the
+/// handlers in the background, so slow handlers do not cause Backlog to time out.
+/// Bodies that cannot be parsed are answered with `400 Bad Request`.
+#[derive(Debug, Clone)]
+pub struct WebhookServer {
+    dispatcher: Arc<WebhookDispatcher>,
+    path: String,
+}
+
+impl WebhookServer {
+    /// Creates a server that accepts webhooks on `/`.
+    pub fn new(dispatcher: WebhookDispatcher) -> Self {
+        Self {
+            dispatcher: Arc::new(dispatcher),
+            path: "/".to_string(),
+        }
+    }
+
+    /// Sets the request path webhooks are posted to.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Returns the router, for embedding into an existing axum application.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route(&self.path, post(receive))
+            .layer(DefaultBodyLimit::max(MAX_BODY_SIZE))
+            .with_state(self.dispatcher.clone())
+    }
+
+    /// Serves webhooks on `listener` until the process exits.
+    pub async fn serve(self, listener: TcpListener) -> std::io::Result<()> {
+        axum::serve(listener, self.router()).await
+    }
+
+    /// Serves webhooks on `listener` until `shutdown` completes.
+    pub async fn serve_with_shutdown<F>(
+        self,
+        listener: TcpListener,
+        shutdown: F,
+    ) -> std::io::Result<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        axum::serve(listener, self.router())
+            .with_graceful_shutdown(shutdown)
+            .await
+    }
+}
+
+async fn receive(
+    State(dispatcher): State<Arc<WebhookDispatcher>>,
+    body: Bytes,
+) -> (StatusCode, String) {
+    match WebhookPayload::from_slice(&body) {
+        Ok(payload) => {
+            tokio::spawn(async move {
+                dispatcher.dispatch(payload).await;
+            });
+            (StatusCode::OK, String::new())
+        }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            format!("Invalid webhook payload: {e}"),
+        ),
+    }
+}
@@ -0,0 +1,70 @@
+use serde_json::json;
+
+/// Recorded webhook body for an issue created activity.
+#[allow(dead_code)]
+pub fn issue_created_payload() -> serde_json::Value {
+    json!({
+        "created": "2024-03-01T09:00:00Z",
+        "project": {
+            "id": 92,
+            "projectKey": "WEB",
+            "name": "Web",
+            "chartEnabled": true,
+            "subtaskingEnabled": true,
+            "projectLeaderCanEditProjectLeader": false,
+            "textFormattingRule": "markdown",
+            "archived": false
+        },
+        "id": 3153,
+        "type": 1,
+        "content": {
+            "id": 4809,
+            "key_id": 12,
+            "summary": "Login fails on Safari",
+            "description": "Steps to reproduce...",
+            "attachments": [],
+            "shared_files": []
+        },
+        "notifications": [],
+        "createdUser": {
+            "id": 1,
+            "userId": "admin",
+            "name": "admin",
+            "roleType": 1,
+            "lang": "ja",
+            "mailAddress": "test@example.com"
+        }
+    })
+}
+
+/// Recorded webhook body for a pull request updated activity whose status changed to merged.
+#[allow(dead_code)]
+pub fn pull_request_merged_payload() -> serde_json::Value {
+    json!({
+        "created": "2024-03-02T10:00:00Z",
+        "project": {"id": 92, "projectKey": "WEB", "name": "Web"},
+        "id": 3154,
+        "type": 19,
+        "content": {
+            "id": 2,
+            "number": 7,
+            "summary": "Add login form",
+            "description": "",
+            "comment": {"id": 36, "content": ""},
+            "changes": [
+                {"field": "status", "new_value": "3", "old_value": "1", "type": "standard"}
+            ],
+            "repository": {"id": 5, "name": "app", "description": ""},
+            "issue": {"id": 4809, "key_id": 12, "summary": "Login fails on Safari", "description": ""}
+        },
+        "notifications": [],
+        "createdUser": {
+            "id": 2,
+            "userId": "dev",
+            "name": "Developer",
+            "roleType": 2,
+            "lang": "en",
+            "mailAddress": "dev@example.com"
+        }
+    })
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "server")]
+
+mod fixtures;
+
+use backlog_core::activity::ActivityType;
+use backlog_webhook::receiver::{WebhookDispatcher, WebhookServer};
+use fixtures::*;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::{mpsc, oneshot};
+
+async fn start_server(dispatcher: WebhookDispatcher) -> (String, oneshot::Sender<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hooks/backlog", listener.local_addr().unwrap());
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+
+    let server = WebhookServer::new(dispatcher).path("/hooks/backlog");
+    tokio::spawn(server.serve_with_shutdown(listener, async {
+        shutdown_rx.await.ok();
+    }));
+
+    (url, shutdown_tx)
+}
+
+#[tokio::test]
+async fn test_server_dispatches_posted_payloads() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let dispatcher = WebhookDispatcher::new().on_any(move |payload| {
+        let tx = tx.clone();
+        async move {
+            tx.send(payload.activity_type()).unwrap();
+        }
+    });
+    let (url, shutdown) = start_server(dispatcher).await;
+
+    let client = reqwest::Client::new();
+    for body in [issue_created_payload(), pull_request_merged_payload()] {
+        let response = client.post(&url).json(&body).send().await.unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    let mut received = Vec::new();
+    for _ in 0..2 {
+        let activity_type = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        received.push(activity_type);
+    }
+    received.sort_by_key(|t| t.map(|t| t as u32));
+    assert_eq!(
+        received,
+        vec![
+            Some(ActivityType::IssueCreated),
+            Some(ActivityType::PullRequestUpdated)
+        ]
+    );
+
+    shutdown.send(()).unwrap();
+}
+
+#[tokio::test]
+async fn test_server_rejects_invalid_payload() {
+    let (url, shutdown) = start_server(WebhookDispatcher::new()).await;
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .body("{\"id\": 1}")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+
+    let response = client.get(&url).send().await.unwrap();
+    assert_eq!(response.status(), 405);
+
+    shutdown.send(()).unwrap();
+}
@@ -0,0 +1,116 @@
+mod fixtures;
+
+use backlog_core::activity::{ActivityType, Content};
+use backlog_core::identifier::{ActivityTypeId, Identifier};
+use backlog_webhook::receiver::{WebhookDispatcher, WebhookPayload};
+use fixtures::*;
+use std::sync::{Arc, Mutex};
+
+fn parse(value: serde_json::Value) -> WebhookPayload {
+    WebhookPayload::from_slice(&serde_json::to_vec(&value).unwrap()).unwrap()
+}
+
+#[test]
+fn test_parse_issue_created_payload() {
+    let payload = parse(issue_created_payload());
+
+    assert_eq!(payload.id.value(), 3153);
+    assert_eq!(payload.project.project_key, "WEB");
+    assert_eq!(payload.activity_type_id(), ActivityTypeId::new(1));
+    assert_eq!(payload.activity_type(), Some(ActivityType::IssueCreated));
+    match &payload.content {
+        Content::IssueCreated(issue) => {
+            assert_eq!(issue.key_id, 12);
+            assert_eq!(issue.summary, "Login fails on Safari");
+        }
+        other => panic!("Expected IssueCreated content, got {other:?}"),
+    }
+    assert!(!payload.is_pull_request_merged());
+}
+
+#[test]
+fn test_parse_pull_request_merged_payload() {
+    let payload = parse(pull_request_merged_payload());
+
+    assert_eq!(
+        payload.activity_type(),
+        Some(ActivityType::PullRequestUpdated)
+    );
+    assert!(payload.is_pull_request_merged());
+    match &payload.content {
+        Content::PullRequest(pr) => {
+            assert_eq!(pr.number.value(), 7);
+            assert_eq!(pr.repository.name, "app");
+        }
+        other => panic!("Expected PullRequest content, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_invalid_payload() {
+    assert!(WebhookPayload::from_slice(b"not json").is_err());
+}
+
+#[test]
+fn test_parse_payload_with_unexpected_content_shape() {
+    let mut body = issue_created_payload();
+    body["type"] = serde_json::json!(12);
+    let payload = WebhookPayload::from_slice(&serde_json::to_vec(&body).unwrap()).unwrap();
+    assert!(matches!(payload.content, Content::Unknown(_)));
+}
+
+#[tokio::test]
+async fn test_dispatcher_routes_by_activity_type() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let created = calls.clone();
+    let merged = calls.clone();
+    let any = calls.clone();
+    let dispatcher = WebhookDispatcher::new()
+        .on_issue_created(move |payload| {
+            let created = created.clone();
+            async move {
+                created
+                    .lock()
+                    .unwrap()
+                    .push(format!("created:{}", payload.id));
+            }
+        })
+        .on_pull_request_merged(move |payload| {
+            let merged = merged.clone();
+            async move {
+                merged
+                    .lock()
+                    .unwrap()
+                    .push(format!("merged:{}", payload.id));
+            }
+        })
+        .on_any(move |payload| {
+            let any = any.clone();
+            async move {
+                any.lock().unwrap().push(format!("any:{}", payload.id));
+            }
+        });
+
+    assert_eq!(dispatcher.dispatch(parse(issue_created_payload())).await, 2);
+    assert_eq!(
+        dispatcher
+            .dispatch_slice(&serde_json::to_vec(&pull_request_merged_payload()).unwrap())
+            .await
+            .unwrap(),
+        2
+    );
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec!["created:3153", "any:3153", "merged:3154", "any:3154"]
+    );
+}
+
+#[tokio::test]
+async fn test_dispatcher_without_matching_handler() {
+    let dispatcher = WebhookDispatcher::new().on(ActivityType::WikiUpdated, |_| async {});
+
+    assert!(!dispatcher.is_empty());
+    assert_eq!(dispatcher.dispatch(parse(issue_created_payload())).await, 0);
+}
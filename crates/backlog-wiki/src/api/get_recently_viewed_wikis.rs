@@ -1,6 +1,7 @@
 use crate::models::Wiki;
 use backlog_api_core::{Error as ApiError, IntoRequest};
 use backlog_api_macros::ToFormParams;
+use backlog_core::Order;
 use derive_builder::Builder;
 use serde::Serialize;
 
@@ -14,7 +15,7 @@ pub type GetRecentlyViewedWikisResponse = Vec<Wiki>;
 pub struct GetRecentlyViewedWikisParams {
     /// Sort order of the results. Either "asc" or "desc". Default is "desc".
     #[builder(default, setter(into, strip_option))]
-    pub order: Option<String>,
+    pub order: Option<Order>,
 
     /// Offset for pagination.
     #[builder(default, setter(into, strip_option))]
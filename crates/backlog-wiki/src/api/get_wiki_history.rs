@@ -1,6 +1,6 @@
-use crate::models::{HistoryOrder, WikiHistory};
+use crate::models::WikiHistory;
 use backlog_api_core::IntoRequest;
-use backlog_core::identifier::WikiId;
+use backlog_core::{Order, identifier::WikiId};
 use serde::Serialize;
 
 /// Response type for getting wiki page history.
@@ -15,7 +15,7 @@ pub struct GetWikiHistoryParams {
     pub min_id: Option<u32>,
     pub max_id: Option<u32>,
     pub count: Option<u32>,
-    pub order: Option<HistoryOrder>,
+    pub order: Option<Order>,
 }
 
 impl GetWikiHistoryParams {
@@ -49,7 +49,7 @@ impl GetWikiHistoryParams {
     }
 
     /// Set the sort order for history entries.
-    pub fn order(mut self, order: HistoryOrder) -> Self {
+    pub fn order(mut self, order: Order) -> Self {
         self.order = Some(order);
         self
     }
@@ -71,17 +71,14 @@ impl IntoRequest for GetWikiHistoryParams {
             #[serde(skip_serializing_if = "Option::is_none")]
             count: Option<u32>,
             #[serde(skip_serializing_if = "Option::is_none")]
-            order: Option<String>,
+            order: Option<Order>,
         }
 
         Query {
             min_id: self.min_id,
             max_id: self.max_id,
             count: self.count,
-            order: self.order.as_ref().map(|o| match o {
-                HistoryOrder::Asc => "asc".to_string(),
-                HistoryOrder::Desc => "desc".to_string(),
-            }),
+            order: self.order,
         }
     }
 }
@@ -108,13 +105,13 @@ mod tests {
             .min_id(100)
             .max_id(200)
             .count(50)
-            .order(HistoryOrder::Asc);
+            .order(Order::Asc);
 
         assert_eq!(params.wiki_id.value(), 123);
         assert_eq!(params.min_id, Some(100));
         assert_eq!(params.max_id, Some(200));
         assert_eq!(params.count, Some(50));
-        assert_eq!(params.order, Some(HistoryOrder::Asc));
+        assert_eq!(params.order, Some(Order::Asc));
     }
 
     #[test]
@@ -139,7 +136,7 @@ mod tests {
             .min_id(100)
             .max_id(200)
             .count(50)
-            .order(HistoryOrder::Asc);
+            .order(Order::Asc);
 
         let query = params.to_query();
         let json = serde_json::to_string(&query).unwrap();
@@ -152,7 +149,7 @@ mod tests {
 
     #[test]
     fn test_get_wiki_history_params_to_query_desc_order() {
-        let params = GetWikiHistoryParams::new(WikiId::new(123)).order(HistoryOrder::Desc);
+        let params = GetWikiHistoryParams::new(WikiId::new(123)).order(Order::Desc);
 
         let query = params.to_query();
         let json = serde_json::to_string(&query).unwrap();
@@ -104,20 +104,8 @@ pub struct WikiHistory {
 }
 
 /// Represents the sort order for wiki history entries.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
-pub enum HistoryOrder {
-    #[serde(rename = "asc")]
-    Asc,
-    #[serde(rename = "desc")]
-    Desc,
-}
-
-impl Default for HistoryOrder {
-    fn default() -> Self {
-        Self::Desc
-    }
-}
+#[deprecated(note = "use `backlog_core::Order`")]
+pub type HistoryOrder = backlog_core::Order;
 
 #[cfg(test)]
 mod tests {
@@ -179,28 +167,4 @@ mod tests {
         assert!(json.contains("\"version\":2"));
         assert!(json.contains("\"name\":\"Test Page\""));
     }
-
-    #[test]
-    fn test_history_order_default() {
-        let order = HistoryOrder::default();
-        assert_eq!(order, HistoryOrder::Desc);
-    }
-
-    #[test]
-    fn test_history_order_serialization() {
-        let asc = HistoryOrder::Asc;
-        let desc = HistoryOrder::Desc;
-
-        assert_eq!(serde_json::to_string(&asc).unwrap(), "\"asc\"");
-        assert_eq!(serde_json::to_string(&desc).unwrap(), "\"desc\"");
-    }
-
-    #[test]
-    fn test_history_order_deserialization() {
-        let asc: HistoryOrder = serde_json::from_str("\"asc\"").unwrap();
-        let desc: HistoryOrder = serde_json::from_str("\"desc\"").unwrap();
-
-        assert_eq!(asc, HistoryOrder::Asc);
-        assert_eq!(desc, HistoryOrder::Desc);
-    }
 }
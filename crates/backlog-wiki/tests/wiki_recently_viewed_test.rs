@@ -1,3 +1,4 @@
+use backlog_core::Order;
 use backlog_wiki::GetRecentlyViewedWikisParamsBuilder;
 use serde_json::json;
 use wiremock::matchers::{method, path, query_param};
@@ -124,7 +125,7 @@ async fn test_get_recently_viewed_wikis_with_order_asc() {
         .await;
 
     let params = GetRecentlyViewedWikisParamsBuilder::default()
-        .order("asc")
+        .order(Order::Asc)
         .build()
         .unwrap();
     let result = wiki_api.get_recently_viewed_wikis(params).await;
@@ -205,7 +206,7 @@ async fn test_get_recently_viewed_wikis_with_all_params() {
         .await;
 
     let params = GetRecentlyViewedWikisParamsBuilder::default()
-        .order("desc")
+        .order(Order::Desc)
         .offset(100u32)
         .count(20u32)
         .build()